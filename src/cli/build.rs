@@ -12,12 +12,33 @@
 //!
 //! Phase control: --clean-task/--no-clean-task, --fetch-task/--no-fetch-task,
 //! --build-task/--no-build-task
-//! Git options: --pull/--no-pull, --revert-ts/--no-revert-ts
+//! Explicit phases: --phases clean,fetch,build (only the listed phases run)
+//! Git options: --pull/--no-pull, --remote NAME, --revert-ts/--no-revert-ts
+//!              --fetch-parallelism N (concurrent clones/pulls, submodule --jobs)
+//! Archives: --skip-existing (reuse cached archive + extracted dir if stamp matches)
+//! Diagnostics: --dump-cmake-args TASK (print configure command, run nothing)
+//!              --install-message MODE (CMAKE_INSTALL_MESSAGE for this build only)
+//! Install: --install-only-changed (skip when install_manifest.txt copies are current)
+//! Selection: --only GLOB / --skip GLOB (repeatable, matched against task names;
+//!            --only matching nothing is an error)
+//! Failures: --keep-going (run remaining tasks, list every failed task at the end)
+//!           --fail-fast=false (parallel groups report every failed child)
+//! After build: --link-into DIR / --copy-into DIR (deploy install/bin)
+//!              --report junit:FILE (per-task results as JUnit XML)
+//! With --dry:  --emit-script FILE (commands as sh, or PowerShell for .ps1)
+//! Preview:     --plan (implies --dry, prints every operation as a tree per task)
+//! First clone: asks before creating the super repo; --assume-yes (-y) skips it
 //! ```
 
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+
 use clap::ArgAction;
 use clap::Args;
 
+use crate::config::types::CmakeInstallMessage;
+use crate::task::{ArchiveMode, CleanFlags, FailureMode, InstallMode, Phase, PhaseControl};
+
 /// Arguments for the `build` command.
 #[derive(Debug, Clone, Default, Args)]
 pub struct BuildArgs {
@@ -45,6 +66,23 @@ pub struct BuildArgs {
     #[command(flatten)]
     pub build_phase: BuildPhaseArgs,
 
+    /// Runs exactly the given phases (comma-separated: clean, fetch, build).
+    /// Overrides the individual phase toggles.
+    #[arg(
+        long = "phases",
+        value_name = "PHASES",
+        value_delimiter = ',',
+        conflicts_with_all = [
+            "clean_task",
+            "no_clean_task",
+            "fetch_task",
+            "no_fetch_task",
+            "build_task",
+            "no_build_task",
+        ]
+    )]
+    pub phases: Vec<Phase>,
+
     /// Pull behavior toggles.
     #[command(flatten)]
     pub pull_behavior: PullArgs,
@@ -53,15 +91,76 @@ pub struct BuildArgs {
     #[command(flatten)]
     pub revert_ts_behavior: RevertTsArgs,
 
+    /// Confirmation prompts.
+    #[command(flatten)]
+    pub prompt: PromptArgs,
+
     /// When --reextract is given, directories controlled by git will be
     /// deleted even if they contain uncommitted changes.
     #[arg(long = "ignore-uncommitted-changes")]
     pub ignore_uncommitted: bool,
 
+    /// Fetches up to N tasks of a parallel group at once and passes
+    /// `--jobs N` to `git submodule update`. Default: 1 (sequential).
+    #[arg(long = "fetch-parallelism", value_name = "N")]
+    pub fetch_parallelism: Option<NonZeroUsize>,
+
     /// Don't terminate msbuild.exe instances after building.
     #[arg(long = "keep-msbuild")]
     pub keep_msbuild: bool,
 
+    /// Prints the cmake configure command line for the given task and exits
+    /// without running anything.
+    #[arg(long = "dump-cmake-args", value_name = "TASK")]
+    pub dump_cmake_args: Option<String>,
+
+    /// Install step options.
+    #[command(flatten)]
+    pub install: InstallArgs,
+
+    /// Archive download options.
+    #[command(flatten)]
+    pub archive: ArchiveArgs,
+
+    /// Task name filters.
+    #[command(flatten)]
+    pub filter: TaskFilterArgs,
+
+    /// Failure handling.
+    #[command(flatten)]
+    pub failure: FailureArgs,
+
+    /// After a successful build, symlinks every file of `paths.install_bin`
+    /// into this MO2 instance (copies where symlinks are not permitted).
+    #[arg(long = "link-into", value_name = "DIR", conflicts_with = "copy_into")]
+    pub link_into: Option<PathBuf>,
+
+    /// After a successful build, copies every file of `paths.install_bin`
+    /// into this MO2 instance.
+    #[arg(long = "copy-into", value_name = "DIR", conflicts_with = "link_into")]
+    pub copy_into: Option<PathBuf>,
+
+    /// Prints a profile of where build time went once the build ends.
+    #[arg(long = "measure")]
+    pub measure: bool,
+
+    /// Also writes the build profile as JSON to this file (implies --measure).
+    #[arg(long = "measure-json", value_name = "FILE")]
+    pub measure_json: Option<PathBuf>,
+
+    /// Writes per-task results to a file, e.g. `junit:results.xml`.
+    #[arg(long = "report", value_name = "FORMAT:FILE", value_parser = parse_report_target)]
+    pub report: Option<ReportTarget>,
+
+    /// With --dry, writes every command the build would run to this script
+    /// (`PowerShell` for `.ps1`, `sh` otherwise).
+    #[arg(long = "emit-script", value_name = "FILE")]
+    pub emit_script: Option<PathBuf>,
+
+    /// Build preview options.
+    #[command(flatten)]
+    pub preview: PreviewArgs,
+
     /// Tasks to run. Specify 'super' to only build modorganizer projects.
     /// Globs like 'installer_*' are supported.
     #[arg(value_name = "TASK")]
@@ -81,6 +180,17 @@ pub struct CleanDownloadArgs {
     pub reextract: bool,
 }
 
+impl CleanDownloadArgs {
+    /// Returns the clean actions selected by these flags.
+    #[must_use]
+    pub fn clean_flags(&self) -> CleanFlags {
+        let mut flags = CleanFlags::empty();
+        flags.set(CleanFlags::REDOWNLOAD, self.redownload);
+        flags.set(CleanFlags::REEXTRACT, self.reextract);
+        flags
+    }
+}
+
 /// Clean build actions.
 #[derive(Debug, Clone, Default, Args)]
 pub struct CleanBuildArgs {
@@ -95,6 +205,17 @@ pub struct CleanBuildArgs {
     pub rebuild: bool,
 }
 
+impl CleanBuildArgs {
+    /// Returns the clean actions selected by these flags.
+    #[must_use]
+    pub fn clean_flags(&self) -> CleanFlags {
+        let mut flags = CleanFlags::empty();
+        flags.set(CleanFlags::RECONFIGURE, self.reconfigure);
+        flags.set(CleanFlags::REBUILD, self.rebuild);
+        flags
+    }
+}
+
 /// Full clean action.
 #[derive(Debug, Clone, Default, Args)]
 pub struct CleanFullArgs {
@@ -140,6 +261,163 @@ pub struct BuildPhaseArgs {
     pub no_build_task: bool,
 }
 
+/// Format of a `--report` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// `JUnit` XML, one test case per task.
+    Junit,
+}
+
+/// Destination of a `--report` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportTarget {
+    format: ReportFormat,
+    path: PathBuf,
+}
+
+impl ReportTarget {
+    /// Report format.
+    #[must_use]
+    pub const fn format(&self) -> ReportFormat {
+        self.format
+    }
+
+    /// File the report is written to.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Parses a `FORMAT:FILE` report target.
+fn parse_report_target(value: &str) -> Result<ReportTarget, String> {
+    let Some((format, path)) = value.split_once(':') else {
+        return Err("expected FORMAT:FILE, e.g. junit:results.xml".to_string());
+    };
+    let format = match format.to_ascii_lowercase().as_str() {
+        "junit" => ReportFormat::Junit,
+        other => return Err(format!("unknown report format '{other}' (expected junit)")),
+    };
+    if path.is_empty() {
+        return Err("report file is empty".to_string());
+    }
+    Ok(ReportTarget {
+        format,
+        path: PathBuf::from(path),
+    })
+}
+
+/// Install step options.
+#[derive(Debug, Clone, Default, Args)]
+pub struct InstallArgs {
+    /// Overrides `cmake.install_message` for this build only.
+    #[arg(long = "install-message", value_name = "MODE", ignore_case = true)]
+    pub install_message: Option<CmakeInstallMessage>,
+
+    /// Skips `cmake --install` for tasks whose build outputs are not newer
+    /// than the copies listed in `install_manifest.txt`. Ignored with --rebuild.
+    #[arg(long = "install-only-changed")]
+    pub install_only_changed: bool,
+}
+
+impl InstallArgs {
+    /// Returns the install mode selected by `--install-only-changed`.
+    #[must_use]
+    pub const fn install_mode(&self) -> InstallMode {
+        if self.install_only_changed {
+            InstallMode::OnlyChanged
+        } else {
+            InstallMode::Always
+        }
+    }
+}
+
+/// Archive download options.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ArchiveArgs {
+    /// Skips downloading and extracting archives (Explorer++, stylesheets)
+    /// whose cached file matches its fetch stamp and whose extracted
+    /// directory exists, even with --redownload or --reextract.
+    #[arg(long = "skip-existing")]
+    pub skip_existing: bool,
+}
+
+impl ArchiveArgs {
+    /// Returns the archive mode selected by `--skip-existing`.
+    #[must_use]
+    pub const fn archive_mode(&self) -> ArchiveMode {
+        if self.skip_existing {
+            ArchiveMode::SkipExisting
+        } else {
+            ArchiveMode::FollowFlags
+        }
+    }
+}
+
+/// Build preview options.
+#[derive(Debug, Clone, Default, Args)]
+pub struct PreviewArgs {
+    /// Runs the build as --dry and prints every operation it would perform
+    /// (clones, pulls, downloads, extractions, cmake and msbuild calls),
+    /// grouped by task.
+    #[arg(long = "plan")]
+    pub plan: bool,
+}
+
+/// Task name filters, applied to the default task tree or the given tasks.
+#[derive(Debug, Clone, Default, Args)]
+pub struct TaskFilterArgs {
+    /// Only runs tasks whose name matches this glob, e.g.
+    /// 'modorganizer-uibase' or 'modorganizer-installer_*'. Repeatable.
+    #[arg(long = "only", value_name = "GLOB")]
+    pub only: Vec<String>,
+
+    /// Doesn't run tasks whose name matches this glob, e.g. 'translations'.
+    /// Repeatable, applied after --only.
+    #[arg(long = "skip", value_name = "GLOB")]
+    pub skip: Vec<String>,
+}
+
+/// Failure handling.
+#[derive(Debug, Clone, Default, Args)]
+pub struct FailureArgs {
+    /// Keeps running the remaining tasks after one fails and lists every
+    /// failed task at the end. Ctrl+C still stops the build.
+    #[arg(long = "keep-going")]
+    pub keep_going: bool,
+
+    /// With --fail-fast=false, every task of a parallel group runs to the end
+    /// and the error lists each failed task instead of only the first.
+    #[arg(
+        long = "fail-fast",
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true"
+    )]
+    pub fail_fast: Option<bool>,
+}
+
+impl FailureArgs {
+    /// Returns the failure mode selected by `--fail-fast`.
+    #[must_use]
+    pub const fn failure_mode(&self) -> FailureMode {
+        match self.fail_fast {
+            Some(false) => FailureMode::Aggregate,
+            _ => FailureMode::FailFast,
+        }
+    }
+}
+
+/// Confirmation prompts.
+#[derive(Debug, Clone, Default, Args)]
+pub struct PromptArgs {
+    /// Doesn't ask before cloning all repositories into a fresh build
+    /// directory. The question is also skipped when stdin is not a terminal.
+    #[arg(short = 'y', long = "assume-yes")]
+    pub assume_yes: bool,
+}
+
 /// Pull behavior toggles.
 #[derive(Debug, Clone, Default, Args)]
 pub struct PullArgs {
@@ -150,6 +428,10 @@ pub struct PullArgs {
     /// Don't pull repos that are already cloned.
     #[arg(long = "no-pull", action = ArgAction::SetTrue, conflicts_with = "pull")]
     pub no_pull: bool,
+
+    /// Remote to pull from, such as 'upstream' (overrides `task.pull_remote`).
+    #[arg(long = "remote", value_name = "NAME", conflicts_with = "no_pull")]
+    pub remote: Option<String>,
 }
 
 /// Revert .ts behavior toggles.
@@ -201,6 +483,16 @@ impl BuildArgs {
         }
     }
 
+    /// Returns the explicit phase selection from `--phases`, if given.
+    #[must_use]
+    pub fn phase_control(&self) -> Option<PhaseControl> {
+        if self.phases.is_empty() {
+            None
+        } else {
+            Some(PhaseControl::from_phases(&self.phases))
+        }
+    }
+
     /// Returns the effective pull setting.
     #[must_use]
     pub const fn pull_setting(&self) -> Option<bool> {
@@ -277,8 +569,46 @@ pub struct ListArgs {
     #[arg(short = 'i', long)]
     pub aliases: bool,
 
-    /// With -a; when given, acts like the tasks given to `build` and
-    /// shows only the tasks that would run.
+    /// Shows tasks whose sources are not cloned or downloaded yet.
+    #[arg(short = 'm', long, conflicts_with = "aliases")]
+    pub missing: bool,
+
+    /// With -a, -m or --dump-download-urls; when given, acts like the tasks
+    /// given to `build` and shows only the tasks that would run.
     #[arg(value_name = "TASK")]
     pub tasks: Vec<String>,
+
+    /// Diagnostic dumps.
+    #[command(flatten)]
+    pub dump: ListDumpArgs,
+
+    /// Output format.
+    #[command(flatten)]
+    pub output: ListOutputArgs,
+}
+
+/// Output format for `list`.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ListOutputArgs {
+    /// Prints the tasks as a JSON array with each task's name, whether it is
+    /// enabled and its branch, after config overrides.
+    #[arg(
+        long,
+        conflicts_with_all = ["aliases", "missing", "dump_super_layout", "dump_download_urls"]
+    )]
+    pub json: bool,
+}
+
+/// Diagnostic dumps for `list`.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ListDumpArgs {
+    /// Prints the directories in `paths.build` and `modorganizer_super`
+    /// with their git state: clone, submodule or plain directory, and branch.
+    #[arg(long, conflicts_with_all = ["aliases", "missing"])]
+    pub dump_super_layout: bool,
+
+    /// Prints every git, download and Transifex URL the tasks would contact,
+    /// sorted, without fetching anything.
+    #[arg(long, conflicts_with_all = ["aliases", "missing", "dump_super_layout"])]
+    pub dump_download_urls: bool,
 }
@@ -0,0 +1,37 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Download cache command arguments.
+//!
+//! ```text
+//! cache seed
+//!   → download every task archive into paths.downloads (default: paths.cache),
+//!     no git, no build
+//! ```
+
+use clap::{Args, Subcommand};
+
+/// Arguments for the `cache` command.
+#[derive(Debug, Clone, Args)]
+pub struct CacheArgs {
+    /// Cache subcommand.
+    #[command(subcommand)]
+    pub subcommand: CacheSubcommand,
+}
+
+/// Download cache subcommands.
+#[derive(Debug, Clone, Subcommand)]
+pub enum CacheSubcommand {
+    /// Downloads every archive the build needs into `paths.downloads`.
+    Seed(CacheSeedArgs),
+}
+
+/// Arguments for cache seed subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct CacheSeedArgs {
+    /// Re-downloads archives that are already cached.
+    #[arg(short = 'f', long)]
+    pub force: bool,
+}
@@ -0,0 +1,55 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Clean command arguments.
+//!
+//! ```text
+//! clean [--redownload] [--reextract] [--reconfigure] [--rebuild] [tasks...]
+//! --all (-a) implies: --redownload --reextract --reconfigure --rebuild
+//!   → clean phase only, no fetch, no build
+//! ```
+
+use clap::{ArgAction, Args};
+
+use crate::cli::build::{CleanBuildArgs, CleanDownloadArgs};
+use crate::task::CleanFlags;
+
+/// Arguments for the `clean` command.
+#[derive(Debug, Clone, Default, Args)]
+pub struct CleanArgs {
+    /// Clean download actions.
+    #[command(flatten)]
+    pub clean_download: CleanDownloadArgs,
+
+    /// Clean build actions.
+    #[command(flatten)]
+    pub clean_build: CleanBuildArgs,
+
+    /// Runs every clean action.
+    /// Implies --redownload, --reextract, --reconfigure, --rebuild.
+    #[arg(short = 'a', long, action = ArgAction::SetTrue)]
+    pub all: bool,
+
+    /// When --reextract is given, directories controlled by git will be
+    /// deleted even if they contain uncommitted changes.
+    #[arg(long = "ignore-uncommitted-changes")]
+    pub ignore_uncommitted: bool,
+
+    /// Tasks to clean. Globs like 'installer_*' are supported.
+    /// Cleans every task when empty.
+    #[arg(value_name = "TASK")]
+    pub tasks: Vec<String>,
+}
+
+impl CleanArgs {
+    /// Returns the clean actions selected by the flags.
+    #[must_use]
+    pub fn clean_flags(&self) -> CleanFlags {
+        if self.all {
+            return CleanFlags::all();
+        }
+        self.clean_download.clean_flags() | self.clean_build.clean_flags()
+    }
+}
@@ -0,0 +1,45 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Config file command arguments.
+//!
+//! ```text
+//! config migrate <INPUT> <OUTPUT>
+//!   → rewrite renamed keys, drop removed keys with a warning
+//! config validate
+//!   → check required [paths] keys are set and exist, fail on errors
+//! ```
+
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+
+/// Arguments for the `config` command.
+#[derive(Debug, Clone, Args)]
+pub struct ConfigArgs {
+    /// Config subcommand.
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+/// Config file subcommands.
+#[derive(Debug, Clone, Subcommand)]
+pub enum ConfigSubcommand {
+    /// Upgrades an old config file to the current keys.
+    Migrate(ConfigMigrateArgs),
+
+    /// Checks that the paths tasks need are set and exist.
+    Validate,
+}
+
+/// Arguments for config migrate subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct ConfigMigrateArgs {
+    /// Config file to read.
+    pub input: PathBuf,
+
+    /// Path to write the migrated config to; may be the same as the input.
+    pub output: PathBuf,
+}
@@ -0,0 +1,24 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Environment check command arguments.
+//!
+//! ```text
+//! doctor --list-qt
+//!   → scan QTDIR, Qt installer/aqt roots and vcpkg for Qt prefixes
+//!   → print version, prefix/bin/translations and [paths] lines to use
+//! ```
+
+use clap::Args;
+
+/// Arguments for the `doctor` command.
+#[derive(Debug, Clone, Args)]
+#[command(arg_required_else_help = true)]
+pub struct DoctorArgs {
+    /// Lists Qt installations found in `QTDIR`, the Qt installer and aqt
+    /// roots and vcpkg, with the config lines that select each one.
+    #[arg(long = "list-qt")]
+    pub list_qt: bool,
+}
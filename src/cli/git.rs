@@ -16,6 +16,12 @@
 //!   → add remote to all repos
 //! git branches
 //!   → list repos not on master
+//! git unshallow [PATH]
+//!   → fetch full history of shallow clones
+//! git exec [--continue-on-error] -- ARGS...
+//!   → run any git command in every repo
+//! git status
+//!   → branch / uncommitted / stashed per repo
 //! ```
 
 use clap::{Args, Subcommand, ValueEnum};
@@ -46,6 +52,15 @@ pub enum GitSubcommand {
 
     /// Lists all git repos that are not on master.
     Branches(BranchesArgs),
+
+    /// Fetches the full history of all shallow clones.
+    Unshallow(UnshallowArgs),
+
+    /// Runs a git command in every repo, e.g. `mob git exec -- log --oneline -1`.
+    Exec(ExecArgs),
+
+    /// Shows the branch and pending changes of every repo, without modifying anything.
+    Status,
 }
 
 /// Arguments for set-remotes subcommand.
@@ -124,3 +139,23 @@ pub struct BranchesArgs {
     #[arg(short = 'a', long)]
     pub all: bool,
 }
+
+/// Arguments for unshallow subcommand.
+#[derive(Debug, Clone, Default, Args)]
+pub struct UnshallowArgs {
+    /// Only use this repo instead of going through all of them.
+    #[arg(value_name = "PATH")]
+    pub path: Option<PathBuf>,
+}
+
+/// Arguments for exec subcommand.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ExecArgs {
+    /// Keeps going after a repo fails instead of stopping at the first one.
+    #[arg(long = "continue-on-error")]
+    pub continue_on_error: bool,
+
+    /// Arguments passed to git, after `--`.
+    #[arg(last = true, required = true, value_name = "ARGS")]
+    pub args: Vec<String>,
+}
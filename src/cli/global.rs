@@ -11,16 +11,36 @@
 //! --ini FILE        ← Additional config files (can repeat)
 //! --dry             ← Simulate filesystem ops
 //! --log-level N     ← Console verbosity (0-6)
+//! --quiet           ← No progress bars, console warnings and errors only
+//! --github-annotations ← Warnings/errors as ::warning/::error lines (auto on CI)
 //! --file-log-level  ← File verbosity (overrides --log-level)
 //! --destination DIR ← paths.prefix override
 //! --set KEY=VAL     ← Direct config override
+//! --env KEY=VAL     ← Tool environment overlay ([env] section)
+//! --dump-on-failure ← Diagnostic bundle directory on error
+//! --low-priority    ← Below-normal priority for spawned tools
+//! --jobs N          ← Max tasks building at once, cmake --build --parallel N
 //!
 //! Precedence: CLI flags > --set > --ini > defaults
 //! ```
 
-use clap::Args;
+use clap::{Args, ValueEnum};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
+use crate::core::process::priority::ProcessPriority;
+use crate::logging::Rotation;
+
+/// Format of the log file written by `--log-file`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum LogFormat {
+    /// Human-readable text, like the console.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
 /// Global options available for all commands.
 #[derive(Debug, Clone, Default, Args)]
 pub struct GlobalOptions {
@@ -40,6 +60,10 @@ pub struct GlobalOptions {
     )]
     pub log_level: Option<u8>,
 
+    /// Console output options.
+    #[command(flatten)]
+    pub console: ConsoleOptions,
+
     /// File log level, overrides --log-level for the log file.
     #[arg(long = "file-log-level", value_name = "LEVEL", value_parser = clap::value_parser!(u8).range(0..=6)
     )]
@@ -49,6 +73,19 @@ pub struct GlobalOptions {
     #[arg(long = "log-file", value_name = "FILE")]
     pub log_file: Option<PathBuf>,
 
+    /// Format of the log file.
+    #[arg(
+        long = "log-format",
+        value_name = "FORMAT",
+        value_enum,
+        default_value_t
+    )]
+    pub log_format: LogFormat,
+
+    /// Rotates the log file: `hourly`, `daily`, or at a size like `10M`.
+    #[arg(long = "log-rotation", value_name = "ROTATION")]
+    pub log_rotation: Option<Rotation>,
+
     /// Base output directory (will contain build/, install/, etc.).
     #[arg(short = 'd', long = "destination", value_name = "DIR")]
     pub prefix: Option<PathBuf>,
@@ -58,13 +95,67 @@ pub struct GlobalOptions {
     #[arg(short = 's', long = "set", value_name = "OPTION", action = clap::ArgAction::Append)]
     pub options: Vec<String>,
 
+    /// Sets an environment variable for every spawned tool, such as `VCPKG_ROOT=C:/vcpkg`.
+    /// Can be specified multiple times; overrides the `[env]` config section.
+    #[arg(long = "env", value_name = "KEY=VALUE", value_parser = parse_env_assignment, action = clap::ArgAction::Append)]
+    pub env: Vec<(String, String)>,
+
+    /// Writes a diagnostic bundle (config, log, failed command, tool versions)
+    /// into this directory when a command fails.
+    #[arg(long = "dump-on-failure", value_name = "DIR")]
+    pub dump_on_failure: Option<PathBuf>,
+
+    /// Merges the `[profiles.<NAME>]` block of the config over the base settings.
+    #[arg(
+        long = "config-profile",
+        visible_alias = "profile",
+        value_name = "NAME"
+    )]
+    pub config_profile: Option<String>,
+
+    /// Builds at most N tasks of a parallel group at once and passes N to
+    /// `cmake --build --parallel`. Default: the number of logical CPUs.
+    #[arg(short = 'j', long = "jobs", value_name = "N")]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Runs every spawned tool (cmake, msbuild, git, ...) at below-normal
+    /// priority so the machine stays responsive during long builds.
+    #[arg(long = "low-priority")]
+    pub low_priority: bool,
+
     /// Disables auto loading of INI files, only uses --ini.
     /// The first --ini must be the master INI file.
     #[arg(long = "no-default-inis")]
     pub no_default_inis: bool,
 }
 
+/// Console output options.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ConsoleOptions {
+    /// Hides download progress bars and only logs warnings and errors to the
+    /// console, unless --log-level is given.
+    #[arg(short = 'q', long)]
+    pub quiet: bool,
+
+    /// Also prints warnings and errors as GitHub Actions annotations
+    /// (`::warning`/`::error` lines on stdout). On by default when
+    /// `GITHUB_ACTIONS=true`, except for commands printing structured output
+    /// such as `list` and `options`.
+    #[arg(long = "github-annotations")]
+    pub github_annotations: bool,
+}
+
 impl GlobalOptions {
+    /// Returns the scheduling priority of spawned tools, from `--low-priority`.
+    #[must_use]
+    pub const fn process_priority(&self) -> ProcessPriority {
+        if self.low_priority {
+            ProcessPriority::BelowNormal
+        } else {
+            ProcessPriority::Normal
+        }
+    }
+
     /// Converts command-line options to configuration overrides.
     ///
     /// This is equivalent to C++ mob's `convert_cl_to_conf()`.
@@ -74,6 +165,12 @@ impl GlobalOptions {
 
         if let Some(level) = self.log_level {
             overrides.push(format!("global/output_log_level={level}"));
+        } else if self.console.quiet {
+            overrides.push("global/output_log_level=2".to_string());
+        }
+
+        if self.console.quiet {
+            overrides.push("global/progress=false".to_string());
         }
 
         // file_log_level falls back to log_level if not specified
@@ -96,3 +193,15 @@ impl GlobalOptions {
         overrides
     }
 }
+
+/// Parses a `KEY=VALUE` environment assignment.
+fn parse_env_assignment(s: &str) -> Result<(String, String), String> {
+    let Some((key, value)) = s.split_once('=') else {
+        return Err(format!("expected KEY=VALUE, got '{s}'"));
+    };
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("missing variable name in '{s}'"));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
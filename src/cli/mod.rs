@@ -10,18 +10,28 @@
 //! ```text
 //! mob [global options] <command>
 //! build [tasks...]
-//! list
+//! clean [--redownload|--reextract|--reconfigure|--rebuild|--all] [tasks...]
+//! list [--json]
 //! release {devbuild|official}
 //! git {set-remotes|ignore-ts|add-remote|branches}
 //! pr
 //! cmake-config
 //! tx
+//! cache seed
+//! doctor --list-qt
+//! completions <shell>
+//! config migrate
 //! ```
 
 pub mod build;
+pub mod cache;
+pub mod clean;
 pub mod cmake;
+pub mod config;
+pub mod doctor;
 pub mod git;
 pub mod global;
+pub mod options;
 pub mod pr;
 pub mod release;
 
@@ -30,13 +40,19 @@ mod tests;
 pub mod tx;
 
 use crate::cli::build::{BuildArgs, ListArgs};
+use crate::cli::cache::CacheArgs;
+use crate::cli::clean::CleanArgs;
 use crate::cli::cmake::CmakeConfigArgs;
+use crate::cli::config::ConfigArgs;
+use crate::cli::doctor::DoctorArgs;
 use crate::cli::git::GitArgs;
 use crate::cli::global::GlobalOptions;
+use crate::cli::options::OptionsArgs;
 use crate::cli::pr::PrArgs;
 use crate::cli::release::ReleaseArgs;
 use crate::cli::tx::TxArgs;
 use clap::{Parser, Subcommand};
+use clap_complete::Shell;
 
 /// `ModOrganizer` Build Tool - Rust Port
 ///
@@ -83,7 +99,7 @@ pub enum Command {
     Version,
 
     /// Lists all options and their values from the INIs.
-    Options,
+    Options(OptionsArgs),
 
     /// Lists the INIs used by mob.
     Inis,
@@ -91,6 +107,9 @@ pub enum Command {
     /// Builds tasks.
     Build(BuildArgs),
 
+    /// Runs only the clean phase of tasks.
+    Clean(CleanArgs),
+
     /// Lists available tasks.
     List(ListArgs),
 
@@ -106,11 +125,44 @@ pub enum Command {
     /// Manages transifex translations.
     Tx(TxArgs),
 
+    /// Manages the download cache.
+    Cache(CacheArgs),
+
+    /// Upgrades config files.
+    Config(ConfigArgs),
+
+    /// Checks the build environment.
+    Doctor(DoctorArgs),
+
+    /// Prints a shell completion script to stdout.
+    Completions {
+        /// Shell to generate completions for.
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
     /// Print `CMake` configuration variables.
     #[command(name = "cmake-config")]
     CmakeConfig(CmakeConfigArgs),
 }
 
+impl Command {
+    /// Returns whether the command prints output meant for other programs
+    /// to stdout, which annotations enabled by `GITHUB_ACTIONS` would corrupt.
+    #[must_use]
+    pub const fn writes_structured_stdout(&self) -> bool {
+        matches!(
+            self,
+            Self::Version
+                | Self::Options(_)
+                | Self::Inis
+                | Self::List(_)
+                | Self::Completions { .. }
+                | Self::CmakeConfig(_)
+        )
+    }
+}
+
 /// Parses command-line arguments.
 #[must_use]
 pub fn parse() -> Cli {
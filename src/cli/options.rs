@@ -0,0 +1,28 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Options command arguments.
+//!
+//! ```text
+//! options [--filter <glob>] [--changed]
+//!   Config::options()          --> every key = value
+//!   --filter 'paths.*'         --> keys matching the glob
+//!   --changed                  --> values differing from Config::default()
+//! ```
+
+use clap::Args;
+
+/// Arguments for the `options` command.
+#[derive(Debug, Clone, Default, Args)]
+pub struct OptionsArgs {
+    /// Only shows the options whose key matches this glob, e.g. 'paths.*' or
+    /// 'transifex.*'.
+    #[arg(long, value_name = "GLOB")]
+    pub filter: Option<String>,
+
+    /// Only shows the options whose value differs from the built-in default.
+    #[arg(long)]
+    pub changed: bool,
+}
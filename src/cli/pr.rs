@@ -15,12 +15,17 @@
 //!
 //! USAGE:
 //! $ mob pr find modorganizer/123
+//! $ mob pr find 123 --prefetch-prs     (org search, parallel + ETag cache)
 //! $ mob pr pull modorganizer/123 --github-token $TOKEN
 //! $ mob pr revert modorganizer/123
+//! $ mob pr find 123 --github-api-base https://ghe.example.com/api/v3
 //! ```
 
 use clap::{Args, ValueEnum};
 
+/// API base URL of github.com.
+pub const DEFAULT_GITHUB_API_BASE: &str = "https://api.github.com";
+
 /// Arguments for the `pr` command.
 #[derive(Debug, Clone, Args)]
 pub struct PrArgs {
@@ -28,6 +33,21 @@ pub struct PrArgs {
     #[arg(long = "github-token", value_name = "TOKEN", env = "GITHUB_TOKEN")]
     pub github_token: Option<String>,
 
+    /// GitHub API base URL, e.g. `https://ghe.example.com/api/v3` for
+    /// GitHub Enterprise.
+    #[arg(
+        long = "github-api-base",
+        value_name = "URL",
+        env = "GITHUB_API_URL",
+        default_value = DEFAULT_GITHUB_API_BASE
+    )]
+    pub github_api_base: String,
+
+    /// Fetches PR details of search results concurrently and caches them
+    /// by `ETag` in `paths.cache`.
+    #[arg(long)]
+    pub prefetch_prs: bool,
+
     #[arg(value_name = "OP")]
     pub operation: PrOperation,
 
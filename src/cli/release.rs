@@ -13,11 +13,17 @@
 //! --version X.Y.Z, --suffix "-beta"
 //! official:
 //! --bin/--pdbs/--src, --inst
+//! --notes-since REF   CHANGELOG-<version>.md from git log REF..HEAD
+//! both:
+//! --checksums   SHA256SUMS for every produced file
+//! --format      7z (default) | zip
 //! ```
 
 use clap::{Args, Subcommand};
 use std::path::PathBuf;
 
+use crate::task::tools::packer::ArchiveFormat;
+
 /// Arguments for the `release` command.
 #[derive(Debug, Clone, Args)]
 pub struct ReleaseArgs {
@@ -66,6 +72,15 @@ pub struct DevbuildArgs {
     /// Ignores file size warnings and existing release directories.
     #[arg(long)]
     pub force: bool,
+
+    /// Writes a `SHA256SUMS` file covering every produced archive and
+    /// copied installer to the output directory.
+    #[arg(long)]
+    pub checksums: bool,
+
+    /// Archive format; `zip` is written without 7-Zip.
+    #[arg(long, value_enum, default_value_t = ArchiveFormat::SevenZip)]
+    pub format: ArchiveFormat,
 }
 
 impl DevbuildArgs {
@@ -112,6 +127,20 @@ pub struct OfficialArgs {
     /// Ignores file size warnings and existing release directories.
     #[arg(long)]
     pub force: bool,
+
+    /// Writes a `SHA256SUMS` file covering every produced archive and
+    /// copied installer to the output directory.
+    #[arg(long)]
+    pub checksums: bool,
+
+    /// Archive format; `zip` is written without 7-Zip.
+    #[arg(long, value_enum, default_value_t = ArchiveFormat::SevenZip)]
+    pub format: ArchiveFormat,
+
+    /// Writes `CHANGELOG-<version>.md` with the commits of every repo since
+    /// this git ref (tag, branch or commit).
+    #[arg(long = "notes-since", value_name = "REF")]
+    pub notes_since: Option<String>,
 }
 
 impl OfficialArgs {
@@ -3,8 +3,11 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::cli::Cli;
+use crate::cli::build::ReportFormat;
+use crate::cli::{Cli, Command};
+use crate::task::{CleanFlags, FailureMode};
 use clap::Parser;
+use std::path::Path;
 
 #[test]
 fn test_parse_version() {
@@ -33,6 +36,35 @@ fn test_parse_git_set_remotes() {
     insta::assert_debug_snapshot!("parse_git_set_remotes", cli);
 }
 
+#[test]
+fn test_parse_git_unshallow() {
+    let cli = Cli::try_parse_from(["mob", "git", "unshallow", "/path/to/repo"]).unwrap();
+    insta::assert_debug_snapshot!("parse_git_unshallow", cli);
+}
+
+#[test]
+fn test_parse_git_exec() {
+    let cli = Cli::try_parse_from([
+        "mob",
+        "git",
+        "exec",
+        "--continue-on-error",
+        "--",
+        "log",
+        "--oneline",
+        "-1",
+    ])
+    .unwrap();
+    insta::assert_debug_snapshot!("parse_git_exec", cli);
+    assert!(Cli::try_parse_from(["mob", "git", "exec"]).is_err());
+}
+
+#[test]
+fn test_parse_git_status() {
+    let cli = Cli::try_parse_from(["mob", "git", "status"]).unwrap();
+    insta::assert_debug_snapshot!("parse_git_status", cli);
+}
+
 #[test]
 fn test_parse_pr() {
     let cli = Cli::try_parse_from(["mob", "pr", "find", "modorganizer/123"]).unwrap();
@@ -44,3 +76,210 @@ fn test_parse_tx_get() {
     let cli = Cli::try_parse_from(["mob", "tx", "get", "-m", "80", "/path/to/tx"]).unwrap();
     insta::assert_debug_snapshot!("parse_tx_get", cli);
 }
+
+#[test]
+fn test_parse_tx_get_since() {
+    let cli = Cli::try_parse_from(["mob", "tx", "get", "--since", "/path/to/tx"]).unwrap();
+    insta::assert_debug_snapshot!("parse_tx_get_since", cli);
+}
+
+#[test]
+fn test_parse_build_report() {
+    let cli = Cli::try_parse_from(["mob", "build", "--report", "JUnit:out/results.xml"]).unwrap();
+    let Some(Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    let report = args.report.unwrap();
+    assert_eq!(report.format(), ReportFormat::Junit);
+    assert_eq!(report.path(), Path::new("out/results.xml"));
+
+    assert!(Cli::try_parse_from(["mob", "build", "--report", "results.xml"]).is_err());
+    assert!(Cli::try_parse_from(["mob", "build", "--report", "tap:results.tap"]).is_err());
+}
+
+#[test]
+fn test_parse_build_keep_going() {
+    let cli = Cli::try_parse_from(["mob", "build", "--keep-going"]).unwrap();
+    let Some(Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert!(args.failure.keep_going);
+}
+
+#[test]
+fn test_parse_build_fail_fast() {
+    let failure_mode = |args: &[&str]| {
+        let cli = Cli::try_parse_from(args).unwrap();
+        let Some(Command::Build(args)) = cli.command else {
+            panic!("expected build command");
+        };
+        (args.failure.failure_mode(), args.tasks)
+    };
+
+    assert_eq!(
+        failure_mode(&["mob", "build", "usvfs"]),
+        (FailureMode::FailFast, vec!["usvfs".to_string()])
+    );
+    assert_eq!(
+        failure_mode(&["mob", "build", "--fail-fast", "usvfs"]),
+        (FailureMode::FailFast, vec!["usvfs".to_string()])
+    );
+    assert_eq!(
+        failure_mode(&["mob", "build", "--fail-fast=false"]),
+        (FailureMode::Aggregate, vec![])
+    );
+}
+
+#[test]
+fn test_parse_build_plan() {
+    let cli = Cli::try_parse_from(["mob", "build", "--plan", "usvfs"]).unwrap();
+    let Some(Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert!(args.preview.plan);
+    assert!(!cli.global.dry);
+}
+
+#[test]
+fn test_parse_cache_seed() {
+    let cli = Cli::try_parse_from(["mob", "cache", "seed", "--force"]).unwrap();
+    insta::assert_debug_snapshot!("parse_cache_seed", cli);
+}
+
+#[test]
+fn test_parse_doctor_list_qt() {
+    let cli = Cli::try_parse_from(["mob", "doctor", "--list-qt"]).unwrap();
+    assert!(matches!(cli.command, Some(Command::Doctor(args)) if args.list_qt));
+}
+
+#[test]
+fn test_parse_completions() {
+    let cli = Cli::try_parse_from(["mob", "completions", "zsh"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Command::Completions {
+            shell: clap_complete::Shell::Zsh
+        })
+    ));
+    assert!(Cli::try_parse_from(["mob", "completions", "tcsh"]).is_err());
+}
+
+#[test]
+fn test_parse_config_migrate() {
+    let cli = Cli::try_parse_from(["mob", "config", "migrate", "old.toml", "mob.toml"]).unwrap();
+    insta::assert_debug_snapshot!("parse_config_migrate", cli);
+}
+
+#[test]
+fn test_parse_tx_validate() {
+    let cli = Cli::try_parse_from(["mob", "tx", "validate", "-t", "team", "/path/to/tx"]).unwrap();
+    insta::assert_debug_snapshot!("parse_tx_validate", cli);
+}
+
+#[test]
+fn test_parse_tx_get_since_conflicts_with_force() {
+    let result = Cli::try_parse_from(["mob", "tx", "get", "--since", "--force", "/path/to/tx"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_dump_on_failure() {
+    let cli =
+        Cli::try_parse_from(["mob", "--dump-on-failure", "bundles", "list"]).expect("should parse");
+    assert_eq!(
+        cli.global.dump_on_failure,
+        Some(std::path::PathBuf::from("bundles"))
+    );
+}
+
+#[test]
+fn test_parse_config_validate() {
+    use crate::cli::config::ConfigSubcommand;
+
+    let cli = Cli::try_parse_from(["mob", "config", "validate"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(Command::Config(args)) if matches!(args.subcommand, ConfigSubcommand::Validate)
+    ));
+}
+
+#[test]
+fn test_parse_build_only_skip() {
+    let cli = Cli::try_parse_from([
+        "mob",
+        "build",
+        "--only",
+        "modorganizer-*",
+        "--only",
+        "usvfs",
+        "--skip",
+        "modorganizer-installer_*",
+    ])
+    .unwrap();
+    let Some(Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert_eq!(args.filter.only, ["modorganizer-*", "usvfs"]);
+    assert_eq!(args.filter.skip, ["modorganizer-installer_*"]);
+    assert!(args.tasks.is_empty());
+}
+
+#[test]
+fn test_parse_clean() {
+    let cli = Cli::try_parse_from(["mob", "clean", "-e", "--rebuild", "usvfs"]).unwrap();
+    let Some(Command::Clean(args)) = cli.command else {
+        panic!("expected clean command");
+    };
+    assert_eq!(
+        args.clean_flags(),
+        CleanFlags::REEXTRACT | CleanFlags::REBUILD
+    );
+    assert_eq!(args.tasks, ["usvfs"]);
+}
+
+#[test]
+fn test_parse_clean_all() {
+    let cli = Cli::try_parse_from(["mob", "clean", "--all"]).unwrap();
+    let Some(Command::Clean(args)) = cli.command else {
+        panic!("expected clean command");
+    };
+    assert_eq!(args.clean_flags(), CleanFlags::all());
+    assert!(args.tasks.is_empty());
+
+    let cli = Cli::try_parse_from(["mob", "clean"]).unwrap();
+    let Some(Command::Clean(args)) = cli.command else {
+        panic!("expected clean command");
+    };
+    assert!(args.clean_flags().is_empty());
+}
+
+#[test]
+fn test_parse_options_filter() {
+    let cli = Cli::try_parse_from(["mob", "options", "--filter", "paths.*", "--changed"]).unwrap();
+    let Some(Command::Options(args)) = cli.command else {
+        panic!("expected options command");
+    };
+    assert_eq!(args.filter.as_deref(), Some("paths.*"));
+    assert!(args.changed);
+
+    let cli = Cli::try_parse_from(["mob", "options"]).unwrap();
+    let Some(Command::Options(args)) = cli.command else {
+        panic!("expected options command");
+    };
+    assert!(args.filter.is_none());
+    assert!(!args.changed);
+}
+
+#[test]
+fn test_writes_structured_stdout() {
+    let structured = |args: &[&str]| {
+        Cli::try_parse_from(args)
+            .unwrap()
+            .command
+            .is_some_and(|command| command.writes_structured_stdout())
+    };
+    assert!(structured(&["mob", "list", "--json"]));
+    assert!(structured(&["mob", "options"]));
+    assert!(!structured(&["mob", "build"]));
+    assert!(!structured(&["mob", "release", "devbuild"]));
+}
@@ -9,9 +9,11 @@
 //!
 //! ```text
 //! tx get -k API_KEY -t TEAM -p PROJECT
-//!   → pull translations (min threshold, force)
+//!   → pull translations (min threshold, force, --since)
 //! tx build
 //!   → compile .ts → .qm via lrelease
+//! tx validate [PATH]
+//!   → check .tx/config against transifex.team/project/url
 //! ```
 
 use clap::{Args, Subcommand};
@@ -33,6 +35,9 @@ pub enum TxSubcommand {
 
     /// Builds all .qm files from translation sources.
     Build(TxBuildArgs),
+
+    /// Checks the .tx/config in the transifex directory for problems.
+    Validate(TxValidateArgs),
 }
 
 /// Arguments for tx get subcommand.
@@ -63,6 +68,15 @@ pub struct TxGetArgs {
     #[arg(short = 'f', long)]
     pub force: bool,
 
+    /// Only pulls translations newer upstream than the local files, and all
+    /// of them once after .tx/config changed since the last pull.
+    #[arg(long, conflicts_with = "force")]
+    pub since: bool,
+
+    /// Only downloads files newer than the last git commit of the local files.
+    #[arg(long = "use-git-timestamps")]
+    pub use_git_timestamps: bool,
+
     /// Path that will contain the .tx directory.
     #[arg(value_name = "PATH")]
     pub path: PathBuf,
@@ -79,3 +93,24 @@ pub struct TxBuildArgs {
     #[arg(value_name = "DESTINATION")]
     pub destination: PathBuf,
 }
+
+/// Arguments for tx validate subcommand.
+#[derive(Debug, Clone, Args)]
+pub struct TxValidateArgs {
+    /// Expected Transifex team, defaults to `transifex.team`.
+    #[arg(short = 't', long = "team", value_name = "TEAM")]
+    pub team: Option<String>,
+
+    /// Expected Transifex project, defaults to `transifex.project`.
+    #[arg(short = 'p', long = "project", value_name = "PROJECT")]
+    pub project: Option<String>,
+
+    /// Expected Transifex URL, defaults to `transifex.url`.
+    #[arg(short = 'u', long = "url", value_name = "URL")]
+    pub url: Option<String>,
+
+    /// Path that contains the .tx directory, defaults to the translations
+    /// task directory.
+    #[arg(value_name = "PATH")]
+    pub path: Option<PathBuf>,
+}
@@ -5,141 +5,144 @@
 
 //! Build command implementation for mob-rs.
 
+use std::io::{IsTerminal, Write as _};
+use std::num::NonZeroUsize;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::cli::build::BuildArgs;
+use crate::cmd::measure::MeasureReport;
+use crate::cmd::report::JunitReport;
 use crate::config::Config;
+use crate::config::types::CmakeInstallMessage;
+use crate::core::process::priority::ProcessPriority;
+use crate::core::process::script::{self, Script, ScriptShell, ScriptStep};
+use crate::core::process::timing::ProcessTimings;
 use crate::error::Result;
+use crate::git::query::is_git_repo;
 use crate::task::manager::TaskManager;
-use crate::task::registry::TaskRegistry;
-use crate::task::tasks::explorerpp::ExplorerPPTask;
-use crate::task::tasks::installer::InstallerTask;
-use crate::task::tasks::licenses::LicensesTask;
-use crate::task::tasks::modorganizer::ModOrganizerTask;
-use crate::task::tasks::stylesheets::StylesheetsTask;
-use crate::task::tasks::translations::TranslationsTask;
-use crate::task::tasks::usvfs::UsvfsTask;
-use crate::task::{CleanFlags, ParallelTasks, Task};
-
-/// Built-in task names that have dedicated task types (not `ModOrganizerTask`).
-pub(crate) const BUILTIN_TASKS: &[&str] = &[
-    "usvfs",
-    "modorganizer",
-    "stylesheets",
-    "explorerpp",
-    "licenses",
-    "translations",
-    "installer",
-];
-
-/// Default `ModOrganizer` sub-projects, matching C++ mob's `add_tasks()` in `main.cpp`.
-///
-/// These are the `modorganizer-*` repos that C++ mob hardcodes. Without this list,
-/// mob-rs only knows about `BUILTIN_TASKS` (7 items) and whatever `mob.toml` defines,
-/// leaving ~25 sub-projects unregistered and therefore never cloned or built.
-///
-/// Each entry is `(canonical_name, &[alternate_names])`. Alternate names come from
-/// transifex slugs which sometimes differ from project names.
-const DEFAULT_MO_PROJECTS: &[(&str, &[&str])] = &[
-    // Sequential group 1 (parallel with usvfs)
-    ("cmake_common", &[]),
-    // Sequential group 2 (single)
-    ("modorganizer-uibase", &[]),
-    // Sequential group 3 (parallel)
-    ("modorganizer-archive", &[]),
-    ("modorganizer-lootcli", &[]),
-    ("modorganizer-esptk", &[]),
-    ("modorganizer-bsatk", &[]),
-    ("modorganizer-nxmhandler", &[]),
-    ("modorganizer-helper", &[]),
-    ("modorganizer-game_bethesda", &[]),
-    // Sequential group 4 (parallel)
-    ("modorganizer-bsapacker", &["bsa_packer"]),
-    ("modorganizer-tool_inieditor", &["inieditor"]),
-    ("modorganizer-tool_inibakery", &["inibakery"]),
-    ("modorganizer-preview_bsa", &[]),
-    ("modorganizer-preview_base", &[]),
-    ("modorganizer-diagnose_basic", &[]),
-    ("modorganizer-check_fnis", &[]),
-    ("modorganizer-installer_bain", &[]),
-    ("modorganizer-installer_manual", &[]),
-    ("modorganizer-installer_bundle", &[]),
-    ("modorganizer-installer_quick", &[]),
-    ("modorganizer-installer_fomod", &[]),
-    ("modorganizer-installer_fomod_csharp", &[]),
-    ("modorganizer-installer_omod", &[]),
-    ("modorganizer-installer_wizard", &[]),
-    ("modorganizer-bsa_extractor", &[]),
-    ("modorganizer-plugin_python", &[]),
-    // Sequential group 5 (parallel, alongside stylesheets/licenses/explorerpp)
-    ("modorganizer-tool_configurator", &["pycfg"]),
-    ("modorganizer-fnistool", &[]),
-    ("modorganizer-basic_games", &[]),
-    (
-        "modorganizer-script_extender_plugin_checker",
-        &["scriptextenderpluginchecker"],
-    ),
-    ("modorganizer-form43_checker", &["form43checker"]),
-    ("modorganizer-preview_dds", &["ddspreview"]),
-    // "modorganizer" itself has alternate name "organizer" — handled in BUILTIN_TASKS
-];
+use crate::task::plan::{Plan, format_plan};
+use crate::task::registry::builtin::{default_task_tree, task_from_name};
+use crate::task::registry::{TaskFilter, TaskRegistry};
+use crate::task::results::TaskResults;
+use crate::task::timing::RunTimings;
+use crate::task::tools::cmake::CmakeTool;
+use crate::task::{CleanFlags, PhaseControl};
+use crate::utility::fs::link::{DeployMode, deploy_dir_async};
+use crate::utility::fs::text::{LineEndings, write_text};
+use anyhow::Context;
+use tokio_util::sync::CancellationToken;
+
+/// Rough download size of one repository, used by the first-clone question.
+const ESTIMATED_REPO_MB: usize = 60;
 
 /// Main handler for build command.
 ///
 /// When no specific tasks are given, builds all tasks in the order defined by
 /// C++ mob's `add_tasks()` — sequential groups with parallel sub-tasks.
-/// When specific tasks are given, resolves and runs them sequentially.
+/// When specific tasks are given, resolves them and lets the
+/// [`TaskManager`] order them by their dependencies; tasks that don't depend
+/// on each other run together, whatever their order on the command line.
 ///
 /// # Errors
 ///
 /// Returns an error if configuration fails, task resolution fails, or the task
 /// runner reports a build failure.
-pub async fn run_build_command(args: &BuildArgs, config: &Config, dry_run: bool) -> Result<()> {
-    let config = Arc::new(config.clone());
+pub async fn run_build_command(
+    args: &BuildArgs,
+    config: &Config,
+    dry_run: bool,
+    jobs: Option<NonZeroUsize>,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let dry_run = dry_run || args.preview.plan;
+    let mut config = config.clone();
+    if let Some(ref remote) = args.pull_behavior.remote {
+        apply_pull_remote(&mut config, remote);
+    }
+    let config = Arc::new(config);
+
+    let registry = TaskRegistry::for_config(&config);
+
+    if let Some(ref pattern) = args.dump_cmake_args {
+        for line in
+            dump_cmake_args(&registry, &config, pattern, args.install.install_message).await?
+        {
+            println!("{line}");
+        }
+        return Ok(());
+    }
+
+    if args.emit_script.is_some() && !dry_run {
+        anyhow::bail!("--emit-script requires --dry");
+    }
 
     let clean_flags = compute_clean_flags(args);
-    let do_clean = args.clean_phase.clean_task || !clean_flags.is_empty();
-    let do_fetch = !args.fetch_phase.no_fetch_task;
-    let do_build = !args.build_phase.no_build_task;
+    let phases = args.phase_control().unwrap_or_else(|| {
+        PhaseControl::new()
+            .with_clean(args.clean_phase.clean_task || !clean_flags.is_empty())
+            .with_fetch(!args.fetch_phase.no_fetch_task)
+            .with_build(!args.build_phase.no_build_task)
+    });
 
-    let mut manager = TaskManager::new(Arc::clone(&config))
+    let manager = jobs.map_or_else(
+        || TaskManager::new(Arc::clone(&config), cancel_token),
+        |jobs| TaskManager::with_concurrency(Arc::clone(&config), jobs.get(), cancel_token),
+    );
+    let manager = manager
         .with_dry_run(dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority)
+        .with_install_message(args.install.install_message)
+        .with_install_mode(args.install.install_mode())
+        .with_archive_mode(args.archive.archive_mode())
+        .with_continue_on_error(args.failure.keep_going)
+        .with_failure_mode(args.failure.failure_mode())
+        .with_fetch_parallelism(args.fetch_parallelism.unwrap_or(NonZeroUsize::MIN))
+        .with_build_jobs(jobs)
         .with_clean_flags(clean_flags)
-        .with_do_clean(do_clean)
-        .with_do_fetch(do_fetch)
-        .with_do_build(do_build);
-
-    let cancel_token = manager.cancel_token();
-    tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            tracing::warn!("Received Ctrl+C, interrupting tasks...");
-            cancel_token.cancel();
-        }
-    });
+        .with_do_clean(phases.do_clean())
+        .with_do_fetch(phases.do_fetch())
+        .with_do_build(phases.do_build());
 
-    let mut registry = TaskRegistry::new(config.aliases.clone());
-    register_config_tasks(&mut registry, &config);
-    register_default_projects(&mut registry);
-    registry.register_all(BUILTIN_TASKS.iter().map(std::string::ToString::to_string));
-    // Register alternate name for modorganizer
-    registry.register("organizer".to_string());
+    let recorders = Recorders::new(args);
+    let mut manager = recorders.attach(manager);
 
-    if args.tasks.is_empty() {
+    let tasks = if args.tasks.is_empty() {
         // No specific tasks requested — use the full ordered task tree
         // matching C++ mob's add_tasks() sequential groups.
-        add_default_task_tree(&mut manager);
+        default_task_tree()
     } else {
-        // Specific tasks requested — resolve and run sequentially
-        let resolved_names = resolve_task_names(&registry, args)?;
-        for name in resolved_names {
-            manager.add(task_from_name(name));
-        }
+        // Specific tasks requested — resolve them, the manager orders them by
+        // their dependencies and runs independent ones together
+        resolve_task_names(&registry, args)?
+            .into_iter()
+            .map(|name| task_from_name(&name))
+            .collect()
+    };
+    let filter = TaskFilter::new(&args.filter.only, &args.filter.skip)?;
+    for task in filter.apply(tasks)? {
+        manager.add(task);
+    }
+
+    if manager.phases().do_fetch() && !dry_run && !args.prompt.assume_yes {
+        confirm_first_clone(&manager, &config)?;
     }
 
-    match manager.run_all().await {
+    let started = Instant::now();
+
+    let result = manager.run_all().await;
+
+    recorders
+        .finish(args, started, result.is_ok(), config.global.line_endings)
+        .await?;
+
+    match result {
         Ok(()) => {
             tracing::info!("Build completed successfully");
-            Ok(())
+            deploy_install(args, &config, dry_run).await
         }
         Err(e) => {
             eprintln!("Build failed: {e}");
@@ -148,151 +151,279 @@ pub async fn run_build_command(args: &BuildArgs, config: &Config, dry_run: bool)
     }
 }
 
-fn compute_clean_flags(args: &BuildArgs) -> CleanFlags {
-    let mut clean_flags = CleanFlags::empty();
-    if args.clean_download.redownload || args.clean_full.new_build {
-        clean_flags |= CleanFlags::REDOWNLOAD;
+/// Collectors of the run requested by `--plan`, `--measure`, `--report` and
+/// `--emit-script`.
+struct Recorders<'a> {
+    plan: Option<Arc<Plan>>,
+    measure: Option<(Arc<RunTimings>, Arc<ProcessTimings>)>,
+    results: Option<Arc<TaskResults>>,
+    script: Option<(&'a Path, Arc<Script>)>,
+}
+
+impl<'a> Recorders<'a> {
+    /// Creates the collectors requested by `args`.
+    fn new(args: &'a BuildArgs) -> Self {
+        Self {
+            plan: args.preview.plan.then(|| Arc::new(Plan::new())),
+            measure: (args.measure || args.measure_json.is_some())
+                .then(|| (Arc::new(RunTimings::new()), Arc::new(ProcessTimings::new()))),
+            results: args.report.is_some().then(|| Arc::new(TaskResults::new())),
+            script: args
+                .emit_script
+                .as_deref()
+                .map(|path| (path, Arc::new(Script::new()))),
+        }
     }
-    if args.clean_download.reextract || args.clean_full.new_build {
-        clean_flags |= CleanFlags::REEXTRACT;
+
+    /// Passes every collector to `manager`.
+    fn attach(&self, mut manager: TaskManager) -> TaskManager {
+        if let Some(plan) = &self.plan {
+            manager = manager.with_plan(Arc::clone(plan));
+        }
+        if let Some((phases, processes)) = &self.measure {
+            manager = manager.with_measure(Arc::clone(phases), Arc::clone(processes));
+        }
+        if let Some(results) = &self.results {
+            manager = manager.with_results(Arc::clone(results));
+        }
+        if let Some((_, script)) = &self.script {
+            manager = manager.with_script(Arc::clone(script));
+        }
+        manager
     }
-    if args.clean_build.reconfigure || args.clean_full.new_build {
-        clean_flags |= CleanFlags::RECONFIGURE;
+
+    /// Prints or writes what was collected during the run that began at
+    /// `started`.
+    async fn finish(
+        &self,
+        args: &BuildArgs,
+        started: Instant,
+        build_ok: bool,
+        endings: LineEndings,
+    ) -> Result<()> {
+        if let Some(plan) = &self.plan {
+            print!("{}", format_plan(&plan.take()));
+        }
+        if let Some((path, script)) = &self.script {
+            write_script(path, &script.take(), endings).await?;
+        }
+        if let Some((phases, processes)) = &self.measure {
+            let report = MeasureReport::new(started.elapsed(), &phases.take(), &processes.take());
+            report_measurements(args, &report, build_ok, endings).await?;
+        }
+        if let Some(results) = &self.results {
+            let report = JunitReport::new(started.elapsed(), &results.take());
+            write_report(args, &report, build_ok, endings).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Asks before the first fetch into a build directory without a super
+/// repository, which clones every repository of the task list.
+///
+/// Skipped when stdin is not a terminal.
+fn confirm_first_clone(manager: &TaskManager, config: &Config) -> Result<()> {
+    let Some(build) = &config.paths.build else {
+        return Ok(());
+    };
+    if is_git_repo(build) || !std::io::stdin().is_terminal() {
+        return Ok(());
     }
-    if args.clean_build.rebuild || args.clean_full.new_build {
-        clean_flags |= CleanFlags::REBUILD;
+    let repos = manager.missing_repos()?.len();
+    if repos == 0 {
+        return Ok(());
+    }
+
+    eprint!("{} ", first_clone_prompt(repos, build));
+    std::io::stderr()
+        .flush()
+        .context("Failed to write prompt")?;
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .context("Failed to read answer")?;
+    let answer = answer.trim();
+    if answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes") {
+        Ok(())
+    } else {
+        anyhow::bail!("Aborted, nothing was cloned (use --assume-yes to skip this question)")
     }
-    clean_flags
 }
 
-pub(crate) fn register_config_tasks(registry: &mut TaskRegistry, config: &Config) {
-    for name in config.tasks.keys() {
-        // Skip alias names (e.g., "super", "plugins") — they are config override
-        // scopes, not actual buildable tasks. In C++ mob, `[super:task]` applies
-        // overrides to all tasks in the `super` alias group.
-        if config.aliases.contains_key(name) {
-            continue;
-        }
+/// The first-clone question, with a size estimate of `ESTIMATED_REPO_MB` per repository.
+fn first_clone_prompt(repos: usize, path: &Path) -> String {
+    let tenths_gb = (repos * ESTIMATED_REPO_MB * 10).div_ceil(1024);
+    format!(
+        "This will clone {repos} repositories (~{}.{} GB) into {}. Continue? [y/N]",
+        tenths_gb / 10,
+        tenths_gb % 10,
+        path.display()
+    )
+}
+
+/// Prints the `--measure` build profile and writes it to `--measure-json`.
+///
+/// A JSON write error only fails the command if the build itself succeeded.
+async fn report_measurements(
+    args: &BuildArgs,
+    report: &MeasureReport,
+    build_ok: bool,
+    endings: LineEndings,
+) -> Result<()> {
+    for line in report.format_lines() {
+        println!("{line}");
+    }
 
-        // Skip built-in task names — they have their own task types and should
-        // not be registered as modorganizer-* repos.
-        if BUILTIN_TASKS.contains(&name.as_str()) {
-            continue;
+    let Some(path) = &args.measure_json else {
+        return Ok(());
+    };
+    match report.write_json(path, endings).await {
+        Err(e) if build_ok => Err(e),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to write build profile");
+            Ok(())
         }
+        Ok(()) => Ok(()),
+    }
+}
 
-        registry.register(name.clone());
-        if let Some(short) = name.strip_prefix("modorganizer-") {
-            registry.register(short.to_string());
-        } else if name != "modorganizer" {
-            registry.register(format!("modorganizer-{name}"));
+/// Writes the `--report` file.
+///
+/// A write error only fails the command if the build itself succeeded.
+async fn write_report(
+    args: &BuildArgs,
+    report: &JunitReport,
+    build_ok: bool,
+    endings: LineEndings,
+) -> Result<()> {
+    let Some(target) = &args.report else {
+        return Ok(());
+    };
+    match report.write(target.format(), target.path(), endings).await {
+        Err(e) if build_ok => Err(e),
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to write build report");
+            Ok(())
         }
+        Ok(()) => Ok(()),
     }
 }
 
-/// Registers all default `ModOrganizer` sub-projects and their alternate names.
-pub(crate) fn register_default_projects(registry: &mut TaskRegistry) {
-    for &(canonical, alternates) in DEFAULT_MO_PROJECTS {
-        registry.register(canonical.to_string());
+/// Writes the commands recorded during the dry run to `path`.
+async fn write_script(path: &Path, steps: &[ScriptStep], endings: LineEndings) -> Result<()> {
+    let contents = script::render(steps, ScriptShell::from_path(path));
+    write_text(path, &contents, endings)
+        .await
+        .with_context(|| format!("Failed to write script: {}", path.display()))?;
+    tracing::info!(path = %path.display(), steps = steps.len(), "Wrote build script");
+    Ok(())
+}
+
+/// Deploys `paths.install_bin` into the instance given by `--link-into`/`--copy-into`.
+async fn deploy_install(args: &BuildArgs, config: &Config, dry_run: bool) -> Result<()> {
+    let (target, mode) = match (&args.link_into, &args.copy_into) {
+        (Some(dir), _) => (dir, DeployMode::Link),
+        (None, Some(dir)) => (dir, DeployMode::Copy),
+        (None, None) => return Ok(()),
+    };
 
-        // Also register the short name (without `modorganizer-` prefix)
-        if let Some(short) = canonical.strip_prefix("modorganizer-") {
-            registry.register(short.to_string());
-        }
+    let install_bin = config
+        .paths
+        .install_bin
+        .as_ref()
+        .context("paths.install_bin not configured")?;
+    let install_bin = std::path::absolute(install_bin)
+        .with_context(|| format!("failed to resolve {}", install_bin.display()))?;
 
-        // Register alternate names (transifex slugs, etc.)
-        for &alt in alternates {
-            registry.register(alt.to_string());
-        }
+    if dry_run {
+        tracing::info!(
+            src = %install_bin.display(),
+            dst = %target.display(),
+            ?mode,
+            "[DRY-RUN] would deploy install"
+        );
+        return Ok(());
     }
+
+    let summary = deploy_dir_async(&install_bin, target, mode)
+        .await
+        .with_context(|| format!("failed to deploy install into {}", target.display()))?;
+    tracing::info!(
+        dst = %target.display(),
+        linked = summary.linked(),
+        copied = summary.copied(),
+        "Deployed install"
+    );
+    Ok(())
 }
 
-/// Builds the default task tree matching C++ mob's `add_tasks()` ordering.
+/// Resolves `pattern` to tasks and formats their `CMake` configure command lines.
 ///
-/// Tasks are organized into sequential groups. Within each group, tasks run
-/// in parallel. Groups execute sequentially because later groups depend on
-/// earlier ones (e.g., `uibase` must be built before `archive`).
+/// Each configure step becomes one line: the `cmake` program followed by the
+/// resolved arguments, quoted where needed so the line can be pasted into a shell.
 ///
-/// ```text
-/// Group 1 (parallel): usvfs, cmake_common
-/// Group 2 (single):   modorganizer-uibase
-/// Group 3 (parallel): archive, lootcli, esptk, bsatk, nxmhandler, helper, game_bethesda
-/// Group 4 (parallel): bsapacker, tool_inieditor, ... plugin_python
-/// Group 5 (parallel): stylesheets, licenses, explorerpp, pycfg, ... modorganizer
-/// Group 6 (single):   translations
-/// Group 7 (single):   installer
-/// ```
-fn add_default_task_tree(manager: &mut TaskManager) {
-    // Group 1: usvfs + cmake_common (parallel)
-    manager.add(Task::Parallel(
-        ParallelTasks::new(vec![])
-            .with_task(Task::Usvfs(UsvfsTask::new()))
-            .with_task(mo("cmake_common")),
-    ));
-
-    // Group 2: modorganizer-uibase (must complete before group 3)
-    manager.add(mo("modorganizer-uibase"));
-
-    // Group 3: parallel batch
-    manager.add(Task::Parallel(
-        ParallelTasks::new(vec![])
-            .with_task(mo("modorganizer-archive"))
-            .with_task(mo("modorganizer-lootcli"))
-            .with_task(mo("modorganizer-esptk"))
-            .with_task(mo("modorganizer-bsatk"))
-            .with_task(mo("modorganizer-nxmhandler"))
-            .with_task(mo("modorganizer-helper"))
-            .with_task(mo("modorganizer-game_bethesda")),
-    ));
-
-    // Group 4: parallel batch
-    manager.add(Task::Parallel(
-        ParallelTasks::new(vec![])
-            .with_task(mo("modorganizer-bsapacker"))
-            .with_task(mo("modorganizer-tool_inieditor"))
-            .with_task(mo("modorganizer-tool_inibakery"))
-            .with_task(mo("modorganizer-preview_bsa"))
-            .with_task(mo("modorganizer-preview_base"))
-            .with_task(mo("modorganizer-diagnose_basic"))
-            .with_task(mo("modorganizer-check_fnis"))
-            .with_task(mo("modorganizer-installer_bain"))
-            .with_task(mo("modorganizer-installer_manual"))
-            .with_task(mo("modorganizer-installer_bundle"))
-            .with_task(mo("modorganizer-installer_quick"))
-            .with_task(mo("modorganizer-installer_fomod"))
-            .with_task(mo("modorganizer-installer_fomod_csharp"))
-            .with_task(mo("modorganizer-installer_omod"))
-            .with_task(mo("modorganizer-installer_wizard"))
-            .with_task(mo("modorganizer-bsa_extractor"))
-            .with_task(mo("modorganizer-plugin_python")),
-    ));
-
-    // Group 5: parallel batch (including stylesheets, licenses, explorerpp)
-    manager.add(Task::Parallel(
-        ParallelTasks::new(vec![])
-            .with_task(Task::Stylesheets(StylesheetsTask::new()))
-            .with_task(Task::Licenses(LicensesTask::new()))
-            .with_task(Task::ExplorerPP(ExplorerPPTask::new()))
-            .with_task(mo("modorganizer-tool_configurator"))
-            .with_task(mo("modorganizer-fnistool"))
-            .with_task(mo("modorganizer-basic_games"))
-            .with_task(mo("modorganizer-script_extender_plugin_checker"))
-            .with_task(mo("modorganizer-form43_checker"))
-            .with_task(mo("modorganizer-preview_dds"))
-            .with_task(Task::ModOrganizer(ModOrganizerTask::new(
-                "modorganizer".to_string(),
-            ))),
-    ));
-
-    // Group 6: translations (single)
-    manager.add(Task::Translations(TranslationsTask::new()));
-
-    // Group 7: installer (single)
-    manager.add(Task::Installer(InstallerTask::new()));
+/// # Errors
+///
+/// Returns an error if the pattern does not resolve, a configure step cannot be
+/// built, or none of the resolved tasks has a `CMake` configure step.
+pub(crate) async fn dump_cmake_args(
+    registry: &TaskRegistry,
+    config: &Config,
+    pattern: &str,
+    install_message: Option<CmakeInstallMessage>,
+) -> Result<Vec<String>> {
+    let names = registry.resolve(&[pattern.to_string()])?;
+    let program = CmakeTool::program(config);
+
+    let mut lines = Vec::new();
+    for name in names {
+        for mut tool in task_from_name(&name).cmake_configure_tools(config)? {
+            if let Some(message) = install_message {
+                tool = tool.install_message(message);
+            }
+            let args = tool
+                .configure_args(config)
+                .await
+                .with_context(|| format!("failed to resolve cmake arguments for '{name}'"))?;
+            let command = std::iter::once(program.display().to_string())
+                .chain(args)
+                .map(|arg| quote_arg(&arg))
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(command);
+        }
+    }
+
+    if lines.is_empty() {
+        anyhow::bail!("Task '{pattern}' has no CMake configure step");
+    }
+
+    Ok(lines)
+}
+
+/// Makes every task pull from `remote`, taking precedence over `[tasks.*]` overrides.
+fn apply_pull_remote(config: &mut Config, remote: &str) {
+    config.task.git_behavior.pull_remote = remote.to_string();
+    for task in config.tasks.values_mut() {
+        task.pull_remote = None;
+    }
+}
+
+/// Wraps an argument in double quotes if it is empty or contains whitespace.
+fn quote_arg(arg: &str) -> String {
+    if arg.is_empty() || arg.contains(char::is_whitespace) {
+        format!("\"{arg}\"")
+    } else {
+        arg.to_string()
+    }
 }
 
-/// Shorthand for creating a `ModOrganizerTask` wrapped in `Task`.
-fn mo(name: &str) -> Task {
-    Task::ModOrganizer(ModOrganizerTask::new(name.to_string()))
+fn compute_clean_flags(args: &BuildArgs) -> CleanFlags {
+    if args.clean_full.new_build {
+        return CleanFlags::all();
+    }
+    args.clean_download.clean_flags() | args.clean_build.clean_flags()
 }
 
 fn resolve_task_names(registry: &TaskRegistry, args: &BuildArgs) -> Result<Vec<String>> {
@@ -312,24 +443,3 @@ fn resolve_task_names(registry: &TaskRegistry, args: &BuildArgs) -> Result<Vec<S
 
     Ok(resolved_names)
 }
-
-fn task_from_name(name: String) -> Task {
-    match name.as_str() {
-        "usvfs" => Task::Usvfs(UsvfsTask::new()),
-        "stylesheets" | "ss" => Task::Stylesheets(StylesheetsTask::new()),
-        "explorerpp" | "explorer++" => Task::ExplorerPP(ExplorerPPTask::new()),
-        "licenses" => Task::Licenses(LicensesTask::new()),
-        "translations" => Task::Translations(TranslationsTask::new()),
-        "installer" => Task::Installer(InstallerTask::new()),
-        // Resolve alternate names to their canonical modorganizer-* form
-        "organizer" => mo("modorganizer"),
-        "bsa_packer" => mo("modorganizer-bsapacker"),
-        "inieditor" => mo("modorganizer-tool_inieditor"),
-        "inibakery" => mo("modorganizer-tool_inibakery"),
-        "pycfg" => mo("modorganizer-tool_configurator"),
-        "scriptextenderpluginchecker" => mo("modorganizer-script_extender_plugin_checker"),
-        "form43checker" => mo("modorganizer-form43_checker"),
-        "ddspreview" => mo("modorganizer-preview_dds"),
-        _ => Task::ModOrganizer(ModOrganizerTask::new(name)),
-    }
-}
@@ -0,0 +1,89 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Download cache command implementation.
+//!
+//! ```text
+//! cache seed --> StylesheetsTask::downloads + ExplorerPPTask::downloads
+//!            --> DownloaderTool per (url, cache file), skip extract/build
+//! ```
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::cli::cache::{CacheArgs, CacheSeedArgs, CacheSubcommand};
+use crate::config::Config;
+use crate::core::process::priority::ProcessPriority;
+use crate::error::Result;
+use crate::task::tasks::explorerpp::ExplorerPPTask;
+use crate::task::tasks::stylesheets::StylesheetsTask;
+use crate::task::tools::downloader::DownloaderTool;
+use crate::task::tools::{Tool, ToolContext};
+
+/// Main handler for cache command.
+///
+/// # Errors
+///
+/// Returns an error if `paths.cache` is not configured or a download fails.
+pub async fn run_cache_command(
+    args: &CacheArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    match &args.subcommand {
+        CacheSubcommand::Seed(seed_args) => {
+            seed_cache(seed_args, config, dry_run, priority, cancel_token).await
+        }
+    }
+}
+
+/// Returns the `(url, cache file)` pair of every archive downloaded by tasks.
+///
+/// # Errors
+///
+/// Returns an error if `paths.cache` is not configured.
+pub fn cache_downloads(config: &Config) -> Result<Vec<(String, PathBuf)>> {
+    let mut downloads = StylesheetsTask::downloads(config)?;
+    downloads.extend(ExplorerPPTask::downloads(config)?);
+    Ok(downloads)
+}
+
+async fn seed_cache(
+    seed_args: &CacheSeedArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let config = Arc::new(config.clone());
+    let ctx = ToolContext::new(Arc::clone(&config), cancel_token.clone(), dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority);
+
+    let downloads = cache_downloads(&config)?;
+    let force = seed_args.force || config.global.clean_download_actions.redownload;
+
+    for (url, file) in &downloads {
+        info!(url = %url, file = %file.display(), "Seeding download cache");
+        DownloaderTool::new()
+            .url(url)
+            .file(file)
+            .force(force)
+            .run(&ctx)
+            .await
+            .with_context(|| format!("failed to download {url}"))?;
+    }
+
+    if !dry_run {
+        println!("{} archive(s) cached", downloads.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Clean command implementation.
+//!
+//! ```text
+//! clean --> CleanArgs::clean_flags --> TaskManager (clean phase only)
+//!       --> every task of the default tree, or the resolved task names
+//! ```
+
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+use crate::cli::clean::CleanArgs;
+use crate::config::Config;
+use crate::core::process::priority::ProcessPriority;
+use crate::error::Result;
+use crate::task::manager::TaskManager;
+use crate::task::registry::TaskRegistry;
+use crate::task::registry::builtin::{default_task_tree, task_from_name};
+
+/// Main handler for clean command.
+///
+/// Runs the clean phase of the selected tasks with the selected
+/// [`CleanFlags`](crate::task::CleanFlags), without fetching or building.
+///
+/// # Errors
+///
+/// Returns an error if no clean action is selected, task resolution fails, or
+/// a task fails to clean.
+pub async fn run_clean_command(
+    args: &CleanArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let clean_flags = args.clean_flags();
+    if clean_flags.is_empty() {
+        anyhow::bail!(
+            "Nothing to clean, pass --redownload, --reextract, --reconfigure, --rebuild or --all"
+        );
+    }
+
+    let mut config = config.clone();
+    if args.ignore_uncommitted {
+        config.global.ignore_uncommitted = true;
+    }
+    let config = Arc::new(config);
+
+    let tasks = if args.tasks.is_empty() {
+        default_task_tree()
+    } else {
+        let names = TaskRegistry::for_config(&config).resolve(&args.tasks)?;
+        tracing::info!(tasks = ?names, "Resolved tasks to clean");
+        names.iter().map(|name| task_from_name(name)).collect()
+    };
+
+    let mut manager = TaskManager::new(Arc::clone(&config), cancel_token)
+        .with_dry_run(dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority)
+        .with_clean_flags(clean_flags)
+        .with_do_clean(true)
+        .with_do_fetch(false)
+        .with_do_build(false);
+
+    for task in tasks {
+        manager.add(task);
+    }
+    manager.run_all().await?;
+
+    tracing::info!(?clean_flags, "Clean completed successfully");
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Shell completion command implementation.
+//!
+//! ```text
+//! completions <shell>
+//!   Cli::command() --> clap_complete::generate(shell, "mob") --> stdout
+//! ```
+//!
+//! Usage: `mob completions zsh > _mob`.
+
+use std::io::Write;
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use crate::cli::Cli;
+use crate::error::Result;
+
+/// Main handler for completions command.
+///
+/// # Errors
+///
+/// Currently infallible; returns `Result` for consistency with other commands.
+pub fn run_completions_command(shell: Shell) -> Result<()> {
+    write_completions(shell, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Writes the completion script for `shell` to `out`.
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+#[cfg(test)]
+mod tests;
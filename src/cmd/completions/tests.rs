@@ -0,0 +1,24 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use clap_complete::Shell;
+
+use super::write_completions;
+
+fn completions(shell: Shell) -> String {
+    let mut out = Vec::new();
+    write_completions(shell, &mut out);
+    String::from_utf8(out).expect("utf-8 script")
+}
+
+#[test]
+fn test_write_completions_lists_commands() {
+    for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+        let script = completions(shell);
+        assert!(script.contains("mob"), "{shell}: no program name");
+        assert!(script.contains("cmake-config"), "{shell}: no subcommands");
+        assert!(script.contains("low-priority"), "{shell}: no global flags");
+    }
+}
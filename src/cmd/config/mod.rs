@@ -0,0 +1,166 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Config-related commands for mob-rs.
+//!
+//! ```text
+//! options [--filter <glob>] [--changed]
+//!   config.options() --> keep keys matching --filter
+//!                    --> keep values != Config::default().options()  (--changed)
+//!                    --> format_option_lines()
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::cli::cmake::{CmakeConfigArgs, CmakeVariable};
+use crate::cli::config::{ConfigArgs, ConfigMigrateArgs, ConfigSubcommand};
+use crate::cli::options::OptionsArgs;
+use crate::config::migrate::{MIGRATIONS, MigrationNote, migrate_config};
+use crate::config::paths::IssueSeverity;
+use crate::config::{Config, format_option_lines};
+use crate::error::Result;
+use anyhow::{Context, anyhow};
+use tracing::{info, warn};
+use wax::Program as _;
+
+/// Display current configuration options.
+///
+/// # Errors
+///
+/// Returns an error if `--filter` is not a valid glob.
+pub fn run_options_command(args: &OptionsArgs, config: &Config) -> Result<()> {
+    for line in format_option_lines(&select_options(args, config)?) {
+        println!("{line}");
+    }
+    Ok(())
+}
+
+/// Returns the options of `config` kept by `--filter` and `--changed`.
+///
+/// # Errors
+///
+/// Returns an error if `--filter` is not a valid glob.
+pub fn select_options(args: &OptionsArgs, config: &Config) -> Result<BTreeMap<String, String>> {
+    let glob = args
+        .filter
+        .as_deref()
+        .map(|pattern| {
+            wax::Glob::new(pattern).with_context(|| format!("Invalid glob pattern: {pattern}"))
+        })
+        .transpose()?;
+    let defaults = args.changed.then(|| Config::default().options());
+
+    Ok(config
+        .options()
+        .into_iter()
+        .filter(|(key, _)| glob.as_ref().is_none_or(|glob| glob.is_match(key.as_str())))
+        .filter(|(key, value)| {
+            defaults
+                .as_ref()
+                .is_none_or(|defaults| defaults.get(key) != Some(value))
+        })
+        .collect())
+}
+
+/// Display loaded configuration files.
+pub fn run_inis_command(config_files: &[String]) {
+    if config_files.is_empty() {
+        println!("No configuration files loaded");
+    } else {
+        for line in config_files {
+            println!("{line}");
+        }
+    }
+}
+
+/// Run the cmake-config command.
+///
+/// # Errors
+///
+/// Returns an error if `paths.install` is not configured when querying `InstallPrefix`.
+pub fn run_cmake_config_command(args: &CmakeConfigArgs, config: &Config) -> Result<()> {
+    match args.variable {
+        CmakeVariable::PrefixPath => {
+            println!("{}", config.paths.cmake_prefix_path());
+            Ok(())
+        }
+        CmakeVariable::InstallPrefix => config.paths.cmake_install_prefix().map_or_else(
+            || Err(anyhow!("paths.install not configured")),
+            |path| {
+                println!("{path}");
+                Ok(())
+            },
+        ),
+    }
+}
+
+/// Run the config command.
+///
+/// `load_config` is only called by subcommands that check the loaded config,
+/// so `config migrate` still works when the current config doesn't load.
+///
+/// # Errors
+///
+/// Returns an error if the input cannot be read or parsed, the output cannot
+/// be written, the config cannot be loaded, or validation finds an error.
+pub fn run_config_command(
+    args: &ConfigArgs,
+    load_config: impl FnOnce() -> Result<Config>,
+) -> Result<()> {
+    match &args.subcommand {
+        ConfigSubcommand::Migrate(migrate_args) => run_config_migrate(migrate_args),
+        ConfigSubcommand::Validate => run_config_validate(&load_config()?),
+    }
+}
+
+/// Prints one line per `[paths]` problem; fails if any is an error.
+fn run_config_validate(config: &Config) -> Result<()> {
+    let issues = config.paths.validate();
+    let mut errors = 0;
+    for issue in &issues {
+        match issue.severity() {
+            IssueSeverity::Error => {
+                errors += 1;
+                println!("error: {issue}");
+            }
+            IssueSeverity::Warning => println!("warning: {issue}"),
+        }
+    }
+
+    if errors > 0 {
+        anyhow::bail!("config has {errors} error(s)");
+    }
+    if issues.is_empty() {
+        println!("config OK");
+    }
+    Ok(())
+}
+
+fn run_config_migrate(args: &ConfigMigrateArgs) -> Result<()> {
+    let contents = std::fs::read_to_string(&args.input)
+        .with_context(|| format!("failed to read {}", args.input.display()))?;
+    let migration = migrate_config(&contents, MIGRATIONS)
+        .with_context(|| format!("failed to parse {}", args.input.display()))?;
+
+    for note in migration.notes() {
+        match note {
+            MigrationNote::Renamed { .. } => info!("{note}"),
+            MigrationNote::Removed { .. } => warn!("{note}"),
+        }
+    }
+
+    std::fs::write(&args.output, migration.output())
+        .with_context(|| format!("failed to write {}", args.output.display()))?;
+    info!(
+        "migrated {} to {} ({} change(s))",
+        args.input.display(),
+        args.output.display(),
+        migration.notes().len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::path::PathBuf;
+
+use super::select_options;
+use crate::cli::options::OptionsArgs;
+use crate::config::Config;
+
+fn args(filter: Option<&str>, changed: bool) -> OptionsArgs {
+    OptionsArgs {
+        filter: filter.map(str::to_string),
+        changed,
+    }
+}
+
+#[test]
+fn test_select_options_all() {
+    let config = Config::default();
+    let options = select_options(&args(None, false), &config).unwrap();
+    assert_eq!(options, config.options());
+}
+
+#[test]
+fn test_select_options_filter() {
+    let config = Config::default();
+    let options = select_options(&args(Some("transifex.*"), false), &config).unwrap();
+
+    assert!(!options.is_empty());
+    assert!(options.keys().all(|key| key.starts_with("transifex.")));
+    assert!(options.contains_key("transifex.team"));
+}
+
+#[test]
+fn test_select_options_changed() {
+    let mut config = Config::default();
+    config.versions.usvfs = "v0.5.0".to_string();
+    config.paths.prefix = Some(PathBuf::from("/test/prefix"));
+
+    let options = select_options(&args(None, true), &config).unwrap();
+    assert_eq!(
+        options.keys().collect::<Vec<_>>(),
+        ["paths.prefix", "versions.usvfs"]
+    );
+
+    let options = select_options(&args(Some("versions.*"), true), &config).unwrap();
+    assert_eq!(options.keys().collect::<Vec<_>>(), ["versions.usvfs"]);
+
+    assert!(
+        select_options(&args(None, true), &Config::default())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_select_options_invalid_glob() {
+    let error = select_options(&args(Some("paths.{"), false), &Config::default()).unwrap_err();
+    assert!(error.to_string().contains("Invalid glob pattern"));
+}
@@ -0,0 +1,211 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Diagnostic bundle written by `--dump-on-failure`.
+//!
+//! ```text
+//! command error --> write_failure_bundle(dir)
+//!   dir/mob-failure-<unix-secs>/
+//!     error.txt          full error chain
+//!     version.txt        mob version, OS, arch, profile
+//!     config.txt         resolved options (secrets hidden)
+//!     log.txt            copy of the log file
+//!     failed-command.txt failed process of the error + captured output
+//!     tools.txt          first line of each tool's --version
+//! ```
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+
+use crate::config::Config;
+use crate::config::types::ToolsConfig;
+use crate::core::process::builder::{ProcessBuilder, ProcessFlags};
+use crate::core::process::failure::ProcessFailure;
+use crate::error::Result;
+use crate::utility::fs::text::LineEndings;
+
+/// Timeout for each tool version probe.
+const TOOL_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Environment variable name fragments whose values are hidden in `config.txt`.
+const SENSITIVE_ENV_WORDS: &[&str] = &["TOKEN", "KEY", "SECRET", "PASSWORD"];
+
+/// Writes a diagnostic bundle for `error` into a new directory under `dir`.
+///
+/// `config` is `None` when configuration could not be loaded; tool paths then
+/// fall back to their defaults. Returns the bundle directory.
+///
+/// # Errors
+///
+/// Returns an error if the bundle directory or one of its files cannot be written.
+pub async fn write_failure_bundle(
+    dir: &Path,
+    config: Option<&Config>,
+    log_file: Option<&Path>,
+    error: &anyhow::Error,
+) -> Result<PathBuf> {
+    let failure = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ProcessFailure>());
+    let endings = config.map_or_else(LineEndings::default, |c| c.global.line_endings);
+
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let bundle = dir.join(format!("mob-failure-{secs}"));
+    tokio::fs::create_dir_all(&bundle)
+        .await
+        .with_context(|| format!("Failed to create bundle directory: {}", bundle.display()))?;
+
+    write_file(&bundle, endings, "error.txt", &format_error(error)).await?;
+    write_file(&bundle, endings, "version.txt", &version_info().join("\n")).await?;
+    write_file(&bundle, endings, "config.txt", &format_config(config)).await?;
+
+    let failed = failure.map_or_else(
+        || "no process failure recorded\n".to_string(),
+        |f| {
+            let mut out = String::new();
+            let _ = writeln!(out, "command:   {}", f.command());
+            if let Some(cwd) = f.cwd() {
+                let _ = writeln!(out, "cwd:       {}", cwd.display());
+            }
+            let _ = writeln!(out, "exit code: {}", f.exit_code());
+            let _ = writeln!(out, "\n--- stdout ---\n{}", f.stdout());
+            let _ = writeln!(out, "--- stderr ---\n{}", f.stderr());
+            out
+        },
+    );
+    write_file(&bundle, endings, "failed-command.txt", &failed).await?;
+
+    let log_file = log_file.map(Path::to_path_buf).or_else(|| {
+        config
+            .map(|c| c.global.log_file.clone())
+            .filter(|p| !p.as_os_str().is_empty())
+    });
+    let log_file = match log_file {
+        Some(path) if tokio::fs::metadata(&path).await.is_ok_and(|m| m.is_file()) => Some(path),
+        _ => None,
+    };
+    match log_file {
+        Some(path) => {
+            tokio::fs::copy(&path, bundle.join("log.txt"))
+                .await
+                .with_context(|| format!("Failed to copy log file: {}", path.display()))?;
+        }
+        None => write_file(&bundle, endings, "log.txt", "no log file\n").await?,
+    }
+
+    let default_tools = ToolsConfig::default();
+    let tools = config.map_or(&default_tools, |c| &c.tools);
+    write_file(&bundle, endings, "tools.txt", &probe_tools(tools).await).await?;
+
+    Ok(bundle)
+}
+
+/// Returns mob's build information, one `key: value` per line.
+#[must_use]
+pub fn version_info() -> Vec<String> {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    vec![
+        format!("mob {}", env!("CARGO_PKG_VERSION")),
+        format!("os:      {}", std::env::consts::OS),
+        format!("arch:    {}", std::env::consts::ARCH),
+        format!("profile: {profile}"),
+    ]
+}
+
+async fn write_file(bundle: &Path, endings: LineEndings, name: &str, contents: &str) -> Result<()> {
+    let path = bundle.join(name);
+    tokio::fs::write(&path, endings.apply(contents))
+        .await
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+fn format_error(error: &anyhow::Error) -> String {
+    let mut out = format!("{error:#}\n\nCaused by:\n");
+    for (i, cause) in error.chain().enumerate() {
+        let _ = writeln!(out, "  {i}: {cause}");
+    }
+    out
+}
+
+/// Formats resolved options, hiding values of sensitive `env.*` entries.
+///
+/// `format_options()` already hides the Transifex key.
+fn format_config(config: Option<&Config>) -> String {
+    let Some(config) = config else {
+        return "configuration could not be loaded\n".to_string();
+    };
+
+    let mut out = String::new();
+    for line in config.format_options() {
+        let line = match line.split_once(" = ") {
+            Some((key, _)) if is_sensitive_env(key.trim_end()) => format!("{key} = [hidden]"),
+            _ => line,
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+fn is_sensitive_env(key: &str) -> bool {
+    let Some(name) = key.strip_prefix("env.") else {
+        return false;
+    };
+    let name = name.to_ascii_uppercase();
+    SENSITIVE_ENV_WORDS.iter().any(|word| name.contains(word))
+}
+
+async fn probe_tools(tools: &ToolsConfig) -> String {
+    let probes: [(&str, &Path, &str); 5] = [
+        ("git", Path::new("git"), "--version"),
+        ("cmake", &tools.cmake, "--version"),
+        ("7z", &tools.sevenz, "i"),
+        ("tx", &tools.tx, "--version"),
+        ("lrelease", &tools.lrelease, "-version"),
+    ];
+
+    let mut out = String::new();
+    for (name, program, arg) in probes {
+        let version = probe_version(program, arg)
+            .await
+            .unwrap_or_else(|| "not found".to_string());
+        let _ = writeln!(out, "{name:<9}{version}");
+    }
+    out
+}
+
+/// Returns the first non-empty output line of `program arg`.
+async fn probe_version(program: &Path, arg: &str) -> Option<String> {
+    let output = ProcessBuilder::new(program)
+        .arg(arg)
+        .flag(ProcessFlags::ALLOW_FAILURE)
+        .capture_output()
+        .quiet()
+        .timeout(TOOL_PROBE_TIMEOUT)
+        .run()
+        .await
+        .ok()?;
+
+    output
+        .stdout()
+        .lines()
+        .chain(output.stderr().lines())
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,86 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use super::{format_config, is_sensitive_env, version_info, write_failure_bundle};
+use crate::config::Config;
+
+#[test]
+fn test_is_sensitive_env() {
+    assert!(is_sensitive_env("env.GITHUB_TOKEN"));
+    assert!(is_sensitive_env("env.api_key"));
+    assert!(!is_sensitive_env("env.VCPKG_ROOT"));
+    assert!(!is_sensitive_env("transifex.team"));
+}
+
+#[test]
+fn test_format_config_hides_secrets() {
+    let mut config = Config::default();
+    config.transifex.key = "secret-key".into();
+    config.env.insert("TX_TOKEN".into(), "secret-token".into());
+    config.env.insert("VCPKG_ROOT".into(), "C:/vcpkg".into());
+
+    let text = format_config(Some(&config));
+
+    assert!(!text.contains("secret-key"));
+    assert!(!text.contains("secret-token"));
+    assert!(text.contains("C:/vcpkg"));
+    assert_eq!(format_config(None), "configuration could not be loaded\n");
+}
+
+#[test]
+fn test_version_info() {
+    let info = version_info();
+    assert_eq!(info[0], format!("mob {}", env!("CARGO_PKG_VERSION")));
+}
+
+#[tokio::test]
+async fn test_write_failure_bundle() {
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let log = temp.path().join("mob.log");
+    std::fs::write(&log, "log line\n").expect("write log");
+
+    let error = anyhow::anyhow!("inner").context("outer");
+    let bundle = write_failure_bundle(temp.path(), None, Some(&log), &error)
+        .await
+        .expect("bundle");
+
+    for name in [
+        "error.txt",
+        "version.txt",
+        "config.txt",
+        "log.txt",
+        "failed-command.txt",
+        "tools.txt",
+    ] {
+        assert!(bundle.join(name).is_file(), "missing {name}");
+    }
+    let error_text = std::fs::read_to_string(bundle.join("error.txt")).expect("read");
+    assert!(error_text.starts_with("outer: inner"));
+    let log_text = std::fs::read_to_string(bundle.join("log.txt")).expect("read");
+    assert_eq!(log_text, "log line\n");
+}
+
+#[tokio::test]
+async fn test_write_failure_bundle_includes_failed_process() {
+    use anyhow::Context;
+
+    use crate::core::process::builder::ProcessBuilder;
+
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let error = ProcessBuilder::raw("exit 57")
+        .capture_output()
+        .run()
+        .await
+        .context("outer")
+        .expect_err("process should fail");
+
+    let bundle = write_failure_bundle(temp.path(), None, None, &error)
+        .await
+        .expect("bundle");
+
+    let failed = std::fs::read_to_string(bundle.join("failed-command.txt")).expect("read");
+    assert!(failed.contains("exit 57"));
+    assert!(failed.contains("exit code: 57"));
+}
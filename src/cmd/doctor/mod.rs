@@ -0,0 +1,353 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Environment check command implementation.
+//!
+//! ```text
+//! doctor --list-qt
+//!   QtRoots::from_env(config)
+//!     QTDIR                         <prefix>
+//!     paths.qt_install              <prefix>
+//!     C:/Qt, ~/Qt, /opt/Qt          <root>/<version>/<compiler>
+//!     paths.vcpkg, VCPKG_ROOT       <root>/installed/<triplet> (tools/Qt6/bin)
+//!        |
+//!        v
+//!   find_qt_installs() --> prefixes with bin/qmake or bin/qtpaths
+//!        |
+//!        v
+//!   format_qt_installs() --> version, paths, suggested [paths] lines
+//! ```
+//!
+//! Discovery is read-only and only looks at directory layouts; nothing is run.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::cli::doctor::DoctorArgs;
+use crate::config::Config;
+use crate::error::Result;
+
+/// Executables whose presence in `bin/` marks a Qt prefix.
+const QT_TOOLS: [&str; 4] = ["qmake", "qmake6", "qtpaths", "qtpaths6"];
+
+/// Where a Qt installation was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QtSource {
+    /// The `QTDIR` environment variable.
+    QtDir,
+    /// `paths.qt_install` in the config.
+    Config,
+    /// The Qt online installer or aqt layout.
+    Installer,
+    /// A vcpkg `installed/<triplet>` tree.
+    Vcpkg,
+}
+
+impl std::fmt::Display for QtSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::QtDir => "QTDIR",
+            Self::Config => "paths.qt_install",
+            Self::Installer => "installer",
+            Self::Vcpkg => "vcpkg",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One discovered Qt installation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QtInstall {
+    /// Qt version, if it could be determined.
+    version: Option<String>,
+    /// Where the installation was found.
+    source: QtSource,
+    /// Installation prefix (`paths.qt_install`).
+    prefix: PathBuf,
+    /// Directory containing `qmake`/`qtpaths` (`paths.qt_bin`).
+    bin: PathBuf,
+    /// Directory containing the `.qm` files (`paths.qt_translations`).
+    translations: PathBuf,
+}
+
+impl QtInstall {
+    /// Returns the Qt version, if it could be determined.
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns where the installation was found.
+    #[must_use]
+    pub const fn source(&self) -> QtSource {
+        self.source
+    }
+
+    /// Returns the installation prefix (`paths.qt_install`).
+    #[must_use]
+    pub fn prefix(&self) -> &Path {
+        &self.prefix
+    }
+
+    /// Returns the directory containing `qmake`/`qtpaths` (`paths.qt_bin`).
+    #[must_use]
+    pub fn bin(&self) -> &Path {
+        &self.bin
+    }
+
+    /// Returns the directory containing the `.qm` files
+    /// (`paths.qt_translations`).
+    #[must_use]
+    pub fn translations(&self) -> &Path {
+        &self.translations
+    }
+}
+
+/// Directories scanned for Qt installations.
+#[derive(Debug, Clone, Default)]
+pub struct QtRoots {
+    /// Prefixes checked directly: `QTDIR` and `paths.qt_install`.
+    prefixes: Vec<(QtSource, PathBuf)>,
+    /// Installer/aqt roots such as `C:/Qt`.
+    installer_roots: Vec<PathBuf>,
+    /// vcpkg roots.
+    vcpkg_roots: Vec<PathBuf>,
+}
+
+impl QtRoots {
+    /// Adds a prefix that is checked directly.
+    #[must_use]
+    pub fn with_prefix(mut self, source: QtSource, prefix: PathBuf) -> Self {
+        self.prefixes.push((source, prefix));
+        self
+    }
+
+    /// Adds an installer/aqt root.
+    #[must_use]
+    pub fn with_installer_root(mut self, root: PathBuf) -> Self {
+        self.installer_roots.push(root);
+        self
+    }
+
+    /// Adds a vcpkg root.
+    #[must_use]
+    pub fn with_vcpkg_root(mut self, root: PathBuf) -> Self {
+        self.vcpkg_roots.push(root);
+        self
+    }
+
+    /// Returns the prefixes checked directly.
+    #[must_use]
+    pub fn prefixes(&self) -> &[(QtSource, PathBuf)] {
+        &self.prefixes
+    }
+
+    /// Returns the installer/aqt roots.
+    #[must_use]
+    pub fn installer_roots(&self) -> &[PathBuf] {
+        &self.installer_roots
+    }
+
+    /// Returns the vcpkg roots.
+    #[must_use]
+    pub fn vcpkg_roots(&self) -> &[PathBuf] {
+        &self.vcpkg_roots
+    }
+
+    /// Collects the roots from the environment and `config`.
+    #[must_use]
+    pub fn from_env(config: &Config) -> Self {
+        let mut roots = Self::default();
+
+        if let Some(qtdir) = std::env::var_os("QTDIR") {
+            roots.prefixes.push((QtSource::QtDir, PathBuf::from(qtdir)));
+        }
+        if let Some(qt_install) = &config.paths.qt_install {
+            roots.prefixes.push((QtSource::Config, qt_install.clone()));
+        }
+
+        roots.installer_roots.push(PathBuf::from("C:/Qt"));
+        for home in ["USERPROFILE", "HOME"] {
+            if let Some(home) = std::env::var_os(home) {
+                roots.installer_roots.push(PathBuf::from(home).join("Qt"));
+            }
+        }
+        roots.installer_roots.push(PathBuf::from("/opt/Qt"));
+
+        if let Some(vcpkg) = &config.paths.vcpkg {
+            roots.vcpkg_roots.push(vcpkg.clone());
+        }
+        if let Some(vcpkg) = std::env::var_os("VCPKG_ROOT") {
+            roots.vcpkg_roots.push(PathBuf::from(vcpkg));
+        }
+
+        roots
+    }
+}
+
+/// Main handler for doctor command.
+///
+/// # Errors
+///
+/// Currently infallible; returns `Result` for consistency with other commands.
+pub fn run_doctor_command(args: &DoctorArgs, config: &Config) -> Result<()> {
+    if args.list_qt {
+        let installs = find_qt_installs(&QtRoots::from_env(config));
+        print!(
+            "{}",
+            format_qt_installs(&installs, config.paths.qt_install.as_deref())
+        );
+    }
+    Ok(())
+}
+
+/// Finds every Qt installation under `roots`, skipping duplicate prefixes.
+#[must_use]
+pub fn find_qt_installs(roots: &QtRoots) -> Vec<QtInstall> {
+    let mut installs = Vec::new();
+
+    for (source, prefix) in &roots.prefixes {
+        if has_qt_tool(&prefix.join("bin")) {
+            installs.push(standard_install(*source, prefix.clone(), None));
+        }
+    }
+
+    for root in &roots.installer_roots {
+        for version_dir in subdirs(root) {
+            let Some(version) = file_name(&version_dir) else {
+                continue;
+            };
+            if !version.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            for prefix in subdirs(&version_dir) {
+                if has_qt_tool(&prefix.join("bin")) {
+                    installs.push(standard_install(
+                        QtSource::Installer,
+                        prefix,
+                        Some(version.clone()),
+                    ));
+                }
+            }
+        }
+    }
+
+    for root in &roots.vcpkg_roots {
+        for prefix in subdirs(&root.join("installed")) {
+            let bin = prefix.join("tools").join("Qt6").join("bin");
+            if has_qt_tool(&bin) {
+                installs.push(QtInstall {
+                    version: cmake_version(&prefix.join("share").join("Qt6")),
+                    source: QtSource::Vcpkg,
+                    translations: prefix.join("translations").join("Qt6"),
+                    bin,
+                    prefix,
+                });
+            }
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    installs.retain(|install| seen.insert(install.prefix.clone()));
+    installs
+}
+
+/// Formats `installs` with the `[paths]` lines that select each one.
+///
+/// The installation matching `configured` is marked as in use.
+#[must_use]
+pub fn format_qt_installs(installs: &[QtInstall], configured: Option<&Path>) -> String {
+    let mut out = String::new();
+    if installs.is_empty() {
+        out.push_str(
+            "No Qt installation found. Install Qt (see README) or set \
+             paths.qt_install in mob.toml.\n",
+        );
+        return out;
+    }
+
+    for (index, install) in installs.iter().enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        let version = install.version.as_deref().unwrap_or("(unknown version)");
+        let in_use = if configured == Some(install.prefix.as_path()) {
+            ", in use"
+        } else {
+            ""
+        };
+        let _ = writeln!(out, "Qt {version} ({}{in_use})", install.source);
+        let _ = writeln!(out, "  prefix:       {}", install.prefix.display());
+        let _ = writeln!(out, "  bin:          {}", install.bin.display());
+        let _ = writeln!(out, "  translations: {}", install.translations.display());
+        out.push_str("  config:\n    [paths]\n");
+        let _ = writeln!(out, "    qt_install = '{}'", install.prefix.display());
+        if install.bin != install.prefix.join("bin") {
+            let _ = writeln!(out, "    qt_bin = '{}'", install.bin.display());
+        }
+        if install.translations != install.prefix.join("translations") {
+            let _ = writeln!(
+                out,
+                "    qt_translations = '{}'",
+                install.translations.display()
+            );
+        }
+    }
+    out
+}
+
+/// An installation with the default `bin/` and `translations/` layout.
+fn standard_install(source: QtSource, prefix: PathBuf, version: Option<String>) -> QtInstall {
+    QtInstall {
+        version: cmake_version(&prefix.join("lib").join("cmake").join("Qt6"))
+            .or_else(|| cmake_version(&prefix.join("lib").join("cmake").join("Qt5Core")))
+            .or(version),
+        source,
+        bin: prefix.join("bin"),
+        translations: prefix.join("translations"),
+        prefix,
+    }
+}
+
+/// Returns whether `bin` contains `qmake` or `qtpaths`.
+fn has_qt_tool(bin: &Path) -> bool {
+    QT_TOOLS
+        .iter()
+        .any(|tool| bin.join(tool).is_file() || bin.join(format!("{tool}.exe")).is_file())
+}
+
+/// Reads `PACKAGE_VERSION` from the `*ConfigVersion.cmake` file in `dir`.
+fn cmake_version(dir: &Path) -> Option<String> {
+    let name = file_name(dir)?;
+    let content = std::fs::read_to_string(dir.join(format!("{name}ConfigVersion.cmake"))).ok()?;
+    content.lines().find_map(|line| {
+        let rest = line.trim().strip_prefix("set(PACKAGE_VERSION")?;
+        let version = rest.trim().trim_end_matches(')').trim().trim_matches('"');
+        (!version.is_empty()).then(|| version.to_string())
+    })
+}
+
+/// Sorted subdirectories of `dir`; empty if it can't be read.
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::fs;
+use std::path::Path;
+
+use super::{QtRoots, QtSource, find_qt_installs, format_qt_installs};
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().expect("parent")).expect("create dirs");
+    fs::write(path, content).expect("write file");
+}
+
+#[test]
+fn test_find_qt_installs_installer_and_vcpkg_layouts() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let qt_root = temp.path().join("Qt");
+    write(&qt_root.join("6.7.2/msvc2022_64/bin/qmake.exe"), "");
+    write(
+        &qt_root.join("6.7.2/msvc2022_64/lib/cmake/Qt6/Qt6ConfigVersion.cmake"),
+        "set(PACKAGE_VERSION \"6.7.2\")\n",
+    );
+    write(&qt_root.join("Tools/QtCreator/bin/qmake.exe"), "");
+    fs::create_dir_all(qt_root.join("6.8.0/msvc2022_64/bin")).expect("create dirs");
+
+    let vcpkg = temp.path().join("vcpkg");
+    let triplet = vcpkg.join("installed/x64-windows");
+    write(&triplet.join("tools/Qt6/bin/qtpaths6.exe"), "");
+    write(
+        &triplet.join("share/Qt6/Qt6ConfigVersion.cmake"),
+        "set(PACKAGE_VERSION \"6.8.1\")\n",
+    );
+
+    let roots = QtRoots::default()
+        .with_prefix(QtSource::Config, qt_root.join("6.7.2/msvc2022_64"))
+        .with_installer_root(qt_root.clone())
+        .with_vcpkg_root(vcpkg);
+    let installs = find_qt_installs(&roots);
+
+    assert_eq!(installs.len(), 2);
+    assert_eq!(installs[0].source(), QtSource::Config);
+    assert_eq!(installs[0].version(), Some("6.7.2"));
+    assert_eq!(installs[1].source(), QtSource::Vcpkg);
+    assert_eq!(installs[1].version(), Some("6.8.1"));
+    assert_eq!(installs[1].bin(), triplet.join("tools/Qt6/bin"));
+    assert_eq!(installs[1].translations(), triplet.join("translations/Qt6"));
+}
+
+#[test]
+fn test_format_qt_installs_suggests_config_lines() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let prefix = temp.path().join("Qt/6.7.2/msvc2022_64");
+    write(&prefix.join("bin/qmake"), "");
+    let roots = QtRoots::default().with_installer_root(temp.path().join("Qt"));
+    let installs = find_qt_installs(&roots);
+
+    let text = format_qt_installs(&installs, Some(&prefix));
+
+    assert!(text.starts_with("Qt 6.7.2 (installer, in use)\n"));
+    assert!(text.contains(&format!("    qt_install = '{}'\n", prefix.display())));
+    assert!(!text.contains("qt_bin ="));
+}
+
+#[test]
+fn test_format_qt_installs_empty() {
+    assert!(format_qt_installs(&[], None).starts_with("No Qt installation found."));
+}
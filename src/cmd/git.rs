@@ -5,17 +5,37 @@
 
 //! Git command implementation for mob-rs.
 
-use crate::cli::git::{GitArgs, GitSubcommand, IgnoreTsState};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::bail;
+use tokio_util::sync::CancellationToken;
+
+use crate::cli::git::{ExecArgs, GitArgs, GitSubcommand, IgnoreTsState, UnshallowArgs};
 use crate::config::Config;
+use crate::core::process::priority::ProcessPriority;
 use crate::error::Result;
-use crate::git::ops::{add_remote_to_repos, list_branches, set_ignore_ts, set_remotes_for_all};
+use crate::git::discovery::get_repos;
+use crate::git::ops::{
+    OnError, add_remote_to_repos, exec_in_repos, format_repo_statuses, list_branches,
+    list_shallow_repos, repo_statuses, set_ignore_ts, set_remotes_for_all,
+};
+use crate::git::query::is_shallow;
+use crate::task::tools::git::GitTool;
+use crate::task::tools::{Tool, ToolContext};
 
 /// Main handler for git command.
 ///
 /// # Errors
 ///
 /// Returns an error if any git operation fails.
-pub fn run_git_command(args: &GitArgs, config: &Config, dry_run: bool) -> Result<()> {
+pub async fn run_git_command(
+    args: &GitArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
     match &args.subcommand {
         GitSubcommand::SetRemotes(sr) => {
             let key_path = sr.key.as_deref();
@@ -84,5 +104,103 @@ pub fn run_git_command(args: &GitArgs, config: &Config, dry_run: bool) -> Result
                 Err(e)
             }
         },
+        GitSubcommand::Unshallow(us) => run_unshallow(us, config, dry_run, priority, cancel_token)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to unshallow: {e}");
+                e
+            }),
+        GitSubcommand::Exec(ex) => run_exec(ex, config, dry_run),
+        GitSubcommand::Status => match repo_statuses(config) {
+            Ok(statuses) => {
+                print!("{}", format_repo_statuses(&statuses));
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Failed to query repo status: {e}");
+                Err(e)
+            }
+        },
+    }
+}
+
+/// Runs `git <args>` in every repo, printing each repo's output under a header.
+fn run_exec(args: &ExecArgs, config: &Config, dry_run: bool) -> Result<()> {
+    let command = format!("git {}", args.args.join(" "));
+
+    if dry_run {
+        for repo in get_repos(config)? {
+            println!("[DRY-RUN] would run `{command}` in {}", repo.display());
+        }
+        return Ok(());
+    }
+
+    let on_error = if args.continue_on_error {
+        OnError::Continue
+    } else {
+        OnError::Stop
+    };
+    let failed = exec_in_repos(config, &args.args, on_error, |repo, result| {
+        println!("==> {} <==", repo.display());
+        match result {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => println!("{output}"),
+            Err(e) => eprintln!("{e:#}"),
+        }
+    })?;
+
+    if !failed.is_empty() {
+        for repo in &failed {
+            eprintln!("failed: {}", repo.display());
+        }
+        bail!("`{command}` failed in {} repos", failed.len());
+    }
+    Ok(())
+}
+
+/// Fetches the full history of every shallow repo, or only of `args.path`.
+async fn run_unshallow(
+    args: &UnshallowArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let repos: Vec<PathBuf> = match &args.path {
+        Some(path) => {
+            if !is_shallow(path)? {
+                println!("{} is not a shallow clone", path.display());
+                return Ok(());
+            }
+            vec![path.clone()]
+        }
+        None => list_shallow_repos(config)?,
+    };
+
+    if repos.is_empty() {
+        println!("No shallow clones found");
+        return Ok(());
+    }
+
+    let config = Arc::new(config.clone());
+    let ctx = ToolContext::new(Arc::clone(&config), cancel_token.clone(), dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority);
+
+    let mut failed = 0;
+    for repo in &repos {
+        let tool = GitTool::new().path(repo).unshallow_op();
+        match tool.run(&ctx).await {
+            Ok(()) => println!("{}", repo.display()),
+            Err(e) => {
+                eprintln!("{}: {e:#}", repo.display());
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{failed} of {} repos could not be unshallowed", repos.len());
     }
+    Ok(())
 }
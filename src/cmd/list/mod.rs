@@ -0,0 +1,171 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! List command implementation for mob-rs.
+//!
+//! ```text
+//! list [--json]
+//!   TaskRegistry (config tasks, MO projects, built-ins, aliases)
+//!        |
+//!        v
+//!   task names --> one per line
+//!              --> --json: [{ name, enabled, branch }] from task_config(name)
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::cli::build::ListArgs;
+use crate::config::Config;
+use crate::error::Result;
+use crate::git::discovery::super_layout;
+use crate::task::UrlKind;
+use crate::task::registry::TaskRegistry;
+use crate::task::registry::builtin::task_from_name;
+
+/// One task in the `list --json` output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListEntry {
+    /// Task name.
+    pub name: String,
+    /// `enabled` from the task's resolved config.
+    pub enabled: bool,
+    /// `mo_branch` from the task's resolved config.
+    pub branch: String,
+}
+
+/// Main handler for list command.
+///
+/// # Errors
+///
+/// Returns an error if task resolution fails.
+pub fn run_list_command(args: &ListArgs, config: &Config) -> Result<()> {
+    if args.aliases {
+        if config.aliases.is_empty() {
+            println!("No aliases defined");
+        } else {
+            for (name, targets) in &config.aliases {
+                println!("{} = {}", name, targets.join(", "));
+            }
+        }
+        return Ok(());
+    }
+
+    if args.dump.dump_super_layout {
+        if let Some(build) = &config.paths.build {
+            println!("{}", build.display());
+        }
+        for entry in super_layout(config)? {
+            println!("{}", entry.to_line());
+        }
+        return Ok(());
+    }
+
+    let registry = TaskRegistry::for_config(config);
+
+    if args.missing {
+        let names = if args.tasks.is_empty() {
+            registry.all_tasks().iter().cloned().collect()
+        } else {
+            registry.resolve(&args.tasks)?
+        };
+        let missing = missing_sources(&names, config)?;
+        if missing.is_empty() {
+            println!("All task sources are present");
+        } else {
+            for (task, path) in &missing {
+                println!("{task}  {}", path.display());
+            }
+        }
+        return Ok(());
+    }
+
+    if args.dump.dump_download_urls {
+        let names = if args.tasks.is_empty() {
+            registry.all_tasks().iter().cloned().collect()
+        } else {
+            registry.resolve(&args.tasks)?
+        };
+        for (kind, url) in external_urls(&names, config) {
+            println!("{:<9}  {url}", kind.as_str());
+        }
+        return Ok(());
+    }
+
+    let tasks_to_list = if args.all && !args.tasks.is_empty() {
+        match registry.resolve(&args.tasks) {
+            Ok(names) => names,
+            Err(e) => {
+                eprintln!("Failed to resolve task patterns: {e}");
+                return Err(e);
+            }
+        }
+    } else {
+        registry.all_tasks().iter().cloned().collect()
+    };
+
+    if args.output.json {
+        let entries = list_entries(&tasks_to_list, config);
+        let json =
+            serde_json::to_string_pretty(&entries).context("Failed to serialize task list")?;
+        println!("{json}");
+        return Ok(());
+    }
+
+    if tasks_to_list.is_empty() {
+        println!("No tasks found");
+    } else {
+        for task in &tasks_to_list {
+            println!("{task}");
+        }
+    }
+    Ok(())
+}
+
+/// Returns the `list --json` entries of `names`, with per-task overrides
+/// applied.
+#[must_use]
+pub fn list_entries(names: &[String], config: &Config) -> Vec<ListEntry> {
+    names
+        .iter()
+        .map(|name| {
+            let task_config = config.task_config(name);
+            ListEntry {
+                name: name.clone(),
+                enabled: task_config.enabled,
+                branch: task_config.mo_branch,
+            }
+        })
+        .collect()
+}
+
+/// Returns `(task, path)` for every task source not present locally.
+///
+/// # Errors
+///
+/// Returns an error if `paths.build` is not configured.
+fn missing_sources(names: &[String], config: &Config) -> Result<Vec<(String, PathBuf)>> {
+    let mut missing = Vec::new();
+    for name in names {
+        for path in task_from_name(name).missing_sources(config)? {
+            missing.push((name.clone(), path));
+        }
+    }
+    Ok(missing)
+}
+
+/// Returns the sorted, de-duplicated external URLs of the given tasks.
+fn external_urls(names: &[String], config: &Config) -> BTreeSet<(UrlKind, String)> {
+    names
+        .iter()
+        .flat_map(|name| task_from_name(name).external_urls(config))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use super::{ListEntry, list_entries};
+use crate::config::Config;
+use crate::config::merge::TaskConfigOverride;
+
+#[test]
+fn test_list_entries_apply_task_overrides() {
+    let mut config = Config::default();
+    config.tasks.insert(
+        "usvfs".to_string(),
+        TaskConfigOverride {
+            enabled: Some(false),
+            mo_branch: Some("dev".to_string()),
+            ..TaskConfigOverride::default()
+        },
+    );
+
+    let entries = list_entries(&["usvfs".to_string(), "explorerpp".to_string()], &config);
+
+    assert_eq!(
+        entries,
+        vec![
+            ListEntry {
+                name: "usvfs".to_string(),
+                enabled: false,
+                branch: "dev".to_string(),
+            },
+            ListEntry {
+                name: "explorerpp".to_string(),
+                enabled: true,
+                branch: "master".to_string(),
+            },
+        ]
+    );
+    let json = serde_json::to_string(&entries[0]).unwrap();
+    assert_eq!(json, r#"{"name":"usvfs","enabled":false,"branch":"dev"}"#);
+}
@@ -0,0 +1,230 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Build profile printed by `build --measure`.
+//!
+//! ```text
+//! task::timing     (phase wall time per task)  --,
+//!                                                 +--> MeasureReport
+//! process::timing  (wall time per process)     --'      |
+//!                                                        v
+//!   phases:     clean | fetch | build_and_install   format_lines()
+//!   categories: fetch | configure | build |         write_json(path, endings)
+//!               install | archive | other
+//!   slowest:    top 10 process invocations
+//! ```
+//!
+//! Process categories are derived from the program and its arguments, e.g.
+//! `cmake --build` counts as build and `cmake --install` as install.
+
+use std::cmp::Reverse;
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::core::process::timing::ProcessTiming;
+use crate::error::Result;
+use crate::task::Phase;
+use crate::task::timing::PhaseTiming;
+use crate::utility::fs::text::{LineEndings, write_text};
+
+/// Number of individual invocations listed in the report.
+const SLOWEST_COUNT: usize = 10;
+
+/// Longest command line shown in the text report.
+const MAX_COMMAND_LEN: usize = 100;
+
+/// What a process invocation was spent on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessCategory {
+    /// Cloning, pulling and fetching sources.
+    Fetch,
+    /// `CMake` configure steps.
+    Configure,
+    /// Compiling (`cmake --build`, `MSBuild`, `lrelease`).
+    Build,
+    /// `cmake --install`.
+    Install,
+    /// Creating or extracting archives.
+    Archive,
+    /// Anything else.
+    Other,
+}
+
+impl ProcessCategory {
+    /// Returns all categories in report order.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::Fetch,
+            Self::Configure,
+            Self::Build,
+            Self::Install,
+            Self::Archive,
+            Self::Other,
+        ]
+    }
+
+    /// Returns the display name for this category.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Fetch => "fetch",
+            Self::Configure => "configure",
+            Self::Build => "build",
+            Self::Install => "install",
+            Self::Archive => "archive",
+            Self::Other => "other",
+        }
+    }
+
+    /// Classifies a process invocation by its program and arguments.
+    #[must_use]
+    pub fn of(timing: &ProcessTiming) -> Self {
+        let args: Vec<&str> = timing.command().split_whitespace().skip(1).collect();
+        match timing.program().to_ascii_lowercase().as_str() {
+            "git" | "curl" => Self::Fetch,
+            "cmake" if args.contains(&"--build") => Self::Build,
+            "cmake" if args.contains(&"--install") => Self::Install,
+            "cmake" => Self::Configure,
+            "msbuild" | "ninja" | "lrelease" => Self::Build,
+            "7z" | "7za" => Self::Archive,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Time spent in one phase or category, in seconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TimeEntry {
+    name: &'static str,
+    seconds: f64,
+}
+
+/// One of the slowest process invocations.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SlowInvocation {
+    category: ProcessCategory,
+    command: String,
+    seconds: f64,
+}
+
+/// Aggregated timings of a whole build.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MeasureReport {
+    total_seconds: f64,
+    phases: Vec<TimeEntry>,
+    categories: Vec<TimeEntry>,
+    slowest: Vec<SlowInvocation>,
+}
+
+impl MeasureReport {
+    /// Aggregates phase and process timings of a build that took `total`.
+    #[must_use]
+    pub fn new(total: Duration, phases: &[PhaseTiming], processes: &[ProcessTiming]) -> Self {
+        let phase_totals = Phase::all()
+            .iter()
+            .map(|phase| TimeEntry {
+                name: phase.name(),
+                seconds: phases
+                    .iter()
+                    .filter(|t| t.phase() == *phase)
+                    .map(PhaseTiming::duration)
+                    .sum::<Duration>()
+                    .as_secs_f64(),
+            })
+            .collect();
+
+        let category_totals = ProcessCategory::all()
+            .iter()
+            .map(|category| TimeEntry {
+                name: category.name(),
+                seconds: processes
+                    .iter()
+                    .filter(|t| ProcessCategory::of(t) == *category)
+                    .map(ProcessTiming::duration)
+                    .sum::<Duration>()
+                    .as_secs_f64(),
+            })
+            .collect();
+
+        let mut sorted: Vec<&ProcessTiming> = processes.iter().collect();
+        sorted.sort_by_key(|t| Reverse(t.duration()));
+        let slowest = sorted
+            .into_iter()
+            .take(SLOWEST_COUNT)
+            .map(|t| SlowInvocation {
+                category: ProcessCategory::of(t),
+                command: t.command().to_string(),
+                seconds: t.duration().as_secs_f64(),
+            })
+            .collect();
+
+        Self {
+            total_seconds: total.as_secs_f64(),
+            phases: phase_totals,
+            categories: category_totals,
+            slowest,
+        }
+    }
+
+    /// Formats the report as lines for the terminal.
+    #[must_use]
+    pub fn format_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Build profile: {:.1}s total", self.total_seconds)];
+
+        lines.push("  Phases (wall time)".to_string());
+        for entry in &self.phases {
+            lines.push(format!("    {:<18} {:>9.1}s", entry.name, entry.seconds));
+        }
+
+        lines.push("  Processes (summed, parallel tasks overlap)".to_string());
+        for entry in &self.categories {
+            lines.push(format!("    {:<18} {:>9.1}s", entry.name, entry.seconds));
+        }
+
+        if !self.slowest.is_empty() {
+            lines.push(format!("  Slowest {} invocations", self.slowest.len()));
+            for (i, slow) in self.slowest.iter().enumerate() {
+                let mut line = format!(
+                    "    {:>2}. {:>9.1}s  {:<9}  ",
+                    i + 1,
+                    slow.seconds,
+                    slow.category.name()
+                );
+                match slow.command.char_indices().nth(MAX_COMMAND_LEN) {
+                    Some((end, _)) => {
+                        let _ = write!(line, "{}...", &slow.command[..end]);
+                    }
+                    None => line.push_str(&slow.command),
+                }
+                lines.push(line);
+            }
+        }
+
+        lines
+    }
+
+    /// Writes the report as JSON to `path` with `endings`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report cannot be serialized or the file cannot be written.
+    pub async fn write_json(&self, path: &Path, endings: LineEndings) -> Result<()> {
+        let json =
+            serde_json::to_string_pretty(self).context("Failed to serialize build profile")?;
+        write_text(path, &json, endings)
+            .await
+            .with_context(|| format!("Failed to write build profile: {}", path.display()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,96 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::time::Duration;
+
+use super::{MeasureReport, ProcessCategory};
+use crate::core::process::timing::ProcessTiming;
+use crate::task::Phase;
+use crate::task::timing::PhaseTiming;
+use crate::utility::fs::text::LineEndings;
+
+fn process(program: &str, command: &str, secs: u64) -> ProcessTiming {
+    ProcessTiming::new(
+        program.to_string(),
+        command.to_string(),
+        Duration::from_secs(secs),
+    )
+}
+
+#[test]
+fn test_process_category_of() {
+    let cases = [
+        (process("git", "git clone url", 1), ProcessCategory::Fetch),
+        (
+            process("cmake", "cmake -G VS ..", 1),
+            ProcessCategory::Configure,
+        ),
+        (
+            process("cmake", "cmake --build vsbuild", 1),
+            ProcessCategory::Build,
+        ),
+        (
+            process("cmake", "cmake --install vsbuild", 1),
+            ProcessCategory::Install,
+        ),
+        (
+            process("MSBuild", "MSBuild.exe x.sln", 1),
+            ProcessCategory::Build,
+        ),
+        (process("7z", "7z a out.7z", 1), ProcessCategory::Archive),
+        (
+            process("python", "python setup.py", 1),
+            ProcessCategory::Other,
+        ),
+    ];
+    for (timing, expected) in cases {
+        assert_eq!(
+            ProcessCategory::of(&timing),
+            expected,
+            "{}",
+            timing.command()
+        );
+    }
+}
+
+#[test]
+fn test_measure_report() {
+    let phases = [
+        PhaseTiming::new("a".into(), Phase::Fetch, Duration::from_secs(3)),
+        PhaseTiming::new("b".into(), Phase::Fetch, Duration::from_secs(2)),
+        PhaseTiming::new("a".into(), Phase::BuildAndInstall, Duration::from_secs(20)),
+    ];
+    let mut processes: Vec<ProcessTiming> = (1..=12)
+        .map(|i| process("cmake", &format!("cmake --build step{i}"), i))
+        .collect();
+    processes.push(process("git", "git pull", 4));
+
+    let report = MeasureReport::new(Duration::from_secs(30), &phases, &processes);
+    insta::assert_snapshot!("measure_report", report.format_lines().join("\n"));
+}
+
+#[tokio::test]
+async fn test_measure_report_write_json() {
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let path = temp.path().join("profile.json");
+    let report = MeasureReport::new(
+        Duration::from_secs(5),
+        &[],
+        &[process("7z", "7z x a.7z", 2)],
+    );
+
+    report
+        .write_json(&path, LineEndings::Crlf)
+        .await
+        .expect("write json");
+
+    let text = std::fs::read_to_string(&path).expect("read");
+    assert!(text.contains("\r\n"));
+    let json: serde_json::Value = serde_json::from_str(&text).expect("parse");
+    assert_eq!(json["total_seconds"], 5.0);
+    assert_eq!(json["slowest"][0]["category"], "archive");
+    assert_eq!(json["categories"][4]["name"], "archive");
+    assert_eq!(json["categories"][4]["seconds"], 2.0);
+}
@@ -7,13 +7,23 @@
 //!
 //! ```text
 //! CLI args --> cmd::run_* handlers
-//!   build, config, git, list, pr, release, tx
+//!   build, cache, clean, completions, config, doctor, git, list, pr, release, tx
+//! command error --> diagnostics (--dump-on-failure)
+//! build --measure --> measure (build profile)
+//! build --report  --> report (JUnit XML)
 //! ```
 
 pub mod build;
+pub mod cache;
+pub mod clean;
+pub mod completions;
 pub mod config;
+pub mod diagnostics;
+pub mod doctor;
 pub mod git;
 pub mod list;
+pub mod measure;
 pub mod pr;
 pub mod release;
+pub mod report;
 pub mod tx;
@@ -7,6 +7,16 @@
 //!
 //! ```text
 //! GitHub API --> PrMatch (PR + path) --> local fetch+checkout
+//! Client: net::http_client([net]), pooled with downloads
+//!
+//! --prefetch-prs:
+//! search items --> JoinSet (≤ PREFETCH_LIMIT) --> get_pr_info_cached
+//!                                                  If-None-Match → 304 → cache
+//!
+//! Every request: send_with_retry()
+//!   5xx                                  --> retry with backoff
+//!   403/429 + Retry-After or
+//!     X-RateLimit-Remaining: 0           --> sleep until reset (≤ 60s), retry
 //! ```
 //!
 //! # Key Types
@@ -16,6 +26,7 @@
 //! | `PrInfo`         | GitHub PR data from API         |
 //! | `PrMatch`        | Matched PR with local repo path |
 //! | `SearchResponse` | GitHub search API response      |
+//! | `PrInfoCache`    | `ETag`-keyed PR details on disk |
 
 use crate::cli::pr::{PrArgs, PrOperation};
 use crate::config::Config;
@@ -24,11 +35,37 @@ use crate::error::Result;
 use crate::git::cmd::checkout;
 use crate::git::ops::fetch_refspec;
 use crate::git::query::is_git_repo;
+use crate::net::http_client;
 use anyhow::Context;
-use reqwest::Client;
-use serde::Deserialize;
+use reqwest::header::{ETAG, HeaderMap, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Client, RequestBuilder, Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinSet;
 use tracing::{debug, info, warn};
 
+/// Maximum concurrent PR detail requests with `--prefetch-prs`.
+const PREFETCH_LIMIT: usize = 4;
+
+/// Attempts per GitHub request while it fails with 5xx or a rate limit.
+const GITHUB_ATTEMPTS: u32 = 4;
+
+/// Delay before retrying a 5xx response; doubled on every further attempt.
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest wait for a rate limit to reset before retrying.
+const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_mins(1);
+
+/// How PR details of search results are fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrFetchMode {
+    /// One request at a time, no cache.
+    Serial,
+    /// Up to [`PREFETCH_LIMIT`] requests at once, cached by `ETag`.
+    Prefetch,
+}
+
 /// GitHub PR information from API
 #[derive(Debug, Deserialize)]
 pub struct PrInfo {
@@ -77,6 +114,70 @@ pub struct PullRequestLink {
     pub url: String,
 }
 
+/// On-disk cache of PR details keyed by `ETag`.
+///
+/// Conditional requests answered with `304 Not Modified` do not count
+/// against the GitHub rate limit.
+#[derive(Debug, Clone)]
+pub struct PrInfoCache {
+    dir: PathBuf,
+}
+
+/// Cached response body of one PR.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedPrInfo {
+    etag: String,
+    body: String,
+}
+
+impl PrInfoCache {
+    /// Creates a cache storing entries in `dir`.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Creates a cache in `paths.cache/github-prs`, if `paths.cache` is set.
+    #[must_use]
+    pub fn from_config(config: &Config) -> Option<Self> {
+        config
+            .paths
+            .cache
+            .as_ref()
+            .map(|cache| Self::new(cache.join("github-prs")))
+    }
+
+    fn entry_path(&self, org: &str, repo: &str, pr: u64) -> PathBuf {
+        self.dir.join(format!("{org}_{repo}_{pr}.json"))
+    }
+
+    /// Returns the cached `(etag, body)` of a PR, if any.
+    pub async fn load(&self, org: &str, repo: &str, pr: u64) -> Option<(String, String)> {
+        let contents = tokio::fs::read_to_string(self.entry_path(org, repo, pr))
+            .await
+            .ok()?;
+        let entry: CachedPrInfo = serde_json::from_str(&contents).ok()?;
+        Some((entry.etag, entry.body))
+    }
+
+    /// Stores the response of a PR. Failures only disable caching.
+    pub async fn store(&self, org: &str, repo: &str, pr: u64, etag: &str, body: &str) {
+        let entry = CachedPrInfo {
+            etag: etag.to_string(),
+            body: body.to_string(),
+        };
+        let path = self.entry_path(org, repo, pr);
+        let result = async {
+            tokio::fs::create_dir_all(&self.dir).await?;
+            tokio::fs::write(&path, serde_json::to_string(&entry)?).await
+        }
+        .await;
+        if let Err(e) = result {
+            debug!(path = %path.display(), error = %e, "failed to cache PR info");
+        }
+    }
+}
+
 /// Matched PR across repositories
 #[derive(Debug)]
 pub struct PrMatch {
@@ -179,6 +280,75 @@ pub fn parse_pr_arg(pr: &str) -> Result<(Option<String>, u64)> {
     }
 }
 
+/// Builds an authenticated GitHub API GET request.
+fn github_get(client: &Client, token: &str, url: &str) -> RequestBuilder {
+    client
+        .get(url)
+        .header("Authorization", format!("Bearer {token}"))
+        .header("Accept", "application/vnd.github.v3+json")
+}
+
+/// Sends `request`, retrying 5xx responses and waiting out rate limits.
+///
+/// Returns the last response once it succeeds, fails for another reason, or
+/// [`GITHUB_ATTEMPTS`] are used up.
+async fn send_with_retry(request: RequestBuilder) -> reqwest::Result<Response> {
+    let mut attempt = 0;
+    loop {
+        let Some(retry) = request
+            .try_clone()
+            .filter(|_| attempt + 1 < GITHUB_ATTEMPTS)
+        else {
+            return request.send().await;
+        };
+        let response = retry.send().await?;
+        let Some(delay) = retry_delay(&response, attempt) else {
+            return Ok(response);
+        };
+
+        warn!(
+            status = response.status().as_u16(),
+            url = %response.url(),
+            attempt = attempt + 1,
+            delay_ms = delay.as_millis(),
+            "GitHub request failed, retrying"
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Returns how long to wait before retrying `response`, or `None` if it
+/// shouldn't be retried.
+fn retry_delay(response: &Response, attempt: u32) -> Option<Duration> {
+    let status = response.status();
+    if status.is_server_error() {
+        return Some(RETRY_BACKOFF * 2u32.pow(attempt));
+    }
+    if status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS {
+        return rate_limit_wait(response.headers(), SystemTime::now());
+    }
+    None
+}
+
+/// Returns the wait requested by `Retry-After` or until `X-RateLimit-Reset`
+/// when no requests remain, capped at [`MAX_RATE_LIMIT_WAIT`].
+fn rate_limit_wait(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let number =
+        |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.trim().parse().ok() };
+
+    let wait = if let Some(seconds) = number(RETRY_AFTER.as_str()) {
+        Duration::from_secs(seconds)
+    } else if number("x-ratelimit-remaining") == Some(0) {
+        let reset = number("x-ratelimit-reset")?;
+        let now = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Duration::from_secs(reset.saturating_sub(now))
+    } else {
+        return None;
+    };
+    Some(wait.min(MAX_RATE_LIMIT_WAIT))
+}
+
 /// Get PR info from GitHub API
 ///
 /// # Errors
@@ -190,23 +360,16 @@ pub fn parse_pr_arg(pr: &str) -> Result<(Option<String>, u64)> {
 pub async fn get_pr_info(
     client: &Client,
     token: &str,
+    api: &str,
     org: &str,
     repo: &str,
     pr: u64,
 ) -> Result<PrInfo> {
-    let url = format!("https://api.github.com/repos/{org}/{repo}/pulls/{pr}");
+    let url = api_url(api, &format!("repos/{org}/{repo}/pulls/{pr}"));
 
     debug!(org, repo, pr, "fetching PR info from GitHub API");
 
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {token}"))
-        .header("Accept", "application/vnd.github.v3+json")
-        .header(
-            "User-Agent",
-            format!("mob-rs/{}", env!("CARGO_PKG_VERSION")),
-        )
-        .send()
+    let response = send_with_retry(github_get(client, token, &url))
         .await
         .with_context(|| format!("failed to request PR info from {url}"))?;
 
@@ -228,50 +391,48 @@ pub async fn get_pr_info(
     Ok(pr_info)
 }
 
-/// Search for matching PRs across repos
+/// Get PR info from GitHub API, revalidating a cached copy by `ETag`.
 ///
 /// # Errors
 ///
 /// Returns an error if:
 /// - The network request fails.
 /// - The GitHub API returns a non-success status code.
-/// - The response body cannot be parsed as `SearchResponse`.
-pub async fn search_prs(
+/// - The response body or cached copy cannot be parsed as `PrInfo`.
+pub async fn get_pr_info_cached(
     client: &Client,
     token: &str,
+    api: &str,
+    cache: &PrInfoCache,
     org: &str,
-    author: Option<&str>,
-    pr_number: Option<u64>,
-) -> Result<Vec<SearchItem>> {
-    let mut query_parts = vec![format!("org:{}", org), "type:pr".to_string()];
+    repo: &str,
+    pr: u64,
+) -> Result<PrInfo> {
+    let url = api_url(api, &format!("repos/{org}/{repo}/pulls/{pr}"));
+    let cached = cache.load(org, repo, pr).await;
 
-    if let Some(author) = author {
-        query_parts.push(format!("author:{author}"));
-    }
+    debug!(
+        org,
+        repo,
+        pr,
+        cached = cached.is_some(),
+        "fetching PR info from GitHub API"
+    );
 
-    if let Some(number) = pr_number {
-        // Search by PR number in title/body (GitHub search limitation)
-        query_parts.push(format!("{number} in:title,body"));
+    let mut request = github_get(client, token, &url);
+    if let Some((ref etag, _)) = cached {
+        request = request.header(IF_NONE_MATCH, etag);
     }
-
-    let query = query_parts.join(" ");
-    // URL-encode the query manually
-    let encoded_query = query.replace(' ', "+").replace(':', "%3A");
-    let url = format!("https://api.github.com/search/issues?q={encoded_query}&per_page=100");
-
-    debug!(query, "searching GitHub for PRs");
-
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {token}"))
-        .header("Accept", "application/vnd.github.v3+json")
-        .header(
-            "User-Agent",
-            format!("mob-rs/{}", env!("CARGO_PKG_VERSION")),
-        )
-        .send()
+    let response = send_with_retry(request)
         .await
-        .with_context(|| format!("failed to search GitHub for PRs: {url}"))?;
+        .with_context(|| format!("failed to request PR info from {url}"))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED
+        && let Some((_, body)) = cached
+    {
+        debug!(org, repo, pr, "PR info not modified, using cache");
+        return serde_json::from_str(&body).context("failed to parse cached PR info");
+    }
 
     if !response.status().is_success() {
         let status = response.status();
@@ -283,18 +444,144 @@ pub async fn search_prs(
         .into());
     }
 
-    let search_result = response
-        .json::<SearchResponse>()
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = response
+        .text()
         .await
-        .with_context(|| "failed to parse GitHub search results")?;
+        .with_context(|| format!("failed to read PR info from {url}"))?;
+    let pr_info = serde_json::from_str::<PrInfo>(&body)
+        .with_context(|| "failed to parse PR info from GitHub API")?;
 
-    debug!(
-        total = search_result.total_count,
-        found = search_result.items.len(),
-        "GitHub search completed"
+    if let Some(etag) = etag {
+        cache.store(org, repo, pr, &etag, &body).await;
+    }
+
+    Ok(pr_info)
+}
+
+/// Results per search page; the maximum GitHub allows.
+const SEARCH_PER_PAGE: usize = 100;
+
+/// Search pages fetched at most, so a broad query can't run away.
+const MAX_SEARCH_PAGES: u32 = 10;
+
+/// Search for matching PRs across repos
+///
+/// Follows result pages until every match is fetched, up to
+/// [`MAX_SEARCH_PAGES`]; a truncated result is logged as a warning.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The network request fails.
+/// - The GitHub API returns a non-success status code.
+/// - The response body cannot be parsed as `SearchResponse`.
+pub async fn search_prs(
+    client: &Client,
+    token: &str,
+    api: &str,
+    org: &str,
+    author: Option<&str>,
+    pr_number: Option<u64>,
+) -> Result<Vec<SearchItem>> {
+    let query = search_query(org, author, pr_number);
+    search_pages(client, token, &api_url(api, "search/issues"), &query).await
+}
+
+/// Fetches the pages of `query` from the search endpoint `endpoint`.
+async fn search_pages(
+    client: &Client,
+    token: &str,
+    endpoint: &str,
+    query: &str,
+) -> Result<Vec<SearchItem>> {
+    debug!(query, "searching GitHub for PRs");
+
+    let mut items = Vec::new();
+    let mut total_count = 0;
+    for page in 1..=MAX_SEARCH_PAGES {
+        let url = search_url(endpoint, query, page)?;
+        let response = send_with_retry(github_get(client, token, url.as_str()))
+            .await
+            .with_context(|| format!("failed to search GitHub for PRs: {url}"))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(NetworkError::HttpError {
+                status: status.as_u16(),
+                url: format!("{url} (error: {body})"),
+            }
+            .into());
+        }
+
+        let search_result = response
+            .json::<SearchResponse>()
+            .await
+            .with_context(|| "failed to parse GitHub search results")?;
+
+        total_count = search_result.total_count;
+        let page_len = search_result.items.len();
+        items.extend(search_result.items);
+        if page_len < SEARCH_PER_PAGE || items.len() as u64 >= total_count {
+            debug!(
+                total = total_count,
+                found = items.len(),
+                "GitHub search completed"
+            );
+            return Ok(items);
+        }
+    }
+
+    warn!(
+        total = total_count,
+        found = items.len(),
+        "GitHub search truncated after {MAX_SEARCH_PAGES} pages"
     );
+    Ok(items)
+}
+
+/// Joins `path` to the API base URL `api`, with or without a trailing slash.
+fn api_url(api: &str, path: &str) -> String {
+    format!(
+        "{}/{}",
+        api.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    )
+}
+
+/// Builds the GitHub search query for PRs in `org`.
+fn search_query(org: &str, author: Option<&str>, pr_number: Option<u64>) -> String {
+    let mut query_parts = vec![format!("org:{org}"), "type:pr".to_string()];
+
+    if let Some(author) = author {
+        query_parts.push(format!("author:{author}"));
+    }
 
-    Ok(search_result.items)
+    if let Some(number) = pr_number {
+        // Search by PR number in title/body (GitHub search limitation)
+        query_parts.push(format!("{number} in:title,body"));
+    }
+
+    query_parts.join(" ")
+}
+
+/// Returns the URL of result `page` of `query` on the search endpoint `endpoint`,
+/// with the `q` parameter percent-encoded.
+fn search_url(endpoint: &str, query: &str, page: u32) -> Result<Url> {
+    Url::parse_with_params(
+        endpoint,
+        [
+            ("q", query),
+            ("per_page", &SEARCH_PER_PAGE.to_string()),
+            ("page", &page.to_string()),
+        ],
+    )
+    .context("failed to build GitHub search URL")
 }
 
 /// Find local repository path for a given repo name
@@ -318,28 +605,113 @@ fn find_local_repo(config: &Config, repo_name: &str) -> Option<std::path::PathBu
     None
 }
 
+/// Splits `<api base>/repos/{org}/{repo}` into `(org, repo)`.
+fn split_repository_url(url: &str) -> Option<(String, String)> {
+    let mut parts = url.rsplit('/');
+    let repo = parts.next().filter(|s| !s.is_empty())?;
+    let org = parts.next().filter(|s| !s.is_empty())?;
+    Some((org.to_string(), repo.to_string()))
+}
+
+/// A search item with its org/repo and fetched PR details.
+type FetchedItem = (String, String, SearchItem, Result<PrInfo>);
+
+/// Fetches PR details one at a time.
+async fn fetch_serial(
+    client: &Client,
+    token: &str,
+    api: &str,
+    targets: Vec<(String, String, SearchItem)>,
+) -> Vec<FetchedItem> {
+    let mut fetched = Vec::with_capacity(targets.len());
+    for (org, repo, item) in targets {
+        let info = get_pr_info(client, token, api, &org, &repo, item.number).await;
+        fetched.push((org, repo, item, info));
+    }
+    fetched
+}
+
+/// Fetches PR details with at most [`PREFETCH_LIMIT`] requests in flight.
+async fn fetch_concurrent(
+    client: &Client,
+    token: &str,
+    api: &str,
+    cache: Option<PrInfoCache>,
+    targets: Vec<(String, String, SearchItem)>,
+) -> Vec<FetchedItem> {
+    let mut fetched = Vec::with_capacity(targets.len());
+    let mut set = JoinSet::new();
+
+    for (org, repo, item) in targets {
+        while set.len() >= PREFETCH_LIMIT {
+            if let Some(joined) = set.join_next().await {
+                push_joined(&mut fetched, joined);
+            }
+        }
+
+        let client = client.clone();
+        let token = token.to_string();
+        let api = api.to_string();
+        let cache = cache.clone();
+        set.spawn(async move {
+            let info = match cache {
+                Some(ref cache) => {
+                    get_pr_info_cached(&client, &token, &api, cache, &org, &repo, item.number).await
+                }
+                None => get_pr_info(&client, &token, &api, &org, &repo, item.number).await,
+            };
+            (org, repo, item, info)
+        });
+    }
+
+    while let Some(joined) = set.join_next().await {
+        push_joined(&mut fetched, joined);
+    }
+    fetched
+}
+
+fn push_joined(
+    fetched: &mut Vec<FetchedItem>,
+    joined: std::result::Result<FetchedItem, tokio::task::JoinError>,
+) {
+    match joined {
+        Ok(item) => fetched.push(item),
+        Err(e) => warn!(error = %e, "PR prefetch task failed"),
+    }
+}
+
 /// Convert search items to `PrMatch` with local paths
+///
+/// Matches are sorted by PR number, then repository, whatever order the
+/// details arrived in.
 async fn items_to_matches(
     client: &Client,
     token: &str,
+    api: &str,
     items: Vec<SearchItem>,
     config: &Config,
+    mode: PrFetchMode,
 ) -> Result<Vec<PrMatch>> {
-    let mut matches = Vec::new();
-
+    let mut targets = Vec::with_capacity(items.len());
     for item in items {
-        // Extract org/repo from repository_url
-        // Format: https://api.github.com/repos/{org}/{repo}
-        let parts: Vec<&str> = item.repository_url.split('/').collect();
-        if parts.len() < 2 {
+        let Some((org, repo)) = split_repository_url(&item.repository_url) else {
             warn!(url = %item.repository_url, "invalid repository URL format");
             continue;
+        };
+        targets.push((org, repo, item));
+    }
+
+    let fetched = match mode {
+        PrFetchMode::Serial => fetch_serial(client, token, api, targets).await,
+        PrFetchMode::Prefetch => {
+            let cache = PrInfoCache::from_config(config);
+            fetch_concurrent(client, token, api, cache, targets).await
         }
-        let org = parts[parts.len() - 2].to_string();
-        let repo = parts[parts.len() - 1].to_string();
+    };
 
-        // Get detailed PR info to get head ref
-        let pr_info = match get_pr_info(client, token, &org, &repo, item.number).await {
+    let mut matches = Vec::with_capacity(fetched.len());
+    for (org, repo, item, info) in fetched {
+        let pr_info = match info {
             Ok(info) => info,
             Err(e) => {
                 warn!(org, repo, pr = item.number, error = %e, "failed to get PR details");
@@ -354,17 +726,23 @@ async fn items_to_matches(
             .map(|r| r.clone_url.clone())
             .unwrap_or_default();
 
+        let local_path = find_local_repo(config, &repo);
         matches.push(PrMatch::new(
-            repo.clone(),
+            repo,
             item.number,
             item.title,
             pr_info.head.ref_name,
             pr_info.head.sha,
             clone_url,
-            find_local_repo(config, &repo),
+            local_path,
         ));
     }
 
+    matches.sort_by(|a, b| {
+        a.pr_number
+            .cmp(&b.pr_number)
+            .then_with(|| a.repo.cmp(&b.repo))
+    });
     Ok(matches)
 }
 
@@ -387,21 +765,35 @@ pub async fn run_pr_command(args: &PrArgs, config: &Config) -> Result<()> {
     let (repo_filter, pr_number) = parse_pr_arg(&args.pr)
         .with_context(|| format!("failed to parse PR argument: {}", args.pr))?;
 
-    let client = reqwest::Client::new();
+    let client = &http_client(&config.net)?;
+    let api = &args.github_api_base;
+    let mode = if args.prefetch_prs {
+        PrFetchMode::Prefetch
+    } else {
+        PrFetchMode::Serial
+    };
 
     match args.operation {
-        PrOperation::Find => run_pr_find(&client, token, repo_filter, pr_number, config).await,
-        PrOperation::Pull => run_pr_pull(&client, token, repo_filter, pr_number, config).await,
-        PrOperation::Revert => run_pr_revert(&client, token, repo_filter, pr_number, config).await,
+        PrOperation::Find => {
+            run_pr_find(client, token, api, repo_filter, pr_number, config, mode).await
+        }
+        PrOperation::Pull => {
+            run_pr_pull(client, token, api, repo_filter, pr_number, config, mode).await
+        }
+        PrOperation::Revert => {
+            run_pr_revert(client, token, api, repo_filter, pr_number, config, mode).await
+        }
     }
 }
 
 async fn run_pr_find(
     client: &Client,
     token: &str,
+    api: &str,
     repo_filter: Option<String>,
     pr_number: u64,
     config: &Config,
+    mode: PrFetchMode,
 ) -> Result<()> {
     info!("Searching for matching PRs...");
 
@@ -410,7 +802,7 @@ async fn run_pr_find(
 
     let items = if let Some(ref repo) = repo_filter {
         // Specific repo - fetch PR directly
-        let pr_info = get_pr_info(client, token, org, repo, pr_number).await?;
+        let pr_info = get_pr_info(client, token, api, org, repo, pr_number).await?;
         info!(
             org,
             repo,
@@ -422,10 +814,10 @@ async fn run_pr_find(
         vec![]
     } else {
         // Search across all repos in org
-        search_prs(client, token, org, None, Some(pr_number)).await?
+        search_prs(client, token, api, org, None, Some(pr_number)).await?
     };
 
-    let matches = items_to_matches(client, token, items, config).await?;
+    let matches = items_to_matches(client, token, api, items, config, mode).await?;
 
     if matches.is_empty() && repo_filter.is_none() {
         warn!(org = %org, "No matching PRs found in organization");
@@ -471,9 +863,11 @@ async fn run_pr_find(
 async fn run_pr_pull(
     client: &Client,
     token: &str,
+    api: &str,
     repo_filter: Option<String>,
     pr_number: u64,
     config: &Config,
+    mode: PrFetchMode,
 ) -> Result<()> {
     info!("Fetching and checking out PR...");
 
@@ -481,7 +875,7 @@ async fn run_pr_pull(
 
     let matches = if let Some(ref repo) = repo_filter {
         // Specific repo
-        let pr_info = get_pr_info(client, token, org, repo, pr_number).await?;
+        let pr_info = get_pr_info(client, token, api, org, repo, pr_number).await?;
         let local_path = find_local_repo(config, repo);
 
         if local_path.is_none() {
@@ -500,8 +894,8 @@ async fn run_pr_pull(
         )]
     } else {
         // Search and convert
-        let items = search_prs(client, token, org, None, Some(pr_number)).await?;
-        items_to_matches(client, token, items, config).await?
+        let items = search_prs(client, token, api, org, None, Some(pr_number)).await?;
+        items_to_matches(client, token, api, items, config, mode).await?
     };
 
     for m in matches {
@@ -549,9 +943,11 @@ async fn run_pr_pull(
 async fn run_pr_revert(
     client: &Client,
     token: &str,
+    api: &str,
     repo_filter: Option<String>,
     pr_number: u64,
     config: &Config,
+    mode: PrFetchMode,
 ) -> Result<()> {
     info!("Reverting repositories to master...");
 
@@ -576,8 +972,8 @@ async fn run_pr_revert(
         )]
     } else {
         // Search first
-        let items = search_prs(client, token, org, None, Some(pr_number)).await?;
-        items_to_matches(client, token, items, config).await?
+        let items = search_prs(client, token, api, org, None, Some(pr_number)).await?;
+        items_to_matches(client, token, api, items, config, mode).await?
     };
 
     for m in matches {
@@ -3,7 +3,13 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{find_local_repo, parse_pr_arg};
+use super::{
+    GITHUB_ATTEMPTS, MAX_RATE_LIMIT_WAIT, MAX_SEARCH_PAGES, PrInfoCache, SEARCH_PER_PAGE, api_url,
+    find_local_repo, get_pr_info, parse_pr_arg, rate_limit_wait, search_pages, search_query,
+    search_url, split_repository_url,
+};
+use crate::cli::pr::DEFAULT_GITHUB_API_BASE;
+use crate::config::Config;
 use tempfile::TempDir;
 
 fn temp_dir() -> TempDir {
@@ -115,3 +121,272 @@ fn test_find_local_repo_not_found() {
     let found = find_local_repo(&config, "nonexistent");
     assert!(found.is_none());
 }
+
+#[test]
+fn test_split_repository_url() {
+    assert_eq!(
+        split_repository_url("https://api.github.com/repos/ModOrganizer2/modorganizer"),
+        Some(("ModOrganizer2".to_string(), "modorganizer".to_string()))
+    );
+    assert_eq!(split_repository_url("modorganizer"), None);
+    assert_eq!(split_repository_url("https://api.github.com/repos/"), None);
+}
+
+#[tokio::test]
+async fn test_pr_info_cache_round_trip() {
+    let temp = temp_dir();
+    let cache = PrInfoCache::new(temp.path().join("github-prs"));
+
+    assert_eq!(cache.load("org", "repo", 7).await, None);
+
+    cache
+        .store("org", "repo", 7, "\"abc\"", "{\"number\":7}")
+        .await;
+    assert_eq!(
+        cache.load("org", "repo", 7).await,
+        Some(("\"abc\"".to_string(), "{\"number\":7}".to_string()))
+    );
+    assert_eq!(cache.load("org", "repo", 8).await, None);
+}
+
+#[test]
+fn test_pr_info_cache_from_config() {
+    let mut config = Config::default();
+    config.paths.cache = None;
+    assert!(PrInfoCache::from_config(&config).is_none());
+
+    config.paths.cache = Some(std::path::PathBuf::from("cache"));
+    assert!(PrInfoCache::from_config(&config).is_some());
+}
+
+#[test]
+fn test_search_url_encodes_spaces_and_qualifiers() {
+    let query = search_query("ModOrganizer2", None, None);
+    assert_eq!(query, "org:ModOrganizer2 type:pr");
+
+    let url = search_url(
+        &api_url(DEFAULT_GITHUB_API_BASE, "search/issues"),
+        &query,
+        1,
+    )
+    .unwrap();
+    assert_eq!(
+        url.as_str(),
+        "https://api.github.com/search/issues?q=org%3AModOrganizer2+type%3Apr&per_page=100&page=1"
+    );
+}
+
+#[test]
+fn test_search_url_encodes_special_characters() {
+    let query = search_query("ModOrganizer2", Some("dev&#é"), Some(1234));
+    let url = search_url(
+        &api_url(DEFAULT_GITHUB_API_BASE, "search/issues"),
+        &query,
+        2,
+    )
+    .unwrap();
+
+    assert_eq!(
+        url.query(),
+        Some(
+            "q=org%3AModOrganizer2+type%3Apr+author%3Adev%26%23%C3%A9\
+             +1234+in%3Atitle%2Cbody&per_page=100&page=2"
+        )
+    );
+    let (_, q) = url.query_pairs().find(|(key, _)| key == "q").unwrap();
+    assert_eq!(q, query);
+}
+
+#[test]
+fn test_api_url_joins_with_or_without_trailing_slash() {
+    for base in [
+        "https://ghe.example.com/api/v3",
+        "https://ghe.example.com/api/v3/",
+    ] {
+        assert_eq!(
+            api_url(base, "repos/ModOrganizer2/modorganizer/pulls/12"),
+            "https://ghe.example.com/api/v3/repos/ModOrganizer2/modorganizer/pulls/12"
+        );
+    }
+    assert_eq!(
+        api_url(DEFAULT_GITHUB_API_BASE, "/search/issues"),
+        "https://api.github.com/search/issues"
+    );
+}
+
+/// Serves a search result of `total` PRs, `SEARCH_PER_PAGE` per page.
+struct SearchResponder {
+    total: usize,
+}
+
+impl wiremock::Respond for SearchResponder {
+    fn respond(&self, request: &wiremock::Request) -> wiremock::ResponseTemplate {
+        let page: usize = request
+            .url
+            .query_pairs()
+            .find(|(key, _)| key == "page")
+            .and_then(|(_, page)| page.parse().ok())
+            .unwrap_or(1);
+        let start = (page - 1) * SEARCH_PER_PAGE;
+        let items: Vec<_> = (start..self.total.min(start + SEARCH_PER_PAGE))
+            .map(|number| {
+                serde_json::json!({
+                    "number": number,
+                    "title": format!("PR {number}"),
+                    "repository_url": "https://api.github.com/repos/ModOrganizer2/modorganizer",
+                    "state": "open",
+                })
+            })
+            .collect();
+        wiremock::ResponseTemplate::new(200)
+            .set_body_json(serde_json::json!({ "total_count": self.total, "items": items }))
+    }
+}
+
+async fn search_mock(total: usize) -> (wiremock::MockServer, Vec<super::SearchItem>) {
+    let server = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::path("/search/issues"))
+        .respond_with(SearchResponder { total })
+        .mount(&server)
+        .await;
+
+    let api = format!("{}/search/issues", server.uri());
+    let items = search_pages(&reqwest::Client::new(), "token", &api, "org:ModOrganizer2")
+        .await
+        .unwrap();
+    (server, items)
+}
+
+#[tokio::test]
+async fn test_search_pages_follows_pages() {
+    let (server, items) = search_mock(250).await;
+
+    assert_eq!(items.len(), 250);
+    assert_eq!(items[249].number, 249);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_search_pages_stops_at_page_limit() {
+    let (server, items) = search_mock(5000).await;
+
+    assert_eq!(items.len(), MAX_SEARCH_PAGES as usize * SEARCH_PER_PAGE);
+    assert_eq!(
+        server.received_requests().await.unwrap().len(),
+        MAX_SEARCH_PAGES as usize
+    );
+}
+
+fn headers(pairs: &[(&'static str, &str)]) -> reqwest::header::HeaderMap {
+    pairs
+        .iter()
+        .map(|(name, value)| {
+            (
+                reqwest::header::HeaderName::from_static(name),
+                value.parse().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_rate_limit_wait() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let now = UNIX_EPOCH + Duration::from_secs(1_000);
+
+    assert_eq!(
+        rate_limit_wait(&headers(&[("retry-after", "7")]), now),
+        Some(Duration::from_secs(7))
+    );
+    assert_eq!(
+        rate_limit_wait(
+            &headers(&[
+                ("x-ratelimit-remaining", "0"),
+                ("x-ratelimit-reset", "1030")
+            ]),
+            now
+        ),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(
+        rate_limit_wait(
+            &headers(&[
+                ("x-ratelimit-remaining", "0"),
+                ("x-ratelimit-reset", "99999")
+            ]),
+            now
+        ),
+        Some(MAX_RATE_LIMIT_WAIT)
+    );
+    // A plain 403 (e.g. a bad token) isn't retried
+    assert_eq!(
+        rate_limit_wait(&headers(&[("x-ratelimit-remaining", "42")]), now),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_get_pr_info_retries_server_errors() {
+    let server = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::path(
+        "/repos/ModOrganizer2/modorganizer/pulls/12",
+    ))
+    .respond_with(wiremock::ResponseTemplate::new(502))
+    .up_to_n_times(1)
+    .mount(&server)
+    .await;
+    wiremock::Mock::given(wiremock::matchers::path(
+        "/repos/ModOrganizer2/modorganizer/pulls/12",
+    ))
+    .respond_with(
+        wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "number": 12,
+            "title": "Fix things",
+            "state": "open",
+            "head": { "ref": "fix", "sha": "abc123", "repo": null },
+        })),
+    )
+    .mount(&server)
+    .await;
+
+    let info = get_pr_info(
+        &reqwest::Client::new(),
+        "token",
+        &server.uri(),
+        "ModOrganizer2",
+        "modorganizer",
+        12,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(info.title, "Fix things");
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_pr_info_gives_up_after_attempts() {
+    let server = wiremock::MockServer::start().await;
+    wiremock::Mock::given(wiremock::matchers::any())
+        .respond_with(wiremock::ResponseTemplate::new(503))
+        .mount(&server)
+        .await;
+
+    let err = get_pr_info(
+        &reqwest::Client::new(),
+        "token",
+        &server.uri(),
+        "ModOrganizer2",
+        "modorganizer",
+        12,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(err.to_string().contains("http error 503"), "{err}");
+    assert_eq!(
+        server.received_requests().await.unwrap().len(),
+        GITHUB_ATTEMPTS as usize
+    );
+}
@@ -6,8 +6,12 @@
 //! Release command — packaging and distribution.
 //!
 //! ```text
-//! devbuild --> bin/pdbs/src (.7z)
-//! official --> bin/pdbs + installer
+//! devbuild --> bin/pdbs/src (.7z, or .zip with --format zip)
+//! official --> validate branch (parallel ls-remote) --> checkout
+//!          --> fetch (parallel, --jobs) --> build (sequential)
+//!          --> bin/pdbs + installer
+//!          --> --notes-since REF: CHANGELOG-<version>.md, commits per repo
+//! --checksums --> SHA256SUMS: "<sha256>  <file name>" per produced file
 //! ```
 //!
 //! # Archive Contents
@@ -17,40 +21,54 @@
 //! | bin     | install/bin | `__pycache__`                  |
 //! | pdbs    | install/pdb | `__pycache__`                  |
 //! | src     | build/*     | `.git`, `*.dll`, `*.exe`, etc. |
+//!
+//! `[release] src_excludes` adds to the src excludes, or replaces them when
+//! `src_excludes_replace` is set.
 
+use std::fmt::Write as _;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::error::Result;
 use anyhow::Context;
 use tokio::fs;
+use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
 use crate::cli::release::{DevbuildArgs, OfficialArgs, ReleaseArgs, ReleaseMode};
 use crate::config::Config;
+use crate::config::types::ReleaseConfig;
+use crate::core::process::priority::ProcessPriority;
 use crate::git::cmd::checkout;
 use crate::git::discovery::get_repos;
-use crate::git::ops::remote_branch_exists;
-use crate::task::Task;
+use crate::git::ops::{log_since, remote_branch_exists};
+use crate::git::query::has_revision;
 use crate::task::manager::TaskManager;
-use crate::task::tasks::explorerpp::ExplorerPPTask;
-use crate::task::tasks::installer::InstallerTask;
-use crate::task::tasks::licenses::LicensesTask;
-use crate::task::tasks::modorganizer::ModOrganizerTask;
-use crate::task::tasks::stylesheets::StylesheetsTask;
-use crate::task::tasks::translations::TranslationsTask;
+use crate::task::registry::builtin::builtin_tasks;
 use crate::task::tasks::usvfs::UsvfsTask;
-use crate::task::tools::packer::PackerTool;
+use crate::task::tools::downloader::sha256_file;
+use crate::task::tools::git::RemoteBranchCache;
+use crate::task::tools::packer::{ArchiveFormat, PackerTool};
 use crate::task::tools::{Tool, ToolContext};
+use crate::task::{ParallelTasks, Task};
+use crate::utility::fs::atomic::copy_atomic;
+use crate::utility::fs::text::{LineEndings, write_text};
 
 mod version;
 
+/// Remote branch checks run at once when validating an official release.
+const BRANCH_CHECK_CONCURRENCY: usize = 8;
+
+/// Name of the checksum manifest written by `--checksums`.
+const CHECKSUMS_FILE: &str = "SHA256SUMS";
+
 const BIN_EXCLUDES: &[&str] = &["__pycache__"];
 const PDB_EXCLUDES: &[&str] = &["__pycache__"];
 const SRC_EXCLUDES: &[&str] = &[
-    r"\..*",
-    "explorer++",
+    ".*",
+    "explorer++*",
     "stylesheets",
     "transifex-translations",
     "*.log",
@@ -76,43 +94,69 @@ const SRC_EXCLUDES: &[&str] = &[
 /// - Version determination fails.
 /// - Output directory cannot be resolved or created.
 /// - Creation of binary, PDB, or source archives fails.
+/// - `tasks.usvfs.usvfs_arch` builds only one architecture.
 /// - No repositories are found for an official release.
 /// - Repository operations (git checkout, etc.) fail.
-pub async fn run_release_command(args: &ReleaseArgs, config: &Config, dry_run: bool) -> Result<()> {
+pub async fn run_release_command(
+    args: &ReleaseArgs,
+    config: &Config,
+    dry_run: bool,
+    jobs: Option<NonZeroUsize>,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    UsvfsTask::ensure_all_arches(config)?;
+
     match &args.mode {
-        ReleaseMode::Devbuild(devbuild) => run_devbuild(devbuild, config, dry_run).await,
-        ReleaseMode::Official(official) => run_official(official, config, dry_run).await,
+        ReleaseMode::Devbuild(devbuild) => {
+            run_devbuild(devbuild, config, dry_run, priority, cancel_token).await
+        }
+        ReleaseMode::Official(official) => {
+            run_official(official, config, dry_run, jobs, priority, cancel_token).await
+        }
     }
 }
 
-async fn run_devbuild(args: &DevbuildArgs, config: &Config, dry_run: bool) -> Result<()> {
+async fn run_devbuild(
+    args: &DevbuildArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
     let version = version::determine_version(args, config).await?;
-    let output_dir = resolve_output_dir(args, config)?;
+    let output_dir = resolve_output_dir(args, config, &version)?;
 
     ensure_output_dir(&output_dir, dry_run).await?;
 
     let suffix = args.suffix.as_deref();
     let config = Arc::new(config.clone());
-    let tool_ctx = ToolContext::new(Arc::clone(&config), CancellationToken::new(), dry_run);
+    let tool_ctx = ToolContext::new(Arc::clone(&config), cancel_token.clone(), dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority);
 
     info!(version = %version, output_dir = %output_dir.display(), "Preparing devbuild release");
 
+    let mut artifacts = Vec::new();
+
     if args.create_bin() {
         let install_bin = config
             .paths
             .install_bin
             .as_ref()
             .context("paths.install_bin not configured")?;
-        let archive_path = output_dir.join(archive_name(&version, suffix, None));
+        let archive_path = output_dir.join(archive_name(&version, suffix, None, args.format));
         ensure_output_file(&archive_path, args.force)?;
         create_directory_archive(
             &tool_ctx,
             install_bin,
             &archive_path,
             BIN_EXCLUDES,
+            args.format,
             "install/bin",
         )
         .await?;
+        artifacts.push(archive_path);
     }
 
     if args.create_pdbs() {
@@ -121,30 +165,36 @@ async fn run_devbuild(args: &DevbuildArgs, config: &Config, dry_run: bool) -> Re
             .install_pdbs
             .as_ref()
             .context("paths.install_pdbs not configured")?;
-        let archive_path = output_dir.join(archive_name(&version, suffix, Some("pdbs")));
+        let archive_path =
+            output_dir.join(archive_name(&version, suffix, Some("pdbs"), args.format));
         ensure_output_file(&archive_path, args.force)?;
         create_directory_archive(
             &tool_ctx,
             install_pdbs,
             &archive_path,
             PDB_EXCLUDES,
+            args.format,
             "install/pdbs",
         )
         .await?;
+        artifacts.push(archive_path);
     }
 
     if args.create_src() {
         let source_root = modorganizer_super_dir(config.as_ref())?;
-        let archive_path = output_dir.join(archive_name(&version, suffix, Some("src")));
+        let archive_path =
+            output_dir.join(archive_name(&version, suffix, Some("src"), args.format));
         ensure_output_file(&archive_path, args.force)?;
         create_directory_archive(
             &tool_ctx,
             &source_root,
             &archive_path,
-            SRC_EXCLUDES,
+            &src_excludes(&config.release),
+            args.format,
             "modorganizer_super",
         )
         .await?;
+        artifacts.push(archive_path);
     }
 
     if args.copy_installer() {
@@ -153,77 +203,154 @@ async fn run_devbuild(args: &DevbuildArgs, config: &Config, dry_run: bool) -> Re
             .install_installer
             .as_ref()
             .context("paths.install_installer not configured")?;
-        copy_installer_files(installer_dir, &output_dir, args.force, dry_run).await?;
+        artifacts
+            .extend(copy_installer_files(installer_dir, &output_dir, args.force, dry_run).await?);
+    }
+
+    if args.checksums {
+        let endings = config.global.line_endings;
+        write_checksums(&output_dir, &artifacts, endings, args.force, dry_run).await?;
     }
 
     Ok(())
 }
 
-async fn run_official(args: &OfficialArgs, config: &Config, dry_run: bool) -> Result<()> {
+async fn run_official(
+    args: &OfficialArgs,
+    config: &Config,
+    dry_run: bool,
+    jobs: Option<NonZeroUsize>,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
     let repos = get_repos(config).context("failed to discover repositories")?;
-    let repo_count = repos.len();
 
     if repos.is_empty() {
         anyhow::bail!("no repositories found under paths.build; run build/fetch first");
     }
 
-    validate_official_branch(&repos, repo_count, args, config)?;
+    // Shared by the branch validation and the build pipeline
+    let branches = Arc::new(RemoteBranchCache::new());
+    validate_official_branch(&repos, &args.branch, config, &branches).await?;
+    if let Some(since) = &args.notes_since {
+        validate_notes_since(&repos, since)?;
+    }
     checkout_official_repos(&repos, args, dry_run)?;
-    run_official_build_pipeline(config, dry_run, args.build_installer()).await?;
-    create_official_archives(args, config, dry_run).await
+    let build_installer = args.build_installer();
+    run_official_build_pipeline(
+        config,
+        dry_run,
+        build_installer,
+        jobs,
+        priority,
+        cancel_token,
+        &branches,
+    )
+    .await?;
+    create_official_archives(args, config, &repos, dry_run, priority, cancel_token).await
 }
 
-fn validate_official_branch(
+/// Checks that `branch` exists on the remote of every repo.
+///
+/// Up to [`BRANCH_CHECK_CONCURRENCY`] `git ls-remote` calls run at once; the
+/// error lists every repo missing the branch.
+async fn validate_official_branch(
     repos: &[PathBuf],
-    repo_count: usize,
-    args: &OfficialArgs,
+    branch: &str,
     config: &Config,
+    branches: &Arc<RemoteBranchCache>,
 ) -> Result<()> {
     info!(
-        branch = %args.branch,
-        repos = repo_count,
+        branch = %branch,
+        repos = repos.len(),
         "Validating branch exists on all repositories"
     );
 
+    let mut checks = JoinSet::new();
     let mut missing = Vec::new();
     for repo in repos {
         let repo_name = repo
             .file_name()
             .and_then(|name| name.to_str())
-            .with_context(|| format!("invalid repo path: {}", repo.display()))?;
+            .with_context(|| format!("invalid repo path: {}", repo.display()))?
+            .to_string();
 
         let url = format!(
             "{}{}/{}.git",
             config.task.git_url_prefix, config.task.mo_org, repo_name
         );
 
-        debug!(repo = %repo_name, branch = %args.branch, "checking remote branch");
-
-        if !remote_branch_exists(&url, &args.branch)
-            .with_context(|| format!("failed to check branch for {repo_name}"))?
-        {
-            missing.push(repo_name.to_string());
+        if checks.len() >= BRANCH_CHECK_CONCURRENCY {
+            join_branch_check(&mut checks, &mut missing).await?;
         }
+
+        let branch = branch.to_string();
+        let branches = Arc::clone(branches);
+        checks.spawn_blocking(move || {
+            debug!(repo = %repo_name, branch = %branch, "checking remote branch");
+            let exists = branches
+                .get_or_check(&url, &branch, || remote_branch_exists(&url, &branch))
+                .with_context(|| format!("failed to check branch for {repo_name}"))?;
+            Ok((repo_name, exists))
+        });
+    }
+    while !checks.is_empty() {
+        join_branch_check(&mut checks, &mut missing).await?;
     }
 
     if !missing.is_empty() {
+        missing.sort_unstable();
         let missing_list = missing.join(", ");
-        anyhow::bail!(
-            "branch '{}' not found for repositories: {}",
-            args.branch,
-            missing_list
-        );
+        anyhow::bail!("branch '{branch}' not found for repositories: {missing_list}");
     }
 
     info!(
-        branch = %args.branch,
-        count = repo_count,
+        branch = %branch,
+        count = repos.len(),
         "All repos have the required branch; proceeding with official release"
     );
 
     Ok(())
 }
 
+/// Checks that the `--notes-since` ref exists in every repo, so the release
+/// notes do not fail after everything was built.
+fn validate_notes_since(repos: &[PathBuf], since: &str) -> Result<()> {
+    let mut missing = Vec::new();
+    for repo in repos {
+        if !has_revision(repo, since)? {
+            missing.push(repo.file_name().map_or_else(
+                || repo.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            ));
+        }
+    }
+
+    if !missing.is_empty() {
+        missing.sort_unstable();
+        anyhow::bail!(
+            "--notes-since ref '{since}' not found in repositories: {}",
+            missing.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Waits for the next branch check, adding its repo to `missing` if the
+/// branch does not exist.
+async fn join_branch_check(
+    checks: &mut JoinSet<Result<(String, bool)>>,
+    missing: &mut Vec<String>,
+) -> Result<()> {
+    if let Some(joined) = checks.join_next().await {
+        let (repo_name, exists) = joined.context("branch check panicked")??;
+        if !exists {
+            missing.push(repo_name);
+        }
+    }
+    Ok(())
+}
+
 fn checkout_official_repos(repos: &[PathBuf], args: &OfficialArgs, dry_run: bool) -> Result<()> {
     info!(branch = %args.branch, "Checking out all repositories to branch");
 
@@ -255,72 +382,98 @@ fn checkout_official_repos(repos: &[PathBuf], args: &OfficialArgs, dry_run: bool
     Ok(())
 }
 
+/// Fetches and builds everything an official release ships.
+///
+/// All sources are fetched first, up to `jobs` at once, since fetches do not
+/// depend on each other. The builds then run one task after the other in
+/// dependency order.
 async fn run_official_build_pipeline(
     config: &Config,
     dry_run: bool,
     build_installer: bool,
+    jobs: Option<NonZeroUsize>,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+    branches: &Arc<RemoteBranchCache>,
 ) -> Result<()> {
     info!("Starting full build pipeline");
 
     let config = Arc::new(config.clone());
-    let mut manager = TaskManager::new(Arc::clone(&config))
-        .with_dry_run(dry_run)
+    let manager = || {
+        TaskManager::new(Arc::clone(&config), cancel_token)
+            .with_dry_run(dry_run)
+            .with_env(config.env_overlay())
+            .with_priority(priority)
+            .with_branch_cache(Arc::clone(branches))
+    };
+
+    let mut fetch = manager()
+        .with_fetch_parallelism(jobs.unwrap_or(NonZeroUsize::MIN))
         .with_do_fetch(true)
-        .with_do_build(true);
-
-    // Add all standard build tasks in the correct order
-    // These mirror the BUILTIN_TASKS from main.rs
-    manager.add(Task::Usvfs(UsvfsTask::new()));
-    manager.add(Task::ModOrganizer(ModOrganizerTask::new(
-        "modorganizer".to_string(),
-    )));
-    manager.add(Task::Stylesheets(StylesheetsTask::new()));
-    manager.add(Task::ExplorerPP(ExplorerPPTask::new()));
-    manager.add(Task::Licenses(LicensesTask::new()));
-    manager.add(Task::Translations(TranslationsTask::new()));
-
-    // Build installer if requested
-    if build_installer {
-        manager.add(Task::Installer(InstallerTask::new()));
+        .with_do_build(false);
+    fetch.add(Task::Parallel(ParallelTasks::new(official_tasks(
+        &config,
+        build_installer,
+    ))));
+    fetch.run_all().await.context("fetching sources failed")?;
+
+    let mut build = manager().with_do_fetch(false).with_do_build(true);
+    for task in official_tasks(&config, build_installer) {
+        build.add(task);
     }
-
-    manager.run_all().await.context("build pipeline failed")?;
+    build.run_all().await.context("build pipeline failed")?;
 
     info!("Build completed successfully");
 
     Ok(())
 }
 
+/// Tasks of an official release, in build order.
+fn official_tasks(config: &Config, build_installer: bool) -> Vec<Task> {
+    builtin_tasks(config)
+        .into_iter()
+        .filter(|task| build_installer || !matches!(task, Task::Installer(_)))
+        .collect()
+}
+
 async fn create_official_archives(
     args: &OfficialArgs,
     config: &Config,
+    repos: &[PathBuf],
     dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
 ) -> Result<()> {
-    let output_dir = resolve_official_output_dir(args, config)?;
+    let version = version::determine_official_version(config).await?;
+    let output_dir = resolve_official_output_dir(args, config, &version)?;
     ensure_output_dir(&output_dir, dry_run).await?;
 
-    let version = version::determine_official_version(config).await?;
     info!(version = %version, output_dir = %output_dir.display(), "Creating release archives");
 
     let config = Arc::new(config.clone());
-    let tool_ctx = ToolContext::new(Arc::clone(&config), CancellationToken::new(), dry_run);
+    let tool_ctx = ToolContext::new(Arc::clone(&config), cancel_token.clone(), dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority);
 
+    let mut artifacts = Vec::new();
     if args.create_bin() {
         let install_bin = config
             .paths
             .install_bin
             .as_ref()
             .context("paths.install_bin not configured")?;
-        let archive_path = output_dir.join(archive_name(&version, None, None));
+        let archive_path = output_dir.join(archive_name(&version, None, None, args.format));
         ensure_output_file(&archive_path, args.force)?;
         create_directory_archive(
             &tool_ctx,
             install_bin,
             &archive_path,
             BIN_EXCLUDES,
+            args.format,
             "install/bin",
         )
         .await?;
+        artifacts.push(archive_path);
     }
 
     if args.create_pdbs() {
@@ -329,16 +482,18 @@ async fn create_official_archives(
             .install_pdbs
             .as_ref()
             .context("paths.install_pdbs not configured")?;
-        let archive_path = output_dir.join(archive_name(&version, None, Some("pdbs")));
+        let archive_path = output_dir.join(archive_name(&version, None, Some("pdbs"), args.format));
         ensure_output_file(&archive_path, args.force)?;
         create_directory_archive(
             &tool_ctx,
             install_pdbs,
             &archive_path,
             PDB_EXCLUDES,
+            args.format,
             "install/pdbs",
         )
         .await?;
+        artifacts.push(archive_path);
     }
 
     // Phase 5: Copy installer to output dir
@@ -348,7 +503,20 @@ async fn create_official_archives(
             .install_installer
             .as_ref()
             .context("paths.install_installer not configured")?;
-        copy_installer_files(installer_dir, &output_dir, args.force, dry_run).await?;
+        artifacts
+            .extend(copy_installer_files(installer_dir, &output_dir, args.force, dry_run).await?);
+    }
+
+    if args.checksums {
+        let endings = config.global.line_endings;
+        write_checksums(&output_dir, &artifacts, endings, args.force, dry_run).await?;
+    }
+
+    if let Some(since) = &args.notes_since {
+        let notes = output_dir.join(notes_file_name(&version));
+        ensure_output_file(&notes, args.force)?;
+        let endings = config.global.line_endings;
+        write_release_notes(repos, since, &version, &notes, endings, dry_run).await?;
     }
 
     info!(
@@ -360,33 +528,96 @@ async fn create_official_archives(
     Ok(())
 }
 
-fn resolve_official_output_dir(args: &OfficialArgs, config: &Config) -> Result<PathBuf> {
-    if let Some(dir) = &args.output_dir {
-        return Ok(dir.clone());
+/// Name of the release notes written by `--notes-since`.
+fn notes_file_name(version: &str) -> String {
+    format!("CHANGELOG-{version}.md")
+}
+
+/// Writes the commits of every repo since `since` to `notes`, grouped by
+/// repo. Repos without commits in the range are left out.
+async fn write_release_notes(
+    repos: &[PathBuf],
+    since: &str,
+    version: &str,
+    notes: &Path,
+    endings: LineEndings,
+    dry_run: bool,
+) -> Result<()> {
+    let mut changes = Vec::new();
+    for repo in repos {
+        let commits = log_since(repo, since)?;
+        if commits.is_empty() {
+            debug!(repo = %repo.display(), since, "no commits since ref");
+            continue;
+        }
+        let name = repo.file_name().map_or_else(
+            || repo.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        );
+        changes.push((name, commits));
     }
 
-    let prefix = config
-        .paths
-        .prefix()
-        .context("paths.prefix not configured")?;
-    Ok(prefix.join("releases"))
+    if dry_run {
+        info!(
+            path = %notes.display(),
+            since,
+            repos = changes.len(),
+            commits = changes.iter().map(|(_, commits)| commits.len()).sum::<usize>(),
+            "[DRY-RUN] would write release notes"
+        );
+        return Ok(());
+    }
+
+    write_text(
+        notes,
+        &format_release_notes(version, since, &changes),
+        endings,
+    )
+    .await
+    .with_context(|| format!("failed to write {}", notes.display()))?;
+    info!(path = %notes.display(), repos = changes.len(), "Wrote release notes");
+    Ok(())
+}
+
+/// Formats release notes as Markdown with one section per repo.
+fn format_release_notes(version: &str, since: &str, changes: &[(String, Vec<String>)]) -> String {
+    let mut out = format!("# Changelog for {version}\n\nChanges since `{since}`.\n");
+    for (repo, commits) in changes {
+        let _ = write!(out, "\n## {repo}\n\n");
+        for commit in commits {
+            let _ = writeln!(out, "- {commit}");
+        }
+    }
+    out
+}
+
+fn resolve_official_output_dir(
+    args: &OfficialArgs,
+    config: &Config,
+    version: &str,
+) -> Result<PathBuf> {
+    let base = match &args.output_dir {
+        Some(dir) => dir.clone(),
+        None => default_output_dir(config)?,
+    };
+    Ok(config.release.output_layout.apply(&base, version))
 }
 
 /// Finds `.exe` files in `installer_dir`, sorts them, and copies each to
-/// `output_dir`. Warns and returns `Ok(())` when the directory is missing or
-/// contains no executables.
+/// `output_dir`, returning the copies. Warns and returns nothing when the
+/// directory is missing or contains no executables.
 async fn copy_installer_files(
     installer_dir: &Path,
     output_dir: &Path,
     force: bool,
     dry_run: bool,
-) -> Result<()> {
+) -> Result<Vec<PathBuf>> {
     if !installer_dir.exists() {
         warn!(
             path = %installer_dir.display(),
             "Installer directory not found; skipping copy"
         );
-        return Ok(());
+        return Ok(Vec::new());
     }
 
     let mut entries = fs::read_dir(installer_dir).await.with_context(|| {
@@ -419,7 +650,7 @@ async fn copy_installer_files(
             path = %installer_dir.display(),
             "No installer executables found"
         );
-        return Ok(());
+        return Ok(Vec::new());
     }
 
     if installers.len() > 1 {
@@ -431,6 +662,7 @@ async fn copy_installer_files(
 
     installers.sort();
 
+    let mut copied = Vec::new();
     for installer in installers {
         let filename = installer
             .file_name()
@@ -444,32 +676,78 @@ async fn copy_installer_files(
                 dst = %destination.display(),
                 "[DRY-RUN] would copy installer"
             );
+            copied.push(destination);
             continue;
         }
 
-        fs::copy(&installer, &destination).await.with_context(|| {
-            format!(
-                "failed to copy {} to {}",
-                installer.display(),
-                destination.display()
-            )
-        })?;
+        copy_atomic(&installer, &destination)
+            .await
+            .with_context(|| {
+                format!(
+                    "failed to copy {} to {}",
+                    installer.display(),
+                    destination.display()
+                )
+            })?;
 
         info!(
             src = %installer.display(),
             dst = %destination.display(),
             "Copied installer"
         );
+        copied.push(destination);
     }
 
-    Ok(())
+    Ok(copied)
 }
 
-fn resolve_output_dir(args: &DevbuildArgs, config: &Config) -> Result<PathBuf> {
-    if let Some(dir) = &args.output_dir {
-        return Ok(dir.clone());
+/// Writes `SHA256SUMS` to `output_dir` with one `<sha256>  <file name>` line
+/// per artifact, hashing each file as a stream.
+async fn write_checksums(
+    output_dir: &Path,
+    artifacts: &[PathBuf],
+    endings: LineEndings,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let manifest = output_dir.join(CHECKSUMS_FILE);
+    ensure_output_file(&manifest, force)?;
+
+    if dry_run {
+        info!(
+            path = %manifest.display(),
+            files = artifacts.len(),
+            "[DRY-RUN] would write checksums"
+        );
+        return Ok(());
+    }
+
+    let mut contents = String::new();
+    for artifact in artifacts {
+        let name = artifact
+            .file_name()
+            .with_context(|| format!("artifact has no file name: {}", artifact.display()))?;
+        let hash = sha256_file(artifact).await?;
+        let _ = writeln!(contents, "{hash}  {}", name.to_string_lossy());
     }
 
+    write_text(&manifest, &contents, endings)
+        .await
+        .with_context(|| format!("failed to write {}", manifest.display()))?;
+    info!(path = %manifest.display(), files = artifacts.len(), "Wrote checksums");
+    Ok(())
+}
+
+fn resolve_output_dir(args: &DevbuildArgs, config: &Config, version: &str) -> Result<PathBuf> {
+    let base = match &args.output_dir {
+        Some(dir) => dir.clone(),
+        None => default_output_dir(config)?,
+    };
+    Ok(config.release.output_layout.apply(&base, version))
+}
+
+/// Returns `prefix/releases`, used when no `--output-dir` is given.
+fn default_output_dir(config: &Config) -> Result<PathBuf> {
     let prefix = config
         .paths
         .prefix()
@@ -511,6 +789,7 @@ async fn create_directory_archive(
     base_dir: &Path,
     archive_path: &Path,
     excludes: &[&str],
+    format: ArchiveFormat,
     label: &str,
 ) -> Result<()> {
     if !base_dir.exists() {
@@ -527,6 +806,7 @@ async fn create_directory_archive(
         .archive(archive_path)
         .base_dir(base_dir)
         .exclude_patterns(excludes)
+        .format(format)
         .pack_dir_op();
 
     packer
@@ -537,6 +817,17 @@ async fn create_directory_archive(
     Ok(())
 }
 
+/// Returns the source archive excludes: the built-in list merged with or
+/// replaced by `[release] src_excludes`.
+fn src_excludes(release: &ReleaseConfig) -> Vec<&str> {
+    let configured = release.src_excludes.iter().map(String::as_str);
+    if release.src_excludes_replace {
+        configured.collect()
+    } else {
+        SRC_EXCLUDES.iter().copied().chain(configured).collect()
+    }
+}
+
 fn modorganizer_super_dir(config: &Config) -> Result<PathBuf> {
     let build_dir = config
         .paths
@@ -546,7 +837,12 @@ fn modorganizer_super_dir(config: &Config) -> Result<PathBuf> {
     Ok(build_dir.join("modorganizer_super"))
 }
 
-fn archive_name(version: &str, suffix: Option<&str>, what: Option<&str>) -> String {
+fn archive_name(
+    version: &str,
+    suffix: Option<&str>,
+    what: Option<&str>,
+    format: ArchiveFormat,
+) -> String {
     let mut parts = vec!["Mod.Organizer".to_string(), version.to_string()];
 
     if let Some(suffix) = suffix.filter(|s| !s.is_empty()) {
@@ -557,7 +853,7 @@ fn archive_name(version: &str, suffix: Option<&str>, what: Option<&str>) -> Stri
         parts.push(what.to_string());
     }
 
-    format!("{}.7z", parts.join("-"))
+    format!("{}.{}", parts.join("-"), format.extension())
 }
 
 #[cfg(test)]
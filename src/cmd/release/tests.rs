@@ -6,13 +6,19 @@
 use super::version::default_rc_path;
 use super::{
     DevbuildArgs, OfficialArgs, archive_name, ensure_output_dir, ensure_output_file,
-    modorganizer_super_dir, resolve_official_output_dir, resolve_output_dir,
+    format_release_notes, modorganizer_super_dir, resolve_official_output_dir, resolve_output_dir,
+    src_excludes, validate_notes_since, validate_official_branch, write_checksums,
 };
 use crate::cli::release::{
     BinaryOutputArgs, OfficialInstallerArgs, OfficialOutputArgs, PdbOutputArgs,
 };
 use crate::config::Config;
+use crate::config::types::{ReleaseConfig, ReleaseOutputLayout};
+use crate::task::tools::git::RemoteBranchCache;
+use crate::task::tools::packer::ArchiveFormat;
+use crate::utility::fs::text::LineEndings;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tempfile::TempDir;
 use tokio::fs;
 
@@ -24,25 +30,42 @@ fn temp_dir() -> TempDir {
 fn test_archive_name_cases() {
     // Consolidate all archive_name test cases into a single snapshot
     let cases = vec![
-        ("basic", archive_name("2.5.0", None, None)),
-        ("with_suffix", archive_name("2.5.0", Some("rc1"), None)),
-        ("with_what", archive_name("2.5.0", None, Some("pdbs"))),
+        (
+            "basic",
+            archive_name("2.5.0", None, None, ArchiveFormat::SevenZip),
+        ),
+        (
+            "with_suffix",
+            archive_name("2.5.0", Some("rc1"), None, ArchiveFormat::SevenZip),
+        ),
+        (
+            "with_what",
+            archive_name("2.5.0", None, Some("pdbs"), ArchiveFormat::SevenZip),
+        ),
         (
             "suffix_and_what",
-            archive_name("2.5.0", Some("beta"), Some("src")),
+            archive_name("2.5.0", Some("beta"), Some("src"), ArchiveFormat::SevenZip),
         ),
         (
             "empty_suffix_ignored",
-            archive_name("2.5.0", Some(""), Some("pdbs")),
+            archive_name("2.5.0", Some(""), Some("pdbs"), ArchiveFormat::SevenZip),
         ),
         (
             "empty_what_ignored",
-            archive_name("2.5.0", Some("rc1"), Some("")),
+            archive_name("2.5.0", Some("rc1"), Some(""), ArchiveFormat::SevenZip),
         ),
     ];
     insta::assert_yaml_snapshot!("archive_name_cases", cases);
 }
 
+#[test]
+fn test_archive_name_zip() {
+    assert_eq!(
+        archive_name("2.5.0", None, Some("pdbs"), ArchiveFormat::Zip),
+        "Mod.Organizer-2.5.0-pdbs.zip"
+    );
+}
+
 #[test]
 fn test_resolve_output_dir_from_args() {
     let args = DevbuildArgs {
@@ -50,7 +73,7 @@ fn test_resolve_output_dir_from_args() {
         ..Default::default()
     };
     let config = Config::default();
-    let result = resolve_output_dir(&args, &config).unwrap();
+    let result = resolve_output_dir(&args, &config, "2.5.0").unwrap();
     insta::assert_yaml_snapshot!(
         "resolve_output_dir_from_args",
         result.to_string_lossy().replace('\\', "/")
@@ -67,18 +90,40 @@ fn test_resolve_output_dir_from_config() {
         },
         ..Default::default()
     };
-    let result = resolve_output_dir(&args, &config).unwrap();
+    let result = resolve_output_dir(&args, &config, "2.5.0").unwrap();
     insta::assert_yaml_snapshot!(
         "resolve_output_dir_from_config",
         result.to_string_lossy().replace('\\', "/")
     );
 }
 
+#[test]
+fn test_resolve_output_dir_versioned_layout() {
+    let mut config = Config {
+        paths: crate::config::paths::PathsConfig {
+            prefix: Some(PathBuf::from("/mo2")),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    config.release.output_layout = ReleaseOutputLayout::Versioned;
+
+    let result = resolve_output_dir(&DevbuildArgs::default(), &config, "2.5.0").unwrap();
+    assert_eq!(result, PathBuf::from("/mo2/releases/2.5.0"));
+
+    let args = DevbuildArgs {
+        output_dir: Some(PathBuf::from("/custom/output")),
+        ..Default::default()
+    };
+    let result = resolve_output_dir(&args, &config, "2.5.0").unwrap();
+    assert_eq!(result, PathBuf::from("/custom/output/2.5.0"));
+}
+
 #[test]
 fn test_resolve_output_dir_error_no_prefix() {
     let args = DevbuildArgs::default();
     let config = Config::default();
-    let result = resolve_output_dir(&args, &config);
+    let result = resolve_output_dir(&args, &config, "2.5.0");
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
     assert!(
@@ -106,9 +151,12 @@ fn test_resolve_official_output_dir_from_args() {
             },
         },
         force: false,
+        checksums: false,
+        format: ArchiveFormat::SevenZip,
+        notes_since: None,
     };
     let config = Config::default();
-    let result = resolve_official_output_dir(&args, &config).unwrap();
+    let result = resolve_official_output_dir(&args, &config, "2.5.0").unwrap();
     insta::assert_yaml_snapshot!(
         "resolve_official_output_dir_from_args",
         result.to_string_lossy().replace('\\', "/")
@@ -221,3 +269,187 @@ fn test_modorganizer_super_dir() {
         result.to_string_lossy().replace('\\', "/")
     );
 }
+
+#[tokio::test]
+async fn test_write_checksums_manifest() {
+    let temp = temp_dir();
+    let archive = temp.path().join("Mod.Organizer-2.5.0.7z");
+    let installer = temp.path().join("Mod.Organizer-2.5.0.exe");
+    fs::write(&archive, "abc").await.unwrap();
+    fs::write(&installer, "").await.unwrap();
+    let artifacts = vec![archive, installer];
+
+    write_checksums(temp.path(), &artifacts, LineEndings::Lf, false, true)
+        .await
+        .unwrap();
+    assert!(!temp.path().join("SHA256SUMS").exists());
+
+    write_checksums(temp.path(), &artifacts, LineEndings::Lf, false, false)
+        .await
+        .unwrap();
+    let manifest = fs::read_to_string(temp.path().join("SHA256SUMS"))
+        .await
+        .unwrap();
+    assert_eq!(
+        manifest,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  \
+         Mod.Organizer-2.5.0.7z\n\
+         e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  \
+         Mod.Organizer-2.5.0.exe\n"
+    );
+
+    let error = write_checksums(temp.path(), &artifacts, LineEndings::Lf, false, false)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("already exists"), "{error}");
+}
+
+#[test]
+fn test_src_excludes_merge_and_replace() {
+    let mut release = ReleaseConfig::default();
+    let defaults = src_excludes(&release).len();
+    assert!(src_excludes(&release).contains(&"*.dll"));
+
+    release.src_excludes = vec!["*.pdb".to_string()];
+    let merged = src_excludes(&release);
+    assert_eq!(merged.len(), defaults + 1);
+    assert_eq!(merged.last(), Some(&"*.pdb"));
+
+    release.src_excludes_replace = true;
+    assert_eq!(src_excludes(&release), ["*.pdb"]);
+}
+
+#[tokio::test]
+async fn test_validate_official_branch_reports_all_missing() {
+    let temp = temp_dir();
+    let git = |dir: &std::path::Path, args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("failed to run git")
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    };
+
+    // Remotes: repo1 has the release branch, repo3 does not, repo2 is missing
+    for name in ["repo1", "repo3"] {
+        let remote = temp.path().join("org").join(format!("{name}.git"));
+        std::fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--quiet"]);
+        git(
+            &remote,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--allow-empty",
+                "--quiet",
+                "-m",
+                "init",
+            ],
+        );
+        if name == "repo1" {
+            git(&remote, &["branch", "release-2.5"]);
+        }
+    }
+
+    let mut config = Config::default();
+    config.task.git_url_prefix = format!("file://{}/", temp.path().display());
+    config.task.mo_org = "org".to_string();
+    let repos: Vec<PathBuf> = ["repo1", "repo2", "repo3"]
+        .iter()
+        .map(|name| temp.path().join("build").join(name))
+        .collect();
+
+    let branches = Arc::new(RemoteBranchCache::new());
+    let err = validate_official_branch(&repos, "release-2.5", &config, &branches)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "branch 'release-2.5' not found for repositories: repo2, repo3"
+    );
+
+    // The second check is answered from the shared cache
+    let url = format!("{}org/repo2.git", config.task.git_url_prefix);
+    assert_eq!(branches.get(&url, "release-2.5"), Some(false));
+    validate_official_branch(&repos[..1], "release-2.5", &config, &branches)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn test_validate_notes_since_reports_repos_without_ref() {
+    let temp = temp_dir();
+    let git = |dir: &std::path::Path, args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("failed to run git")
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    };
+
+    let repos: Vec<PathBuf> = ["repo1", "repo2", "repo3"]
+        .iter()
+        .map(|name| temp.path().join(name))
+        .collect();
+    for repo in &repos {
+        std::fs::create_dir_all(repo).unwrap();
+        git(repo, &["init", "--quiet"]);
+        git(
+            repo,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--allow-empty",
+                "--quiet",
+                "-m",
+                "init",
+            ],
+        );
+    }
+    git(&repos[0], &["tag", "v2.5.0"]);
+
+    validate_notes_since(&repos[..1], "v2.5.0").unwrap();
+    let err = validate_notes_since(&repos, "v2.5.0").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "--notes-since ref 'v2.5.0' not found in repositories: repo2, repo3"
+    );
+}
+
+#[test]
+fn test_format_release_notes() {
+    let changes = vec![
+        (
+            "modorganizer".to_string(),
+            vec![
+                "abc1234 Fix crash".to_string(),
+                "def5678 Add option".to_string(),
+            ],
+        ),
+        (
+            "modorganizer-uibase".to_string(),
+            vec!["0123abc Bump version".to_string()],
+        ),
+    ];
+
+    assert_eq!(
+        format_release_notes("2.5.2", "v2.5.1", &changes),
+        "# Changelog for 2.5.2\n\n\
+         Changes since `v2.5.1`.\n\n\
+         ## modorganizer\n\n\
+         - abc1234 Fix crash\n\
+         - def5678 Add option\n\n\
+         ## modorganizer-uibase\n\n\
+         - 0123abc Bump version\n"
+    );
+}
@@ -0,0 +1,148 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Machine-readable build results written by `build --report`.
+//!
+//! ```text
+//! task::results (pass/fail + duration per task)
+//!        |
+//!        v
+//!   JunitReport::new(total, results)
+//!        |
+//!        v
+//!   <testsuites>
+//!     <testsuite name="mob build">
+//!       <testcase name="<task>" time=".."/>             passed
+//!       <testcase ..><failure message=".."/></testcase>  failed
+//! ```
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+
+use crate::cli::build::ReportFormat;
+use crate::error::Result;
+use crate::task::results::TaskResult;
+use crate::utility::fs::text::{LineEndings, write_text};
+
+/// Name of the single test suite holding all tasks.
+const SUITE_NAME: &str = "mob build";
+
+/// `JUnit` XML report with one test case per task.
+#[derive(Debug, Clone)]
+pub struct JunitReport {
+    total: Duration,
+    results: Vec<TaskResult>,
+}
+
+impl JunitReport {
+    /// Creates a report for a build that ran `total` and produced `results`.
+    #[must_use]
+    pub fn new(total: Duration, results: &[TaskResult]) -> Self {
+        Self {
+            total,
+            results: results.to_vec(),
+        }
+    }
+
+    /// Returns the number of failed tasks.
+    #[must_use]
+    pub fn failures(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.failure().is_some())
+            .count()
+    }
+
+    /// Renders the report as `JUnit` XML.
+    #[must_use]
+    pub fn to_xml(&self) -> String {
+        let tests = self.results.len();
+        let failures = self.failures();
+        let time = self.total.as_secs_f64();
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuites name=\"{SUITE_NAME}\" tests=\"{tests}\" failures=\"{failures}\" time=\"{time:.3}\">"
+        );
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{SUITE_NAME}\" tests=\"{tests}\" failures=\"{failures}\" time=\"{time:.3}\">"
+        );
+
+        for result in &self.results {
+            let name = escape_xml(result.task());
+            let time = result.duration().as_secs_f64();
+            match result.failure() {
+                None => {
+                    let _ = writeln!(
+                        out,
+                        "    <testcase name=\"{name}\" classname=\"mob\" time=\"{time:.3}\"/>"
+                    );
+                }
+                Some(failure) => {
+                    let message = escape_xml(failure.lines().next().unwrap_or_default());
+                    let _ = writeln!(
+                        out,
+                        "    <testcase name=\"{name}\" classname=\"mob\" time=\"{time:.3}\">"
+                    );
+                    let _ = writeln!(
+                        out,
+                        "      <failure message=\"{message}\">{}</failure>",
+                        escape_xml(failure)
+                    );
+                    let _ = writeln!(out, "    </testcase>");
+                }
+            }
+        }
+
+        out.push_str("  </testsuite>\n</testsuites>\n");
+        out
+    }
+
+    /// Writes the report to `path` in `format` with `endings`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub async fn write(
+        &self,
+        format: ReportFormat,
+        path: &Path,
+        endings: LineEndings,
+    ) -> Result<()> {
+        let contents = match format {
+            ReportFormat::Junit => self.to_xml(),
+        };
+        write_text(path, &contents, endings)
+            .await
+            .with_context(|| format!("Failed to write build report: {}", path.display()))?;
+        Ok(())
+    }
+}
+
+/// Escapes text for use in XML attributes and element content.
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            c if c.is_control() && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests;
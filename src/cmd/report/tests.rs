@@ -0,0 +1,48 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::time::Duration;
+
+use super::JunitReport;
+use crate::task::results::TaskResult;
+
+#[test]
+fn test_junit_report_passed_and_failed() {
+    let results = [
+        TaskResult::new("usvfs".to_string(), Duration::from_millis(1500), None),
+        TaskResult::new(
+            "modorganizer-uibase".to_string(),
+            Duration::from_millis(250),
+            Some("failed to build <uibase>: exit code 1\ncaused by \"link\"".to_string()),
+        ),
+    ];
+    let report = JunitReport::new(Duration::from_secs(2), &results);
+
+    assert_eq!(report.failures(), 1);
+    assert_eq!(
+        report.to_xml(),
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuites name=\"mob build\" tests=\"2\" failures=\"1\" time=\"2.000\">
+  <testsuite name=\"mob build\" tests=\"2\" failures=\"1\" time=\"2.000\">
+    <testcase name=\"usvfs\" classname=\"mob\" time=\"1.500\"/>
+    <testcase name=\"modorganizer-uibase\" classname=\"mob\" time=\"0.250\">
+      <failure message=\"failed to build &lt;uibase&gt;: exit code 1\">failed to build &lt;uibase&gt;: exit code 1&#10;caused by &quot;link&quot;</failure>
+    </testcase>
+  </testsuite>
+</testsuites>
+"
+    );
+}
+
+#[test]
+fn test_junit_report_empty() {
+    let report = JunitReport::new(Duration::ZERO, &[]);
+    assert_eq!(report.failures(), 0);
+    assert!(
+        report
+            .to_xml()
+            .contains("tests=\"0\" failures=\"0\" time=\"0.000\"")
+    );
+}
@@ -0,0 +1,398 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Transifex command implementation for mob-rs.
+//!
+//! ```text
+//! tx get      --> TransifexTool init/config/pull
+//! tx build    --> LreleaseTool per discovered project
+//! tx validate --> parse .tx/config, compare with transifex.team/project/url
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::cli::tx::TxSubcommand;
+use crate::cli::tx::{TxArgs, TxBuildArgs, TxGetArgs, TxValidateArgs};
+use crate::config::Config;
+use crate::config::types::TransifexPullMode;
+use crate::core::process::priority::ProcessPriority;
+use crate::error::Result;
+use crate::task::tasks::translations::{TranslationsTask, discover_projects};
+use crate::task::tools::lrelease::LreleaseTool;
+use crate::task::tools::transifex::TransifexTool;
+use crate::task::tools::{Tool, ToolContext};
+use anyhow::{Context, bail};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+/// Main handler for tx command.
+///
+/// # Errors
+///
+/// Returns an error if configuration fails or the tx tool fails.
+pub async fn run_tx_command(
+    args: &TxArgs,
+    config: &Config,
+    dry_run: bool,
+    priority: ProcessPriority,
+    cancel_token: &CancellationToken,
+) -> Result<()> {
+    let config = Arc::new(config.clone());
+    let ctx = ToolContext::new(Arc::clone(&config), cancel_token.clone(), dry_run)
+        .with_env(config.env_overlay())
+        .with_priority(priority);
+
+    match &args.subcommand {
+        TxSubcommand::Get(get_args) => run_tx_get(get_args, &config, &ctx).await,
+        TxSubcommand::Build(build_args) => run_tx_build(build_args, &ctx).await,
+        TxSubcommand::Validate(validate_args) => run_tx_validate(validate_args, &config),
+    }
+}
+
+async fn run_tx_get(get_args: &TxGetArgs, config: &Config, ctx: &ToolContext) -> Result<()> {
+    let key = if let Some(k) = &get_args.key {
+        k.clone()
+    } else {
+        eprintln!("TX API key required (use -k/--key or TX_TOKEN environment variable)");
+        bail!("transifex api key missing");
+    };
+
+    let team = if let Some(t) = &get_args.team {
+        t.clone()
+    } else {
+        eprintln!("Transifex team required (use -t/--team)");
+        bail!("transifex team missing");
+    };
+
+    let project = if let Some(p) = &get_args.project {
+        p.clone()
+    } else {
+        eprintln!("Transifex project required (use -p/--project)");
+        bail!("transifex project missing");
+    };
+
+    let url = get_args.url.as_ref().map_or_else(
+        || format!("{}/{}/{}", config.transifex.url, team, project),
+        std::clone::Clone::clone,
+    );
+    let minimum = get_args.minimum.unwrap_or(100);
+
+    if ctx.is_dry_run() {
+        info!(
+            path = %get_args.path.display(),
+            "[DRY-RUN] would initialize transifex directory"
+        );
+    } else {
+        TransifexTool::new()
+            .root(&get_args.path)
+            .init_op()
+            .run(ctx)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to initialize transifex: {e}");
+                e
+            })?;
+    }
+
+    if ctx.is_dry_run() {
+        info!(
+            path = %get_args.path.display(),
+            url = %url,
+            "[DRY-RUN] would configure transifex remote"
+        );
+    } else {
+        TransifexTool::new()
+            .root(&get_args.path)
+            .api_key(&key)
+            .url(&url)
+            .config_op()
+            .run(ctx)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to configure transifex: {e}");
+                e
+            })?;
+    }
+
+    if ctx.is_dry_run() {
+        info!(
+            path = %get_args.path.display(),
+            minimum = minimum,
+            force = get_args.force,
+            since = get_args.since,
+            "[DRY-RUN] would pull translations"
+        );
+    } else {
+        TransifexTool::new()
+            .root(&get_args.path)
+            .api_key(&key)
+            .minimum(minimum)
+            .force(get_args.force)
+            .pull_mode(if get_args.since {
+                TransifexPullMode::Incremental
+            } else {
+                TransifexPullMode::Full
+            })
+            .use_git_timestamps(get_args.use_git_timestamps)
+            .pull_op()
+            .run(ctx)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to pull translations: {e}");
+                e
+            })?;
+    }
+
+    Ok(())
+}
+
+async fn run_tx_build(build_args: &TxBuildArgs, ctx: &ToolContext) -> Result<()> {
+    let mut source = build_args.source.clone();
+    let tx_dir = source.join(".tx");
+    let translations_dir = source.join("translations");
+    if tx_dir.exists() && translations_dir.exists() {
+        source = translations_dir;
+    }
+
+    let projects = discover_projects(&source).await.map_err(|e| {
+        eprintln!("Failed to discover translation projects: {e}");
+        e
+    })?;
+
+    if projects.is_empty() {
+        if ctx.is_dry_run() {
+            info!(
+                path = %source.display(),
+                "[DRY-RUN] no translation projects found"
+            );
+        } else {
+            eprintln!("No translation projects found in {}", source.display());
+        }
+        return Ok(());
+    }
+
+    if !ctx.is_dry_run() && !build_args.destination.exists() {
+        tokio::fs::create_dir_all(&build_args.destination)
+            .await
+            .map_err(|e| {
+                eprintln!(
+                    "Failed to create destination directory {}: {}",
+                    build_args.destination.display(),
+                    e
+                );
+                e
+            })?;
+    }
+
+    for project in projects {
+        if ctx.is_dry_run() {
+            info!(
+                project = %project.name(),
+                files = project.ts_files().len(),
+                "[DRY-RUN] would compile translations"
+            );
+        } else {
+            LreleaseTool::new()
+                .project(project.name())
+                .sources(project.ts_files())
+                .output_dir(&build_args.destination)
+                .run(ctx)
+                .await
+                .map_err(|e| {
+                    eprintln!(
+                        "Failed to compile translations for project {}: {}",
+                        project.name(),
+                        e
+                    );
+                    e
+                })?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run_tx_validate(validate_args: &TxValidateArgs, config: &Config) -> Result<()> {
+    let root = match &validate_args.path {
+        Some(path) => path.clone(),
+        None => TranslationsTask::source_path(config)?,
+    };
+    let config_path = root.join(".tx").join("config");
+    let contents = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+
+    let expected = TxExpected::new(
+        validate_args
+            .team
+            .as_deref()
+            .unwrap_or(&config.transifex.team),
+        validate_args
+            .project
+            .as_deref()
+            .unwrap_or(&config.transifex.project),
+        validate_args
+            .url
+            .as_deref()
+            .unwrap_or(&config.transifex.url),
+    );
+
+    let issues = validate_tx_config(&contents, &expected);
+    if issues.is_empty() {
+        println!("{}: ok", config_path.display());
+        return Ok(());
+    }
+
+    for issue in &issues {
+        println!("{}: {issue}", config_path.display());
+    }
+    bail!(
+        "{} problem(s) found in {}",
+        issues.len(),
+        config_path.display()
+    );
+}
+
+/// Values a `.tx/config` is expected to agree with.
+#[derive(Debug, Clone, Copy)]
+pub struct TxExpected<'a> {
+    team: &'a str,
+    project: &'a str,
+    url: &'a str,
+}
+
+impl<'a> TxExpected<'a> {
+    /// Creates the expected values for the organization `team`, the project
+    /// slug `project` and the host `url`.
+    #[must_use]
+    pub const fn new(team: &'a str, project: &'a str, url: &'a str) -> Self {
+        Self { team, project, url }
+    }
+
+    /// Transifex organization (the `transifex.team` slug).
+    #[must_use]
+    pub const fn team(&self) -> &'a str {
+        self.team
+    }
+
+    /// Transifex project slug.
+    #[must_use]
+    pub const fn project(&self) -> &'a str {
+        self.project
+    }
+
+    /// Transifex host URL.
+    #[must_use]
+    pub const fn url(&self) -> &'a str {
+        self.url
+    }
+}
+
+/// Checks the contents of a `.tx/config` file against `expected`.
+///
+/// Resource sections must be named `o:<team>:p:<project>:r:<resource>` and
+/// carry a `file_filter` with a `<lang>` placeholder; `[main]` must name the
+/// configured host. Returns one message per problem, empty if the file is sane.
+#[must_use]
+pub fn validate_tx_config(contents: &str, expected: &TxExpected<'_>) -> Vec<String> {
+    let mut issues = Vec::new();
+    let mut sections: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    let mut current: Option<String> = None;
+
+    for (index, raw) in contents.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let name = name.trim().to_string();
+            sections.entry(name.clone()).or_default();
+            current = Some(name);
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            issues.push(format!("line {}: cannot parse '{line}'", index + 1));
+            continue;
+        };
+        let Some(section) = &current else {
+            issues.push(format!("line {}: key outside of a section", index + 1));
+            continue;
+        };
+        sections
+            .entry(section.clone())
+            .or_default()
+            .insert(key.trim().to_string(), value.trim().to_string());
+    }
+
+    match sections.get("main") {
+        None => issues.push("missing [main] section".to_string()),
+        Some(main) => match main.get("host") {
+            None => issues.push("[main] has no host".to_string()),
+            Some(host) if host.trim_end_matches('/') != expected.url().trim_end_matches('/') => {
+                issues.push(format!(
+                    "[main] host is '{host}', expected '{}'",
+                    expected.url()
+                ));
+            }
+            Some(_) => {}
+        },
+    }
+
+    let resources: Vec<_> = sections
+        .iter()
+        .filter(|(name, _)| *name != "main")
+        .collect();
+    if resources.is_empty() {
+        issues.push("no resources configured, tx pull will download nothing".to_string());
+    }
+
+    for (name, keys) in resources {
+        match parse_resource_id(name) {
+            Some((org, project, _)) => {
+                if org != expected.team() {
+                    issues.push(format!(
+                        "[{name}] organization is '{org}', expected '{}'",
+                        expected.team()
+                    ));
+                }
+                if project != expected.project() {
+                    issues.push(format!(
+                        "[{name}] project is '{project}', expected '{}'",
+                        expected.project()
+                    ));
+                }
+            }
+            None => issues.push(format!(
+                "[{name}] is not an o:<organization>:p:<project>:r:<resource> id"
+            )),
+        }
+
+        match keys.get("file_filter") {
+            None => issues.push(format!("[{name}] has no file_filter")),
+            Some(filter) if !filter.contains("<lang>") => {
+                issues.push(format!("[{name}] file_filter '{filter}' has no <lang>"));
+            }
+            Some(_) => {}
+        }
+    }
+
+    issues
+}
+
+/// Splits `o:<org>:p:<project>:r:<resource>` into its three slugs.
+fn parse_resource_id(name: &str) -> Option<(&str, &str, &str)> {
+    let rest = name.strip_prefix("o:")?;
+    let (org, rest) = rest.split_once(":p:")?;
+    let (project, resource) = rest.split_once(":r:")?;
+    if org.is_empty() || project.is_empty() || resource.is_empty() {
+        return None;
+    }
+    Some((org, project, resource))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use super::{TxExpected, validate_tx_config};
+
+const EXPECTED: TxExpected<'static> = TxExpected::new(
+    "mod-organizer-2-team",
+    "mod-organizer-2",
+    "https://app.transifex.com",
+);
+
+#[test]
+fn test_validate_tx_config_ok() {
+    let contents = "\
+[main]
+host = https://app.transifex.com/
+
+[o:mod-organizer-2-team:p:mod-organizer-2:r:organizer]
+file_filter = translations/mod-organizer-2.organizer/<lang>.ts
+type = QT
+";
+    assert!(validate_tx_config(contents, &EXPECTED).is_empty());
+}
+
+#[test]
+fn test_validate_tx_config_mismatches() {
+    let contents = "\
+[main]
+host = https://www.transifex.com
+
+[o:other-team:p:mod-organizer-2:r:organizer]
+file_filter = translations/organizer.ts
+
+[mod-organizer-2.organizer]
+file_filter = translations/<lang>.ts
+";
+    assert_eq!(
+        validate_tx_config(contents, &EXPECTED),
+        vec![
+            "[main] host is 'https://www.transifex.com', expected 'https://app.transifex.com'",
+            "[mod-organizer-2.organizer] is not an o:<organization>:p:<project>:r:<resource> id",
+            "[o:other-team:p:mod-organizer-2:r:organizer] organization is 'other-team', expected 'mod-organizer-2-team'",
+            "[o:other-team:p:mod-organizer-2:r:organizer] file_filter 'translations/organizer.ts' has no <lang>",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_tx_config_no_resources() {
+    let contents = "[main]\nhost = https://app.transifex.com\n";
+    assert_eq!(
+        validate_tx_config(contents, &EXPECTED),
+        vec!["no resources configured, tx pull will download nothing"]
+    );
+}
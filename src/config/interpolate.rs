@@ -0,0 +1,222 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! `${...}` references inside string values.
+//!
+//! ```text
+//! merged sources --> interpolate() --> Config deserialization --> resolve_and_validate()
+//!
+//! [paths]
+//! install = "${prefix}/install"          bare name --> same table (paths.prefix)
+//! [downloads]
+//! file = "MO2-${versions.usvfs}.7z"      qualified --> paths.*, versions.*, task.*
+//! literal = "$${not_a_reference}"        $${ --> ${
+//! ```
+//!
+//! A reference is looked up in the merged sources first, then in the built-in
+//! defaults, and may itself contain references. Unknown references, unset
+//! values and cycles are reported as `ConfigError::InvalidValue`.
+//!
+//! `[profiles.*]` blocks are skipped; a selected profile is interpolated once it
+//! has been merged over the other sources.
+
+use std::collections::BTreeMap;
+
+use config::{Source as _, Value, ValueKind};
+
+use super::Config;
+use crate::error::{ConfigError, Result};
+
+/// Tables that references may point into.
+const REFERENCE_ROOTS: [&str; 3] = ["paths", "versions", "task"];
+
+/// Expands every `${...}` reference in the string values of `root`.
+///
+/// # Errors
+///
+/// Returns `ConfigError::InvalidValue` for malformed, unknown or unset
+/// references and for reference cycles.
+pub fn interpolate(root: &mut Value) -> Result<()> {
+    let mut interpolator = Interpolator::new(root.clone())?;
+    let mut path = Vec::new();
+    if let ValueKind::Table(table) = &mut root.kind {
+        for (key, value) in sorted(table) {
+            if key == "profiles" {
+                continue;
+            }
+            path.push(key.clone());
+            interpolator.walk(value, &mut path)?;
+            path.pop();
+        }
+    }
+    Ok(())
+}
+
+/// Looks up and expands references against a snapshot of the merged sources
+/// and the built-in defaults.
+struct Interpolator {
+    root: Value,
+    defaults: Value,
+    resolved: BTreeMap<String, String>,
+    stack: Vec<String>,
+}
+
+impl Interpolator {
+    fn new(root: Value) -> Result<Self> {
+        let defaults = config::Config::try_from(&Config::default())?.collect()?;
+        Ok(Self {
+            root,
+            defaults: Value::new(None, ValueKind::Table(defaults)),
+            resolved: BTreeMap::new(),
+            stack: Vec::new(),
+        })
+    }
+
+    /// Expands the strings below `value`, whose key is `path`.
+    fn walk(&mut self, value: &mut Value, path: &mut Vec<String>) -> Result<()> {
+        match &mut value.kind {
+            ValueKind::String(text) if text.contains('$') => {
+                *text = self.expand(text, path)?;
+            }
+            ValueKind::Table(table) => {
+                for (key, value) in sorted(table) {
+                    path.push(key.clone());
+                    self.walk(value, path)?;
+                    path.pop();
+                }
+            }
+            ValueKind::Array(values) => {
+                for value in values {
+                    self.walk(value, path)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Expands the references in `text`, the value of the key `path`.
+    fn expand(&mut self, text: &str, path: &[String]) -> Result<String> {
+        let mut expanded = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("${") {
+            if rest[..start].ends_with('$') {
+                expanded.push_str(&rest[..start - 1]);
+                expanded.push_str("${");
+                rest = &rest[start + 2..];
+                continue;
+            }
+            expanded.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}') else {
+                return Err(invalid(path, format!("unterminated reference in '{text}'")));
+            };
+            let name = rest[start + 2..start + end].trim();
+            let reference = qualify(name, path).ok_or_else(|| {
+                invalid(
+                    path,
+                    format!("'${{{name}}}' is not a ${{paths.*}}, ${{versions.*}} or ${{task.*}} reference"),
+                )
+            })?;
+            expanded.push_str(&self.resolve(&reference, path)?);
+            rest = &rest[start + end + 1..];
+        }
+        expanded.push_str(rest);
+        Ok(expanded)
+    }
+
+    /// Returns the expanded value of the dotted key `reference`.
+    fn resolve(&mut self, reference: &str, path: &[String]) -> Result<String> {
+        if let Some(value) = self.resolved.get(reference) {
+            return Ok(value.clone());
+        }
+        if let Some(position) = self.stack.iter().position(|key| key == reference) {
+            let mut cycle = self.stack[position..].to_vec();
+            cycle.push(reference.to_string());
+            return Err(invalid(
+                path,
+                format!("reference cycle: {}", cycle.join(" -> ")),
+            ));
+        }
+
+        let text = self.lookup(reference, path)?;
+        self.stack.push(reference.to_string());
+        let reference_path: Vec<String> = reference.split('.').map(str::to_string).collect();
+        let value = self.expand(&text, &reference_path);
+        self.stack.pop();
+
+        let value = value?;
+        self.resolved.insert(reference.to_string(), value.clone());
+        Ok(value)
+    }
+
+    /// Returns the raw value of `reference` from the sources or the defaults.
+    fn lookup(&self, reference: &str, path: &[String]) -> Result<String> {
+        let found = find(&self.root, reference).or_else(|| find(&self.defaults, reference));
+        match found.map(|value| &value.kind) {
+            None | Some(ValueKind::Nil) => Err(invalid(
+                path,
+                format!("reference '${{{reference}}}' is unknown or not set"),
+            )),
+            Some(ValueKind::Table(_) | ValueKind::Array(_)) => Err(invalid(
+                path,
+                format!("reference '${{{reference}}}' is not a single value"),
+            )),
+            Some(ValueKind::String(text)) => Ok(text.clone()),
+            Some(kind) => Ok(kind.to_string()),
+        }
+    }
+}
+
+/// Turns `name` into a dotted key below one of [`REFERENCE_ROOTS`].
+///
+/// A name without a dot refers to a key of the table containing `path`.
+fn qualify(name: &str, path: &[String]) -> Option<String> {
+    let reference = if name.contains('.') {
+        name.to_string()
+    } else {
+        let table = path.split_last().map_or(&[][..], |(_, table)| table);
+        let mut keys = table.to_vec();
+        keys.push(name.to_string());
+        keys.join(".")
+    };
+    let root = reference.split('.').next()?;
+    let valid = !name.is_empty()
+        && REFERENCE_ROOTS.contains(&root)
+        && reference.split('.').all(|key| !key.is_empty());
+    valid.then_some(reference)
+}
+
+/// Returns the entries of `table` ordered by key, so errors do not depend on
+/// the map's iteration order.
+fn sorted(table: &mut config::Map<String, Value>) -> Vec<(&String, &mut Value)> {
+    let mut entries: Vec<_> = table.iter_mut().collect();
+    entries.sort_by_key(|(key, _)| *key);
+    entries
+}
+
+/// Returns the value at the dotted key `reference` below `root`.
+fn find<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    reference
+        .split('.')
+        .try_fold(root, |value, key| match &value.kind {
+            ValueKind::Table(table) => table.get(key),
+            _ => None,
+        })
+}
+
+/// Builds the error for the value at `path`.
+fn invalid(path: &[String], message: String) -> anyhow::Error {
+    let (key, section) = path
+        .split_last()
+        .map_or((String::new(), String::new()), |(key, section)| {
+            (key.clone(), section.join("."))
+        });
+    ConfigError::InvalidValue {
+        section,
+        key,
+        message,
+    }
+    .into()
+}
@@ -12,23 +12,54 @@
 //!   .add_toml_file(req)
 //!   .add_toml_file(opt)
 //!   .add_toml_str()
+//!   .add_json_file() / .add_json_str()
+//!   .add_yaml_file() / .add_yaml_str()
 //!   .with_env_prefix()
+//!   .with_profile()
 //!   .set()
 //!        |
 //!        v
-//!    build() --> Config
+//!    build() --> ${...} interpolation --> Config
+//!
+//! with_profile(name):
+//!   files + env --> [profiles.<name>] --> set() overrides
 //! ```
+//!
+//! Every source is merged in the order it was added, whatever its format.
+//! JSON and YAML sources are parsed once up front so a syntax error names the
+//! file as `ConfigError::ParseError`.
 
 use std::path::PathBuf;
 
+use config::Source as _;
+
 use super::Config;
-use crate::error::Result;
+use super::interpolate::interpolate;
+use crate::error::{ConfigError, Result};
 
 /// Builder for loading configuration from multiple sources.
 pub struct ConfigLoader {
     builder: config::ConfigBuilder<config::builder::DefaultState>,
     env_prefix: Option<String>,
+    profile: Option<String>,
     files: Vec<(String, PathBuf)>,
+    parse_checks: Vec<(PathBuf, Box<dyn config::Source + Send + Sync>)>,
+}
+
+/// Source holding the contents of a selected `[profiles.<name>]` block.
+#[derive(Debug, Clone)]
+struct ProfileSource(config::Map<String, config::Value>);
+
+impl config::Source for ProfileSource {
+    fn clone_into_box(&self) -> Box<dyn config::Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(
+        &self,
+    ) -> std::result::Result<config::Map<String, config::Value>, config::ConfigError> {
+        Ok(self.0.clone())
+    }
 }
 
 impl ConfigLoader {
@@ -37,7 +68,9 @@ impl ConfigLoader {
         Self {
             builder: config::Config::builder(),
             env_prefix: None,
+            profile: None,
             files: Vec::new(),
+            parse_checks: Vec::new(),
         }
     }
 
@@ -80,12 +113,70 @@ impl ConfigLoader {
         self
     }
 
+    /// Adds a JSON configuration file to the loader.
+    ///
+    /// Same keys and layering as [`add_toml_file`](Self::add_toml_file).
+    #[must_use]
+    pub fn add_json_file<P: AsRef<std::path::Path>>(self, path: P) -> Self {
+        self.add_file(path.as_ref(), config::FileFormat::Json)
+    }
+
+    /// Adds JSON configuration content to the loader.
+    #[must_use]
+    pub fn add_json_str(self, content: &str) -> Self {
+        self.add_str(content, config::FileFormat::Json)
+    }
+
+    /// Adds a YAML configuration file to the loader.
+    ///
+    /// Same keys and layering as [`add_toml_file`](Self::add_toml_file).
+    #[must_use]
+    pub fn add_yaml_file<P: AsRef<std::path::Path>>(self, path: P) -> Self {
+        self.add_file(path.as_ref(), config::FileFormat::Yaml)
+    }
+
+    /// Adds YAML configuration content to the loader.
+    #[must_use]
+    pub fn add_yaml_str(self, content: &str) -> Self {
+        self.add_str(content, config::FileFormat::Yaml)
+    }
+
+    fn add_file(mut self, path: &std::path::Path, format: config::FileFormat) -> Self {
+        let source = config::File::from(path).format(format).required(true);
+        if path.exists() {
+            self.parse_checks
+                .push((path.to_path_buf(), Box::new(source.clone())));
+        }
+        self.builder = self.builder.add_source(source);
+        self.files.push(("file".to_string(), path.to_path_buf()));
+        self
+    }
+
+    fn add_str(mut self, content: &str, format: config::FileFormat) -> Self {
+        let source = config::File::from_str(content, format);
+        self.parse_checks
+            .push((PathBuf::from("<string>"), Box::new(source.clone())));
+        self.builder = self.builder.add_source(source);
+        self.files
+            .push(("string".to_string(), PathBuf::from("<string>")));
+        self
+    }
+
     #[must_use]
     pub fn with_env_prefix(mut self, prefix: &str) -> Self {
         self.env_prefix = Some(prefix.to_string());
         self
     }
 
+    /// Selects a `[profiles.<name>]` block to merge over files and environment.
+    ///
+    /// Overrides from `set()` still take precedence over the profile.
+    #[must_use]
+    pub fn with_profile(mut self, name: &str) -> Self {
+        self.profile = Some(name.to_string());
+        self
+    }
+
     /// Sets a configuration override.
     ///
     /// # Errors
@@ -107,9 +198,20 @@ impl ConfigLoader {
     /// Returns an error if:
     /// - Required configuration files are missing.
     /// - Configuration files have invalid TOML syntax.
+    /// - JSON or YAML sources cannot be parsed (`ConfigError::ParseError`).
     /// - Environment variables cannot be parsed.
+    /// - A `${...}` reference is unknown, unset or part of a cycle
+    ///   (`ConfigError::InvalidValue`).
     /// - The merged configuration cannot be deserialized into the `Config` struct.
+    /// - The profile selected with `with_profile()` does not exist.
     pub fn build(self) -> Result<Config> {
+        for (path, source) in &self.parse_checks {
+            source.collect().map_err(|e| ConfigError::ParseError {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            })?;
+        }
+
         let builder = match &self.env_prefix {
             Some(prefix) => self.builder.add_source(
                 config::Environment::with_prefix(prefix)
@@ -118,8 +220,27 @@ impl ConfigLoader {
             ),
             None => self.builder,
         };
-        let cfg = builder.build()?;
-        let mut config: Config = cfg.try_deserialize()?;
+        let cfg = builder.clone().build()?;
+        let mut config = deserialize(&cfg)?;
+
+        if let Some(name) = &self.profile {
+            let Some(profile) = config.profiles.get(name) else {
+                let available: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+                return Err(ConfigError::UnknownProfile {
+                    name: name.clone(),
+                    available: if available.is_empty() {
+                        "none".to_string()
+                    } else {
+                        available.join(", ")
+                    },
+                }
+                .into());
+            };
+            let table = profile.clone().into_table()?;
+            let cfg = builder.add_source(ProfileSource(table)).build()?;
+            config = deserialize(&cfg)?;
+        }
+
         config.resolve_and_validate()?;
         Ok(config)
     }
@@ -139,6 +260,13 @@ impl ConfigLoader {
     }
 }
 
+/// Expands `${...}` references in the merged sources and deserializes them.
+fn deserialize(cfg: &config::Config) -> Result<Config> {
+    let mut root = config::Value::new(None, config::ValueKind::Table(cfg.collect()?));
+    interpolate(&mut root)?;
+    Ok(root.try_deserialize()?)
+}
+
 impl Default for ConfigLoader {
     fn default() -> Self {
         Self::new()
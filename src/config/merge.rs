@@ -11,9 +11,15 @@
 //!
 //! Only explicitly set fields (`Some`) in override replace base values.
 
+use std::num::NonZeroU32;
+
 use serde::{Deserialize, Serialize};
 
-use super::types::{BuildConfiguration, GitBehavior, GitCloneOptions, RemoteSetup, TaskConfig};
+use super::types::{
+    BuildConfiguration, CmakeGenerator, GitBehavior, GitCloneOptions, RemoteSetup, TaskConfig,
+    UsvfsArch,
+};
+use crate::core::env::types::Arch;
 
 /// Task configuration with optional fields for field-level merging.
 ///
@@ -38,15 +44,36 @@ pub struct TaskConfigOverride {
     /// Git behavior: don't pull if repo is already cloned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_pull: Option<bool>,
+    /// Git behavior: remote to pull from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_remote: Option<String>,
+    /// Git behavior: how often a failed clone or pull is tried in total.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch_attempts: Option<NonZeroU32>,
+    /// Git behavior: run `git lfs pull` after a clone or pull.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_lfs: Option<bool>,
     /// Build configuration (Debug, Release, `RelWithDebInfo`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration: Option<BuildConfiguration>,
+    /// Target architecture for `CMake` projects (x86, x64).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub architecture: Option<Arch>,
+    /// `CMake` generator for `ModOrganizer` projects (vs, ninja, jom).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cmake_generator: Option<CmakeGenerator>,
+    /// Architectures built by the USVFS task (both, x64, x86).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usvfs_arch: Option<UsvfsArch>,
     /// Git URL prefix for cloning.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_url_prefix: Option<String>,
     /// Use shallow clones (--depth 1).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_shallow: Option<bool>,
+    /// Clone only the configured branch (--single-branch).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_single_branch: Option<bool>,
     /// GitHub organization for the new origin remote.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remote_org: Option<String>,
@@ -82,16 +109,34 @@ pub(super) fn merge_task_config(
             .unwrap_or_else(|| base.mo_fallback.clone()),
         git_behavior: GitBehavior {
             no_pull: override_config.no_pull.unwrap_or(base.git_behavior.no_pull),
+            pull_remote: override_config
+                .pull_remote
+                .clone()
+                .unwrap_or_else(|| base.git_behavior.pull_remote.clone()),
+            fetch_attempts: override_config
+                .fetch_attempts
+                .unwrap_or(base.git_behavior.fetch_attempts),
+            git_lfs: override_config.git_lfs.unwrap_or(base.git_behavior.git_lfs),
         },
         configuration: override_config.configuration.unwrap_or(base.configuration),
+        architecture: override_config.architecture.unwrap_or(base.architecture),
+        cmake_generator: override_config
+            .cmake_generator
+            .unwrap_or(base.cmake_generator),
+        usvfs_arch: override_config.usvfs_arch.unwrap_or(base.usvfs_arch),
         git_url_prefix: override_config
             .git_url_prefix
             .clone()
             .unwrap_or_else(|| base.git_url_prefix.clone()),
+        // Limits whole top-level task groups, so it is only read from [task]
+        timeout_secs: base.timeout_secs,
         git_clone: GitCloneOptions {
             git_shallow: override_config
                 .git_shallow
                 .unwrap_or(base.git_clone.git_shallow),
+            git_single_branch: override_config
+                .git_single_branch
+                .unwrap_or(base.git_clone.git_single_branch),
         },
         remote_setup: RemoteSetup {
             remote_org: override_config
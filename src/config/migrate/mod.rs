@@ -0,0 +1,301 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Upgrades old config files for `mob config migrate`.
+//!
+//! ```text
+//! old mob.toml --> toml_edit::DocumentMut
+//!                    |
+//!   MIGRATIONS  ---> Rename { from, to }  move value, keep comments
+//!                    Remove { key, note } drop key, warn
+//!                    |
+//!                    v
+//!              Migration { output, notes }
+//! ```
+//!
+//! Rules on `task.*` keys also apply to every `[tasks.<name>]` table. Every
+//! breaking change to a config key adds an entry to [`MIGRATIONS`].
+
+use toml_edit::{DocumentMut, Item, Table, TableLike};
+
+use crate::error::Result;
+
+/// One upgrade rule for a dotted config key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationRule {
+    /// The key moved to a new name; its value is kept.
+    Rename {
+        /// Old dotted key, e.g. `task.old_name`.
+        from: &'static str,
+        /// New dotted key.
+        to: &'static str,
+    },
+    /// The key no longer exists and is dropped.
+    Remove {
+        /// Dotted key.
+        key: &'static str,
+        /// What to use instead.
+        note: &'static str,
+    },
+}
+
+/// Rules applied by `mob config migrate`, oldest first.
+///
+/// Keys from C++ mob's INI files that have no TOML counterpart are removed.
+pub const MIGRATIONS: &[MigrationRule] = &[
+    MigrationRule::Remove {
+        key: "global.reconfigure",
+        note: "use `mob build --reconfigure`",
+    },
+    MigrationRule::Remove {
+        key: "global.rebuild",
+        note: "use `mob build --rebuild`",
+    },
+    MigrationRule::Remove {
+        key: "global.clean_task",
+        note: "use `mob build --clean-task`",
+    },
+    MigrationRule::Remove {
+        key: "global.fetch_task",
+        note: "use `mob build --fetch-task`",
+    },
+    MigrationRule::Remove {
+        key: "global.build_task",
+        note: "use `mob build --build-task`",
+    },
+    MigrationRule::Remove {
+        key: "task.git_username",
+        note: "set `user.name` in git config",
+    },
+    MigrationRule::Remove {
+        key: "task.git_email",
+        note: "set `user.email` in git config",
+    },
+    MigrationRule::Remove {
+        key: "task.set_origin_remote",
+        note: "setting `task.remote_org` renames origin",
+    },
+    MigrationRule::Remove {
+        key: "task.remote_key",
+        note: "configure SSH keys in git",
+    },
+    MigrationRule::Remove {
+        key: "tools.jom",
+        note: "jom is not used",
+    },
+    MigrationRule::Remove {
+        key: "tools.patch",
+        note: "patch is not used",
+    },
+    MigrationRule::Remove {
+        key: "tools.nuget",
+        note: "nuget is not used",
+    },
+    MigrationRule::Remove {
+        key: "tools.vswhere",
+        note: "Visual Studio is located automatically",
+    },
+    MigrationRule::Remove {
+        key: "tools.perl",
+        note: "perl is not used",
+    },
+    MigrationRule::Remove {
+        key: "tools.devenv",
+        note: "devenv is not used",
+    },
+    MigrationRule::Remove {
+        key: "versions.vs",
+        note: "Visual Studio is located automatically",
+    },
+    MigrationRule::Remove {
+        key: "versions.vs_year",
+        note: "Visual Studio is located automatically",
+    },
+    MigrationRule::Remove {
+        key: "paths.third_party",
+        note: "third-party tools are found on PATH",
+    },
+    MigrationRule::Remove {
+        key: "paths.patches",
+        note: "patches are not applied",
+    },
+    MigrationRule::Remove {
+        key: "paths.pf_x86",
+        note: "Program Files is located automatically",
+    },
+    MigrationRule::Remove {
+        key: "paths.pf_x64",
+        note: "Program Files is located automatically",
+    },
+    MigrationRule::Remove {
+        key: "paths.vs",
+        note: "Visual Studio is located automatically",
+    },
+];
+
+/// One change made by [`migrate_config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationNote {
+    /// A key was moved to its new name.
+    Renamed {
+        /// Old dotted key.
+        from: String,
+        /// New dotted key.
+        to: String,
+    },
+    /// A key was dropped.
+    Removed {
+        /// Dotted key.
+        key: String,
+        /// Why it was dropped and what to use instead.
+        reason: String,
+    },
+}
+
+impl std::fmt::Display for MigrationNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Renamed { from, to } => write!(f, "renamed {from} to {to}"),
+            Self::Removed { key, reason } => write!(f, "removed {key}: {reason}"),
+        }
+    }
+}
+
+/// Result of migrating one config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    output: String,
+    notes: Vec<MigrationNote>,
+}
+
+impl Migration {
+    /// Returns the migrated file contents.
+    #[must_use]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Returns one message per applied rule, in order.
+    #[must_use]
+    pub fn notes(&self) -> &[MigrationNote] {
+        &self.notes
+    }
+}
+
+/// Applies `rules` to the TOML in `contents`.
+///
+/// Formatting and comments of untouched keys are preserved. When both the
+/// old and new key of a rename are set, the new one wins.
+///
+/// # Errors
+///
+/// Returns an error if `contents` is not valid TOML.
+pub fn migrate_config(contents: &str, rules: &[MigrationRule]) -> Result<Migration> {
+    let mut doc: DocumentMut = contents.parse()?;
+    let mut notes = Vec::new();
+
+    for rule in rules {
+        match *rule {
+            MigrationRule::Rename { from, to } => {
+                for (from, to) in scoped(&doc, from).into_iter().zip(scoped(&doc, to)) {
+                    let Some(value) = remove_key(&mut doc, &from) else {
+                        continue;
+                    };
+                    if get_key(&doc, &to).is_some() {
+                        notes.push(MigrationNote::Removed {
+                            key: from.join("."),
+                            reason: format!("{} is already set", to.join(".")),
+                        });
+                    } else {
+                        insert_key(&mut doc, &to, value);
+                        notes.push(MigrationNote::Renamed {
+                            from: from.join("."),
+                            to: to.join("."),
+                        });
+                    }
+                }
+            }
+            MigrationRule::Remove { key, note } => {
+                for path in scoped(&doc, key) {
+                    if remove_key(&mut doc, &path).is_some() {
+                        notes.push(MigrationNote::Removed {
+                            key: path.join("."),
+                            reason: note.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(Migration {
+        output: doc.to_string(),
+        notes,
+    })
+}
+
+/// Expands a dotted key into every path it applies to in `doc`.
+///
+/// `task.<key>` also yields `tasks.<name>.<key>` for each `[tasks.<name>]`.
+fn scoped(doc: &DocumentMut, key: &str) -> Vec<Vec<String>> {
+    let path: Vec<String> = key.split('.').map(str::to_string).collect();
+    let mut paths = vec![path.clone()];
+
+    if let [section, rest @ ..] = path.as_slice()
+        && section == "task"
+        && let Some(tasks) = doc.get("tasks").and_then(Item::as_table_like)
+    {
+        for (name, _) in tasks.iter() {
+            let mut task_path = vec!["tasks".to_string(), name.to_string()];
+            task_path.extend(rest.iter().cloned());
+            paths.push(task_path);
+        }
+    }
+
+    paths
+}
+
+/// Returns the table holding the last segment of `path`, if it exists.
+fn parent<'a>(doc: &'a DocumentMut, path: &[String]) -> Option<&'a dyn TableLike> {
+    let (_, tables) = path.split_last()?;
+    let mut table: &dyn TableLike = doc.as_table();
+    for segment in tables {
+        table = table.get(segment)?.as_table_like()?;
+    }
+    Some(table)
+}
+
+fn get_key<'a>(doc: &'a DocumentMut, path: &[String]) -> Option<&'a Item> {
+    parent(doc, path)?.get(path.last()?)
+}
+
+fn remove_key(doc: &mut DocumentMut, path: &[String]) -> Option<Item> {
+    let (key, tables) = path.split_last()?;
+    let mut table: &mut dyn TableLike = doc.as_table_mut();
+    for segment in tables {
+        table = table.get_mut(segment)?.as_table_like_mut()?;
+    }
+    table.remove(key)
+}
+
+fn insert_key(doc: &mut DocumentMut, path: &[String], value: Item) {
+    let Some((key, tables)) = path.split_last() else {
+        return;
+    };
+    let mut table: &mut dyn TableLike = doc.as_table_mut();
+    for segment in tables {
+        let item = table
+            .entry(segment)
+            .or_insert_with(|| Item::Table(Table::new()));
+        let Some(next) = item.as_table_like_mut() else {
+            return;
+        };
+        table = next;
+    }
+    table.insert(key, value);
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use super::*;
+
+const RENAME: &[MigrationRule] = &[MigrationRule::Rename {
+    from: "task.old_shallow",
+    to: "task.git_shallow",
+}];
+
+#[test]
+fn rename_moves_value_and_keeps_comments() {
+    let input = "# my config\n[task]\n# shallow clones\nold_shallow = false\nmo_org = \"Me\"\n";
+
+    let migration = migrate_config(input, RENAME).unwrap();
+
+    assert_eq!(
+        migration.output(),
+        "# my config\n[task]\nmo_org = \"Me\"\ngit_shallow = false\n"
+    );
+    assert_eq!(
+        migration.notes(),
+        [MigrationNote::Renamed {
+            from: "task.old_shallow".to_string(),
+            to: "task.git_shallow".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn rename_applies_to_per_task_tables() {
+    let input = "[tasks.usvfs]\nold_shallow = true\n";
+
+    let migration = migrate_config(input, RENAME).unwrap();
+
+    assert!(migration.output().contains("git_shallow = true"));
+    assert!(!migration.output().contains("old_shallow"));
+    assert_eq!(
+        migration.notes()[0].to_string(),
+        "renamed tasks.usvfs.old_shallow to tasks.usvfs.git_shallow"
+    );
+}
+
+#[test]
+fn rename_keeps_existing_new_key() {
+    let input = "[task]\nold_shallow = false\ngit_shallow = true\n";
+
+    let migration = migrate_config(input, RENAME).unwrap();
+
+    assert_eq!(migration.output(), "[task]\ngit_shallow = true\n");
+    assert!(matches!(
+        migration.notes(),
+        [MigrationNote::Removed { key, .. }] if key == "task.old_shallow"
+    ));
+}
+
+#[test]
+fn builtin_rules_remove_legacy_keys() {
+    let input = "[global]\ndry = true\nreconfigure = true\n\n[tools]\njom = \"jom.exe\"\n";
+
+    let migration = migrate_config(input, MIGRATIONS).unwrap();
+
+    assert_eq!(migration.output(), "[global]\ndry = true\n\n[tools]\n");
+    let keys: Vec<_> = migration
+        .notes()
+        .iter()
+        .map(|note| match note {
+            MigrationNote::Removed { key, .. } => key.as_str(),
+            MigrationNote::Renamed { to, .. } => to.as_str(),
+        })
+        .collect();
+    assert_eq!(keys, ["global.reconfigure", "tools.jom"]);
+}
+
+#[test]
+fn current_config_is_unchanged() {
+    let input = "[global]\ndry = false\n\n[task]\ngit_shallow = true\n";
+
+    let migration = migrate_config(input, MIGRATIONS).unwrap();
+
+    assert_eq!(migration.output(), input);
+    assert!(migration.notes().is_empty());
+}
+
+#[test]
+fn invalid_toml_is_an_error() {
+    assert!(migrate_config("[global\n", MIGRATIONS).is_err());
+}
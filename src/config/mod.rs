@@ -15,7 +15,8 @@
 //! 4. local mob.toml (cwd)
 //! 5. --config
 //! 6. MOB_* env vars
-//! 7. CLI overrides
+//! 7. [profiles.<name>] selected by --config-profile
+//! 8. CLI overrides
 //! ```
 //!
 //! # Environment Variable Mapping
@@ -36,8 +37,10 @@
 //! git_shallow = false # override for usvfs only
 //! ```
 
+pub mod interpolate;
 pub mod loader;
 pub mod merge;
+pub mod migrate;
 pub mod paths;
 pub mod types;
 
@@ -49,13 +52,15 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use wax::Program as _;
 
+use crate::core::env::container::Env;
 use crate::error::Result;
 
 use loader::ConfigLoader;
 use merge::TaskConfigOverride;
 use paths::PathsConfig;
 use types::{
-    Aliases, CmakeConfig, GlobalConfig, TaskConfig, ToolsConfig, TransifexConfig, VersionsConfig,
+    Aliases, CmakeConfig, DownloadsConfig, EnvOverlay, GlobalConfig, NetConfig, ReleaseConfig,
+    StylesheetsConfig, TaskConfig, ToolsConfig, TransifexConfig, VersionsConfig,
 };
 
 /// Complete application configuration.
@@ -73,14 +78,28 @@ pub struct Config {
     /// Per-task configuration overrides (field-level merging).
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub tasks: BTreeMap<String, TaskConfigOverride>,
+    /// HTTP download options.
+    pub downloads: DownloadsConfig,
+    /// HTTP client options.
+    pub net: NetConfig,
+    /// Release command options.
+    pub release: ReleaseConfig,
     /// Tool paths.
     pub tools: ToolsConfig,
     /// Transifex configuration.
     pub transifex: TransifexConfig,
     /// Version numbers.
     pub versions: VersionsConfig,
+    /// Stylesheet releases.
+    pub stylesheets: StylesheetsConfig,
     /// Paths configuration.
     pub paths: PathsConfig,
+    /// Extra environment variables for tool processes.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: EnvOverlay,
+    /// Named settings blocks merged over the base config when selected.
+    #[serde(skip_serializing)]
+    pub profiles: BTreeMap<String, config::Value>,
 }
 
 impl Config {
@@ -173,6 +192,14 @@ impl Config {
         self.task.clone()
     }
 
+    /// Builds the environment overlay from the `[env]` section.
+    ///
+    /// The overlay is layered on top of each tool's process environment.
+    #[must_use]
+    pub fn env_overlay(&self) -> Env {
+        Env::from_map(self.env.clone())
+    }
+
     /// Resolve all paths and validate configuration.
     ///
     /// # Errors
@@ -192,21 +219,27 @@ impl Config {
     /// Output is deterministically ordered using `BTreeMap`.
     #[must_use]
     pub fn format_options(&self) -> Vec<String> {
+        format_option_lines(&self.options())
+    }
+
+    /// Returns every configuration option as dotted key and display value,
+    /// the entries [`format_options`](Self::format_options) prints.
+    #[must_use]
+    pub fn options(&self) -> BTreeMap<String, String> {
         let mut options = BTreeMap::new();
         self.format_global_options(&mut options);
         self.format_cmake_options(&mut options);
         self.format_task_options(&mut options);
+        self.format_downloads_options(&mut options);
+        self.format_net_options(&mut options);
+        self.format_release_options(&mut options);
         self.format_tools_options(&mut options);
         self.format_transifex_options(&mut options);
         self.format_versions_options(&mut options);
+        self.format_stylesheets_options(&mut options);
         self.format_paths_options(&mut options);
-
-        let max_key_len = options.keys().map(String::len).max().unwrap_or(0);
-
+        self.format_env_options(&mut options);
         options
-            .into_iter()
-            .map(|(key, value)| format!("{key:<max_key_len$} = {value}"))
-            .collect()
     }
 
     fn format_global_options(&self, options: &mut BTreeMap<String, String>) {
@@ -235,6 +268,11 @@ impl Config {
             "global.ignore_uncommitted".into(),
             self.global.ignore_uncommitted.to_string(),
         );
+        options.insert(
+            "global.line_endings".into(),
+            self.global.line_endings.to_string(),
+        );
+        options.insert("global.progress".into(), self.global.progress.to_string());
     }
 
     fn format_cmake_options(&self, options: &mut BTreeMap<String, String>) {
@@ -245,6 +283,51 @@ impl Config {
         if !self.cmake.host.is_empty() {
             options.insert("cmake.host".into(), self.cmake.host.clone());
         }
+        options.insert(
+            "cmake.staged_install".into(),
+            self.cmake.staged_install.to_string(),
+        );
+        options.insert(
+            "cmake.export_compile_commands".into(),
+            self.cmake.export_compile_commands.to_string(),
+        );
+        if let Some(level) = self.cmake.parallel_level {
+            options.insert("cmake.parallel_level".into(), level.to_string());
+        }
+        if let Some(launcher) = &self.cmake.compiler_launcher {
+            options.insert("cmake.compiler_launcher".into(), launcher.clone());
+        }
+    }
+
+    fn format_downloads_options(&self, options: &mut BTreeMap<String, String>) {
+        options.insert(
+            "downloads.parallel_chunks".into(),
+            self.downloads.parallel_chunks.to_string(),
+        );
+    }
+
+    fn format_net_options(&self, options: &mut BTreeMap<String, String>) {
+        options.insert("net.timeout_secs".into(), self.net.timeout_secs.to_string());
+        if let Some(proxy) = self.net.proxy_for_display() {
+            options.insert("net.proxy".into(), proxy);
+        }
+    }
+
+    fn format_release_options(&self, options: &mut BTreeMap<String, String>) {
+        options.insert(
+            "release.output_layout".into(),
+            self.release.output_layout.to_string(),
+        );
+        if !self.release.src_excludes.is_empty() {
+            options.insert(
+                "release.src_excludes".into(),
+                self.release.src_excludes.join(", "),
+            );
+        }
+        options.insert(
+            "release.src_excludes_replace".into(),
+            self.release.src_excludes_replace.to_string(),
+        );
     }
 
     fn format_task_options(&self, options: &mut BTreeMap<String, String>) {
@@ -258,18 +341,47 @@ impl Config {
             "task.no_pull".into(),
             self.task.git_behavior.no_pull.to_string(),
         );
+        options.insert(
+            "task.pull_remote".into(),
+            self.task.git_behavior.pull_remote.clone(),
+        );
+        options.insert(
+            "task.fetch_attempts".into(),
+            self.task.git_behavior.fetch_attempts.to_string(),
+        );
+        options.insert(
+            "task.git_lfs".into(),
+            self.task.git_behavior.git_lfs.to_string(),
+        );
         options.insert(
             "task.configuration".into(),
             self.task.configuration.to_string(),
         );
+        options.insert(
+            "task.architecture".into(),
+            self.task.architecture.to_string(),
+        );
+        options.insert(
+            "task.cmake_generator".into(),
+            self.task.cmake_generator.to_string(),
+        );
+        options.insert("task.usvfs_arch".into(), self.task.usvfs_arch.to_string());
         options.insert(
             "task.git_url_prefix".into(),
             self.task.git_url_prefix.clone(),
         );
+        options.insert(
+            "task.timeout_secs".into(),
+            self.task.timeout_secs.to_string(),
+        );
         options.insert(
             "task.git_shallow".into(),
             self.task.git_clone.git_shallow.to_string(),
         );
+        options.insert(
+            "task.git_single_branch".into(),
+            self.task.git_clone.git_single_branch.to_string(),
+        );
         if !self.task.remote_setup.remote_org.is_empty() {
             options.insert(
                 "task.remote_org".into(),
@@ -331,6 +443,10 @@ impl Config {
             "transifex.pull".into(),
             self.transifex.actions.pull.to_string(),
         );
+        options.insert(
+            "transifex.pull_mode".into(),
+            self.transifex.actions.pull_mode.to_string(),
+        );
     }
 
     fn format_versions_options(&self, options: &mut BTreeMap<String, String>) {
@@ -339,16 +455,40 @@ impl Config {
             self.versions.vs_toolset.clone(),
         );
         options.insert("versions.sdk".into(), self.versions.sdk.clone());
+        if let Some(vs_version) = &self.versions.vs_version {
+            options.insert("versions.vs_version".into(), vs_version.clone());
+        }
         options.insert("versions.usvfs".into(), self.versions.usvfs.clone());
         options.insert(
             "versions.explorerpp".into(),
             self.versions.explorerpp.clone(),
         );
+        if let Some(sha256) = &self.versions.explorerpp_sha256 {
+            options.insert("versions.explorerpp_sha256".into(), sha256.clone());
+        }
         for (name, version) in &self.versions.stylesheets {
             options.insert(format!("versions.{name}"), version.clone());
         }
     }
 
+    fn format_stylesheets_options(&self, options: &mut BTreeMap<String, String>) {
+        for (index, release) in self.stylesheets.release.iter().enumerate() {
+            options.insert(
+                format!("stylesheets.release.{index}"),
+                format!(
+                    "{}/{} ({})",
+                    release.user, release.repo, release.version_key
+                ),
+            );
+        }
+    }
+
+    fn format_env_options(&self, options: &mut BTreeMap<String, String>) {
+        for (key, value) in &self.env {
+            options.insert(format!("env.{key}"), value.clone());
+        }
+    }
+
     fn format_paths_options(&self, options: &mut BTreeMap<String, String>) {
         let fmt = |p: &Option<PathBuf>| {
             p.as_ref()
@@ -357,6 +497,7 @@ impl Config {
 
         options.insert("paths.prefix".into(), fmt(&self.paths.prefix));
         options.insert("paths.cache".into(), fmt(&self.paths.cache));
+        options.insert("paths.downloads".into(), fmt(&self.paths.downloads));
         options.insert("paths.build".into(), fmt(&self.paths.build));
         options.insert("paths.install".into(), fmt(&self.paths.install));
         options.insert("paths.install_bin".into(), fmt(&self.paths.install_bin));
@@ -388,3 +529,14 @@ impl Config {
         );
     }
 }
+
+/// Formats `options` as `key = value` lines, with the `=` signs aligned.
+#[must_use]
+pub fn format_option_lines(options: &BTreeMap<String, String>) -> Vec<String> {
+    let max_key_len = options.keys().map(String::len).max().unwrap_or(0);
+
+    options
+        .iter()
+        .map(|(key, value)| format!("{key:<max_key_len$} = {value}"))
+        .collect()
+}
@@ -7,7 +7,7 @@
 //!
 //! ```text
 //! prefix/
-//!   downloads/   (cache)
+//!   downloads/   (cache; also downloaded archives unless `downloads` is set)
 //!   build/
 //!   install/
 //!     bin/       (stylesheets, licenses, translations)
@@ -17,6 +17,13 @@
 //! ```
 //!
 //! All paths are optional and resolved from `prefix` if not set.
+//!
+//! `validate()` (for `mob config validate`):
+//!
+//! ```text
+//! prefix, build, install, install_bin   not set --> error, missing --> warning (created)
+//! qt_install                            not set --> error, missing --> error
+//! ```
 
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -33,6 +40,9 @@ pub struct PathsConfig {
     /// Download cache directory (default: prefix/downloads).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache: Option<PathBuf>,
+    /// Downloaded archives directory (default: `cache`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<PathBuf>,
     /// Licenses directory.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub licenses: Option<PathBuf>,
@@ -100,6 +110,11 @@ impl PathsConfig {
         };
 
         resolve(&mut self.cache, &prefix, "downloads");
+        if let Some(downloads) = &self.downloads
+            && downloads.is_relative()
+        {
+            self.downloads = Some(prefix.join(downloads));
+        }
         resolve(&mut self.build, &prefix, "build");
         resolve(&mut self.install, &prefix, "install");
 
@@ -137,6 +152,7 @@ impl PathsConfig {
         };
         normalize(&mut self.prefix);
         normalize(&mut self.cache);
+        normalize(&mut self.downloads);
         normalize(&mut self.build);
         normalize(&mut self.install);
         normalize(&mut self.install_bin);
@@ -169,6 +185,41 @@ impl PathsConfig {
         })
     }
 
+    /// Returns the directory downloaded archives go to: `downloads`, or
+    /// `cache` when it is not set.
+    #[must_use]
+    pub fn downloads_dir(&self) -> Option<&Path> {
+        self.downloads.as_deref().or(self.cache.as_deref())
+    }
+
+    /// Checks the paths downstream tasks require.
+    ///
+    /// Directories mob creates itself only produce a warning when missing;
+    /// `qt_install` must already exist.
+    #[must_use]
+    pub fn validate(&self) -> Vec<PathIssue> {
+        let created = [
+            ("prefix", &self.prefix),
+            ("build", &self.build),
+            ("install", &self.install),
+            ("install_bin", &self.install_bin),
+        ];
+        let mut issues = Vec::new();
+        for (key, path) in created {
+            issues.extend(PathIssue::check(
+                key,
+                path.as_deref(),
+                IssueSeverity::Warning,
+            ));
+        }
+        issues.extend(PathIssue::check(
+            "qt_install",
+            self.qt_install.as_deref(),
+            IssueSeverity::Error,
+        ));
+        issues
+    }
+
     /// Returns `CMAKE_PREFIX_PATH` value by joining relevant paths.
     /// Uses semicolon on Windows, colon on Unix.
     #[must_use]
@@ -187,9 +238,93 @@ impl PathsConfig {
             .join(separator)
     }
 
+    /// Returns vcpkg's `scripts/buildsystems/vcpkg.cmake`, if `vcpkg` is set.
+    #[must_use]
+    pub fn vcpkg_toolchain(&self) -> Option<PathBuf> {
+        self.vcpkg.as_ref().map(|vcpkg| {
+            vcpkg
+                .join("scripts")
+                .join("buildsystems")
+                .join("vcpkg.cmake")
+        })
+    }
+
     /// Returns `CMAKE_INSTALL_PREFIX` value.
     #[must_use]
     pub fn cmake_install_prefix(&self) -> Option<String> {
         self.install.as_ref().map(|p| p.display().to_string())
     }
 }
+
+/// How serious a [`PathIssue`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    /// The build can still run.
+    Warning,
+    /// The build will fail.
+    Error,
+}
+
+/// A problem with one `[paths]` key found by [`PathsConfig::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathIssue {
+    /// Key name within `[paths]`.
+    key: &'static str,
+    /// How serious the problem is.
+    severity: IssueSeverity,
+    /// What is wrong, e.g. `not set`.
+    message: String,
+}
+
+impl PathIssue {
+    /// Returns the key name within `[paths]`.
+    #[must_use]
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Returns how serious the problem is.
+    #[must_use]
+    pub const fn severity(&self) -> IssueSeverity {
+        self.severity
+    }
+
+    /// Returns what is wrong, e.g. `not set`.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Checks that `path` is set and exists. A missing directory gets
+    /// `missing_severity`; an unset key is always an error.
+    fn check(
+        key: &'static str,
+        path: Option<&Path>,
+        missing_severity: IssueSeverity,
+    ) -> Option<Self> {
+        match path {
+            None => Some(Self {
+                key,
+                severity: IssueSeverity::Error,
+                message: "not set".to_string(),
+            }),
+            Some(path) if !path.exists() => Some(Self {
+                key,
+                severity: missing_severity,
+                message: match missing_severity {
+                    IssueSeverity::Warning => {
+                        format!("{} does not exist, will be created", path.display())
+                    }
+                    IssueSeverity::Error => format!("{} does not exist", path.display()),
+                },
+            }),
+            Some(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for PathIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "paths.{}: {}", self.key, self.message)
+    }
+}
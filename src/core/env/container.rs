@@ -13,7 +13,7 @@
 //! clone shares Arc until copy_for_write()
 //!
 //! Cached VS envs (Windows)
-//! Env::vs_x86 / Env::vs_x64 via OnceLock + capture_vcvars()
+//! Env::vs(arch, vs_version) via a cache keyed by both + capture_vcvars()
 //! ```
 
 use super::types::{EnvData, EnvFlags, EnvKey};
@@ -61,61 +61,31 @@ impl Env {
         }
     }
 
-    /// Returns the Visual Studio x86 environment.
+    /// Returns the Visual Studio environment for the given architecture, of
+    /// the installation selected by `vs_version` (`versions.vs_version`).
     ///
-    /// The result is cached after the first call.
+    /// The result is cached per architecture and version after the first call.
     ///
     /// # Errors
     ///
     /// Returns an error if the Visual Studio environment variables cannot be captured,
     /// typically because Visual Studio is not installed or `vcvarsall.bat` cannot be found.
-    #[cfg(windows)]
-    pub fn vs_x86() -> Result<Self> {
-        use std::sync::OnceLock;
-
-        static VS_X86: OnceLock<std::result::Result<Env, String>> = OnceLock::new();
-        VS_X86
-            .get_or_init(|| {
-                super::vcvars::capture_vcvars(super::types::Arch::X86).map_err(|e| e.to_string())
-            })
-            .clone()
-            .map_err(|e| anyhow::anyhow!(e))
-    }
+    pub fn vs(arch: super::types::Arch, vs_version: Option<&str>) -> Result<Self> {
+        use std::sync::{Mutex, PoisonError};
 
-    /// Returns the Visual Studio x64 environment.
-    ///
-    /// The result is cached after the first call.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the Visual Studio environment variables cannot be captured,
-    /// typically because Visual Studio is not installed or `vcvarsall.bat` cannot be found.
-    #[cfg(windows)]
-    pub fn vs_x64() -> Result<Self> {
-        use std::sync::OnceLock;
+        type Cache =
+            BTreeMap<(super::types::Arch, Option<String>), std::result::Result<Env, String>>;
+        static VS_ENVS: Mutex<Cache> = Mutex::new(BTreeMap::new());
 
-        static VS_X64: OnceLock<std::result::Result<Env, String>> = OnceLock::new();
-        VS_X64
-            .get_or_init(|| {
-                super::vcvars::capture_vcvars(super::types::Arch::X64).map_err(|e| e.to_string())
+        let mut envs = VS_ENVS.lock().unwrap_or_else(PoisonError::into_inner);
+        envs.entry((arch, vs_version.map(str::to_string)))
+            .or_insert_with(|| {
+                super::vcvars::capture_vcvars(arch, vs_version).map_err(|e| e.to_string())
             })
             .clone()
             .map_err(|e| anyhow::anyhow!(e))
     }
 
-    /// Returns the Visual Studio environment for the given architecture.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the Visual Studio environment variables cannot be captured.
-    #[cfg(windows)]
-    pub fn vs(arch: super::types::Arch) -> Result<Self> {
-        match arch {
-            super::types::Arch::X86 => Self::vs_x86(),
-            super::types::Arch::X64 => Self::vs_x64(),
-        }
-    }
-
     /// Sets an environment variable.
     ///
     /// # Arguments
@@ -187,6 +157,15 @@ impl Env {
         self
     }
 
+    /// Copies every variable from `other` into this environment, replacing
+    /// existing values with the same name.
+    pub fn merge(&mut self, other: &Self) -> &mut Self {
+        for (key, value) in other.iter() {
+            self.set(key, value);
+        }
+        self
+    }
+
     /// Prepends a path to the PATH environment variable.
     pub fn prepend_path(&mut self, path: impl AsRef<Path>) -> &mut Self {
         self.modify_path(path, EnvFlags::Prepend)
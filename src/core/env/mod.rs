@@ -9,7 +9,7 @@
 //!
 //! ```text
 //! Env (copy-on-write BTreeMap<String, String>)
-//! Sources: current_env(), Env::vs(Arch), Env::empty()
+//! Sources: current_env(), Env::vs(Arch, vs_version), Env::empty()
 //! Ops: set/get/prepend_path/append_path
 //! ```
 //!
@@ -19,7 +19,6 @@
 
 pub mod container;
 pub mod types;
-#[cfg(windows)]
 pub mod vcvars;
 
 #[cfg(test)]
@@ -117,6 +117,21 @@ fn test_env_from_map() {
     );
 }
 
+#[test]
+fn test_env_merge() {
+    let mut env = Env::new();
+    env.set("KEEP", "base");
+    env.set("OVERRIDE", "base");
+
+    let mut overlay = Env::new();
+    overlay.set("OVERRIDE", "overlay");
+    overlay.set("NEW", "overlay");
+
+    env.merge(&overlay);
+
+    insta::assert_yaml_snapshot!("env_merge", env.to_map());
+}
+
 #[test]
 fn test_env_to_map() {
     let mut env = Env::new();
@@ -14,10 +14,14 @@
 //! EnvData: BTreeMap<EnvKey, String> for deterministic order
 //! ```
 
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Target architecture for builds.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Serialized as `"x86"` / `"x64"` in configuration files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Arch {
     /// 32-bit x86
     X86,
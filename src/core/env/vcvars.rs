@@ -6,7 +6,7 @@
 //! Visual Studio environment variable capture (Windows-only).
 //!
 //! ```text
-//! vs::find_latest()
+//! vs::find_installation(None, vs_version)   (versions.vs_version, else the newest)
 //!   --> capture_vcvars(arch)
 //!       PowerShell: Import-Module DevShell.dll
 //!       Enter-VsDevShell -arch={x86|x64}
@@ -33,16 +33,18 @@ use tracing::{Level, debug, enabled, trace};
 ///
 /// # Arguments
 /// * `arch` - Target architecture (x86 or x64)
+/// * `vs_version` - `versions.vs_version`, the newest installation if `None`
 ///
 /// # Returns
 /// An `Env` containing the Visual Studio environment variables.
 ///
 /// # Errors
 /// Returns an error if VS cannot be found or the `DevShell` fails to initialize.
-pub fn capture_vcvars(arch: Arch) -> Result<Env> {
+pub fn capture_vcvars(arch: Arch, vs_version: Option<&str>) -> Result<Env> {
     debug!(arch = %arch, "Capturing VS environment via Enter-VsDevShell");
 
-    let vs_info = vs::find_latest(None).context("Failed to find Visual Studio installation")?;
+    let vs_info = vs::find_installation(None, vs_version)
+        .context("Failed to find Visual Studio installation")?;
 
     let devshell_dll = vs_info.devshell_dll();
     if !devshell_dll.exists() {
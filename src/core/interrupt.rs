@@ -0,0 +1,41 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Ctrl+C handling shared by every command.
+//!
+//! ```text
+//! main: token = CancellationToken::new()
+//! spawn_ctrl_c_handler(token)   (before dispatching the command)
+//!   1st Ctrl+C --> token.cancel() --> tasks and processes stop cooperatively
+//!   2nd Ctrl+C --> exit(INTERRUPTED) without waiting
+//!
+//! commands get the token --> ToolContext::new(.., token, ..)
+//!   TaskManager::new(config, &token) --> child of token, cancel() only
+//!                                        stops that manager
+//! ```
+
+use tokio_util::sync::CancellationToken;
+
+use crate::error::exit_codes;
+
+/// Spawns the Ctrl+C handler.
+///
+/// The first Ctrl+C cancels `token` so running tasks can stop their
+/// processes and clean up; a second one exits immediately with
+/// [`exit_codes::INTERRUPTED`].
+pub fn spawn_ctrl_c_handler(token: CancellationToken) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            return;
+        }
+        tracing::warn!("Received Ctrl+C, interrupting tasks (press Ctrl+C again to force exit)...");
+        token.cancel();
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            eprintln!("Received Ctrl+C again, exiting");
+            std::process::exit(i32::from(exit_codes::INTERRUPTED));
+        }
+    });
+}
@@ -5,6 +5,8 @@
 
 //! Core modules for process and environment management.
 //!
+//! `interrupt` shares one Ctrl+C cancellation token between all commands.
+//!
 //! ```text
 //!              core
 //!               |
@@ -19,9 +21,9 @@
 //! ```
 
 pub mod env;
+pub mod interrupt;
 pub mod process;
 
-#[cfg(windows)]
 pub mod vs;
 
 #[cfg(windows)]
@@ -8,8 +8,10 @@
 //! ```text
 //! ProcessBuilder
 //!  • new/which/raw/exists/find
-//!  • args/cwd/env/flags/timeout/success_codes/name
+//!  • args/cwd/env/env_overlay/flags/timeout/success_codes/name/priority
 //!  • capture_stdout/stderr/output, quiet, inherit_stdio, stdin
+//!  • count_diagnostics: warning/error lines of logged or captured output
+//!  • record_timings: wall-clock duration for `--measure`
 //!
 //! ProcessFlags: ALLOW_FAILURE, TERMINATE_ON_INTERRUPT, IGNORE_OUTPUT_ON_SUCCESS
 //! StreamFlags: FORWARD_TO_LOG (default), BIT_BUCKET, KEEP_IN_STRING, INHERIT
@@ -19,10 +21,14 @@ use bitflags::bitflags;
 use std::collections::{BTreeMap, BTreeSet};
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
-use std::sync::{OnceLock, RwLock};
+use std::sync::{Arc, OnceLock, RwLock};
 use std::time::Duration;
 
+use super::diagnostics::DiagnosticCounter;
+use super::priority::ProcessPriority;
+use super::timing::ProcessTimings;
 use crate::core::env::container::Env;
+use crate::core::env::current_env;
 use crate::utility::encoding::Encoding;
 
 /// Static cache for executable paths resolved via `which`.
@@ -188,6 +194,12 @@ pub struct ProcessBuilder {
     name: Option<String>,
     /// Timeout for the process
     timeout: Option<Duration>,
+    /// Scheduling priority; `Normal` if unset
+    priority: Option<ProcessPriority>,
+    /// Counter inspecting every stdout/stderr line
+    diagnostics: Option<Arc<DiagnosticCounter>>,
+    /// Collector of the process duration
+    timings: Option<Arc<ProcessTimings>>,
 }
 
 impl ProcessBuilder {
@@ -211,6 +223,9 @@ impl ProcessBuilder {
             success_codes,
             name: None,
             timeout: None,
+            priority: None,
+            diagnostics: None,
+            timings: None,
         }
     }
 
@@ -368,6 +383,21 @@ impl ProcessBuilder {
         self
     }
 
+    /// Layers additional environment variables on top of the process environment.
+    ///
+    /// If no environment was set, the overlay is applied to the current process
+    /// environment. An empty overlay leaves the builder untouched.
+    #[must_use]
+    pub fn env_overlay(mut self, overlay: &Env) -> Self {
+        if overlay.is_empty() {
+            return self;
+        }
+        let mut env = self.env.take().unwrap_or_else(current_env);
+        env.merge(overlay);
+        self.env = Some(env);
+        self
+    }
+
     /// Sets process flags.
     #[must_use]
     pub const fn flags(mut self, flags: ProcessFlags) -> Self {
@@ -474,6 +504,40 @@ impl ProcessBuilder {
         self
     }
 
+    /// Sets the scheduling priority, overriding the `--low-priority` default.
+    #[must_use]
+    pub const fn priority(mut self, priority: ProcessPriority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Sets the scheduling priority unless [`priority`](Self::priority)
+    /// already set one.
+    #[must_use]
+    pub const fn default_priority(mut self, priority: ProcessPriority) -> Self {
+        if self.priority.is_none() {
+            self.priority = Some(priority);
+        }
+        self
+    }
+
+    /// Passes every stdout and stderr line to `counter`.
+    ///
+    /// Lines are only read when the stream is logged or captured, which is the
+    /// default.
+    #[must_use]
+    pub fn count_diagnostics(mut self, counter: Arc<DiagnosticCounter>) -> Self {
+        self.diagnostics = Some(counter);
+        self
+    }
+
+    /// Records how long the process ran in `timings`.
+    #[must_use]
+    pub fn record_timings(mut self, timings: Arc<ProcessTimings>) -> Self {
+        self.timings = Some(timings);
+        self
+    }
+
     // Getters for field access within the process module
 
     /// Returns a reference to the program path.
@@ -483,12 +547,14 @@ impl ProcessBuilder {
     }
 
     /// Returns a slice of the arguments.
-    pub(super) fn args_slice(&self) -> &[String] {
+    #[must_use]
+    pub(crate) fn args_slice(&self) -> &[String] {
         &self.args
     }
 
     /// Returns a reference to the working directory, if set.
-    pub(super) const fn working_dir(&self) -> Option<&PathBuf> {
+    #[must_use]
+    pub(crate) const fn working_dir(&self) -> Option<&PathBuf> {
         self.cwd.as_ref()
     }
 
@@ -497,6 +563,16 @@ impl ProcessBuilder {
         self.env.as_ref()
     }
 
+    /// Returns the diagnostic counter, if set.
+    pub(super) const fn diagnostic_counter(&self) -> Option<&Arc<DiagnosticCounter>> {
+        self.diagnostics.as_ref()
+    }
+
+    /// Returns the timing collector, if set.
+    pub(super) const fn timing_collector(&self) -> Option<&Arc<ProcessTimings>> {
+        self.timings.as_ref()
+    }
+
     /// Returns the process flags.
     pub(super) const fn process_flags(&self) -> ProcessFlags {
         self.flags
@@ -531,4 +607,9 @@ impl ProcessBuilder {
     pub(super) const fn timeout_duration(&self) -> Option<Duration> {
         self.timeout
     }
+
+    /// Returns the scheduling priority.
+    pub(super) fn process_priority(&self) -> ProcessPriority {
+        self.priority.unwrap_or_default()
+    }
 }
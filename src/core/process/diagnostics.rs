@@ -0,0 +1,141 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Warning and error counts of build tool output.
+//!
+//! ```text
+//! ProcessBuilder::count_diagnostics(counter)
+//!   stdout/stderr reader --> DiagnosticCounter::inspect(line)
+//!     MSBuild/compiler: <origin> : [fatal] warning|error [CODE]: ...
+//!     CMake:            CMake [Deprecation] Warning|Error ...
+//!   --> counts() --> "12 warnings, 0 errors"
+//! ```
+//!
+//! Both patterns are always checked, so `cmake --build` also counts the
+//! compiler diagnostics of the generator it drives. A line seen twice, like
+//! the recap `MSBuild` prints at higher verbosity, is counted once.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{LazyLock, Mutex};
+
+use regex::Regex;
+
+/// `MSBuild` canonical message format, also used by MSVC tools:
+/// `C:\src\a.cpp(12,5): warning C4996: ...`, `LINK : fatal error LNK1104: ...`.
+///
+/// Colons in the origin are only allowed as drive letters, so a path
+/// containing `: warning` does not start a match.
+///
+/// `None` if the pattern failed to compile, in which case nothing matches.
+static MSBUILD: LazyLock<Option<Regex>> = LazyLock::new(|| {
+    Regex::new(
+        r"^[ \t]*(?:[0-9]+>)?(?:[^:]|:[\\/])+:[ \t]*(?:fatal[ \t]+)?(warning|error)(?:[ \t]+[A-Za-z]+[0-9]+)?[ \t]*:",
+    )
+    .ok()
+});
+
+/// `CMake` messages: `CMake Warning at CMakeLists.txt:12 (message):`,
+/// `CMake Warning (dev) at ...`, `CMake Deprecation Warning at ...`.
+static CMAKE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^CMake (?:Deprecation )?(Warning|Error)(?: |:|$)").ok());
+
+/// Severity of a diagnostic line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// `warning`, `CMake Warning`
+    Warning,
+    /// `error`, `fatal error`, `CMake Error`
+    Error,
+}
+
+/// Returns the severity of `line` if it is an `MSBuild` or `CMake` diagnostic.
+#[must_use]
+pub fn classify(line: &str) -> Option<DiagnosticKind> {
+    let severity = [&*MSBUILD, &*CMAKE]
+        .into_iter()
+        .flatten()
+        .find_map(|regex| regex.captures(line)?.get(1))?;
+    match severity.as_str() {
+        "warning" | "Warning" => Some(DiagnosticKind::Warning),
+        _ => Some(DiagnosticKind::Error),
+    }
+}
+
+/// Warning and error counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiagnosticCounts {
+    warnings: usize,
+    errors: usize,
+}
+
+impl DiagnosticCounts {
+    /// Returns the number of warnings.
+    #[must_use]
+    pub const fn warnings(&self) -> usize {
+        self.warnings
+    }
+
+    /// Returns the number of errors.
+    #[must_use]
+    pub const fn errors(&self) -> usize {
+        self.errors
+    }
+}
+
+impl fmt::Display for DiagnosticCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} warning{}, {} error{}",
+            self.warnings,
+            plural(self.warnings),
+            self.errors,
+            plural(self.errors)
+        )
+    }
+}
+
+/// Counts the distinct diagnostic lines of one or more processes.
+#[derive(Debug, Default)]
+pub struct DiagnosticCounter {
+    seen: Mutex<BTreeMap<String, DiagnosticKind>>,
+}
+
+impl DiagnosticCounter {
+    /// Creates a counter with no diagnostics.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts `line` if it is a diagnostic not seen before.
+    pub fn inspect(&self, line: &str) {
+        if let Some(kind) = classify(line) {
+            self.lock().insert(line.trim().to_string(), kind);
+        }
+    }
+
+    /// Returns the counts so far.
+    #[must_use]
+    pub fn counts(&self) -> DiagnosticCounts {
+        self.lock()
+            .values()
+            .fold(DiagnosticCounts::default(), |mut counts, kind| {
+                match kind {
+                    DiagnosticKind::Warning => counts.warnings += 1,
+                    DiagnosticKind::Error => counts.errors += 1,
+                }
+                counts
+            })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, DiagnosticKind>> {
+        self.seen
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
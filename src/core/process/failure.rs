@@ -0,0 +1,88 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Error of a process that exited with an unexpected code.
+//!
+//! ```text
+//! run() / run_with_cancellation()
+//!   unexpected exit code --> Err(ProcessFailure)
+//!                                |
+//!                                v
+//!                  command error (context added by callers)
+//!                                |
+//!                                v
+//!   error.chain() --> downcast_ref::<ProcessFailure>() --> diagnostic bundle
+//! ```
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Details of a process that exited with an unexpected code.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{name} exited with code {exit_code} (expected one of {expected:?})")]
+pub struct ProcessFailure {
+    name: String,
+    expected: BTreeSet<i32>,
+    command: String,
+    cwd: Option<PathBuf>,
+    exit_code: i32,
+    stdout: String,
+    stderr: String,
+}
+
+impl ProcessFailure {
+    /// Creates a new failure record.
+    pub(super) const fn new(
+        name: String,
+        expected: BTreeSet<i32>,
+        command: String,
+        cwd: Option<PathBuf>,
+        exit_code: i32,
+        stdout: String,
+        stderr: String,
+    ) -> Self {
+        Self {
+            name,
+            expected,
+            command,
+            cwd,
+            exit_code,
+            stdout,
+            stderr,
+        }
+    }
+
+    /// Returns the full command line.
+    #[must_use]
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Returns the working directory, if one was set.
+    #[must_use]
+    pub const fn cwd(&self) -> Option<&PathBuf> {
+        self.cwd.as_ref()
+    }
+
+    /// Returns the exit code.
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        self.exit_code
+    }
+
+    /// Returns captured stdout (empty unless the process kept it).
+    #[must_use]
+    pub fn stdout(&self) -> &str {
+        &self.stdout
+    }
+
+    /// Returns captured stderr (empty unless the process kept it).
+    #[must_use]
+    pub fn stderr(&self) -> &str {
+        &self.stderr
+    }
+}
@@ -8,7 +8,7 @@
 //! ```text
 //! run_child() / run_child_with_cancellation()
 //!   stdin task (optional)
-//!   stdout/stderr reader tasks
+//!   stdout/stderr reader tasks (--> DiagnosticCounter::inspect if set)
 //!   mpsc channels buffer lines
 //!   wait (or cancel/timeout)
 //!   --> ProcessOutput { stdout, stderr, exit_code, interrupted }
@@ -20,6 +20,7 @@
 
 use crate::error::Result;
 use anyhow::Context;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, ChildStderr, ChildStdout};
@@ -29,6 +30,7 @@ use tokio_util::sync::CancellationToken;
 use tracing::{debug, trace, warn};
 
 use super::builder::{ProcessBuilder, ProcessOutput, StreamFlags};
+use super::diagnostics::DiagnosticCounter;
 use crate::utility::encoding::{EncodedBuffer, Encoding};
 
 /// Configuration for spawning a stream reader task.
@@ -36,6 +38,7 @@ struct StreamReaderConfig {
     encoding: Encoding,
     flags: StreamFlags,
     process_name: String,
+    diagnostics: Option<Arc<DiagnosticCounter>>,
 }
 
 /// Spawns a reader task for stdout if needed.
@@ -54,8 +57,15 @@ fn spawn_stdout_reader(
         let encoding = config.encoding;
         let flags = config.flags;
         let name = config.process_name.clone();
+        let diagnostics = config.diagnostics.clone();
         tokio::spawn(async move {
-            read_stream(stdout, encoding, flags, &name, "stdout", tx).await;
+            let stream = LineStream {
+                flags,
+                process_name: &name,
+                stream_name: "stdout",
+                diagnostics: diagnostics.as_deref(),
+            };
+            read_stream(stdout, encoding, &stream, tx).await;
         })
     })
 }
@@ -76,8 +86,15 @@ fn spawn_stderr_reader(
         let encoding = config.encoding;
         let flags = config.flags;
         let name = config.process_name.clone();
+        let diagnostics = config.diagnostics.clone();
         tokio::spawn(async move {
-            read_stream(stderr, encoding, flags, &name, "stderr", tx).await;
+            let stream = LineStream {
+                flags,
+                process_name: &name,
+                stream_name: "stderr",
+                diagnostics: diagnostics.as_deref(),
+            };
+            read_stream(stderr, encoding, &stream, tx).await;
         })
     })
 }
@@ -134,11 +151,13 @@ impl ProcessBuilder {
             encoding: self.stdout_config().encoding(),
             flags: self.stdout_config().flags(),
             process_name: name.to_string(),
+            diagnostics: self.diagnostic_counter().cloned(),
         };
         let stderr_config = StreamReaderConfig {
             encoding: self.stderr_config().encoding(),
             flags: self.stderr_config().flags(),
             process_name: name.to_string(),
+            diagnostics: self.diagnostic_counter().cloned(),
         };
 
         let stdout_handle = spawn_stdout_reader(child.stdout.take(), &stdout_config, stdout_tx);
@@ -183,11 +202,13 @@ impl ProcessBuilder {
             encoding: self.stdout_config().encoding(),
             flags: self.stdout_config().flags(),
             process_name: name.to_string(),
+            diagnostics: self.diagnostic_counter().cloned(),
         };
         let stderr_config = StreamReaderConfig {
             encoding: self.stderr_config().encoding(),
             flags: self.stderr_config().flags(),
             process_name: name.to_string(),
+            diagnostics: self.diagnostic_counter().cloned(),
         };
 
         let stdout_handle = spawn_stdout_reader(child.stdout.take(), &stdout_config, stdout_tx);
@@ -231,13 +252,34 @@ impl ProcessBuilder {
     }
 }
 
+/// Where the lines of one stream go.
+struct LineStream<'a> {
+    flags: StreamFlags,
+    process_name: &'a str,
+    stream_name: &'a str,
+    diagnostics: Option<&'a DiagnosticCounter>,
+}
+
+impl LineStream<'_> {
+    /// Logs, inspects and forwards one line.
+    async fn handle(&self, line: String, tx: &mpsc::Sender<String>) {
+        if self.flags.contains(StreamFlags::FORWARD_TO_LOG) {
+            trace!(process = %self.process_name, stream = %self.stream_name, line = %line, "output");
+        }
+        if let Some(diagnostics) = self.diagnostics {
+            diagnostics.inspect(&line);
+        }
+        if self.flags.contains(StreamFlags::KEEP_IN_STRING) {
+            let _ = tx.send(line).await;
+        }
+    }
+}
+
 /// Reads from a stream and processes lines.
 async fn read_stream<R>(
     reader: R,
     encoding: Encoding,
-    flags: StreamFlags,
-    process_name: &str,
-    stream_name: &str,
+    stream: &LineStream<'_>,
     tx: mpsc::Sender<String>,
 ) where
     R: tokio::io::AsyncRead + Unpin,
@@ -250,12 +292,7 @@ async fn read_stream<R>(
         Encoding::Utf8 | Encoding::Unknown => {
             let mut lines = buf_reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                if flags.contains(StreamFlags::FORWARD_TO_LOG) {
-                    trace!(process = %process_name, stream = %stream_name, line = %line, "output");
-                }
-                if flags.contains(StreamFlags::KEEP_IN_STRING) {
-                    let _ = tx.send(line).await;
-                }
+                stream.handle(line, &tx).await;
             }
         }
         _ => {
@@ -272,18 +309,13 @@ async fn read_stream<R>(
                         buffer.add(&read_buf[..n]);
                         // Process complete lines
                         for line in buffer.next_utf8_lines(false) {
-                            if flags.contains(StreamFlags::FORWARD_TO_LOG) {
-                                trace!(process = %process_name, stream = %stream_name, line = %line, "output");
-                            }
-                            if flags.contains(StreamFlags::KEEP_IN_STRING) {
-                                let _ = tx.send(line).await;
-                            }
+                            stream.handle(line, &tx).await;
                         }
                     }
                     Err(e) => {
                         warn!(
-                            process = %process_name,
-                            stream = %stream_name,
+                            process = %stream.process_name,
+                            stream = %stream.stream_name,
                             error = %e,
                             "error reading stream"
                         );
@@ -293,12 +325,7 @@ async fn read_stream<R>(
             }
 
             for line in buffer.next_utf8_lines(true) {
-                if flags.contains(StreamFlags::FORWARD_TO_LOG) {
-                    trace!(process = %process_name, stream = %stream_name, line = %line, "output");
-                }
-                if flags.contains(StreamFlags::KEEP_IN_STRING) {
-                    let _ = tx.send(line).await;
-                }
+                stream.handle(line, &tx).await;
             }
         }
     }
@@ -13,12 +13,24 @@
 //!           stream stdout/stderr
 //!           Windows: CTRL_BREAK + Job Object
 //!       --> ProcessOutput { exit_code, stdout, stderr }
+//!       --> Err(failure::ProcessFailure) on unexpected exit code
+//!       --> timing::ProcessTimings with .record_timings()
+//!       --> diagnostics::DiagnosticCounter with .count_diagnostics()
+//!
+//! --low-priority: ToolContext::with_priority() --> every process it runs
+//!
+//! dry run + --emit-script: tool --> script::Script::record_command() --> Script::take()
 //! ```
 
 pub mod builder;
+pub mod diagnostics;
+pub mod failure;
 mod io;
+pub mod priority;
 mod runner;
+pub mod script;
 #[cfg(test)]
 mod tests;
+pub mod timing;
 #[cfg(windows)]
 mod windows;
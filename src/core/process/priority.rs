@@ -0,0 +1,43 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Scheduling priority of spawned processes.
+//!
+//! ```text
+//! --low-priority --> TaskManager / ToolContext::with_priority(BelowNormal)
+//!                           |
+//!                           v
+//!   ToolContext::run_process() applies it as ProcessBuilder::default_priority
+//!   ProcessBuilder::priority(p) overrides it per process
+//!                           |
+//!                           v
+//!   build_command():  Windows  BELOW_NORMAL/IDLE_PRIORITY_CLASS creation flag
+//!                     Unix     nice -n 10 / nice -n 19 <program> <args>
+//! ```
+
+/// Scheduling priority of a child process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessPriority {
+    /// Inherits mob's own priority.
+    #[default]
+    Normal,
+    /// Below normal: `BELOW_NORMAL_PRIORITY_CLASS` on Windows, `nice -n 10` on Unix.
+    BelowNormal,
+    /// Only runs when the system is idle: `IDLE_PRIORITY_CLASS` on Windows,
+    /// `nice -n 19` on Unix.
+    Idle,
+}
+
+impl ProcessPriority {
+    /// Returns the `nice` increment for this priority, `None` for `Normal`.
+    #[must_use]
+    pub const fn nice_increment(self) -> Option<u8> {
+        match self {
+            Self::Normal => None,
+            Self::BelowNormal => Some(10),
+            Self::Idle => Some(19),
+        }
+    }
+}
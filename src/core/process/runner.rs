@@ -10,7 +10,7 @@
 //!              |
 //!              v
 //!     build_command()
-//!     args, cwd, env, stdio
+//!     args, cwd, env, stdio, priority
 //!              |
 //!              v
 //!          spawn()
@@ -20,8 +20,9 @@
 //!   job_object  run_child
 //!        \       /
 //!         v     v
+//!    record_timing (if collected)
 //!    validate exit_code
-//!    (skip if ALLOW_FAILURE)
+//!    (skip if ALLOW_FAILURE, Err(ProcessFailure) on mismatch)
 //!              |
 //!              v
 //!       ProcessOutput
@@ -31,12 +32,17 @@
 use crate::error::Result;
 use anyhow::Context;
 use std::process::Stdio;
+use std::time::Instant;
 use tokio::process::{Child, Command};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, trace};
 
 use super::builder::{ProcessBuilder, ProcessFlags, ProcessOutput, StreamFlags};
+use super::failure::ProcessFailure;
+use super::timing::ProcessTiming;
 
+#[cfg(windows)]
+use super::priority::ProcessPriority;
 #[cfg(windows)]
 use crate::core::job::JobObject;
 
@@ -69,6 +75,31 @@ impl ProcessBuilder {
         cmd
     }
 
+    /// Returns the error of this process exiting with an unexpected code.
+    fn failure(&self, name: &str, cmd_line: &str, output: &ProcessOutput) -> ProcessFailure {
+        ProcessFailure::new(
+            name.to_string(),
+            self.success_code_set().clone(),
+            cmd_line.to_string(),
+            self.working_dir().cloned(),
+            output.exit_code(),
+            output.stdout().to_string(),
+            output.stderr().to_string(),
+        )
+    }
+
+    /// Records how long this process ran since `started`, if timings are
+    /// collected.
+    fn record_timing(&self, cmd_line: &str, started: Instant) {
+        if let Some(timings) = self.timing_collector() {
+            timings.record(ProcessTiming::new(
+                self.display_name(),
+                cmd_line.to_string(),
+                started.elapsed(),
+            ));
+        }
+    }
+
     /// Spawns and runs the process, waiting for completion.
     ///
     /// This is the main entry point for executing a process.
@@ -92,6 +123,7 @@ impl ProcessBuilder {
         let mut command = self.build_command();
 
         // Spawn the process
+        let started = Instant::now();
         let mut child = command
             .spawn()
             .with_context(|| format!("Failed to spawn: {cmd_line}"))?;
@@ -105,6 +137,7 @@ impl ProcessBuilder {
 
         // Run the process with streaming output
         let output = self.run_child(&name, &mut child).await?;
+        self.record_timing(&cmd_line, started);
 
         // Check exit code
         if !self.process_flags().contains(ProcessFlags::ALLOW_FAILURE)
@@ -113,12 +146,7 @@ impl ProcessBuilder {
             if !output.stderr().is_empty() {
                 error!(process = %name, stderr = %output.stderr(), "process error output");
             }
-            anyhow::bail!(
-                "{} exited with code {} (expected one of {:?})",
-                name,
-                output.exit_code(),
-                self.success_code_set()
-            );
+            return Err(self.failure(&name, &cmd_line, &output).into());
         }
 
         trace!(process = %name, exit_code = output.exit_code(), "completed");
@@ -179,6 +207,7 @@ impl ProcessBuilder {
         let mut command = self.build_command();
 
         // Spawn the process
+        let started = Instant::now();
         let mut child = command
             .spawn()
             .with_context(|| format!("Failed to spawn: {cmd_line}"))?;
@@ -194,6 +223,7 @@ impl ProcessBuilder {
         let output = self
             .run_child_with_cancellation(&name, &mut child, token)
             .await?;
+        self.record_timing(&cmd_line, started);
 
         // Check exit code (unless interrupted or ALLOW_FAILURE)
         if !output.is_interrupted()
@@ -203,12 +233,7 @@ impl ProcessBuilder {
             if !output.stderr().is_empty() {
                 error!(process = %name, stderr = %output.stderr(), "process error output");
             }
-            anyhow::bail!(
-                "{} exited with code {} (expected one of {:?})",
-                name,
-                output.exit_code(),
-                self.success_code_set()
-            );
+            return Err(self.failure(&name, &cmd_line, &output).into());
         }
 
         trace!(
@@ -222,7 +247,7 @@ impl ProcessBuilder {
 
     /// Builds the tokio Command from this builder's configuration.
     fn build_command(&self) -> Command {
-        let mut command = Command::new(self.program());
+        let mut command = self.priority_command();
 
         // Arguments
         command.args(self.args_slice());
@@ -259,12 +284,37 @@ impl ProcessBuilder {
         // Windows-specific: create new process group
         #[cfg(windows)]
         {
-            command.creation_flags(windows::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP.0);
+            use windows::Win32::System::Threading::{
+                BELOW_NORMAL_PRIORITY_CLASS, CREATE_NEW_PROCESS_GROUP, IDLE_PRIORITY_CLASS,
+                PROCESS_CREATION_FLAGS,
+            };
+
+            let priority_class = match self.process_priority() {
+                ProcessPriority::Normal => PROCESS_CREATION_FLAGS(0),
+                ProcessPriority::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+                ProcessPriority::Idle => IDLE_PRIORITY_CLASS,
+            };
+            command.creation_flags((CREATE_NEW_PROCESS_GROUP | priority_class).0);
         }
 
         command
     }
 
+    /// Creates the Command for the program, wrapped in `nice` on Unix when
+    /// the priority is lowered.
+    fn priority_command(&self) -> Command {
+        #[cfg(unix)]
+        if let Some(increment) = self.process_priority().nice_increment() {
+            let mut command = Command::new("nice");
+            command
+                .arg("-n")
+                .arg(increment.to_string())
+                .arg(self.program());
+            return command;
+        }
+        Command::new(self.program())
+    }
+
     /// Converts `StreamFlags` to Stdio configuration.
     fn stdio_from_flags(flags: StreamFlags) -> Stdio {
         if flags.contains(StreamFlags::INHERIT) {
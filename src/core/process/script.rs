@@ -0,0 +1,255 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Commands a dry run would execute, recorded for `--emit-script`.
+//!
+//! ```text
+//! TaskManager::with_script(script)
+//!       |
+//! tool dry-run branch --> ToolContext
+//!   Script::record_command(&ProcessBuilder)   program, args, cwd, env diff
+//!   Script::record_download(url, file)
+//!                           |
+//!                           v
+//!   Script::take() --> render(steps, Sh | PowerShell) --> build.sh / build.ps1
+//! ```
+//!
+//! Only variables that differ from mob's own environment are written, which
+//! covers `[env]`, `--env` and the Visual Studio environment of `MSBuild`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::builder::ProcessBuilder;
+use crate::core::env::current_env;
+
+/// One external action of a dry run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptStep {
+    /// A process invocation.
+    Command {
+        /// Executable to run.
+        program: PathBuf,
+        /// Arguments, unquoted.
+        args: Vec<String>,
+        /// Working directory, if set.
+        cwd: Option<PathBuf>,
+        /// Variables that differ from mob's environment.
+        env: BTreeMap<String, String>,
+    },
+    /// An HTTP download.
+    Download {
+        /// Source URL.
+        url: String,
+        /// Destination file.
+        file: PathBuf,
+    },
+}
+
+/// Shell the script is written for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptShell {
+    /// POSIX `sh`.
+    Sh,
+    /// Windows `PowerShell`.
+    PowerShell,
+}
+
+impl ScriptShell {
+    /// Picks `PowerShell` for `.ps1` files and `sh` for everything else.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("ps1") => Self::PowerShell,
+            _ => Self::Sh,
+        }
+    }
+}
+
+/// Steps of one dry run, in the order they were recorded.
+#[derive(Debug, Default)]
+pub struct Script {
+    steps: Mutex<Vec<ScriptStep>>,
+}
+
+impl Script {
+    /// Creates an empty script.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores the command `builder` would run.
+    pub fn record_command(&self, builder: &ProcessBuilder) {
+        let env = builder
+            .environment()
+            .map(|env| {
+                let current = current_env();
+                env.iter()
+                    .filter(|(key, value)| current.get(key) != Some(*value))
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.push(ScriptStep::Command {
+            program: builder.program().clone(),
+            args: builder.args_slice().to_vec(),
+            cwd: builder.working_dir().cloned(),
+            env,
+        });
+    }
+
+    /// Stores a download of `url` into `file`.
+    pub fn record_download(&self, url: &str, file: &Path) {
+        self.push(ScriptStep::Download {
+            url: url.to_string(),
+            file: file.to_path_buf(),
+        });
+    }
+
+    fn push(&self, step: ScriptStep) {
+        self.steps
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(step);
+    }
+
+    /// Returns and clears all recorded steps.
+    #[must_use]
+    pub fn take(&self) -> Vec<ScriptStep> {
+        std::mem::take(
+            &mut *self
+                .steps
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+}
+
+/// Renders `steps` as a script that stops at the first failure.
+#[must_use]
+pub fn render(steps: &[ScriptStep], shell: ScriptShell) -> String {
+    match shell {
+        ScriptShell::Sh => render_sh(steps),
+        ScriptShell::PowerShell => render_powershell(steps),
+    }
+}
+
+fn render_sh(steps: &[ScriptStep]) -> String {
+    let mut out =
+        String::from("#!/bin/sh\n# Generated by `mob build --dry --emit-script`.\nset -e\n");
+    for step in steps {
+        out.push('\n');
+        match step {
+            ScriptStep::Command {
+                program,
+                args,
+                cwd,
+                env,
+            } => {
+                let mut line = String::new();
+                if !env.is_empty() {
+                    line.push_str("env");
+                    for (key, value) in env {
+                        let _ = write!(line, " {}", sh_quote(&format!("{key}={value}")));
+                    }
+                    line.push(' ');
+                }
+                line.push_str(&sh_quote(&program.display().to_string()));
+                for arg in args {
+                    let _ = write!(line, " {}", sh_quote(arg));
+                }
+                match cwd {
+                    Some(cwd) => {
+                        let cwd = sh_quote(&cwd.display().to_string());
+                        let _ = writeln!(out, "(\n  cd {cwd}\n  {line}\n)");
+                    }
+                    None => {
+                        let _ = writeln!(out, "{line}");
+                    }
+                }
+            }
+            ScriptStep::Download { url, file } => {
+                let _ = writeln!(
+                    out,
+                    "curl -fL --create-dirs -o {} {}",
+                    sh_quote(&file.display().to_string()),
+                    sh_quote(url)
+                );
+            }
+        }
+    }
+    out
+}
+
+fn render_powershell(steps: &[ScriptStep]) -> String {
+    let mut out = String::from(
+        "# Generated by `mob build --dry --emit-script`.\n$ErrorActionPreference = 'Stop'\n",
+    );
+    for step in steps {
+        out.push('\n');
+        match step {
+            ScriptStep::Command {
+                program,
+                args,
+                cwd,
+                env,
+            } => {
+                for (key, value) in env {
+                    let _ = writeln!(
+                        out,
+                        "[Environment]::SetEnvironmentVariable({}, {})",
+                        ps_quote(key),
+                        ps_quote(value)
+                    );
+                }
+                if let Some(cwd) = cwd {
+                    let _ = writeln!(
+                        out,
+                        "Push-Location -LiteralPath {}",
+                        ps_quote(&cwd.display().to_string())
+                    );
+                }
+                let mut line = format!("& {}", ps_quote(&program.display().to_string()));
+                for arg in args {
+                    let _ = write!(line, " {}", ps_quote(arg));
+                }
+                let _ = writeln!(out, "{line}");
+                let _ = writeln!(out, "if ($LASTEXITCODE -ne 0) {{ exit $LASTEXITCODE }}");
+                if cwd.is_some() {
+                    out.push_str("Pop-Location\n");
+                }
+            }
+            ScriptStep::Download { url, file } => {
+                if let Some(parent) = file.parent() {
+                    let _ = writeln!(
+                        out,
+                        "New-Item -ItemType Directory -Force -Path {} | Out-Null",
+                        ps_quote(&parent.display().to_string())
+                    );
+                }
+                let _ = writeln!(
+                    out,
+                    "Invoke-WebRequest -Uri {} -OutFile {}",
+                    ps_quote(url),
+                    ps_quote(&file.display().to_string())
+                );
+            }
+        }
+    }
+    out
+}
+
+/// Quotes `value` for `sh`: `'` becomes `'\''`.
+fn sh_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Quotes `value` for `PowerShell`: `'` becomes `''`.
+fn ps_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
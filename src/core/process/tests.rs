@@ -5,6 +5,7 @@
 
 use super::builder::{ProcessBuilder, ProcessFlags};
 use crate::core::env::container::Env;
+use std::sync::Arc;
 
 #[tokio::test]
 async fn test_process_echo() {
@@ -64,6 +65,36 @@ async fn test_process_env() {
     insta::assert_snapshot!(output.stdout().trim());
 }
 
+#[tokio::test]
+async fn test_process_env_overlay() {
+    let mut base = Env::new();
+    base.set("BASE_VAR", "base");
+    base.set("TEST_VAR", "base");
+
+    let mut overlay = Env::new();
+    overlay.set("TEST_VAR", "overlay");
+
+    #[cfg(windows)]
+    let output = ProcessBuilder::raw("Write-Output \"$env:BASE_VAR $env:TEST_VAR\"")
+        .env(base)
+        .env_overlay(&overlay)
+        .capture_stdout()
+        .run()
+        .await
+        .expect("process should succeed");
+
+    #[cfg(not(windows))]
+    let output = ProcessBuilder::raw("echo \"$BASE_VAR $TEST_VAR\"")
+        .env(base)
+        .env_overlay(&overlay)
+        .capture_stdout()
+        .run()
+        .await
+        .expect("process should succeed");
+
+    insta::assert_snapshot!(output.stdout().trim());
+}
+
 #[test]
 fn test_executable_lookup_found() {
     // cargo should always be available since we're running tests with cargo
@@ -144,3 +175,179 @@ fn test_executable_lookup_not_found() {
         "find_all: should find no matches"
     );
 }
+
+#[tokio::test]
+async fn test_process_failure_is_returned() {
+    let error = ProcessBuilder::raw("exit 57")
+        .capture_output()
+        .run()
+        .await
+        .expect_err("process should fail");
+
+    let failure = error
+        .downcast_ref::<super::failure::ProcessFailure>()
+        .expect("error should be a ProcessFailure");
+    assert!(failure.command().contains("exit 57"));
+    assert_eq!(failure.exit_code(), 57);
+}
+
+#[tokio::test]
+async fn test_process_timing_is_recorded() {
+    let timings = Arc::new(super::timing::ProcessTimings::new());
+    ProcessBuilder::raw("echo mob-timing")
+        .capture_output()
+        .record_timings(Arc::clone(&timings))
+        .run()
+        .await
+        .expect("process should run");
+    ProcessBuilder::raw("echo mob-untimed")
+        .capture_output()
+        .run()
+        .await
+        .expect("process should run");
+
+    let timings = timings.take();
+    assert_eq!(timings.len(), 1);
+    assert!(timings[0].command().contains("echo mob-timing"));
+}
+
+#[test]
+fn test_script_records_and_renders_sh() {
+    use super::script::{self, Script, ScriptShell};
+
+    let script = Script::new();
+    let mut env = crate::core::env::current_env();
+    env.set("MOB_SCRIPT_TEST", "it's set");
+    script.record_command(
+        &ProcessBuilder::new("cmake")
+            .arg("--build")
+            .arg("build dir")
+            .cwd("/src/usvfs")
+            .env(env),
+    );
+    script.record_download("https://example.com/a.7z", std::path::Path::new("/dl/a.7z"));
+
+    let steps = script.take();
+    let rendered = script::render(&steps, ScriptShell::Sh);
+    assert!(rendered.starts_with("#!/bin/sh\n"));
+    assert!(rendered.contains("set -e\n"));
+    assert!(rendered.contains("  cd '/src/usvfs'\n"));
+    assert!(rendered.contains(r"env 'MOB_SCRIPT_TEST=it'\''s set' 'cmake' '--build' 'build dir'"));
+    assert!(rendered.contains("curl -fL --create-dirs -o '/dl/a.7z' 'https://example.com/a.7z'"));
+}
+
+#[test]
+fn test_script_renders_powershell() {
+    use super::script::{self, ScriptShell, ScriptStep};
+
+    assert_eq!(
+        ScriptShell::from_path(std::path::Path::new("build.PS1")),
+        ScriptShell::PowerShell
+    );
+    assert_eq!(
+        ScriptShell::from_path(std::path::Path::new("build.sh")),
+        ScriptShell::Sh
+    );
+
+    let steps = [ScriptStep::Command {
+        program: "msbuild.exe".into(),
+        args: vec!["-p:Configuration=Release".to_string()],
+        cwd: Some("C:\\dev\\o'brien".into()),
+        env: std::iter::once(("VCINSTALLDIR".to_string(), "C:\\VS".to_string())).collect(),
+    }];
+    let rendered = script::render(&steps, ScriptShell::PowerShell);
+    assert!(rendered.contains("$ErrorActionPreference = 'Stop'"));
+    assert!(rendered.contains("[Environment]::SetEnvironmentVariable('VCINSTALLDIR', 'C:\\VS')"));
+    assert!(rendered.contains("Push-Location -LiteralPath 'C:\\dev\\o''brien'"));
+    assert!(rendered.contains("& 'msbuild.exe' '-p:Configuration=Release'"));
+    assert!(rendered.contains("if ($LASTEXITCODE -ne 0) { exit $LASTEXITCODE }"));
+    assert!(rendered.contains("Pop-Location"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_idle_priority_runs_under_nice() {
+    use super::priority::ProcessPriority;
+
+    let output = ProcessBuilder::raw("nice")
+        .priority(ProcessPriority::Idle)
+        .capture_stdout()
+        .run()
+        .await
+        .expect("process should run");
+
+    assert_eq!(output.stdout().trim(), "19");
+}
+
+#[test]
+fn test_priority_nice_increments() {
+    use super::priority::ProcessPriority;
+
+    assert_eq!(ProcessPriority::Normal.nice_increment(), None);
+    assert_eq!(ProcessPriority::BelowNormal.nice_increment(), Some(10));
+    assert_eq!(ProcessPriority::Idle.nice_increment(), Some(19));
+}
+
+#[test]
+fn test_diagnostic_classify() {
+    use super::diagnostics::{DiagnosticKind, classify};
+
+    let lines = [
+        (
+            r"C:\src\a.cpp(12,5): warning C4996: 'strcpy': This function may be unsafe. [C:\src\a.vcxproj]",
+            Some(DiagnosticKind::Warning),
+        ),
+        (
+            r"  1>C:\src\b.cpp(3): error C2065: 'x': undeclared identifier",
+            Some(DiagnosticKind::Error),
+        ),
+        (
+            "LINK : fatal error LNK1104: cannot open file 'usvfs.lib'",
+            Some(DiagnosticKind::Error),
+        ),
+        (
+            "MSBUILD : error MSB1009: Project file does not exist.",
+            Some(DiagnosticKind::Error),
+        ),
+        (
+            "CMake Warning at CMakeLists.txt:12 (message):",
+            Some(DiagnosticKind::Warning),
+        ),
+        (
+            "CMake Deprecation Warning at CMakeLists.txt:1 (cmake_minimum_required):",
+            Some(DiagnosticKind::Warning),
+        ),
+        (
+            "CMake Error at src/CMakeLists.txt:40 (find_package):",
+            Some(DiagnosticKind::Error),
+        ),
+        (r"  Copying C:\build\warning: C4996\file.txt", None),
+        (r"  a.cpp -> C:\src\error: handling\a.obj", None),
+        ("    12 Warning(s)", None),
+        ("-- Performing Test HAVE_WARNING_FLAG - Failed", None),
+        ("  CMake Warning at indented.txt:1:", None),
+    ];
+    for (line, expected) in lines {
+        assert_eq!(classify(line), expected, "{line}");
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_process_counts_diagnostics() {
+    use super::diagnostics::DiagnosticCounter;
+
+    let counter = Arc::new(DiagnosticCounter::new());
+    ProcessBuilder::raw(
+        "echo 'a.cpp(1): warning C4100: unused'; echo 'a.cpp(1): warning C4100: unused'; \
+         echo 'b.cpp(2): warning C4189: unused'; echo 'CMake Error at x.txt:1:' 1>&2",
+    )
+    .count_diagnostics(Arc::clone(&counter))
+    .run()
+    .await
+    .expect("process should succeed");
+
+    let counts = counter.counts();
+    assert_eq!((counts.warnings(), counts.errors()), (2, 1));
+    assert_eq!(counts.to_string(), "2 warnings, 1 error");
+}
@@ -0,0 +1,93 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Wall-clock timings of finished processes, recorded for `--measure`.
+//!
+//! ```text
+//! ProcessBuilder::record_timings(Arc<ProcessTimings>)
+//!       |
+//! run() / run_with_cancellation()
+//!   process exited --> ProcessTimings::record()
+//!                           |
+//!                           v
+//!              ProcessTimings::take() --> build profile
+//! ```
+//!
+//! Only builders given a collector are recorded, so regular runs don't
+//! accumulate entries.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Wall-clock duration of one process invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessTiming {
+    program: String,
+    command: String,
+    duration: Duration,
+}
+
+impl ProcessTiming {
+    /// Creates a new timing record.
+    #[must_use]
+    pub const fn new(program: String, command: String, duration: Duration) -> Self {
+        Self {
+            program,
+            command,
+            duration,
+        }
+    }
+
+    /// Returns the program name (file stem of the executable).
+    #[must_use]
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    /// Returns the full command line.
+    #[must_use]
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Returns how long the process ran.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Timings of the processes of one build, shared by all of its tools.
+#[derive(Debug, Default)]
+pub struct ProcessTimings {
+    timings: Mutex<Vec<ProcessTiming>>,
+}
+
+impl ProcessTimings {
+    /// Creates an empty collection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `timing`.
+    pub fn record(&self, timing: ProcessTiming) {
+        self.timings
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(timing);
+    }
+
+    /// Returns and clears the recorded timings.
+    #[must_use]
+    pub fn take(&self) -> Vec<ProcessTiming> {
+        std::mem::take(
+            &mut *self
+                .timings
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+}
@@ -9,11 +9,19 @@
 //! vswhere.exe --> find_installations() --> VsInstallation
 //!   instance_id, path, version, display_name, flags
 //!   derived: devshell_dll(), msbuild_path(), devenv_path()
+//!
+//! find_installation(vswhere, versions.vs_version)
+//!   --> select_installation(installations, pinned)
+//!   unset   --> newest installation
+//!   "16"    --> newest 16.x, "17.14" --> newest 17.14.x
+//!   "abc1"  --> installation with instance ID abc1
+//!   no match --> error listing the discovered installations
 //! ```
 
 use crate::error::Result;
 use anyhow::Context;
 use serde::Deserialize;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::OnceLock;
@@ -31,6 +39,9 @@ static VSWHERE_PATH: OnceLock<std::result::Result<PathBuf, String>> = OnceLock::
 /// Global cache for latest VS installation.
 static LATEST_INSTALLATION: OnceLock<std::result::Result<VsInstallation, String>> = OnceLock::new();
 
+/// Global cache for all VS installations, searched by [`find_installation`].
+static INSTALLATIONS: OnceLock<std::result::Result<Vec<VsInstallation>, String>> = OnceLock::new();
+
 /// Visual Studio installation information from vswhere JSON output.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -89,6 +100,25 @@ impl VsInstallation {
             .join("devenv.exe")
     }
 
+    /// Whether this installation is selected by `pinned`, either its instance
+    /// ID or a prefix of its version: `"17"` and `"17.14"` match
+    /// `17.14.36915.13`, `"17.1"` does not.
+    #[must_use]
+    pub fn matches(&self, pinned: &str) -> bool {
+        let pinned = pinned.trim();
+        if self.instance_id.eq_ignore_ascii_case(pinned) {
+            return true;
+        }
+        let mut version = self.installation_version.split('.');
+        !pinned.is_empty() && pinned.split('.').all(|part| version.next() == Some(part))
+    }
+
+    /// Major version, e.g. 17 for `17.14.36915.13`.
+    #[must_use]
+    pub fn major(&self) -> u32 {
+        self.version_tuple().0
+    }
+
     /// Parse version into numeric components (major, minor, patch, build).
     ///
     /// Numeric comparison correctly handles "17.14" > "17.9", unlike string comparison.
@@ -233,6 +263,80 @@ fn find_latest_impl() -> Result<VsInstallation> {
         .ok_or_else(|| anyhow::anyhow!("No Visual Studio installations found with C++ tools"))
 }
 
+/// Finds the Visual Studio installation to build with: the one matching
+/// `pinned` (`versions.vs_version`), or the newest one.
+///
+/// The discovered installations are cached for subsequent calls (when no
+/// override provided).
+///
+/// # Errors
+///
+/// Returns an error if discovery fails, no installation is found, or none
+/// matches `pinned`.
+pub fn find_installation(
+    vswhere_override: Option<&Path>,
+    pinned: Option<&str>,
+) -> Result<VsInstallation> {
+    let pinned = pinned.filter(|pinned| !pinned.trim().is_empty());
+    if vswhere_override.is_some() {
+        return select_installation(find_installations(vswhere_override)?, pinned);
+    }
+
+    let installations = INSTALLATIONS
+        .get_or_init(|| find_installations(None).map_err(|e| format!("{e:#}")))
+        .clone()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    select_installation(installations, pinned)
+}
+
+/// Picks the first of `installations` (sorted newest first) that matches
+/// `pinned`, or the first one when `pinned` is `None`.
+///
+/// # Errors
+///
+/// Returns an error if `installations` is empty, or if none matches `pinned`;
+/// the error then lists every discovered installation.
+pub fn select_installation(
+    installations: Vec<VsInstallation>,
+    pinned: Option<&str>,
+) -> Result<VsInstallation> {
+    let Some(pinned) = pinned else {
+        return installations
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No Visual Studio installations found with C++ tools"));
+    };
+
+    if let Some(vs) = installations.iter().find(|vs| vs.matches(pinned)) {
+        debug!(
+            pinned,
+            version = %vs.installation_version,
+            path = %vs.installation_path.display(),
+            "Using pinned Visual Studio installation"
+        );
+        return Ok(vs.clone());
+    }
+
+    let mut message =
+        format!("No Visual Studio installation matches versions.vs_version = \"{pinned}\"");
+    if installations.is_empty() {
+        message.push_str(", none with C++ tools were found");
+    } else {
+        message.push_str(", found:");
+        for vs in &installations {
+            let _ = write!(
+                message,
+                "\n  {} {} (instance {}) at {}",
+                vs.display_name,
+                vs.installation_version,
+                vs.instance_id,
+                vs.installation_path.display()
+            );
+        }
+    }
+    Err(anyhow::anyhow!(message))
+}
+
 /// Parses vswhere JSON output into `VsInstallation` structs.
 fn parse_vswhere_json(json: &str) -> Result<Vec<VsInstallation>> {
     let installations: Vec<VsInstallation> =
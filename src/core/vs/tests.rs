@@ -3,7 +3,7 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{VsInstallation, parse_vswhere_json};
+use super::{VsInstallation, parse_vswhere_json, select_installation};
 use std::path::PathBuf;
 
 #[test]
@@ -158,3 +158,61 @@ fn test_version_tuple_parsing() {
 
     insta::assert_yaml_snapshot!("version_tuple_parsing", vs.version_tuple());
 }
+
+fn installation(instance_id: &str, version: &str) -> VsInstallation {
+    VsInstallation {
+        instance_id: instance_id.to_string(),
+        installation_path: PathBuf::from(format!("C:\\VS\\{instance_id}")),
+        installation_version: version.to_string(),
+        display_name: format!("VS {instance_id}"),
+        is_complete: true,
+        is_prerelease: false,
+    }
+}
+
+#[test]
+fn test_matches_version_prefix_or_instance_id() {
+    let vs = installation("76a9c886", "17.14.36915.13");
+
+    assert!(vs.matches("17"));
+    assert!(vs.matches("17.14"));
+    assert!(vs.matches("17.14.36915.13"));
+    assert!(vs.matches("76A9C886"));
+    assert!(!vs.matches("17.1"));
+    assert!(!vs.matches("16"));
+    assert!(!vs.matches(""));
+}
+
+#[test]
+fn test_select_installation() {
+    let installations = vec![
+        installation("vs2022", "17.14.0.0"),
+        installation("vs2019", "16.11.0.0"),
+    ];
+
+    let latest = select_installation(installations.clone(), None).unwrap();
+    assert_eq!(latest.instance_id, "vs2022");
+
+    let pinned = select_installation(installations.clone(), Some("16")).unwrap();
+    assert_eq!(pinned.instance_id, "vs2019");
+    assert_eq!(pinned.major(), 16);
+
+    let by_id = select_installation(installations, Some("vs2019")).unwrap();
+    assert_eq!(by_id.installation_version, "16.11.0.0");
+
+    assert!(select_installation(Vec::new(), None).is_err());
+}
+
+#[test]
+fn test_select_installation_lists_discovered() {
+    let installations = vec![
+        installation("vs2022", "17.14.0.0"),
+        installation("vs2019", "16.11.0.0"),
+    ];
+
+    let error = select_installation(installations, Some("15")).unwrap_err();
+    insta::assert_snapshot!("select_installation_not_found", error.to_string());
+
+    let error = select_installation(Vec::new(), Some("15")).unwrap_err();
+    assert!(error.to_string().contains("none with C++ tools were found"));
+}
@@ -18,13 +18,30 @@
 //!   Git     Gix, CommandFailed, BranchNotFound
 //!   Network Reqwest, HttpError, Timeout
 //!   Config  ParseError, MissingKey, InvalidValue
-//!   Task    NotFound, ExecutionFailed, Interrupted
+//!   Task    NotFound, ExecutionFailed, Interrupted, DependencyCycle
 //!   Process SpawnFailed, NonZeroExit, Timeout
 //!   Fs      NotFound, PermissionDenied, IoError
 //!   Job     CreateFailed, AssignFailed (Windows)
 //!
 //! All variants boxed => MobError fits in 24 bytes.
 //! ```
+//!
+//! # Exit codes
+//!
+//! The process exit code reflects the category of the first categorized
+//! error in the failure chain (see [`exit_code`]):
+//!
+//! | Code | Category                                  |
+//! |------|-------------------------------------------|
+//! | 0    | Success                                   |
+//! | 1    | Other failure (fs, io, job, bailed, ...)  |
+//! | 2    | Configuration error                       |
+//! | 3    | Network error                             |
+//! | 4    | Git error                                 |
+//! | 5    | Process error                             |
+//! | 6    | Task error                                |
+//!
+//! Command-line usage errors are reported by clap, which also exits with 2.
 
 use thiserror::Error;
 
@@ -80,6 +97,78 @@ pub enum MobError {
     Other(Box<str>),
 }
 
+impl MobError {
+    /// Stable process exit code for this error's category.
+    ///
+    /// See the [module documentation](self#exit-codes) for the mapping.
+    #[must_use]
+    pub const fn exit_code(&self) -> u8 {
+        match self {
+            Self::Config(_) => exit_codes::CONFIG,
+            Self::Network(_) => exit_codes::NETWORK,
+            Self::Git(_) => exit_codes::GIT,
+            Self::Process(_) => exit_codes::PROCESS,
+            Self::Task(_) => exit_codes::TASK,
+            Self::Bailed(_) | Self::Fs(_) | Self::Job(_) | Self::Io(_) | Self::Other(_) => {
+                exit_codes::FAILURE
+            }
+        }
+    }
+}
+
+/// Process exit codes returned by `mob`.
+pub mod exit_codes {
+    /// Uncategorized failure.
+    pub const FAILURE: u8 = 1;
+    /// Configuration error.
+    pub const CONFIG: u8 = 2;
+    /// Network error.
+    pub const NETWORK: u8 = 3;
+    /// Git error.
+    pub const GIT: u8 = 4;
+    /// Process error.
+    pub const PROCESS: u8 = 5;
+    /// Task error.
+    pub const TASK: u8 = 6;
+    /// Forced exit by a second Ctrl+C.
+    pub const INTERRUPTED: u8 = 130;
+}
+
+/// Exit code for an `anyhow` error.
+///
+/// Walks the error chain and returns the code of the first [`MobError`] or
+/// sub-error found, since most call sites propagate sub-errors directly
+/// without wrapping them in [`MobError`]. Falls back to
+/// [`exit_codes::FAILURE`].
+#[must_use]
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    error
+        .chain()
+        .find_map(category_exit_code)
+        .unwrap_or(exit_codes::FAILURE)
+}
+
+/// Exit code for a single error in a chain, if it belongs to a category.
+fn category_exit_code(cause: &(dyn std::error::Error + 'static)) -> Option<u8> {
+    if let Some(err) = cause.downcast_ref::<MobError>() {
+        return Some(err.exit_code());
+    }
+    let code = if cause.is::<ConfigError>() {
+        exit_codes::CONFIG
+    } else if cause.is::<NetworkError>() || cause.is::<reqwest::Error>() {
+        exit_codes::NETWORK
+    } else if cause.is::<GitError>() || cause.is::<GixError>() {
+        exit_codes::GIT
+    } else if cause.is::<ProcessError>() {
+        exit_codes::PROCESS
+    } else if cause.is::<TaskError>() {
+        exit_codes::TASK
+    } else {
+        return None;
+    };
+    Some(code)
+}
+
 /// Create a fatal [`MobError::Bailed`] that terminates the application.
 pub fn bail_out(message: impl Into<String>) -> MobError {
     MobError::Bailed(message.into().into_boxed_str())
@@ -135,6 +224,18 @@ pub enum GixError {
     #[error("failed to get head reference: {0}")]
     Head(#[from] gix::reference::find::existing::Error),
 
+    /// Failed to resolve HEAD to a commit id.
+    #[error("failed to resolve head id: {0}")]
+    HeadId(#[from] Box<gix::reference::head_id::Error>),
+
+    /// Failed to resolve HEAD to a commit.
+    #[error("failed to resolve head commit: {0}")]
+    HeadCommit(#[from] Box<gix::reference::head_commit::Error>),
+
+    /// Failed to describe a commit.
+    #[error("failed to describe commit: {0}")]
+    Describe(#[from] Box<gix::commit::describe::Error>),
+
     /// Repository has no worktree (bare repository).
     #[error("repository has no worktree (bare repository)")]
     BareRepository,
@@ -210,6 +311,27 @@ pub enum NetworkError {
     /// I/O error during download.
     #[error("io error during download: {0}")]
     Io(#[from] std::io::Error),
+
+    /// A resumed download got a range other than the one requested.
+    #[error("server ignored range request to resume {url}")]
+    RangeNotSupported { url: String },
+
+    /// Every candidate URL of a download failed.
+    #[error("download failed from all URLs: {}", urls.join(", "))]
+    AllUrlsFailed {
+        urls: Vec<String>,
+        /// Error of the last URL tried.
+        #[source]
+        last_error: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Downloaded file doesn't have the expected SHA-256 hash.
+    #[error("checksum mismatch for {url}: expected sha256 {expected}, got {actual}")]
+    ChecksumMismatch {
+        expected: String,
+        actual: String,
+        url: String,
+    },
 }
 
 // --- Config Errors ---
@@ -244,6 +366,10 @@ pub enum ConfigError {
     /// Configuration file not found.
     #[error("config file not found: {0}")]
     NotFound(String),
+
+    /// Selected `[profiles.<name>]` block does not exist.
+    #[error("config profile '{name}' not found (available: {available})")]
+    UnknownProfile { name: String, available: String },
 }
 
 // --- Task Errors ---
@@ -266,6 +392,10 @@ pub enum TaskError {
     /// Task dependency failed.
     #[error("task '{task}' failed because dependency '{dependency}' failed")]
     DependencyFailed { task: String, dependency: String },
+
+    /// Task dependencies form a cycle, listed from the first task back to it.
+    #[error("task dependency cycle: {}", .0.join(" -> "))]
+    DependencyCycle(Vec<String>),
 }
 
 // --- Process Errors ---
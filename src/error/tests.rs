@@ -3,7 +3,9 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{ConfigError, MobError, MobResult};
+use super::{
+    ConfigError, GitError, MobError, MobResult, ProcessError, TaskError, exit_code, exit_codes,
+};
 
 #[test]
 fn test_config_error_display() {
@@ -29,3 +31,34 @@ fn test_mob_result_size() {
     let size = std::mem::size_of::<MobResult<()>>();
     assert!(size <= 24, "MobResult<()> is {size} bytes, expected <= 24");
 }
+
+#[test]
+fn test_mob_error_exit_code() {
+    assert_eq!(
+        MobError::from(ConfigError::NotFound("mob.toml".into())).exit_code(),
+        2
+    );
+    assert_eq!(
+        MobError::from(TaskError::NotFound("usvfs".into())).exit_code(),
+        6
+    );
+    assert_eq!(MobError::Other("oops".into()).exit_code(), 1);
+}
+
+#[test]
+fn test_exit_code_walks_chain() {
+    let err = anyhow::Error::new(ProcessError::NonZeroExit {
+        command: "cmake".to_string(),
+        code: 1,
+    })
+    .context("building usvfs");
+    assert_eq!(exit_code(&err), exit_codes::PROCESS);
+
+    let err = anyhow::Error::new(MobError::from(GitError::BranchNotFound {
+        branch: "dev".to_string(),
+    }))
+    .context("fetching");
+    assert_eq!(exit_code(&err), exit_codes::GIT);
+
+    assert_eq!(exit_code(&anyhow::anyhow!("plain")), exit_codes::FAILURE);
+}
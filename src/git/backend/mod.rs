@@ -49,6 +49,36 @@ pub trait GitQuery {
     ///
     /// Returns a `GitError` if repository discovery or reference lookup fails.
     fn has_stashed_changes(path: &Path) -> MobResult<bool>;
+
+    /// Check if the repository is a shallow clone.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GitError` if repository discovery fails.
+    fn is_shallow(path: &Path) -> MobResult<bool>;
+
+    /// Get the full SHA of the commit HEAD points to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GitError` if repository discovery fails or HEAD has no commit.
+    fn current_commit(path: &Path) -> MobResult<String>;
+
+    /// Describe HEAD by its nearest tag, like `git describe --tags`.
+    ///
+    /// Returns `None` if no tag is reachable from HEAD.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GitError` if repository discovery or head resolution fails.
+    fn describe(path: &Path) -> MobResult<Option<String>>;
+
+    /// Check whether `rev` (a tag, branch or commit) names a commit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GitError` if repository discovery fails.
+    fn has_revision(path: &Path, rev: &str) -> MobResult<bool>;
 }
 
 // --- Mutation Trait (Write operations) ---
@@ -221,6 +251,44 @@ impl GitQuery for GixBackend {
             Err(e) => Err(GitError::Gix(GixError::Head(e)).into()),
         }
     }
+
+    fn is_shallow(path: &Path) -> MobResult<bool> {
+        let repo =
+            gix::discover(path).map_err(|e| GitError::Gix(GixError::Discover(Box::new(e))))?;
+        Ok(repo.is_shallow())
+    }
+
+    fn current_commit(path: &Path) -> MobResult<String> {
+        let repo =
+            gix::discover(path).map_err(|e| GitError::Gix(GixError::Discover(Box::new(e))))?;
+        let id = repo
+            .head_id()
+            .map_err(|e| GitError::Gix(GixError::HeadId(Box::new(e))))?;
+        Ok(id.to_string())
+    }
+
+    fn describe(path: &Path) -> MobResult<Option<String>> {
+        use gix::commit::describe::SelectRef;
+
+        let repo =
+            gix::discover(path).map_err(|e| GitError::Gix(GixError::Discover(Box::new(e))))?;
+        let commit = repo
+            .head_commit()
+            .map_err(|e| GitError::Gix(GixError::HeadCommit(Box::new(e))))?;
+        let format = commit
+            .describe()
+            .names(SelectRef::AllTags)
+            .try_format()
+            .map_err(|e| GitError::Gix(GixError::Describe(Box::new(e))))?;
+        Ok(format.map(|format| format.to_string()))
+    }
+
+    fn has_revision(path: &Path, rev: &str) -> MobResult<bool> {
+        let repo =
+            gix::discover(path).map_err(|e| GitError::Gix(GixError::Discover(Box::new(e))))?;
+        let spec = format!("{rev}^{{commit}}");
+        Ok(repo.rev_parse_single(spec.as_str()).is_ok())
+    }
 }
 
 // --- ShellBackend Implementation (Git CLI) ---
@@ -396,6 +464,35 @@ impl GitQuery for ShellBackend {
         let output = Self::git_command(&["stash", "list"], path);
         output.map_or_else(|_| Ok(false), |list| Ok(!list.is_empty()))
     }
+
+    fn is_shallow(path: &Path) -> MobResult<bool> {
+        let output = Self::git_command(&["rev-parse", "--is-shallow-repository"], path)?;
+        Ok(output == "true")
+    }
+
+    fn current_commit(path: &Path) -> MobResult<String> {
+        Self::git_command(&["rev-parse", "HEAD"], path)
+    }
+
+    fn describe(path: &Path) -> MobResult<Option<String>> {
+        // Fails both when no tag is reachable and when there is no repo
+        Self::current_commit(path)?;
+        Ok(Self::git_command(&["describe", "--tags"], path).ok())
+    }
+
+    fn has_revision(path: &Path, rev: &str) -> MobResult<bool> {
+        // Fails both when `rev` is unknown and when there is no repo
+        Self::git_command(&["rev-parse", "--git-dir"], path)?;
+        let spec = format!("{rev}^{{commit}}");
+        let args = [
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            "--end-of-options",
+            &spec,
+        ];
+        Ok(Self::git_command(&args, path).is_ok())
+    }
 }
 
 #[cfg(test)]
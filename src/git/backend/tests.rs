@@ -53,3 +53,103 @@ fn test_backends_consistency() {
     assert!(GixBackend::is_git_repo(temp.path()));
     assert!(ShellBackend::is_git_repo(temp.path()));
 }
+
+#[test]
+fn test_backends_is_shallow() {
+    let temp = temp_dir();
+    let origin = temp.path().join("origin");
+    let clone = temp.path().join("clone");
+    std::fs::create_dir(&origin).expect("failed to create origin");
+
+    ShellBackend::init_repo(&origin).expect("failed to init repo");
+    for message in ["first", "second"] {
+        ShellBackend::git_command(
+            &[
+                "-c",
+                "user.name=mob",
+                "-c",
+                "user.email=mob@example.com",
+                "commit",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+            &origin,
+        )
+        .expect("failed to commit");
+    }
+    assert!(!GixBackend::is_shallow(&origin).unwrap());
+    assert!(!ShellBackend::is_shallow(&origin).unwrap());
+
+    // --depth is ignored for plain local paths, so use a file:// URL
+    let origin_path = origin.display().to_string().replace('\\', "/");
+    let url = format!("file:///{}", origin_path.trim_start_matches('/'));
+    ShellBackend::clone(&url, &clone, None, true).expect("failed to clone");
+    assert!(GixBackend::is_shallow(&clone).unwrap());
+    assert!(ShellBackend::is_shallow(&clone).unwrap());
+}
+
+#[test]
+fn test_backends_current_commit_and_describe() {
+    let temp = temp_dir();
+    let commit = |message: &str| {
+        ShellBackend::git_command(
+            &[
+                "-c",
+                "user.name=mob",
+                "-c",
+                "user.email=mob@example.com",
+                "commit",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+            temp.path(),
+        )
+        .expect("failed to commit");
+    };
+
+    ShellBackend::init_repo(temp.path()).expect("failed to init repo");
+    assert!(GixBackend::current_commit(temp.path()).is_err());
+    assert!(ShellBackend::current_commit(temp.path()).is_err());
+
+    commit("first");
+    let sha = ShellBackend::current_commit(temp.path()).unwrap();
+    assert_eq!(sha.len(), 40);
+    assert_eq!(GixBackend::current_commit(temp.path()).unwrap(), sha);
+    assert_eq!(GixBackend::describe(temp.path()).unwrap(), None);
+    assert_eq!(ShellBackend::describe(temp.path()).unwrap(), None);
+
+    ShellBackend::git_command(&["tag", "v2.5.0"], temp.path()).expect("failed to tag");
+    assert_eq!(
+        GixBackend::describe(temp.path()).unwrap().as_deref(),
+        Some("v2.5.0")
+    );
+    assert_eq!(
+        ShellBackend::describe(temp.path()).unwrap().as_deref(),
+        Some("v2.5.0")
+    );
+
+    for rev in ["v2.5.0", "HEAD", sha.as_str()] {
+        assert!(GixBackend::has_revision(temp.path(), rev).unwrap(), "{rev}");
+        assert!(
+            ShellBackend::has_revision(temp.path(), rev).unwrap(),
+            "{rev}"
+        );
+    }
+    for rev in ["v9.9.9", "--all"] {
+        assert!(
+            !GixBackend::has_revision(temp.path(), rev).unwrap(),
+            "{rev}"
+        );
+        assert!(
+            !ShellBackend::has_revision(temp.path(), rev).unwrap(),
+            "{rev}"
+        );
+    }
+
+    commit("second");
+    let gix = GixBackend::describe(temp.path()).unwrap().unwrap();
+    assert!(gix.starts_with("v2.5.0-1-g"), "{gix}");
+    assert_eq!(ShellBackend::describe(temp.path()).unwrap(), Some(gix));
+}
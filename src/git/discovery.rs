@@ -16,6 +16,17 @@
 //! ```
 //!
 //! Returns sorted list of repo paths for deterministic ordering.
+//!
+//! [`super_layout`] walks the same two levels read-only for `list
+//! --dump-super-layout`:
+//!
+//! ```text
+//! build
+//!   modorganizer_super/  [clone: master]
+//!     uibase/            [submodule: master]
+//!     game_gamebryo/     [directory]
+//!   usvfs/               [clone: (detached)]
+//! ```
 
 use crate::config::Config;
 use crate::error::Result;
@@ -23,7 +34,7 @@ use crate::utility::fs::walk::find_files;
 use anyhow::Context;
 use std::path::{Path, PathBuf};
 
-use super::query::is_git_repo;
+use super::query::{current_branch, is_git_repo};
 
 /// Discover all git repositories in the build directory.
 ///
@@ -80,6 +91,153 @@ pub fn get_repos(config: &Config) -> Result<Vec<PathBuf>> {
     Ok(repos)
 }
 
+/// How a directory in the build tree is tracked by git.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoKind {
+    /// Has its own `.git` directory.
+    Clone,
+    /// Has a `.git` file pointing into a parent repo.
+    Submodule,
+    /// Has no `.git` of its own.
+    Directory,
+}
+
+impl RepoKind {
+    /// Classifies `path` by its `.git` entry.
+    #[must_use]
+    pub fn of(path: &Path) -> Self {
+        let dot_git = path.join(".git");
+        if dot_git.is_dir() {
+            Self::Clone
+        } else if dot_git.is_file() {
+            Self::Submodule
+        } else {
+            Self::Directory
+        }
+    }
+}
+
+/// One directory in the super repo layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutEntry {
+    /// Path of the directory.
+    path: PathBuf,
+    /// Nesting level below `paths.build`, starting at 1.
+    depth: usize,
+    /// How the directory is tracked by git.
+    kind: RepoKind,
+    /// Checked-out branch, `None` if detached or not a repo.
+    branch: Option<String>,
+}
+
+impl LayoutEntry {
+    /// Returns the path of the directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the nesting level below `paths.build`, starting at 1.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns how the directory is tracked by git.
+    #[must_use]
+    pub const fn kind(&self) -> RepoKind {
+        self.kind
+    }
+
+    /// Returns the checked-out branch, `None` if detached or not a repo.
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    /// Formats the entry as an indented tree line.
+    #[must_use]
+    pub fn to_line(&self) -> String {
+        let name = self
+            .path
+            .file_name()
+            .map_or_else(String::new, |n| n.to_string_lossy().into_owned());
+        let indent = "  ".repeat(self.depth);
+        let branch = self.branch.as_deref().unwrap_or("(detached)");
+        match self.kind {
+            RepoKind::Clone => format!("{indent}{name}/  [clone: {branch}]"),
+            RepoKind::Submodule => format!("{indent}{name}/  [submodule: {branch}]"),
+            RepoKind::Directory => format!("{indent}{name}/  [directory]"),
+        }
+    }
+}
+
+/// Describes the directories of `paths.build` and `modorganizer_super`.
+///
+/// Lists every non-hidden directory in `paths.build`, then the ones in
+/// `modorganizer_super` under it, sorted by name. Nothing is modified.
+///
+/// # Errors
+///
+/// Returns an error if `paths.build` is not configured or cannot be read.
+pub fn super_layout(config: &Config) -> Result<Vec<LayoutEntry>> {
+    let build_path = config
+        .paths
+        .build
+        .as_ref()
+        .context("paths.build not configured")?;
+
+    let mut entries = Vec::new();
+    for path in child_dirs(build_path)? {
+        let is_super = path.file_name().is_some_and(|n| n == "modorganizer_super");
+        entries.push(layout_entry(path.clone(), 1));
+        if is_super {
+            for child in child_dirs(&path)? {
+                entries.push(layout_entry(child, 2));
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn layout_entry(path: PathBuf, depth: usize) -> LayoutEntry {
+    let kind = RepoKind::of(&path);
+    let branch = match kind {
+        RepoKind::Directory => None,
+        RepoKind::Clone | RepoKind::Submodule => current_branch(&path).ok().flatten(),
+    };
+    LayoutEntry {
+        path,
+        depth,
+        kind,
+        branch,
+    }
+}
+
+/// Sorted non-hidden subdirectories of `dir`; empty if `dir` is missing.
+fn child_dirs(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut dirs = Vec::new();
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+    {
+        let path = entry
+            .with_context(|| format!("failed to read entry in {}", dir.display()))?
+            .path();
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if path.is_dir() && !hidden {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
 /// Recursively find all .ts files in a directory.
 ///
 /// Uses parallel directory traversal via `ignore::WalkParallel`.
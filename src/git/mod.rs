@@ -25,6 +25,7 @@
 //!    .tracked    .add_remote
 //!    .uncommit   .putty_keys
 //!    .stashed
+//!    .shallow
 //! ```
 //!
 //! **`GixBackend`** — pure Rust, no subprocess, read-only.
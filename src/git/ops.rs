@@ -9,6 +9,10 @@
 //! set_remotes_for_all  configure user/remotes per repo
 //! set_ignore_ts        mark .ts files assume-unchanged
 //! list_branches        report current branch per repo
+//! list_shallow_repos   find shallow clones
+//! repo_statuses        branch/dirty/stash per repo (read-only)
+//! exec_in_repos        run any git command per repo
+//! log_since            one-line commits in <ref>..HEAD
 //! fetch_refspec        fetch specific refspec
 //! remote_branch_exists check remote branch
 //! ```
@@ -18,7 +22,7 @@
 use crate::config::Config;
 use crate::error::Result;
 use anyhow::Context;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, trace};
 
 use super::cmd::{
@@ -26,7 +30,7 @@ use super::cmd::{
     unset_assume_unchanged,
 };
 use super::discovery::get_repos;
-use super::query::current_branch;
+use super::query::{current_branch, has_stashed_changes, has_uncommitted_changes, is_shallow};
 use super::{cmd::git_command, discovery::find_ts_files};
 
 /// Set git remotes for all repositories.
@@ -303,6 +307,228 @@ pub fn list_branches(config: &Config) -> Result<Vec<(PathBuf, String)>> {
     Ok(branches)
 }
 
+/// List all repositories that are shallow clones.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - paths.build is not configured
+/// - Repository discovery fails
+/// - Checking a repository for shallowness fails
+pub fn list_shallow_repos(config: &Config) -> Result<Vec<PathBuf>> {
+    let mut shallow = Vec::new();
+    for repo in get_repos(config)? {
+        if is_shallow(&repo)
+            .with_context(|| format!("failed to check {} for shallowness", repo.display()))?
+        {
+            shallow.push(repo);
+        }
+    }
+    Ok(shallow)
+}
+
+/// Working tree state of one repository, as shown by `mob git status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoStatus {
+    /// Path of the repository.
+    path: PathBuf,
+    /// Checked-out branch, `None` if HEAD is detached.
+    branch: Option<String>,
+    /// Whether there are staged, unstaged or untracked changes.
+    uncommitted: bool,
+    /// Whether there are stashed changes.
+    stashed: bool,
+}
+
+impl RepoStatus {
+    /// Creates the status of a clean repository at `path` with a detached HEAD.
+    #[must_use]
+    pub const fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            branch: None,
+            uncommitted: false,
+            stashed: false,
+        }
+    }
+
+    /// Sets the checked-out branch.
+    #[must_use]
+    pub fn with_branch(mut self, branch: Option<String>) -> Self {
+        self.branch = branch;
+        self
+    }
+
+    /// Sets whether there are uncommitted changes.
+    #[must_use]
+    pub const fn with_uncommitted(mut self, uncommitted: bool) -> Self {
+        self.uncommitted = uncommitted;
+        self
+    }
+
+    /// Sets whether there are stashed changes.
+    #[must_use]
+    pub const fn with_stashed(mut self, stashed: bool) -> Self {
+        self.stashed = stashed;
+        self
+    }
+
+    /// Returns the path of the repository.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the checked-out branch, `None` if HEAD is detached.
+    #[must_use]
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    /// Returns whether there are staged, unstaged or untracked changes.
+    #[must_use]
+    pub const fn uncommitted(&self) -> bool {
+        self.uncommitted
+    }
+
+    /// Returns whether there are stashed changes.
+    #[must_use]
+    pub const fn stashed(&self) -> bool {
+        self.stashed
+    }
+}
+
+/// Query the branch and pending changes of every repository.
+///
+/// Uses the read-only gix backend; nothing is modified.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - paths.build is not configured
+/// - Repository discovery fails
+/// - Querying any repository fails
+pub fn repo_statuses(config: &Config) -> Result<Vec<RepoStatus>> {
+    get_repos(config)?
+        .into_iter()
+        .map(|repo| {
+            let context = || format!("failed to query status of {}", repo.display());
+            let branch = current_branch(&repo).with_context(context)?;
+            let uncommitted = has_uncommitted_changes(&repo).with_context(context)?;
+            let stashed = has_stashed_changes(&repo).with_context(context)?;
+            Ok(RepoStatus::new(repo)
+                .with_branch(branch)
+                .with_uncommitted(uncommitted)
+                .with_stashed(stashed))
+        })
+        .collect()
+}
+
+/// Format `statuses` as a table with one row per repository.
+#[must_use]
+pub fn format_repo_statuses(statuses: &[RepoStatus]) -> String {
+    use std::fmt::Write as _;
+
+    let rows: Vec<[&str; 4]> = statuses
+        .iter()
+        .map(|status| {
+            [
+                status
+                    .path()
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("unknown"),
+                status.branch().unwrap_or("DETACHED"),
+                if status.uncommitted() { "yes" } else { "no" },
+                if status.stashed() { "yes" } else { "no" },
+            ]
+        })
+        .collect();
+
+    let header = ["repo", "branch", "uncommitted", "stashed"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line = format!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    out
+}
+
+/// What [`exec_in_repos`] does after a repo's command fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnError {
+    /// Skip the remaining repos.
+    Stop,
+    /// Run the command in the remaining repos too.
+    Continue,
+}
+
+/// Run `git <args>` in every repository.
+///
+/// `report` receives each repo with its trimmed stdout, or the error, as
+/// soon as the command finishes. Returns the repos where it failed.
+///
+/// # Errors
+///
+/// Returns an error if paths.build is not configured or repository
+/// discovery fails. Failing commands are returned, not raised.
+pub fn exec_in_repos(
+    config: &Config,
+    args: &[String],
+    on_error: OnError,
+    mut report: impl FnMut(&Path, &Result<String>),
+) -> Result<Vec<PathBuf>> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mut failed = Vec::new();
+    for repo in get_repos(config)? {
+        let result = git_command(&args, &repo).map_err(anyhow::Error::from);
+        report(&repo, &result);
+        if result.is_err() {
+            failed.push(repo);
+            if on_error == OnError::Stop {
+                break;
+            }
+        }
+    }
+    Ok(failed)
+}
+
+/// List the commits in `<since>..HEAD` as `<short sha> <subject>` lines,
+/// newest first.
+///
+/// # Errors
+///
+/// Returns an error if `since` is not a valid revision in `repo` or git fails.
+pub fn log_since(repo: &Path, since: &str) -> Result<Vec<String>> {
+    let range = format!("{since}..HEAD");
+    let args = [
+        "log",
+        "--oneline",
+        "--no-decorate",
+        "--end-of-options",
+        &range,
+    ];
+    let output = git_command(&args, repo)
+        .with_context(|| format!("failed to list commits since {since} in {}", repo.display()))?;
+    Ok(output.lines().map(str::to_string).collect())
+}
+
 /// Fetch a specific refspec from a remote URL.
 ///
 /// # Errors
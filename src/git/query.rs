@@ -56,3 +56,39 @@ pub fn has_uncommitted_changes(path: &Path) -> MobResult<bool> {
 pub fn has_stashed_changes(path: &Path) -> MobResult<bool> {
     GixBackend::has_stashed_changes(path)
 }
+
+/// Check if the repository is a shallow clone.
+///
+/// # Errors
+///
+/// Returns a `GitError` if repository discovery fails.
+pub fn is_shallow(path: &Path) -> MobResult<bool> {
+    GixBackend::is_shallow(path)
+}
+
+/// Get the full SHA of the commit HEAD points to.
+///
+/// # Errors
+///
+/// Returns a `GitError` if repository discovery fails or HEAD has no commit.
+pub fn current_commit(path: &Path) -> MobResult<String> {
+    GixBackend::current_commit(path)
+}
+
+/// Describe HEAD by its nearest tag, like `git describe --tags`.
+///
+/// # Errors
+///
+/// Returns a `GitError` if repository discovery or head resolution fails.
+pub fn describe(path: &Path) -> MobResult<Option<String>> {
+    GixBackend::describe(path)
+}
+
+/// Check whether `rev` (a tag, branch or commit) names a commit.
+///
+/// # Errors
+///
+/// Returns a `GitError` if repository discovery fails.
+pub fn has_revision(path: &Path, rev: &str) -> MobResult<bool> {
+    GixBackend::has_revision(path, rev)
+}
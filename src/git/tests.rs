@@ -5,12 +5,13 @@
 
 use crate::config::Config;
 use crate::config::paths::PathsConfig;
-use crate::git::discovery::get_repos;
+use crate::git::discovery::{RepoKind, get_repos, super_layout};
 use crate::git::ops::{
-    add_remote_to_repos, fetch_refspec, list_branches, remote_branch_exists, set_ignore_ts,
+    OnError, RepoStatus, add_remote_to_repos, exec_in_repos, fetch_refspec, format_repo_statuses,
+    list_branches, log_since, remote_branch_exists, repo_statuses, set_ignore_ts,
     set_remotes_for_all,
 };
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tempfile::TempDir;
 
@@ -24,6 +25,17 @@ fn init_test_repo(path: &Path) -> Result<(), Box<gix::init::Error>> {
     Ok(())
 }
 
+/// Run `git <args>` in `repo`, panicking if it fails.
+fn run_git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .expect("failed to run git")
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
 /// Initialize a git repository with an initial commit (for tests needing branches)
 /// Uses shell git for simplicity and to avoid coupling tests to gix internals.
 /// Returns the name of the default branch (master or main depending on git config).
@@ -174,6 +186,70 @@ fn test_get_repos_skips_non_directories() {
     assert_eq!(repos.len(), 0, "expected empty repos when only files exist");
 }
 
+#[test]
+fn test_super_layout_classifies_directories() {
+    let temp = temp_dir();
+    let build = temp.path();
+
+    let usvfs = build.join("usvfs");
+    let super_path = build.join("modorganizer_super");
+    let uibase = super_path.join("uibase");
+    let plain = super_path.join("game_plain");
+    std::fs::create_dir_all(&usvfs).expect("failed to create usvfs");
+    std::fs::create_dir_all(&uibase).expect("failed to create uibase");
+    std::fs::create_dir_all(&plain).expect("failed to create game_plain");
+    std::fs::create_dir_all(build.join(".hidden")).expect("failed to create .hidden");
+
+    let branch = init_test_repo_with_commit(&usvfs).expect("failed to init usvfs");
+    init_test_repo(&super_path).expect("failed to init modorganizer_super");
+    std::fs::write(uibase.join(".git"), "gitdir: ../.git/modules/uibase\n")
+        .expect("failed to write .git file");
+
+    let config = Config {
+        paths: PathsConfig {
+            build: Some(build.to_path_buf()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let layout = super_layout(&config).expect("super_layout should succeed");
+
+    let summary: Vec<_> = layout
+        .iter()
+        .map(|e| {
+            (
+                e.path().strip_prefix(build).unwrap().to_path_buf(),
+                e.depth(),
+                e.kind(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (
+                Path::new("modorganizer_super").to_path_buf(),
+                1,
+                RepoKind::Clone
+            ),
+            (
+                Path::new("modorganizer_super").join("game_plain"),
+                2,
+                RepoKind::Directory
+            ),
+            (
+                Path::new("modorganizer_super").join("uibase"),
+                2,
+                RepoKind::Submodule
+            ),
+            (Path::new("usvfs").to_path_buf(), 1, RepoKind::Clone),
+        ]
+    );
+    assert_eq!(layout[1].to_line(), "    game_plain/  [directory]");
+    assert_eq!(layout[3].to_line(), format!("  usvfs/  [clone: {branch}]"));
+}
+
 #[test]
 fn test_list_branches_returns_all_repos() {
     let temp = temp_dir();
@@ -219,6 +295,130 @@ fn test_list_branches_returns_all_repos() {
     insta::assert_debug_snapshot!(branch_info);
 }
 
+#[test]
+fn test_exec_in_repos_reports_each_repo() {
+    let temp = temp_dir();
+    let build = temp.path();
+    let super_path = build.join("modorganizer_super");
+    for name in ["repo1", "repo2"] {
+        let repo = super_path.join(name);
+        std::fs::create_dir_all(&repo).expect("failed to create repo");
+        let _ = init_test_repo_with_commit(&repo).expect("failed to init repo with commit");
+    }
+    let config = Config {
+        paths: PathsConfig {
+            build: Some(build.to_path_buf()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut outputs = Vec::new();
+    let args = ["rev-parse".to_string(), "--is-inside-work-tree".to_string()];
+    let failed = exec_in_repos(&config, &args, OnError::Stop, |_, result| {
+        outputs.push(result.as_ref().ok().cloned());
+    })
+    .expect("exec_in_repos should succeed");
+    assert!(failed.is_empty());
+    assert_eq!(outputs, vec![Some("true".to_string()); 2]);
+
+    // An unknown command fails in every repo; Stop reports only the first
+    let args = ["no-such-command".to_string()];
+    let mut reported = 0;
+    let failed = exec_in_repos(&config, &args, OnError::Stop, |_, _| reported += 1)
+        .expect("exec_in_repos should succeed");
+    assert_eq!((failed.len(), reported), (1, 1));
+    let failed = exec_in_repos(&config, &args, OnError::Continue, |_, _| {})
+        .expect("exec_in_repos should succeed");
+    assert_eq!(failed.len(), 2);
+}
+
+#[test]
+fn test_repo_statuses_reports_branch_and_changes() {
+    let temp = temp_dir();
+    let build = temp.path();
+    let super_path = build.join("modorganizer_super");
+    let clean = super_path.join("clean");
+    let busy = super_path.join("busy");
+    for repo in [&clean, &busy] {
+        std::fs::create_dir_all(repo).expect("failed to create repo");
+    }
+    let branch = init_test_repo_with_commit(&clean).expect("failed to init clean repo");
+    let _ = init_test_repo_with_commit(&busy).expect("failed to init busy repo");
+
+    // Stash an untracked file, leave another one, and detach HEAD
+    let git = |args: &[&str]| run_git(&busy, args);
+    std::fs::write(busy.join("stashed.txt"), "a").expect("failed to write file");
+    git(&["stash", "push", "--include-untracked", "--quiet"]);
+    std::fs::write(busy.join("untracked.txt"), "b").expect("failed to write file");
+    git(&["checkout", "--detach", "--quiet"]);
+
+    let config = Config {
+        paths: PathsConfig {
+            build: Some(build.to_path_buf()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut statuses = repo_statuses(&config).expect("repo_statuses should succeed");
+    statuses.sort_by(|a, b| a.path().cmp(b.path()));
+
+    assert_eq!(
+        statuses,
+        vec![
+            RepoStatus::new(busy)
+                .with_uncommitted(true)
+                .with_stashed(true),
+            RepoStatus::new(clean).with_branch(Some(branch)),
+        ]
+    );
+}
+
+#[test]
+fn test_log_since_lists_commits_after_ref() {
+    let temp = temp_dir();
+    let repo = temp.path();
+    let _ = init_test_repo_with_commit(repo).expect("failed to init repo");
+
+    let git = |args: &[&str]| run_git(repo, args);
+    git(&["tag", "v1.0.0"]);
+    assert!(
+        log_since(repo, "v1.0.0")
+            .expect("log_since should succeed")
+            .is_empty()
+    );
+
+    git(&["commit", "--allow-empty", "-m", "First change", "--quiet"]);
+    git(&["commit", "--allow-empty", "-m", "Second change", "--quiet"]);
+    let commits = log_since(repo, "v1.0.0").expect("log_since should succeed");
+    assert_eq!(commits.len(), 2);
+    assert!(commits[0].ends_with(" Second change"));
+    assert!(commits[1].ends_with(" First change"));
+
+    assert!(log_since(repo, "does-not-exist").is_err());
+    assert!(log_since(repo, "--all").is_err());
+}
+
+#[test]
+fn test_format_repo_statuses() {
+    let statuses = [
+        RepoStatus::new(PathBuf::from("build/modorganizer"))
+            .with_branch(Some("master".to_string()))
+            .with_uncommitted(true),
+        RepoStatus::new(PathBuf::from(
+            "build/modorganizer_super/modorganizer-uibase",
+        ))
+        .with_stashed(true),
+    ];
+
+    assert_eq!(
+        format_repo_statuses(&statuses),
+        "repo                 branch    uncommitted  stashed\n\
+         modorganizer         master    yes          no\n\
+         modorganizer-uibase  DETACHED  no           yes\n"
+    );
+}
+
 #[test]
 fn test_set_ignore_ts_counts_files_correctly() {
     let temp = temp_dir();
@@ -0,0 +1,200 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! GitHub Actions workflow annotations.
+//!
+//! ```text
+//! warn!/error! event from mob_rs
+//!   --> title: `task` field of the event, else of the closest span with one
+//!   --> ::warning title=<task>::<message> key=value...   (stdout)
+//!       ::error title=<task>::<message> key=value...
+//! ```
+//!
+//! Enabled with `--github-annotations`, or automatically when
+//! `GITHUB_ACTIONS=true` for commands that do not print structured output to
+//! stdout. The regular console and file output is unchanged.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Title used when neither the event nor its spans name a task.
+const DEFAULT_TITLE: &str = "mob";
+
+/// CI annotations printed in addition to the regular output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Annotations {
+    /// No annotations.
+    #[default]
+    Off,
+    /// GitHub Actions `::warning`/`::error` workflow commands.
+    GitHub,
+}
+
+/// Severity of an annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationLevel {
+    /// `::warning`
+    Warning,
+    /// `::error`
+    Error,
+}
+
+impl AnnotationLevel {
+    const fn command(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Returns whether the process runs in a GitHub Actions workflow.
+#[must_use]
+pub fn github_actions() -> bool {
+    std::env::var("GITHUB_ACTIONS").is_ok_and(|value| value == "true")
+}
+
+/// Formats a `::warning` or `::error` workflow command.
+///
+/// `title` defaults to `mob`; line breaks and the characters GitHub treats as
+/// separators are percent-encoded.
+#[must_use]
+pub fn format_annotation(level: AnnotationLevel, title: Option<&str>, message: &str) -> String {
+    let title = escape_property(title.unwrap_or(DEFAULT_TITLE));
+    format!(
+        "::{} title={title}::{}",
+        level.command(),
+        escape_data(message)
+    )
+}
+
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Records whether an [`AnnotationLayer`] printed an `::error`.
+///
+/// Lets the final error of a command be annotated only when no task failure
+/// was annotated already.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorAnnotations(Arc<AtomicBool>);
+
+impl ErrorAnnotations {
+    /// Returns whether an `::error` was printed.
+    #[must_use]
+    pub fn emitted(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn record(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Layer printing warning and error events as workflow annotations.
+pub struct AnnotationLayer<W> {
+    writer: W,
+    errors: ErrorAnnotations,
+}
+
+impl<W> AnnotationLayer<W> {
+    /// Creates a layer writing annotations to `writer`, e.g. `std::io::stdout`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            errors: ErrorAnnotations::default(),
+        }
+    }
+
+    /// Get the record of the `::error` annotations printed by this layer.
+    #[must_use]
+    pub fn error_annotations(&self) -> ErrorAnnotations {
+        self.errors.clone()
+    }
+}
+
+/// `task` field of a span, stored in its extensions.
+struct SpanTask(String);
+
+impl<S, W> Layer<S> for AnnotationLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = EventFields::default();
+        attrs.record(&mut fields);
+        if let (Some(task), Some(span)) = (fields.task, ctx.span(id)) {
+            span.extensions_mut().insert(SpanTask(task));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let level = match *event.metadata().level() {
+            Level::ERROR => AnnotationLevel::Error,
+            Level::WARN => AnnotationLevel::Warning,
+            _ => return,
+        };
+
+        let mut fields = EventFields::default();
+        event.record(&mut fields);
+        let task = fields.task.take().or_else(|| {
+            ctx.event_scope(event)?
+                .find_map(|span| span.extensions().get::<SpanTask>().map(|t| t.0.clone()))
+        });
+
+        let line = format_annotation(level, task.as_deref(), &fields.message());
+        let _ = writeln!(self.writer.make_writer(), "{line}");
+        if level == AnnotationLevel::Error {
+            self.errors.record();
+        }
+    }
+}
+
+/// Message, `task` and remaining fields of an event or span.
+#[derive(Default)]
+struct EventFields {
+    message: String,
+    task: Option<String>,
+    others: String,
+}
+
+impl EventFields {
+    fn message(&self) -> String {
+        format!("{}{}", self.message, self.others)
+    }
+}
+
+impl Visit for EventFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            "task" => self.task = Some(format!("{value:?}")),
+            name => {
+                let _ = write!(self.others, " {name}={value:?}");
+            }
+        }
+    }
+}
@@ -10,12 +10,14 @@
 //!        |
 //!        v
 //!    registry
-//!    |       |
-//!    v       v
-//! Console   File (optional)
-//! EnvFilter EnvFilter
-//! ANSI      non_blocking
+//!    |       |              |
+//!    v       v              v
+//! Console   File (optional) GitHub annotations (optional)
+//! EnvFilter EnvFilter       mob_rs warnings and errors
+//! ANSI      non_blocking    ::warning / ::error lines on stdout
 //! timestamps FmtSpan::CLOSE
+//!           text, or JSON lines (with_json)
+//!           single file, or rolled hourly/daily/by size (with_rotation)
 //!        |
 //!        v
 //!    LogGuard (flush on drop)
@@ -24,21 +26,27 @@
 //!            4=DEBUG  5=TRACE  6=DUMP(+libs)
 //! ```
 
+pub mod annotations;
+
 use anyhow::Context;
 use bon::Builder;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::path::Path;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use tracing::Level;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
-    EnvFilter, Layer,
-    fmt::{self, format::FmtSpan},
+    EnvFilter, Layer, Registry,
+    fmt::{self, MakeWriter, format::FmtSpan},
     layer::SubscriberExt,
     util::SubscriberInitExt,
 };
 
 use crate::error::{ConfigError, Result};
 
+use crate::logging::annotations::{AnnotationLayer, Annotations, ErrorAnnotations};
+
 /// Log level (0-6) for configuration.
 ///
 /// Original mob levels:
@@ -176,6 +184,51 @@ impl<'de> Deserialize<'de> for LogLevel {
     }
 }
 
+/// Rotation policy for the log file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Starts a new `<file>.YYYY-MM-DD-HH` every hour.
+    Hourly,
+    /// Starts a new `<file>.YYYY-MM-DD` every day.
+    Daily,
+    /// Moves the file to `<file>.1` once it would grow past this many bytes.
+    Size(u64),
+}
+
+impl std::str::FromStr for Rotation {
+    type Err = ConfigError;
+
+    /// Parses `hourly`, `daily`, or a size such as `500K`, `10M` or `1G`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || ConfigError::InvalidValue {
+            section: "global".to_string(),
+            key: "log_rotation".to_string(),
+            message: format!("expected 'hourly', 'daily', or a size like '10M', got '{s}'"),
+        };
+
+        let value = s.trim().to_lowercase();
+        match value.as_str() {
+            "hourly" => return Ok(Self::Hourly),
+            "daily" => return Ok(Self::Daily),
+            _ => {}
+        }
+
+        let (digits, multiplier) = match value.char_indices().last() {
+            Some((index, 'k')) => (&value[..index], 1 << 10),
+            Some((index, 'm')) => (&value[..index], 1 << 20),
+            Some((index, 'g')) => (&value[..index], 1 << 30),
+            _ => (value.as_str(), 1),
+        };
+        digits
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(multiplier))
+            .filter(|&bytes| bytes > 0)
+            .map(Self::Size)
+            .ok_or_else(invalid)
+    }
+}
+
 /// Configuration for the logging system.
 #[derive(Debug, Clone, Builder)]
 pub struct LogConfig {
@@ -189,6 +242,12 @@ pub struct LogConfig {
     show_timestamps: bool,
     #[builder(setters(name = with_show_target), default = false)]
     show_target: bool,
+    #[builder(setters(name = with_json), default = false)]
+    json: bool,
+    #[builder(setters(name = with_rotation))]
+    rotation: Option<Rotation>,
+    #[builder(setters(name = with_annotations), default)]
+    annotations: Annotations,
 }
 
 impl Default for LogConfig {
@@ -227,12 +286,40 @@ impl LogConfig {
     pub const fn show_target(&self) -> bool {
         self.show_target
     }
+
+    /// Check if the log file is written as JSON lines instead of text.
+    #[must_use]
+    pub const fn json(&self) -> bool {
+        self.json
+    }
+
+    /// Get the log file rotation policy; `None` writes a single file.
+    #[must_use]
+    pub const fn rotation(&self) -> Option<Rotation> {
+        self.rotation
+    }
+
+    /// Get the CI annotation format warnings and errors are also printed in.
+    #[must_use]
+    pub const fn annotations(&self) -> Annotations {
+        self.annotations
+    }
 }
 
 /// RAII guard that keeps the logging system alive.
 /// When dropped, flushes all pending log writes.
 pub struct LogGuard {
     _file_guard: Option<WorkerGuard>,
+    error_annotations: ErrorAnnotations,
+}
+
+impl LogGuard {
+    /// Returns whether a logged error was already printed as an `::error`
+    /// annotation.
+    #[must_use]
+    pub fn annotated_error(&self) -> bool {
+        self.error_annotations.emitted()
+    }
 }
 
 /// Initialize the logging system with the given configuration.
@@ -284,36 +371,143 @@ pub fn init_logging(config: &LogConfig) -> Result<LogGuard> {
                 .with_context(|| format!("failed to create log directory {}", parent.display()))?;
         }
 
-        let file = std::fs::File::create(log_path)
-            .with_context(|| format!("failed to create log file {}", log_path.display()))?;
-        let (non_blocking, guard) = tracing_appender::non_blocking(file);
-
-        let file_filter = EnvFilter::new(config.file_level().to_filter_string());
+        let (non_blocking, guard) =
+            tracing_appender::non_blocking(log_writer(log_path, config.rotation())?);
 
-        let layer = fmt::layer()
-            .with_writer(non_blocking)
-            .with_target(true)
-            .with_level(true)
-            .with_ansi(false)
-            .with_span_events(FmtSpan::CLOSE)
-            .with_filter(file_filter);
+        let layer = file_layer(non_blocking, config);
 
         (Some(layer), Some(guard))
     } else {
         (None, None)
     };
 
+    let annotation_layer = (config.annotations() == Annotations::GitHub)
+        .then(|| AnnotationLayer::new(std::io::stdout));
+    let error_annotations = annotation_layer
+        .as_ref()
+        .map(AnnotationLayer::error_annotations)
+        .unwrap_or_default();
+    let annotation_layer =
+        annotation_layer.map(|layer| layer.with_filter(EnvFilter::new("mob_rs=warn")));
+
     // Initialize the subscriber
     tracing_subscriber::registry()
-        .with(console_layer)
         .with(file_layer)
+        .with(console_layer)
+        .with(annotation_layer)
         .init();
 
     Ok(LogGuard {
         _file_guard: file_guard,
+        error_annotations,
     })
 }
 
+/// Builds the log file layer writing to `writer` in the configured format.
+fn file_layer<W>(writer: W, config: &LogConfig) -> Box<dyn Layer<Registry> + Send + Sync>
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let file_filter = EnvFilter::new(config.file_level().to_filter_string());
+
+    let layer = fmt::layer()
+        .with_writer(writer)
+        .with_target(true)
+        .with_level(true)
+        .with_ansi(false)
+        .with_span_events(FmtSpan::CLOSE);
+
+    // One JSON object per event; span fields (task, tool, ...) become keys
+    // of the `span` and `spans` objects
+    if config.json() {
+        layer
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_filter(file_filter)
+            .boxed()
+    } else {
+        layer.with_filter(file_filter).boxed()
+    }
+}
+
+/// Opens the log file writer for `rotation`.
+fn log_writer(log_path: &Path, rotation: Option<Rotation>) -> Result<Box<dyn Write + Send>> {
+    let rolling = |rotation| -> Result<Box<dyn Write + Send>> {
+        let file_name = log_path
+            .file_name()
+            .with_context(|| format!("log file has no file name: {}", log_path.display()))?;
+        let directory = log_path.parent().unwrap_or_else(|| Path::new(""));
+        let appender = tracing_appender::rolling::Builder::new()
+            .rotation(rotation)
+            .filename_prefix(file_name.to_string_lossy())
+            .build(directory)
+            .with_context(|| format!("failed to create log file {}", log_path.display()))?;
+        Ok(Box::new(appender))
+    };
+
+    match rotation {
+        None => {
+            let file = File::create(log_path)
+                .with_context(|| format!("failed to create log file {}", log_path.display()))?;
+            Ok(Box::new(file))
+        }
+        Some(Rotation::Hourly) => rolling(tracing_appender::rolling::Rotation::HOURLY),
+        Some(Rotation::Daily) => rolling(tracing_appender::rolling::Rotation::DAILY),
+        Some(Rotation::Size(max_bytes)) => Ok(Box::new(SizeRotatingFile::create(
+            log_path.to_path_buf(),
+            max_bytes,
+        )?)),
+    }
+}
+
+/// Log file that is moved to `<file>.1` before a write would take it past
+/// `max_bytes`, keeping one previous file.
+struct SizeRotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    file: File,
+    written: u64,
+}
+
+impl SizeRotatingFile {
+    fn create(path: PathBuf, max_bytes: u64) -> Result<Self> {
+        let file = File::create(&path)
+            .with_context(|| format!("failed to create log file {}", path.display()))?;
+        Ok(Self {
+            path,
+            max_bytes,
+            file,
+            written: 0,
+        })
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.file.flush()?;
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        std::fs::rename(&self.path, rotated)?;
+        self.file = File::create(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for SizeRotatingFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
 /// Logging context that tracks the current task and tool.
 ///
 /// This replaces the C++ `context` class for providing context in log messages.
@@ -3,7 +3,12 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{LogContext, LogLevel};
+use super::annotations::{AnnotationLayer, AnnotationLevel, format_annotation};
+use super::{LogConfig, LogContext, LogLevel, Rotation, SizeRotatingFile, file_layer};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::SubscriberExt;
 
 #[test]
 fn test_log_context_clear_tool() {
@@ -48,3 +53,143 @@ fn test_log_context_prefix() {
         ]
     );
 }
+
+#[test]
+fn test_log_config_json() {
+    assert!(
+        !LogConfig::default().json(),
+        "text format should be the default"
+    );
+    assert!(LogConfig::builder().with_json(true).build().json());
+}
+
+#[test]
+fn test_file_layer_json_lines() {
+    let captured = Captured::default();
+    let config = LogConfig::builder()
+        .with_json(true)
+        .with_file_level(LogLevel::DEBUG)
+        .build();
+    let subscriber = tracing_subscriber::registry().with(file_layer(captured.clone(), &config));
+
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!("task", task = "cmake");
+        let _guard = span.enter();
+        tracing::info!(path = "build", "Configured");
+    });
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    let lines: Vec<serde_json::Value> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines.len(),
+        2,
+        "expected the event and the span close: {output}"
+    );
+
+    let event = &lines[0];
+    assert_eq!(event["level"], "INFO");
+    assert_eq!(event["fields"]["message"], "Configured");
+    assert_eq!(event["fields"]["path"], "build");
+    assert_eq!(event["span"]["task"], "cmake");
+    assert_eq!(event["spans"][0]["name"], "task");
+}
+
+#[test]
+fn test_rotation_parse() {
+    assert_eq!("daily".parse::<Rotation>().unwrap(), Rotation::Daily);
+    assert_eq!("Hourly".parse::<Rotation>().unwrap(), Rotation::Hourly);
+    assert_eq!("10M".parse::<Rotation>().unwrap(), Rotation::Size(10 << 20));
+    assert_eq!(
+        "512k".parse::<Rotation>().unwrap(),
+        Rotation::Size(512 << 10)
+    );
+    assert_eq!("4096".parse::<Rotation>().unwrap(), Rotation::Size(4096));
+    assert!("0".parse::<Rotation>().is_err());
+    assert!("weekly".parse::<Rotation>().is_err());
+}
+
+#[test]
+fn test_size_rotating_file() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("mob.log");
+    let mut file = SizeRotatingFile::create(path.clone(), 8).unwrap();
+
+    file.write_all(b"first\n").unwrap();
+    file.write_all(b"second\n").unwrap();
+    file.write_all(b"third\n").unwrap();
+    file.flush().unwrap();
+
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "third\n");
+    assert_eq!(
+        std::fs::read_to_string(temp.path().join("mob.log.1")).unwrap(),
+        "second\n"
+    );
+}
+
+#[test]
+fn test_format_annotation() {
+    assert_eq!(
+        format_annotation(AnnotationLevel::Error, Some("usvfs"), "build failed"),
+        "::error title=usvfs::build failed"
+    );
+    assert_eq!(
+        format_annotation(AnnotationLevel::Warning, None, "50% done\nnext: a, b"),
+        "::warning title=mob::50%25 done%0Anext: a, b"
+    );
+    assert_eq!(
+        format_annotation(AnnotationLevel::Error, Some("a:b,c"), "x"),
+        "::error title=a%3Ab%2Cc::x"
+    );
+}
+
+/// Collects everything written by an [`AnnotationLayer`].
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl MakeWriter<'_> for Captured {
+    type Writer = Self;
+
+    fn make_writer(&self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[test]
+fn test_annotation_layer() {
+    let captured = Captured::default();
+    let layer = AnnotationLayer::new(captured.clone());
+    let errors = layer.error_annotations();
+    let subscriber = tracing_subscriber::registry().with(layer);
+
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!("not annotated");
+        tracing::warn!("Low disk space");
+        assert!(!errors.emitted());
+        tracing::error!(task = "licenses", error = %"disk full", "Task failed");
+        let span = tracing::info_span!("task", task = "installer", phase = "build");
+        let _guard = span.enter();
+        tracing::warn!("Installer directory missing");
+    });
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(
+        output,
+        "::warning title=mob::Low disk space\n\
+         ::error title=licenses::Task failed error=disk full\n\
+         ::warning title=installer::Installer directory missing\n"
+    );
+    assert!(errors.emitted());
+}
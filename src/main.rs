@@ -7,15 +7,23 @@
 //!
 //! ```text
 //! cli::parse() --> Logging --> Command Dispatch
-//!   Build | Release | Git | Pr | Tx | Config | List
+//!   Build | Clean | Release | Git | Pr | Tx | Cache | Config | List | Completions
 //! ```
 
+use std::path::Path;
 use std::process::ExitCode;
 
-use mob_rs::cli::global::GlobalOptions;
+use mob_rs::cli::global::{GlobalOptions, LogFormat};
 use mob_rs::cli::{self, Command};
 use mob_rs::cmd::build::run_build_command;
-use mob_rs::cmd::config::{run_cmake_config_command, run_inis_command, run_options_command};
+use mob_rs::cmd::cache::run_cache_command;
+use mob_rs::cmd::clean::run_clean_command;
+use mob_rs::cmd::completions::run_completions_command;
+use mob_rs::cmd::config::{
+    run_cmake_config_command, run_config_command, run_inis_command, run_options_command,
+};
+use mob_rs::cmd::diagnostics::write_failure_bundle;
+use mob_rs::cmd::doctor::run_doctor_command;
 use mob_rs::cmd::git::run_git_command;
 use mob_rs::cmd::list::run_list_command;
 use mob_rs::cmd::pr::run_pr_command;
@@ -23,10 +31,15 @@ use mob_rs::cmd::release::run_release_command;
 use mob_rs::cmd::tx::run_tx_command;
 use mob_rs::config::Config;
 use mob_rs::config::loader::ConfigLoader;
-use mob_rs::logging::init_logging;
-use mob_rs::logging::{LogConfig, LogLevel};
+use mob_rs::core::interrupt::spawn_ctrl_c_handler;
+use mob_rs::error::exit_code;
+use mob_rs::logging::annotations::{
+    AnnotationLevel, Annotations, format_annotation, github_actions,
+};
+use mob_rs::logging::{LogConfig, LogGuard, LogLevel, init_logging};
 
 use mimalloc::MiMalloc;
+use tokio_util::sync::CancellationToken;
 
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
@@ -35,8 +48,8 @@ static GLOBAL: MiMalloc = MiMalloc;
 async fn main() -> ExitCode {
     let cli = cli::parse();
 
-    let log_config = build_log_config(&cli.global);
-    let _log_guard = match init_logging(&log_config) {
+    let log_config = build_log_config(&cli);
+    let log_guard = match init_logging(&log_config) {
         Ok(guard) => guard,
         Err(e) => {
             eprintln!("Failed to initialize logging: {e}");
@@ -44,35 +57,62 @@ async fn main() -> ExitCode {
         }
     };
 
-    dispatch_command(&cli).await
+    dispatch_command(&cli, &log_guard).await
 }
 
-fn build_log_config(global: &GlobalOptions) -> LogConfig {
-    let console_level = global
-        .log_level
-        .and_then(LogLevel::from_u8)
-        .unwrap_or(LogLevel::INFO);
+fn build_log_config(cli: &cli::Cli) -> LogConfig {
+    let global = &cli.global;
+    let level = global.log_level.and_then(LogLevel::from_u8);
+    let console_level = level.unwrap_or(if global.console.quiet {
+        LogLevel::WARN
+    } else {
+        LogLevel::INFO
+    });
 
+    // --quiet only affects the console
     let file_level = global
         .file_log_level
         .and_then(LogLevel::from_u8)
-        .unwrap_or(console_level);
+        .or(level)
+        .unwrap_or(LogLevel::INFO);
 
     LogConfig::builder()
         .with_console_level(console_level)
         .with_file_level(file_level)
         .maybe_with_log_file(global.log_file.as_ref().map(|p| p.display().to_string()))
+        .with_json(global.log_format == LogFormat::Json)
+        .maybe_with_rotation(global.log_rotation)
+        .with_annotations(if github_annotations(cli) {
+            Annotations::GitHub
+        } else {
+            Annotations::Off
+        })
         .build()
 }
 
-async fn dispatch_command(cli: &cli::Cli) -> ExitCode {
+/// `--github-annotations`, or running on GitHub Actions unless the command's
+/// stdout is parsed by other programs.
+fn github_annotations(cli: &cli::Cli) -> bool {
+    cli.global.console.github_annotations
+        || (github_actions()
+            && !cli
+                .command
+                .as_ref()
+                .is_some_and(Command::writes_structured_stdout))
+}
+
+async fn dispatch_command(cli: &cli::Cli, log_guard: &LogGuard) -> ExitCode {
+    let cancel_token = CancellationToken::new();
+    spawn_ctrl_c_handler(cancel_token.clone());
+    let priority = cli.global.process_priority();
+
     let result = match &cli.command {
         Some(Command::Version) => {
             handle_version_command();
             Ok(())
         }
-        Some(Command::Options) => {
-            load_config(&cli.global).map(|config| run_options_command(&config))
+        Some(Command::Options(args)) => {
+            load_config(&cli.global).and_then(|config| run_options_command(args, &config))
         }
         Some(Command::Inis) => {
             let loader = build_config_loader(&cli.global);
@@ -80,26 +120,69 @@ async fn dispatch_command(cli: &cli::Cli) -> ExitCode {
             Ok(())
         }
         Some(Command::Build(args)) => match load_config(&cli.global) {
-            Ok(config) => run_build_command(args, &config, cli.global.dry).await,
+            Ok(config) => {
+                run_build_command(
+                    args,
+                    &config,
+                    cli.global.dry,
+                    cli.global.jobs,
+                    priority,
+                    &cancel_token,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        },
+        Some(Command::Clean(args)) => match load_config(&cli.global) {
+            Ok(config) => {
+                run_clean_command(args, &config, cli.global.dry, priority, &cancel_token).await
+            }
             Err(e) => Err(e),
         },
         Some(Command::List(args)) => {
             load_config(&cli.global).and_then(|config| run_list_command(args, &config))
         }
         Some(Command::Release(args)) => match load_config(&cli.global) {
-            Ok(config) => run_release_command(args, &config, cli.global.dry).await,
+            Ok(config) => {
+                run_release_command(
+                    args,
+                    &config,
+                    cli.global.dry,
+                    cli.global.jobs,
+                    priority,
+                    &cancel_token,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        },
+        Some(Command::Git(args)) => match load_config(&cli.global) {
+            Ok(config) => {
+                run_git_command(args, &config, cli.global.dry, priority, &cancel_token).await
+            }
             Err(e) => Err(e),
         },
-        Some(Command::Git(args)) => load_config(&cli.global)
-            .and_then(|config| run_git_command(args, &config, cli.global.dry)),
         Some(Command::Pr(args)) => match load_config(&cli.global) {
             Ok(config) => run_pr_command(args, &config).await,
             Err(e) => Err(e),
         },
         Some(Command::Tx(args)) => match load_config(&cli.global) {
-            Ok(config) => run_tx_command(args, &config, cli.global.dry).await,
+            Ok(config) => {
+                run_tx_command(args, &config, cli.global.dry, priority, &cancel_token).await
+            }
+            Err(e) => Err(e),
+        },
+        Some(Command::Cache(args)) => match load_config(&cli.global) {
+            Ok(config) => {
+                run_cache_command(args, &config, cli.global.dry, priority, &cancel_token).await
+            }
             Err(e) => Err(e),
         },
+        Some(Command::Config(args)) => run_config_command(args, || load_config(&cli.global)),
+        Some(Command::Doctor(args)) => {
+            load_config(&cli.global).and_then(|config| run_doctor_command(args, &config))
+        }
+        Some(Command::Completions { shell }) => run_completions_command(*shell),
         Some(Command::CmakeConfig(args)) => {
             load_config(&cli.global).and_then(|config| run_cmake_config_command(args, &config))
         }
@@ -113,11 +196,32 @@ async fn dispatch_command(cli: &cli::Cli) -> ExitCode {
         Ok(()) => ExitCode::SUCCESS,
         Err(e) => {
             eprintln!("Error: {e:#}");
-            ExitCode::FAILURE
+            // Task failures logged on the way were annotated already
+            if github_annotations(cli) && !log_guard.annotated_error() {
+                println!(
+                    "{}",
+                    format_annotation(AnnotationLevel::Error, None, &format!("{e:#}"))
+                );
+            }
+            if let Some(dir) = &cli.global.dump_on_failure {
+                dump_failure_bundle(dir, &cli.global, &e).await;
+            }
+            ExitCode::from(exit_code(&e))
         }
     }
 }
 
+async fn dump_failure_bundle(dir: &Path, global: &GlobalOptions, error: &anyhow::Error) {
+    let config = build_config_loader(global).build().ok().map(|mut config| {
+        config.env.extend(global.env.iter().cloned());
+        config
+    });
+    match write_failure_bundle(dir, config.as_ref(), global.log_file.as_deref(), error).await {
+        Ok(bundle) => eprintln!("Diagnostic bundle written to {}", bundle.display()),
+        Err(e) => eprintln!("Failed to write diagnostic bundle: {e:#}"),
+    }
+}
+
 fn handle_version_command() {
     println!("{}", env!("CARGO_PKG_VERSION"));
 }
@@ -127,13 +231,19 @@ fn build_config_loader(global: &GlobalOptions) -> ConfigLoader {
     for ini_path in &global.inis {
         loader = loader.add_toml_file(ini_path);
     }
-    loader.add_toml_file_optional("mob.toml")
+    loader = loader.add_toml_file_optional("mob.toml");
+    if let Some(profile) = &global.config_profile {
+        loader = loader.with_profile(profile);
+    }
+    loader
 }
 
 fn load_config(global: &GlobalOptions) -> mob_rs::error::Result<Config> {
     let loader = build_config_loader(global);
-    loader.build().map_err(|e| {
+    let mut config = loader.build().map_err(|e| {
         eprintln!("Failed to load config: {e}");
         e
-    })
+    })?;
+    config.env.extend(global.env.iter().cloned());
+    Ok(config)
 }
@@ -7,8 +7,8 @@
 //!
 //! ```text
 //! Downloader::new()
-//!   .url() .file() .header()
-//!   .progress() .silent()
+//!   .url() .file() .header() .client()
+//!   .progress() .silent() .reporter()
 //!        |
 //!        +----------+------------+
 //!        v          v            v
@@ -20,19 +20,48 @@
 //!     Bar     [=====>     ] 50MB/100MB
 //!     Spinner * 50MB @ 5MB/s
 //!     Silent  (none)
+//!   ProgressReporter::on_chunk(downloaded, total) per chunk, on_finish once
+//!     TerminalProgress: bar on stderr if it is a TTY and the size is known
+//!     ProgressGroup::reporter(): TerminalProgress drawn with the bars of
+//!       concurrent downloads instead of over them
 //!
-//! Global client: OnceLock, connection pool, keep-alive
-//! Interruption:  AtomicBool -> cleanup partial -> Interrupted
+//! Shared clients: http_client([net]) --> one Client per config, pooled
+//!   user agent, connect/read timeout (net.timeout_secs, 0 = none)
+//!   proxy: net.proxy (+ NO_PROXY) > HTTPS_PROXY/HTTP_PROXY/NO_PROXY env
+//! Interruption:  AtomicBool -> cleanup partial output (file.part is kept) -> Interrupted
+//!
+//! Chunked: .parallel_chunks(N)
+//!   probe Range bytes=0-0 --> 206 + Content-Range total
+//!   N x Range requests --> offsets in file.part --> rename
+//!   (single stream if ranges unsupported or file too small)
+//!   failed range --> file.part cut to the bytes without a gap, kept
+//!   range of another file or length --> file.part removed
+//!
+//! Resume: .resume(true)
+//!   file.part exists --> Range bytes=<len>- --> 206: append, 200: restart
+//!   done --> rename file.part to file; errors keep file.part for next time
 //! ```
 
+use crate::config::types::NetConfig;
 use crate::error::{MobResult, NetworkError};
 use futures_util::StreamExt;
-use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::Client;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
-use tokio::io::AsyncWriteExt;
+use futures_util::future::join_all;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use reqwest::{Client, NoProxy, Proxy, RequestBuilder, StatusCode};
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::io::{IsTerminal, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+/// Smallest byte range a parallel chunk may cover.
+///
+/// Files smaller than two chunks are always downloaded as a single stream.
+pub const MIN_CHUNK_SIZE: u64 = 1024 * 1024;
 
 /// RAII guard that removes a partial download file on Drop unless explicitly kept.
 ///
@@ -69,19 +98,65 @@ impl Drop for PartialFileGuard {
     }
 }
 
-/// Global HTTP client - initialized once, reused across all downloads.
-/// Falls back to a basic client if custom configuration fails.
-fn global_client() -> &'static Client {
-    static CLIENT: OnceLock<Client> = OnceLock::new();
-    CLIENT.get_or_init(|| {
-        Client::builder()
-            .user_agent(format!(
-                "ModOrganizer's mob-rs/{}",
-                env!("CARGO_PKG_VERSION")
-            ))
-            .build()
-            .unwrap_or_else(|_| Client::new())
-    })
+/// What one range of a chunked download wrote to the `.part` file.
+#[derive(Debug, Default)]
+struct RangeWrite {
+    /// Bytes written from the start of the range.
+    written: u64,
+    /// The server answered with a range of another file or length.
+    mismatch: bool,
+}
+
+/// Builds an HTTP client with mob's user agent and the timeouts and proxy of
+/// `config`.
+///
+/// `net.proxy` is used for all requests except the hosts in `NO_PROXY`.
+/// Without it, the `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` environment applies.
+/// Credentials in the proxy URL (`user:pass@`) are sent as basic auth.
+///
+/// # Errors
+///
+/// Returns [`NetworkError::InvalidUrl`] if `net.proxy` isn't a valid URL, or
+/// [`NetworkError::Reqwest`] if the client can't be built.
+pub fn build_http_client(config: &NetConfig) -> MobResult<Client> {
+    let mut builder = Client::builder().user_agent(format!(
+        "ModOrganizer's mob-rs/{}",
+        env!("CARGO_PKG_VERSION")
+    ));
+    if config.timeout_secs > 0 {
+        let timeout = Duration::from_secs(config.timeout_secs);
+        builder = builder.connect_timeout(timeout).read_timeout(timeout);
+    }
+    if let Some(url) = &config.proxy {
+        let proxy = Proxy::all(url.as_str()).map_err(|e| {
+            let url = config.proxy_for_display().unwrap_or_default();
+            NetworkError::InvalidUrl(format!("net.proxy {url}: {e}"))
+        })?;
+        builder = builder.proxy(proxy.no_proxy(NoProxy::from_env()));
+    }
+    Ok(builder.build().map_err(NetworkError::from)?)
+}
+
+/// Returns the shared HTTP client for `config`, building it on first use.
+///
+/// Every caller with the same config gets a handle to the same connection
+/// pool, so downloads and API requests reuse connections.
+///
+/// # Errors
+///
+/// Returns an error if the client can't be built, see [`build_http_client`].
+pub fn http_client(config: &NetConfig) -> MobResult<Client> {
+    static CLIENTS: OnceLock<Mutex<BTreeMap<NetConfig, Client>>> = OnceLock::new();
+    let mut clients = CLIENTS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    let client = match clients.entry(config.clone()) {
+        Entry::Occupied(entry) => entry.get().clone(),
+        Entry::Vacant(entry) => entry.insert(build_http_client(config)?).clone(),
+    };
+    drop(clients);
+    Ok(client)
 }
 
 /// Pre-validated progress bar style for known file sizes.
@@ -124,6 +199,147 @@ pub enum ProgressDisplay {
     Silent,
 }
 
+/// Receives the progress of a download.
+///
+/// `on_chunk` runs for every received chunk, concurrently for chunked
+/// downloads, so implementations should be cheap.
+pub trait ProgressReporter: Send + Sync {
+    /// Called after each chunk with the bytes downloaded so far and the total
+    /// size, if the server reported one.
+    fn on_chunk(&self, downloaded: u64, total: Option<u64>);
+
+    /// Called once when the download ends; `success` is false after errors,
+    /// interrupts and cancellation.
+    fn on_finish(&self, success: bool) {
+        let _ = success;
+    }
+}
+
+/// Renders a progress bar to stderr if it is a terminal and the total size
+/// is known from `Content-Length`.
+///
+/// The bar is created on the first chunk, so use one reporter per download.
+#[derive(Debug, Default)]
+pub struct TerminalProgress {
+    bar: OnceLock<Option<ProgressBar>>,
+    display: Option<MultiProgress>,
+}
+
+impl TerminalProgress {
+    /// Creates a reporter that hasn't drawn anything yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Progress bars of concurrent downloads, drawn together on stderr.
+///
+/// Cloning shares the group.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressGroup {
+    bars: MultiProgress,
+}
+
+impl ProgressGroup {
+    /// Creates an empty group.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a reporter for one download whose bar joins this group.
+    #[must_use]
+    pub fn reporter(&self) -> TerminalProgress {
+        TerminalProgress {
+            bar: OnceLock::new(),
+            display: Some(self.bars.clone()),
+        }
+    }
+}
+
+impl ProgressReporter for TerminalProgress {
+    fn on_chunk(&self, downloaded: u64, total: Option<u64>) {
+        let bar = self.bar.get_or_init(|| {
+            let total = total.filter(|_| std::io::stderr().is_terminal())?;
+            let bar = ProgressBar::new(total);
+            bar.set_style(bar_style());
+            Some(match &self.display {
+                Some(display) => display.add(bar),
+                None => bar,
+            })
+        });
+        if let Some(bar) = bar {
+            bar.set_position(downloaded);
+        }
+    }
+
+    fn on_finish(&self, success: bool) {
+        if let Some(Some(bar)) = self.bar.get() {
+            if success {
+                bar.finish_with_message("done");
+            } else {
+                bar.abandon_with_message("failed");
+            }
+        }
+    }
+}
+
+/// Progress of one download, fed to the progress display and the reporter.
+///
+/// Dropping it before [`Progress::finish`] reports a failed download, which
+/// also covers futures dropped on cancellation.
+struct Progress<'a> {
+    bar: Option<ProgressBar>,
+    reporter: Option<&'a dyn ProgressReporter>,
+    total: Option<u64>,
+    downloaded: AtomicU64,
+    finished: AtomicBool,
+}
+
+impl Progress<'_> {
+    /// Adds `bytes` received bytes.
+    fn add(&self, bytes: u64) {
+        let downloaded = self.downloaded.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        if let Some(bar) = &self.bar {
+            bar.set_position(downloaded);
+        }
+        if let Some(reporter) = self.reporter {
+            reporter.on_chunk(downloaded, self.total);
+        }
+    }
+
+    /// Marks the download as complete.
+    fn finish(&self) {
+        self.finished.store(true, Ordering::Relaxed);
+        if let Some(bar) = &self.bar {
+            bar.finish_with_message("done");
+        }
+        if let Some(reporter) = self.reporter {
+            reporter.on_finish(true);
+        }
+    }
+
+    /// Marks the download as failed with `message` on the progress bar.
+    fn abandon(&self, message: &'static str) {
+        if self.finished.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        if let Some(bar) = &self.bar {
+            bar.abandon_with_message(message);
+        }
+        if let Some(reporter) = self.reporter {
+            reporter.on_finish(false);
+        }
+    }
+}
+
+impl Drop for Progress<'_> {
+    fn drop(&mut self) {
+        self.abandon("failed");
+    }
+}
+
 /// Async HTTP downloader with builder pattern.
 ///
 /// Supports visual progress bars and interruptible downloads.
@@ -145,6 +361,9 @@ pub struct Downloader {
     headers: Vec<(String, String)>,
     interrupt: Arc<AtomicBool>,
     progress_display: ProgressDisplay,
+    parallel_chunks: usize,
+    resume: bool,
+    reporter: Option<Arc<dyn ProgressReporter>>,
 }
 
 impl Default for Downloader {
@@ -159,12 +378,15 @@ impl Downloader {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            client: global_client().clone(),
+            client: http_client(&NetConfig::default()).unwrap_or_else(|_| Client::new()),
             url: None,
             output_file: None,
             headers: Vec::new(),
             interrupt: Arc::new(AtomicBool::new(false)),
             progress_display: ProgressDisplay::default(),
+            parallel_chunks: 1,
+            resume: false,
+            reporter: None,
         }
     }
 
@@ -182,6 +404,13 @@ impl Downloader {
         self
     }
 
+    /// Send requests through `client` instead of the default shared client.
+    #[must_use]
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
     /// Add a custom header.
     #[must_use]
     pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
@@ -203,6 +432,37 @@ impl Downloader {
         self
     }
 
+    /// Report the progress of `download()` to `reporter`, in addition to the
+    /// progress display.
+    #[must_use]
+    pub fn reporter(mut self, reporter: Arc<dyn ProgressReporter>) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    /// Split `download()` into up to `chunks` parallel `Range` requests.
+    ///
+    /// Only engaged when the server answers ranges with `206 Partial Content`,
+    /// reports the total size, and every chunk covers at least [`MIN_CHUNK_SIZE`].
+    /// Otherwise the file is downloaded as a single stream.
+    #[must_use]
+    pub const fn parallel_chunks(mut self, chunks: usize) -> Self {
+        self.parallel_chunks = chunks;
+        self
+    }
+
+    /// Download through `<file>.part` and resume it in later calls.
+    ///
+    /// An existing `.part` file is continued with a `Range` request. If the
+    /// server answers with the whole file instead, the download starts over.
+    /// The `.part` file is kept on failure and renamed to the output file
+    /// once complete.
+    #[must_use]
+    pub const fn resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+
     /// Get a handle to the interrupt flag.
     /// Set to true to interrupt an in-progress download.
     #[must_use]
@@ -210,6 +470,22 @@ impl Downloader {
         Arc::clone(&self.interrupt)
     }
 
+    /// Starts tracking a download of `total` bytes, `resumed_at` of which
+    /// are already there.
+    fn start_progress(&self, total: Option<u64>, resumed_at: u64) -> Progress<'_> {
+        let bar = self.create_progress_bar(total.unwrap_or(0));
+        if let Some(bar) = &bar {
+            bar.set_position(resumed_at);
+        }
+        Progress {
+            bar,
+            reporter: self.reporter.as_deref(),
+            total,
+            downloaded: AtomicU64::new(resumed_at),
+            finished: AtomicBool::new(false),
+        }
+    }
+
     /// Create a progress bar for the download.
     fn create_progress_bar(&self, total_size: u64) -> Option<ProgressBar> {
         match self.progress_display {
@@ -244,6 +520,7 @@ impl Downloader {
     /// - Parent directories cannot be created.
     /// - The output file cannot be created or written to.
     /// - The download is interrupted.
+    /// - A resumed download got a different range than requested.
     pub async fn download(&self) -> MobResult<()> {
         let url = self
             .url
@@ -257,6 +534,31 @@ impl Downloader {
                 message: "no output file specified".to_string(),
             })?;
 
+        let part = part_path(output);
+        let offset = if self.resume {
+            tokio::fs::metadata(&part).await.map_or(0, |m| m.len())
+        } else {
+            0
+        };
+
+        if offset == 0
+            && self.parallel_chunks > 1
+            && let Some(total_size) = self.probe_range_size(url).await
+        {
+            let chunks = usize::try_from(total_size / MIN_CHUNK_SIZE)
+                .unwrap_or(usize::MAX)
+                .min(self.parallel_chunks);
+            if chunks > 1 {
+                return self
+                    .download_chunked(url, output, &part, total_size, chunks)
+                    .await;
+            }
+        }
+
+        if self.resume {
+            return self.download_resumable(url, output, &part, offset).await;
+        }
+
         let mut request = self.client.get(url);
         for (name, value) in &self.headers {
             request = request.header(name.as_str(), value.as_str());
@@ -272,8 +574,7 @@ impl Downloader {
             .into());
         }
 
-        let total_size = response.content_length().unwrap_or(0);
-        let progress_bar = self.create_progress_bar(total_size);
+        let progress = self.start_progress(response.content_length(), 0);
 
         // Create parent directories if needed
         if let Some(parent) = output.parent() {
@@ -305,9 +606,7 @@ impl Downloader {
         while let Some(chunk) = stream.next().await {
             // Check for interrupt
             if self.interrupt.load(Ordering::Relaxed) {
-                if let Some(pb) = &progress_bar {
-                    pb.abandon_with_message("interrupted");
-                }
+                progress.abandon("interrupted");
                 // Guard will clean up the partial file on drop
                 return Err(NetworkError::Interrupted.into());
             }
@@ -320,9 +619,7 @@ impl Downloader {
                     message: format!("failed to write to {}: {}", output.display(), e),
                 })?;
 
-            if let Some(pb) = &progress_bar {
-                pb.inc(chunk.len() as u64);
-            }
+            progress.add(chunk.len() as u64);
         }
 
         file.flush()
@@ -334,14 +631,308 @@ impl Downloader {
 
         // Download successful - keep the file
         guard.keep();
+        progress.finish();
+
+        Ok(())
+    }
+
+    /// Builds a GET request with the custom headers applied.
+    fn request(&self, url: &str) -> RequestBuilder {
+        let mut request = self.client.get(url);
+        for (name, value) in &self.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        request
+    }
+
+    /// Returns the total size if the server serves byte ranges for `url`.
+    async fn probe_range_size(&self, url: &str) -> Option<u64> {
+        let response = self
+            .request(url)
+            .header(RANGE, "bytes=0-0")
+            .send()
+            .await
+            .ok()?;
+        if response.status() != StatusCode::PARTIAL_CONTENT {
+            return None;
+        }
+
+        // Content-Range: bytes 0-0/<total>
+        let content_range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+        let (_, total) = content_range.rsplit_once('/')?;
+        total.trim().parse().ok()
+    }
+
+    /// Downloads `url` into `part`, continuing after its first `offset` bytes,
+    /// then renames it to `output`.
+    async fn download_resumable(
+        &self,
+        url: &str,
+        output: &Path,
+        part: &Path,
+        mut offset: u64,
+    ) -> MobResult<()> {
+        let failed = |message: String| NetworkError::DownloadFailed {
+            url: url.to_string(),
+            message,
+        };
+
+        let mut request = self.request(url);
+        if offset > 0 {
+            request = request.header(RANGE, format!("bytes={offset}-"));
+        }
+        let mut response = request.send().await.map_err(NetworkError::Reqwest)?;
+
+        // The part file is at least as large as the remote file, so it is
+        // stale; start over
+        if offset > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            offset = 0;
+            response = self
+                .request(url)
+                .send()
+                .await
+                .map_err(NetworkError::Reqwest)?;
+        }
+
+        if !response.status().is_success() {
+            return Err(NetworkError::HttpError {
+                status: response.status().as_u16(),
+                url: url.to_string(),
+            }
+            .into());
+        }
+
+        // 200 means the server ignored the range: download everything again
+        let append = offset > 0 && response.status() == StatusCode::PARTIAL_CONTENT;
+        if append && content_range_start(&response) != Some(offset) {
+            return Err(NetworkError::RangeNotSupported {
+                url: url.to_string(),
+            }
+            .into());
+        }
+
+        if let Some(parent) = part.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                failed(format!(
+                    "failed to create parent directory {}: {e}",
+                    parent.display()
+                ))
+            })?;
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(part)
+            .await
+            .map_err(|e| failed(format!("failed to open {}: {e}", part.display())))?;
+
+        let resumed_at = if append { offset } else { 0 };
+        let total_size = response.content_length().map(|length| length + resumed_at);
+        let progress = self.start_progress(total_size, resumed_at);
+
+        // No guard: whatever was written is kept for the next attempt
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            if self.interrupt.load(Ordering::Relaxed) {
+                progress.abandon("interrupted");
+                return Err(NetworkError::Interrupted.into());
+            }
+
+            let chunk = chunk.map_err(NetworkError::Reqwest)?;
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| failed(format!("failed to write to {}: {e}", part.display())))?;
+
+            progress.add(chunk.len() as u64);
+        }
+
+        file.flush()
+            .await
+            .map_err(|e| failed(format!("failed to flush {}: {e}", part.display())))?;
+        drop(file);
+
+        tokio::fs::rename(part, output).await.map_err(|e| {
+            failed(format!(
+                "failed to rename {} to {}: {e}",
+                part.display(),
+                output.display()
+            ))
+        })?;
+        progress.finish();
+
+        Ok(())
+    }
 
-        if let Some(pb) = progress_bar {
-            pb.finish_with_message("done");
+    /// Downloads `total_size` bytes as `chunks` parallel ranges into `part`,
+    /// then renames it to `output`.
+    ///
+    /// If a range fails, `part` is cut back to the bytes downloaded without a
+    /// gap, so a resumed download continues from there. It is only removed
+    /// when a range does not match the probed file. Dropping the download
+    /// leaves the preallocated `part`, which a resume finds complete in size
+    /// and starts over.
+    async fn download_chunked(
+        &self,
+        url: &str,
+        output: &Path,
+        part: &Path,
+        total_size: u64,
+        chunks: usize,
+    ) -> MobResult<()> {
+        let failed = |message: String| NetworkError::DownloadFailed {
+            url: url.to_string(),
+            message,
+        };
+
+        if let Some(parent) = output.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                failed(format!(
+                    "failed to create parent directory {}: {e}",
+                    parent.display()
+                ))
+            })?;
+        }
+
+        // Preallocate so every chunk can write at its own offset
+        let file = tokio::fs::File::create(part)
+            .await
+            .map_err(|e| failed(format!("failed to create {}: {e}", part.display())))?;
+        file.set_len(total_size)
+            .await
+            .map_err(|e| failed(format!("failed to allocate {}: {e}", part.display())))?;
+        drop(file);
+
+        let progress = self.start_progress(Some(total_size), 0);
+        let ranges = chunk_ranges(total_size, chunks);
+        let mut writes: Vec<RangeWrite> = ranges.iter().map(|_| RangeWrite::default()).collect();
+        let results = join_all(
+            ranges
+                .iter()
+                .zip(&mut writes)
+                .map(|(&(start, end), write)| {
+                    self.download_range(url, part, (start, end, total_size), &progress, write)
+                }),
+        )
+        .await;
+
+        if let Some(error) = results.into_iter().find_map(Result::err) {
+            if writes.iter().any(|write| write.mismatch) {
+                let _ = tokio::fs::remove_file(part).await;
+            } else {
+                let downloaded = downloaded_prefix(&ranges, &writes);
+                let file = tokio::fs::OpenOptions::new().write(true).open(part).await;
+                if let Ok(file) = file {
+                    let _ = file.set_len(downloaded).await;
+                }
+            }
+            return Err(error);
         }
 
+        tokio::fs::rename(part, output).await.map_err(|e| {
+            failed(format!(
+                "failed to rename {} to {}: {e}",
+                part.display(),
+                output.display()
+            ))
+        })?;
+        progress.finish();
+
         Ok(())
     }
 
+    /// Downloads bytes `start..=end` of the `total` bytes of `url` into
+    /// `part` at offset `start`, counting them in `write`.
+    ///
+    /// `part` is flushed before returning, also on errors, so `write` only
+    /// counts bytes that are in the file.
+    async fn download_range(
+        &self,
+        url: &str,
+        part: &Path,
+        (start, end, total): (u64, u64, u64),
+        progress: &Progress<'_>,
+        write: &mut RangeWrite,
+    ) -> MobResult<()> {
+        let failed = |message: String| NetworkError::DownloadFailed {
+            url: url.to_string(),
+            message,
+        };
+
+        let response = self
+            .request(url)
+            .header(RANGE, format!("bytes={start}-{end}"))
+            .send()
+            .await
+            .map_err(NetworkError::Reqwest)?;
+        if response.status() != StatusCode::PARTIAL_CONTENT {
+            return Err(failed(format!(
+                "expected 206 for range {start}-{end}, got {}",
+                response.status().as_u16()
+            ))
+            .into());
+        }
+        if !content_range_is(&response, start, end, total) {
+            write.mismatch = true;
+            return Err(failed(format!(
+                "range {start}-{end} does not match a file of {total} bytes"
+            ))
+            .into());
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .open(part)
+            .await
+            .map_err(|e| failed(format!("failed to open {}: {e}", part.display())))?;
+        file.seek(SeekFrom::Start(start))
+            .await
+            .map_err(|e| failed(format!("failed to seek in {}: {e}", part.display())))?;
+
+        let expected = end - start + 1;
+        let mut written: u64 = 0;
+        let mut stream = response.bytes_stream();
+        let result: MobResult<()> = async {
+            while let Some(chunk) = stream.next().await {
+                if self.interrupt.load(Ordering::Relaxed) {
+                    return Err(NetworkError::Interrupted.into());
+                }
+
+                let chunk = chunk.map_err(NetworkError::Reqwest)?;
+                if written + chunk.len() as u64 > expected {
+                    write.mismatch = true;
+                    return Err(
+                        failed(format!("range {start}-{end} returned too many bytes")).into(),
+                    );
+                }
+                file.write_all(&chunk)
+                    .await
+                    .map_err(|e| failed(format!("failed to write to {}: {e}", part.display())))?;
+                written += chunk.len() as u64;
+
+                progress.add(chunk.len() as u64);
+            }
+
+            if written == expected {
+                Ok(())
+            } else {
+                Err(failed(format!(
+                    "range {start}-{end} ended after {written} of {expected} bytes"
+                ))
+                .into())
+            }
+        }
+        .await;
+
+        file.flush()
+            .await
+            .map_err(|e| failed(format!("failed to flush {}: {e}", part.display())))?;
+        write.written = written;
+        result
+    }
+
     /// Download to the configured file with a custom progress callback.
     ///
     /// The callback receives (`bytes_downloaded`, `total_bytes`).
@@ -484,3 +1075,56 @@ impl Downloader {
         Ok(text)
     }
 }
+
+/// Returns `<output>.part`, where unfinished downloads of `output` are kept.
+#[must_use]
+pub fn part_path(output: &Path) -> PathBuf {
+    let mut name = output.as_os_str().to_owned();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// Returns the first byte of a `Content-Range: bytes <start>-<end>/<total>` header.
+fn content_range_start(response: &reqwest::Response) -> Option<u64> {
+    let content_range = response.headers().get(CONTENT_RANGE)?.to_str().ok()?;
+    let (start, _) = content_range
+        .trim()
+        .strip_prefix("bytes ")?
+        .split_once('-')?;
+    start.trim().parse().ok()
+}
+
+/// Returns whether `response` has `Content-Range: bytes <start>-<end>/<total>`.
+fn content_range_is(response: &reqwest::Response, start: u64, end: u64, total: u64) -> bool {
+    response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim() == format!("bytes {start}-{end}/{total}"))
+}
+
+/// Returns how many bytes from the start of a chunked download are in the
+/// file without a gap.
+fn downloaded_prefix(ranges: &[(u64, u64)], writes: &[RangeWrite]) -> u64 {
+    let mut downloaded = 0;
+    for (&(start, end), write) in ranges.iter().zip(writes) {
+        downloaded = start + write.written;
+        if write.written < end - start + 1 {
+            break;
+        }
+    }
+    downloaded
+}
+
+/// Splits `total` bytes into `chunks` contiguous inclusive byte ranges.
+fn chunk_ranges(total: u64, chunks: usize) -> Vec<(u64, u64)> {
+    let chunks = u64::try_from(chunks)
+        .unwrap_or(u64::MAX)
+        .clamp(1, total.max(1));
+    let size = total.div_ceil(chunks);
+    (0..chunks)
+        .map(|i| i * size)
+        .take_while(|&start| start < total)
+        .map(|start| (start, (start + size).min(total) - 1))
+        .collect()
+}
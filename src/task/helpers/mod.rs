@@ -15,17 +15,34 @@
 //! | [`check_source_safe_to_delete`] | Verify git repo has no uncommitted/stashed changes |
 //! | [`safe_remove_source`] | Remove directory with uncommitted changes check |
 //! | [`ensure_dir`] | Create directory if it doesn't exist (dry-run aware) |
-//! | [`copy_file_if_newer`] | Copy file only if source is newer than destination |
+//! | [`copy_file_if_newer`] | Atomically copy file only if source is newer than destination |
+//! | [`staging_install_prefix`] | Staging prefix used by `cmake.staged_install` |
+//! | [`reset_staged_install`] | Remove leftovers of a previous failed staged install |
+//! | [`commit_staged_install`] | Move a finished staged install into the real prefix |
+//! | [`install_is_current`] | Check `install_manifest.txt` copies against build outputs |
+//! | [`fetch_archive`] | Download and extract an archive, honouring `--skip-existing` |
+//! | [`archive_is_current`] | Check a cached archive against its fetch stamp |
+//! | [`retry_async`] | Retry a failing operation with exponential backoff |
 //!
 
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::future::Future;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use anyhow::Context;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::error::Result;
 use crate::git::query::{has_stashed_changes, has_uncommitted_changes, is_git_repo};
-use crate::task::TaskContext;
+use crate::task::tools::Tool;
+use crate::task::tools::downloader::{DownloaderTool, sha256_file};
+use crate::task::tools::extractor::ExtractorTool;
+use crate::task::{ArchiveMode, TaskContext};
+use crate::utility::fs::atomic::{copy_atomic, write_atomic};
+use crate::utility::fs::copy::move_dir_contents_async;
 
 /// Check if a git source directory is safe to delete.
 ///
@@ -156,9 +173,35 @@ pub async fn ensure_dir(ctx: &TaskContext, path: &Path, label: &str) -> Result<(
 
     Ok(())
 }
+/// Returns whether `dst` is missing or older than `src`.
+///
+/// Files whose modification time can't be read count as outdated.
+///
+/// # Errors
+///
+/// Returns an error if the metadata of an existing file cannot be read.
+pub async fn is_outdated(src: &Path, dst: &Path) -> Result<bool> {
+    if !dst.exists() {
+        return Ok(true);
+    }
+
+    let src_meta = tokio::fs::metadata(src)
+        .await
+        .with_context(|| format!("failed to get metadata for {}", src.display()))?;
+    let dst_meta = tokio::fs::metadata(dst)
+        .await
+        .with_context(|| format!("failed to get metadata for {}", dst.display()))?;
+
+    match (src_meta.modified().ok(), dst_meta.modified().ok()) {
+        (Some(s), Some(d)) => Ok(s > d),
+        _ => Ok(true),
+    }
+}
+
 /// Copy a file only if source is newer than destination (or destination doesn't exist).
 ///
-/// This is dry-run aware.
+/// This is dry-run aware. The copy goes through a temporary file, so an
+/// interrupted run never leaves a truncated `dst`.
 ///
 /// # Arguments
 ///
@@ -192,38 +235,384 @@ pub async fn copy_file_if_newer(
     }
 
     // Copy if destination doesn't exist or is older
-    let should_copy = if dst.exists() {
-        let src_meta = tokio::fs::metadata(src)
+    if is_outdated(src, dst).await? {
+        tracing::debug!(
+            src = %src.display(),
+            dst = %dst.display(),
+            "Copying {}", label
+        );
+        copy_atomic(src, dst)
             .await
-            .with_context(|| format!("failed to get metadata for {}", src.display()))?;
-        let dst_meta = tokio::fs::metadata(dst)
+            .with_context(|| format!("failed to copy {} to {}", src.display(), dst.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the staging prefix for a task's staged install.
+///
+/// The staging tree is a sibling of the install prefix (`install.staging/<task>`)
+/// so the final move stays on the same volume and is a cheap rename.
+#[must_use]
+pub fn staging_install_prefix(install_prefix: &Path, task_name: &str) -> PathBuf {
+    let mut name = install_prefix
+        .file_name()
+        .map(std::ffi::OsStr::to_os_string)
+        .unwrap_or_default();
+    name.push(".staging");
+    install_prefix.with_file_name(name).join(task_name)
+}
+
+/// Remove a staging directory left behind by a previous failed install.
+///
+/// A commit that was interrupted after it started moving files (see
+/// [`commit_staged_install`]) is finished into `install_prefix` instead, so
+/// the install tree never stays half-updated. This is dry-run aware.
+///
+/// # Errors
+///
+/// Returns an error if the staging directory cannot be removed or an
+/// interrupted commit cannot be finished.
+pub async fn reset_staged_install(
+    ctx: &TaskContext,
+    staging: &Path,
+    install_prefix: &Path,
+) -> Result<()> {
+    let marker = commit_marker(staging);
+    if tokio::fs::try_exists(&marker).await.unwrap_or(false) {
+        if ctx.is_dry_run() {
+            info!(
+                src = %staging.display(),
+                dst = %install_prefix.display(),
+                "[DRY-RUN] would finish interrupted staged install"
+            );
+            return Ok(());
+        }
+        warn!(
+            src = %staging.display(),
+            dst = %install_prefix.display(),
+            "Finishing interrupted staged install"
+        );
+        return finish_staged_install(staging, install_prefix).await;
+    }
+
+    if !tokio::fs::try_exists(staging).await.unwrap_or(false) {
+        return Ok(());
+    }
+
+    if ctx.is_dry_run() {
+        info!(
+            path = %staging.display(),
+            "[DRY-RUN] would delete stale staging directory"
+        );
+        return Ok(());
+    }
+
+    tokio::fs::remove_dir_all(staging)
+        .await
+        .with_context(|| format!("failed to delete {}", staging.display()))?;
+    Ok(())
+}
+
+/// Move a finished staged install into the install prefix.
+///
+/// A marker next to the staging tree is written before the first file moves
+/// and removed after the last, so a run interrupted in between is finished by
+/// the next [`reset_staged_install`]. Each file is renamed into place, or
+/// copied through a temporary file when the staging tree and install prefix
+/// are on different volumes, so no file is ever half-written. Does nothing if
+/// the staging directory was never created. This is dry-run aware.
+///
+/// # Errors
+///
+/// Returns an error if the marker cannot be written or any file cannot be
+/// moved into the install prefix.
+pub async fn commit_staged_install(
+    ctx: &TaskContext,
+    staging: &Path,
+    install_prefix: &Path,
+) -> Result<()> {
+    if ctx.is_dry_run() {
+        info!(
+            src = %staging.display(),
+            dst = %install_prefix.display(),
+            "[DRY-RUN] would move staged install"
+        );
+        return Ok(());
+    }
+
+    if !tokio::fs::try_exists(staging).await.unwrap_or(false) {
+        return Ok(());
+    }
+
+    info!(
+        src = %staging.display(),
+        dst = %install_prefix.display(),
+        "Moving staged install into place"
+    );
+    write_atomic(
+        &commit_marker(staging),
+        install_prefix.display().to_string().as_bytes(),
+    )
+    .await
+    .context("failed to mark staged install as committing")?;
+    finish_staged_install(staging, install_prefix).await
+}
+
+/// Moves `staging` into `install_prefix`, then removes the commit marker and
+/// the shared staging parent once it is empty.
+async fn finish_staged_install(staging: &Path, install_prefix: &Path) -> Result<()> {
+    if tokio::fs::try_exists(staging).await.unwrap_or(false) {
+        move_dir_contents_async(staging, install_prefix)
             .await
-            .with_context(|| format!("failed to get metadata for {}", dst.display()))?;
+            .with_context(|| {
+                format!(
+                    "failed to move staged install into {}",
+                    install_prefix.display()
+                )
+            })?;
+    }
 
-        let src_modified = src_meta.modified().ok();
-        let dst_modified = dst_meta.modified().ok();
+    let marker = commit_marker(staging);
+    tokio::fs::remove_file(&marker)
+        .await
+        .with_context(|| format!("failed to delete {}", marker.display()))?;
+
+    // Drop the shared `.staging` parent once the last task has committed.
+    if let Some(parent) = staging.parent() {
+        let _ = tokio::fs::remove_dir(parent).await;
+    }
 
-        match (src_modified, dst_modified) {
-            (Some(s), Some(d)) => s > d,
-            _ => true,
+    Ok(())
+}
+
+/// `<staging>.commit`, present while a staged install is being moved.
+fn commit_marker(staging: &Path) -> PathBuf {
+    let mut name = staging.as_os_str().to_os_string();
+    name.push(".commit");
+    PathBuf::from(name)
+}
+
+/// Returns whether the install recorded in `build_dir/install_manifest.txt`
+/// is up to date.
+///
+/// Every listed file must exist (paths under `staging` are looked up in
+/// `install_prefix` instead), and no file in `build_dir` with the same name
+/// may be newer than its installed copy. `CMake` preserves timestamps when
+/// installing, so a no-op rebuild leaves them equal. Returns `false` when
+/// there is no manifest.
+///
+/// The build tree is walked on the blocking thread pool.
+pub async fn install_is_current(
+    build_dir: &Path,
+    install_prefix: &Path,
+    staging: Option<&Path>,
+) -> bool {
+    let build_dir = build_dir.to_path_buf();
+    let install_prefix = install_prefix.to_path_buf();
+    let staging = staging.map(Path::to_path_buf);
+    tokio::task::spawn_blocking(move || {
+        manifest_is_current(&build_dir, &install_prefix, staging.as_deref())
+    })
+    .await
+    .unwrap_or(false)
+}
+
+/// Blocking body of [`install_is_current`].
+fn manifest_is_current(build_dir: &Path, install_prefix: &Path, staging: Option<&Path>) -> bool {
+    let Ok(manifest) = std::fs::read_to_string(build_dir.join("install_manifest.txt")) else {
+        return false;
+    };
+
+    let mut installed: BTreeMap<OsString, SystemTime> = BTreeMap::new();
+    for line in manifest.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let path = Path::new(line);
+        let path = staging
+            .and_then(|staging| path.strip_prefix(staging).ok())
+            .map_or_else(|| path.to_path_buf(), |rel| install_prefix.join(rel));
+
+        let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+            return false;
+        };
+        if let Some(name) = path.file_name() {
+            installed
+                .entry(name.to_os_string())
+                .and_modify(|oldest| *oldest = (*oldest).min(modified))
+                .or_insert(modified);
         }
-    } else {
-        true
+    }
+
+    !installed.is_empty() && !has_newer_build_output(build_dir, &installed)
+}
+
+/// Walks `dir` (skipping `.git`) for a file newer than its installed copy.
+fn has_newer_build_output(dir: &Path, installed: &BTreeMap<OsString, SystemTime>) -> bool {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return true;
     };
 
-    if should_copy {
-        tracing::debug!(
-            src = %src.display(),
-            dst = %dst.display(),
-            "Copying {}", label
+    for entry in entries.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+
+        if file_type.is_dir() {
+            if entry.file_name() != ".git" && has_newer_build_output(&entry.path(), installed) {
+                return true;
+            }
+        } else if let Some(installed_at) = installed.get(&entry.file_name())
+            && let Ok(modified) = entry.metadata().and_then(|m| m.modified())
+            && modified > *installed_at
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Downloads the first working of `urls` into `cache_file` and extracts it
+/// into `output`.
+///
+/// With `sha256`, the downloaded or cached archive must have that hash.
+/// Both steps follow `global.redownload`/`global.reextract`. With
+/// `--skip-existing`, both are skipped when [`archive_is_current`] holds.
+/// A successful fetch writes the archive's fetch stamp.
+///
+/// # Errors
+///
+/// Returns an error if the download, the extraction or writing the stamp fails.
+pub async fn fetch_archive(
+    ctx: &TaskContext,
+    urls: &[String],
+    cache_file: &Path,
+    output: &Path,
+    label: &str,
+    sha256: Option<&str>,
+) -> Result<()> {
+    if ctx.archive_mode() == ArchiveMode::SkipExisting
+        && archive_is_current(cache_file, output).await
+    {
+        info!(
+            archive = %cache_file.display(),
+            output = %output.display(),
+            "{label} is cached and extracted, skipping"
         );
-        tokio::fs::copy(src, dst)
-            .await
-            .with_context(|| format!("failed to copy {} to {}", src.display(), dst.display()))?;
+        return Ok(());
+    }
+
+    let tool_ctx = ctx.tool_context();
+    let clean = &ctx.config().global.clean_download_actions;
+
+    let mut downloader = DownloaderTool::new()
+        .urls(urls.iter().cloned())
+        .file(cache_file)
+        .force(clean.redownload);
+    if let Some(sha256) = sha256 {
+        downloader = downloader.sha256(sha256);
     }
+    downloader
+        .run(&tool_ctx)
+        .await
+        .with_context(|| format!("failed to download {label}"))?;
 
+    ExtractorTool::new()
+        .archive(cache_file)
+        .output(output)
+        .force(clean.reextract)
+        .run(&tool_ctx)
+        .await
+        .with_context(|| format!("failed to extract {label}"))?;
+
+    if !ctx.is_dry_run() {
+        let stamp = fetch_stamp(cache_file).await?;
+        tokio::fs::write(stamp_path(cache_file), stamp)
+            .await
+            .with_context(|| format!("failed to write fetch stamp for {label}"))?;
+    }
     Ok(())
 }
 
+/// Returns whether `cache_file` still has the size and checksum recorded in
+/// its fetch stamp and `output` exists.
+///
+/// Archives fetched before stamps existed are never current.
+pub async fn archive_is_current(cache_file: &Path, output: &Path) -> bool {
+    if !output.is_dir() {
+        return false;
+    }
+    let Ok(recorded) = tokio::fs::read_to_string(stamp_path(cache_file)).await else {
+        return false;
+    };
+    fetch_stamp(cache_file)
+        .await
+        .is_ok_and(|stamp| stamp == recorded)
+}
+
+/// Delay before the first retry of a failed clone or pull; doubled per retry.
+pub const FETCH_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Runs `op` up to `attempts` times, waiting `base_delay`, then twice as
+/// long, and so on between attempts.
+///
+/// Nothing is retried once `ctx` is cancelled, and cancelling during a
+/// wait returns the last error right away.
+///
+/// # Errors
+///
+/// Returns the error of the last attempt.
+pub async fn retry_async<T, F, Fut>(
+    ctx: &TaskContext,
+    attempts: NonZeroU32,
+    base_delay: Duration,
+    mut op: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut delay = base_delay;
+    let mut attempt = 1;
+    loop {
+        let error = match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) => e,
+        };
+        if attempt >= attempts.get() || ctx.is_cancelled() {
+            return Err(error);
+        }
+        warn!(
+            attempt,
+            attempts = attempts.get(),
+            delay_ms = delay.as_millis(),
+            error = %format!("{error:#}"),
+            "Attempt failed, retrying"
+        );
+        tokio::select! {
+            () = ctx.cancel_token().cancelled() => return Err(error),
+            () = tokio::time::sleep(delay) => {}
+        }
+        delay = delay.saturating_mul(2);
+        attempt += 1;
+    }
+}
+
+/// `<cache_file>.stamp`, written next to the archive.
+fn stamp_path(cache_file: &Path) -> PathBuf {
+    let mut name = cache_file.as_os_str().to_owned();
+    name.push(".stamp");
+    PathBuf::from(name)
+}
+
+/// Size and SHA-256 of `cache_file`, as stored in its stamp.
+async fn fetch_stamp(cache_file: &Path) -> Result<String> {
+    let size = tokio::fs::metadata(cache_file)
+        .await
+        .with_context(|| format!("failed to read metadata of {}", cache_file.display()))?
+        .len();
+    let sha256 = sha256_file(cache_file).await?;
+    Ok(format!("size={size}\nsha256={sha256}\n"))
+}
+
 #[cfg(test)]
 mod tests;
@@ -3,8 +3,16 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{TaskContext, check_source_safe_to_delete, copy_file_if_newer, ensure_dir};
+use super::{
+    TaskContext, archive_is_current, check_source_safe_to_delete, commit_staged_install,
+    copy_file_if_newer, ensure_dir, fetch_archive, fetch_stamp, install_is_current, is_outdated,
+    reset_staged_install, retry_async, staging_install_prefix,
+};
+use std::num::NonZeroU32;
+use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime};
 use tempfile::TempDir;
 use tokio_util::sync::CancellationToken;
 
@@ -91,3 +99,273 @@ async fn copy_file_if_newer_dry_run() {
 
     assert!(!dst.exists());
 }
+
+#[test]
+fn staging_install_prefix_is_sibling_of_install() {
+    let install = std::path::Path::new("build").join("install");
+    let staging = staging_install_prefix(&install, "modorganizer-uibase");
+
+    assert_eq!(
+        staging,
+        std::path::Path::new("build")
+            .join("install.staging")
+            .join("modorganizer-uibase")
+    );
+}
+
+#[tokio::test]
+async fn commit_staged_install_moves_into_prefix() {
+    let (temp, ctx) = test_context();
+    let install = temp.path().join("install");
+    let staging = staging_install_prefix(&install, "task");
+    std::fs::create_dir_all(staging.join("bin")).expect("create staging");
+    std::fs::write(staging.join("bin/app.exe"), "new").expect("write");
+    std::fs::create_dir_all(install.join("bin")).expect("create install");
+    std::fs::write(install.join("bin/app.exe"), "old").expect("write");
+
+    commit_staged_install(&ctx, &staging, &install)
+        .await
+        .expect("should commit");
+
+    assert!(!staging.exists());
+    assert!(!temp.path().join("install.staging").exists());
+    assert_eq!(
+        std::fs::read_to_string(install.join("bin/app.exe")).expect("read"),
+        "new"
+    );
+}
+
+#[tokio::test]
+async fn reset_staged_install_keeps_install_intact() {
+    let (temp, ctx) = test_context();
+    let install = temp.path().join("install");
+    let staging = staging_install_prefix(&install, "task");
+    std::fs::create_dir_all(&staging).expect("create staging");
+    std::fs::write(staging.join("half.dll"), "partial").expect("write");
+    std::fs::create_dir_all(&install).expect("create install");
+    std::fs::write(install.join("app.exe"), "old").expect("write");
+
+    reset_staged_install(&ctx, &staging, &install)
+        .await
+        .expect("should reset");
+
+    assert!(!staging.exists());
+    assert_eq!(
+        std::fs::read_to_string(install.join("app.exe")).expect("read"),
+        "old"
+    );
+}
+
+#[tokio::test]
+async fn reset_staged_install_finishes_interrupted_commit() {
+    let (temp, ctx) = test_context();
+    let install = temp.path().join("install");
+    let staging = staging_install_prefix(&install, "task");
+    // A commit moved app.exe, then stopped before plugin.dll
+    std::fs::create_dir_all(&staging).expect("create staging");
+    std::fs::write(staging.join("plugin.dll"), "new").expect("write");
+    std::fs::create_dir_all(&install).expect("create install");
+    std::fs::write(install.join("app.exe"), "new").expect("write");
+    std::fs::write(install.join("plugin.dll"), "old").expect("write");
+    std::fs::write(
+        temp.path().join("install.staging").join("task.commit"),
+        install.display().to_string(),
+    )
+    .expect("write marker");
+
+    reset_staged_install(&ctx, &staging, &install)
+        .await
+        .expect("should finish commit");
+
+    assert!(!temp.path().join("install.staging").exists());
+    assert_eq!(
+        std::fs::read_to_string(install.join("plugin.dll")).expect("read"),
+        "new"
+    );
+}
+
+fn write_with_mtime(path: &Path, mtime: SystemTime) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, "data").unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn install_is_current_compares_manifest_copies() {
+    let temp = temp_dir();
+    let build = temp.path().join("build");
+    let install = temp.path().join("install");
+    let built_at = SystemTime::now() - Duration::from_mins(1);
+
+    write_with_mtime(&build.join("bin").join("plugin.dll"), built_at);
+    write_with_mtime(&install.join("bin").join("plugin.dll"), built_at);
+    assert!(!install_is_current(&build, &install, None).await);
+
+    std::fs::write(
+        build.join("install_manifest.txt"),
+        install.join("bin").join("plugin.dll").display().to_string(),
+    )
+    .unwrap();
+    assert!(install_is_current(&build, &install, None).await);
+
+    write_with_mtime(&build.join("bin").join("plugin.dll"), SystemTime::now());
+    assert!(!install_is_current(&build, &install, None).await);
+}
+
+#[tokio::test]
+async fn install_is_current_maps_staging_paths() {
+    let temp = temp_dir();
+    let build = temp.path().join("build");
+    let install = temp.path().join("install");
+    let staging = staging_install_prefix(&install, "task");
+    let built_at = SystemTime::now() - Duration::from_mins(1);
+
+    write_with_mtime(&build.join("plugin.dll"), built_at);
+    std::fs::create_dir_all(&build).unwrap();
+    std::fs::write(
+        build.join("install_manifest.txt"),
+        staging.join("plugin.dll").display().to_string(),
+    )
+    .unwrap();
+    assert!(!install_is_current(&build, &install, Some(&staging)).await);
+
+    write_with_mtime(&install.join("plugin.dll"), built_at);
+    assert!(install_is_current(&build, &install, Some(&staging)).await);
+}
+
+#[tokio::test]
+async fn archive_is_current_requires_matching_stamp() {
+    let temp = temp_dir();
+    let archive = temp.path().join("pkg.7z");
+    let output = temp.path().join("pkg");
+    std::fs::write(&archive, b"archive").expect("write archive");
+    std::fs::create_dir(&output).expect("create output");
+
+    assert!(!archive_is_current(&archive, &output).await);
+
+    let stamp = fetch_stamp(&archive).await.expect("stamp");
+    let sha256 = crate::task::tools::downloader::sha256_file(&archive)
+        .await
+        .expect("sha256");
+    assert_eq!(stamp, format!("size=7\nsha256={sha256}\n"));
+    std::fs::write(temp.path().join("pkg.7z.stamp"), stamp).expect("write stamp");
+    assert!(archive_is_current(&archive, &output).await);
+
+    std::fs::write(&archive, b"archivf").expect("rewrite archive");
+    assert!(!archive_is_current(&archive, &output).await);
+}
+
+#[tokio::test]
+async fn archive_is_current_requires_output_dir() {
+    let temp = temp_dir();
+    let archive = temp.path().join("pkg.7z");
+    std::fs::write(&archive, b"archive").expect("write archive");
+    let stamp = fetch_stamp(&archive).await.expect("stamp");
+    std::fs::write(temp.path().join("pkg.7z.stamp"), stamp).expect("write stamp");
+
+    assert!(!archive_is_current(&archive, &temp.path().join("pkg")).await);
+}
+
+#[tokio::test]
+async fn fetch_archive_skip_existing_skips_current_archive() {
+    let (temp, ctx) = test_context();
+    let mut config = crate::config::Config::default();
+    config.global.clean_download_actions.redownload = true;
+    config.global.clean_download_actions.reextract = true;
+    let ctx = TaskContext::new(Arc::new(config), ctx.cancel_token().clone())
+        .with_archive_mode(crate::task::ArchiveMode::SkipExisting);
+
+    let archive = temp.path().join("pkg.7z");
+    let output = temp.path().join("pkg");
+    std::fs::write(&archive, b"archive").expect("write archive");
+    std::fs::create_dir(&output).expect("create output");
+    let stamp = fetch_stamp(&archive).await.expect("stamp");
+    std::fs::write(temp.path().join("pkg.7z.stamp"), stamp).expect("write stamp");
+
+    // The URL is unreachable: reaching the downloader would fail.
+    fetch_archive(
+        &ctx,
+        &["http://127.0.0.1:9/pkg.7z".to_string()],
+        &archive,
+        &output,
+        "pkg",
+        None,
+    )
+    .await
+    .expect("current archive is skipped");
+    assert_eq!(std::fs::read(&archive).expect("read archive"), b"archive");
+}
+
+#[tokio::test]
+async fn test_retry_async_retries_until_success() {
+    let (_temp, ctx) = test_context();
+    let attempts = NonZeroU32::new(3).unwrap();
+    let calls = AtomicU32::new(0);
+
+    let result = retry_async(&ctx, attempts, Duration::ZERO, || async {
+        if calls.fetch_add(1, Ordering::Relaxed) < 2 {
+            anyhow::bail!("transient");
+        }
+        Ok("cloned")
+    })
+    .await;
+
+    assert_eq!(result.unwrap(), "cloned");
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+}
+
+#[tokio::test]
+async fn test_retry_async_returns_last_error() {
+    let (_temp, ctx) = test_context();
+    let attempts = NonZeroU32::new(2).unwrap();
+    let calls = AtomicU32::new(0);
+
+    let result: crate::error::Result<()> = retry_async(&ctx, attempts, Duration::ZERO, || async {
+        let call = calls.fetch_add(1, Ordering::Relaxed) + 1;
+        anyhow::bail!("failure {call}")
+    })
+    .await;
+
+    assert_eq!(result.unwrap_err().to_string(), "failure 2");
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn test_retry_async_does_not_retry_when_cancelled() {
+    let (_temp, ctx) = test_context();
+    ctx.cancel_token().cancel();
+    let attempts = NonZeroU32::new(3).unwrap();
+    let calls = AtomicU32::new(0);
+
+    let result: crate::error::Result<()> =
+        retry_async(&ctx, attempts, Duration::from_mins(1), || async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            anyhow::bail!("interrupted")
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn is_outdated_compares_modification_times() {
+    let temp = temp_dir();
+    let ts = temp.path().join("de.ts");
+    let qm = temp.path().join("organizer_de.qm");
+    let earlier = SystemTime::now() - Duration::from_mins(1);
+
+    write_with_mtime(&ts, SystemTime::now());
+    assert!(is_outdated(&ts, &qm).await.expect("missing qm"));
+
+    write_with_mtime(&qm, earlier);
+    assert!(is_outdated(&ts, &qm).await.expect("older qm"));
+
+    write_with_mtime(&ts, earlier - Duration::from_mins(1));
+    assert!(!is_outdated(&ts, &qm).await.expect("newer qm"));
+}
@@ -6,33 +6,70 @@
 //! Task manager for orchestrating task execution.
 //!
 //! ```text
-//! TaskManager::new(config)
-//!   .add_task()  .with_cancel_token()  .dry_run()
+//! TaskManager::new(config, &ctrl_c_token)
+//!   .add_task()  .with_cancel_token()  .dry_run()  .with_env()
+//!   .with_continue_on_error()  .with_failure_mode()  .with_plan()  .with_task_timeout()
+//!   .with_measure()  .with_results()  .with_script()  .with_priority()
 //!   .run().await
+//!       tasks ordered by Taskable::dependencies, followed through the
+//!       default task tree, into levels (see schedule); the independent
+//!       tasks of a level run together, each on its own
 //!       per task: Clean --> Fetch --> Build
-//!       parallel tasks share a global semaphore
+//!       a task running past the timeout ([task] timeout_secs) has its own
+//!       child token cancelled and fails as interrupted
+//!       a failed task stops the run once its level finished, or with
+//!       continue_on_error is collected and reported with every other
+//!       failure at the end; the tasks that depend on it are skipped with
+//!       TaskError::DependencyFailed
+//!       builds share a global semaphore (--jobs, default: CPUs), fetches
+//!       one of --fetch-parallelism slots
+//!       with_failure_mode(Aggregate): a failed level lists each failed
+//!       task, as does a parallel group for its children (--fail-fast=false)
+//!   --> per-task clean/fetch/build timings, slowest first (INFO)
 //! ```
 
+mod schedule;
+
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::error::Result;
+use crate::error::{Result, TaskError};
 use anyhow::Context;
+use futures_util::future;
 use tokio::sync::Semaphore;
 use tokio_util::sync::CancellationToken;
 
 use crate::config::Config;
-
-use super::{CleanFlags, PhaseControl, Task, TaskContext, Taskable};
+use crate::config::types::CmakeInstallMessage;
+use crate::core::env::container::Env;
+use crate::core::process::priority::ProcessPriority;
+use crate::core::process::script::Script;
+use crate::core::process::timing::ProcessTimings;
+use crate::net::ProgressGroup;
+use crate::task::tools::git::RemoteBranchCache;
+
+use super::plan::Plan;
+use super::registry::builtin::default_task_tree;
+use super::results::TaskResults;
+use super::timing::{PhaseTiming, RunTimings, format_summary, summarize};
+use super::{
+    ArchiveMode, CleanFlags, FailureMode, InstallMode, PhaseControl, Task, TaskContext, Taskable,
+    failures_result,
+};
 
 /// Manager for orchestrating task execution.
 ///
-/// Tasks are executed sequentially in the order they were added.
-/// Parallel execution within tasks respects the global concurrency limit.
+/// Tasks run in the order of their dependencies, and tasks that don't depend
+/// on each other run together. Parallel execution respects the global
+/// concurrency limit.
 pub struct TaskManager {
     /// Tasks to execute.
     tasks: Vec<Task>,
 
-    /// Cancellation token for cooperative cancellation.
+    /// Cancellation token for cooperative cancellation; a child of the
+    /// Ctrl+C token.
     cancel_token: CancellationToken,
 
     /// Shared configuration.
@@ -50,42 +87,148 @@ pub struct TaskManager {
 
     /// Phase control toggles.
     phases: PhaseControl,
+
+    /// Environment overlay passed to every task.
+    env: Env,
+
+    /// `CMAKE_INSTALL_MESSAGE` override passed to every task.
+    install_message: Option<CmakeInstallMessage>,
+
+    /// Install mode passed to every task.
+    install_mode: InstallMode,
+
+    /// Maximum concurrent fetches passed to every task.
+    fetch_parallelism: NonZeroUsize,
+
+    /// Job count of each `cmake --build --parallel`; unset leaves it to `CMake`.
+    build_jobs: Option<NonZeroUsize>,
+
+    /// Archive mode passed to every task.
+    archive_mode: ArchiveMode,
+
+    /// Whether the remaining tasks still run after one fails.
+    continue_on_error: bool,
+
+    /// How parallel groups report failing children, passed to every task.
+    failure_mode: FailureMode,
+
+    /// Collects the dry-run operations of every task for `build --plan`.
+    plan: Option<Arc<Plan>>,
+
+    /// Collects the `--measure` phase and process durations.
+    measure: Option<(Arc<RunTimings>, Arc<ProcessTimings>)>,
+
+    /// Collects the result of every task for `build --report`.
+    results: Option<Arc<TaskResults>>,
+
+    /// Collects the commands of a dry run for `--emit-script`.
+    script: Option<Arc<Script>>,
+
+    /// Remote branch checks shared by every task of the run.
+    branch_cache: Arc<RemoteBranchCache>,
+
+    /// Progress bars of every download of the run.
+    progress: ProgressGroup,
+
+    /// Scheduling priority of the processes tools run.
+    priority: ProcessPriority,
+
+    /// How long each top-level task may run; unlimited if unset.
+    task_timeout: Option<Duration>,
 }
 
+/// How long a timed-out task gets to stop after its token is cancelled.
+const CANCEL_GRACE: Duration = Duration::from_secs(10);
+
 impl TaskManager {
-    /// Creates a new `TaskManager` with the given configuration.
+    /// Creates a new `TaskManager` with the given configuration, cancelled
+    /// along with `cancel_token` (the Ctrl+C token).
     ///
-    /// The default concurrency limit is the number of CPU cores.
+    /// The default concurrency limit is the number of CPU cores; the task
+    /// timeout comes from `[task] timeout_secs`.
     #[must_use]
-    pub fn new(config: Arc<Config>) -> Self {
+    pub fn new(config: Arc<Config>, cancel_token: &CancellationToken) -> Self {
         let max_concurrent = std::thread::available_parallelism()
-            .map(std::num::NonZeroUsize::get)
+            .map(NonZeroUsize::get)
             .unwrap_or(4); // Fallback to 4 if unavailable
         Self {
             tasks: Vec::new(),
-            cancel_token: CancellationToken::new(),
-            config,
+            cancel_token: cancel_token.child_token(),
             concurrency_semaphore: Arc::new(Semaphore::new(max_concurrent)),
             dry_run: false,
             clean_flags: CleanFlags::empty(),
             phases: PhaseControl::new(),
+            env: Env::new(),
+            install_message: None,
+            install_mode: InstallMode::Always,
+            fetch_parallelism: NonZeroUsize::MIN,
+            build_jobs: None,
+            archive_mode: ArchiveMode::FollowFlags,
+            continue_on_error: false,
+            failure_mode: FailureMode::FailFast,
+            task_timeout: config.task.task_timeout(),
+            config,
+            plan: None,
+            measure: None,
+            results: None,
+            script: None,
+            branch_cache: Arc::new(RemoteBranchCache::new()),
+            progress: ProgressGroup::new(),
+            priority: ProcessPriority::Normal,
         }
     }
 
-    /// Creates a `TaskManager` with a specific concurrency limit.
+    /// Creates a `TaskManager` with a specific concurrency limit, cancelled
+    /// along with `cancel_token`.
     #[must_use]
-    pub fn with_concurrency(config: Arc<Config>, max_concurrent: usize) -> Self {
+    pub fn with_concurrency(
+        config: Arc<Config>,
+        max_concurrent: usize,
+        cancel_token: &CancellationToken,
+    ) -> Self {
         Self {
             tasks: Vec::new(),
-            cancel_token: CancellationToken::new(),
-            config,
+            cancel_token: cancel_token.child_token(),
             concurrency_semaphore: Arc::new(Semaphore::new(max_concurrent)),
             dry_run: false,
             clean_flags: CleanFlags::empty(),
             phases: PhaseControl::new(),
+            env: Env::new(),
+            install_message: None,
+            install_mode: InstallMode::Always,
+            fetch_parallelism: NonZeroUsize::MIN,
+            build_jobs: None,
+            archive_mode: ArchiveMode::FollowFlags,
+            continue_on_error: false,
+            failure_mode: FailureMode::FailFast,
+            task_timeout: config.task.task_timeout(),
+            config,
+            plan: None,
+            measure: None,
+            results: None,
+            script: None,
+            branch_cache: Arc::new(RemoteBranchCache::new()),
+            progress: ProgressGroup::new(),
+            priority: ProcessPriority::Normal,
         }
     }
 
+    /// Shares `cache` with other managers of the same command instead of
+    /// starting with an empty remote branch cache.
+    #[must_use]
+    pub fn with_branch_cache(mut self, cache: Arc<RemoteBranchCache>) -> Self {
+        self.branch_cache = cache;
+        self
+    }
+
+    /// Sets the scheduling priority of the processes tools run
+    /// (`--low-priority`).
+    #[must_use]
+    pub const fn with_priority(mut self, priority: ProcessPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Sets dry-run mode.
     #[must_use]
     pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
@@ -93,6 +236,61 @@ impl TaskManager {
         self
     }
 
+    /// Keeps running the remaining top-level tasks after one fails.
+    ///
+    /// `run_all` then returns one error listing every failed task.
+    /// Cancellation still stops the run.
+    #[must_use]
+    pub const fn with_continue_on_error(mut self, enable: bool) -> Self {
+        self.continue_on_error = enable;
+        self
+    }
+
+    /// Limits how long each top-level task may run.
+    ///
+    /// A task still running after `timeout` has its cancellation token
+    /// triggered and fails with [`TaskError::Interrupted`]; with
+    /// `continue_on_error` the remaining tasks still run.
+    #[must_use]
+    pub const fn with_task_timeout(mut self, timeout: Duration) -> Self {
+        self.task_timeout = Some(timeout);
+        self
+    }
+
+    /// Collects the dry-run operations of every task into `plan`.
+    #[must_use]
+    pub fn with_plan(mut self, plan: Arc<Plan>) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    /// Records the duration of every top-level phase in `phases` and of
+    /// every process in `processes`, for `--measure`.
+    ///
+    /// Parallel groups are recorded once under `parallel`.
+    #[must_use]
+    pub fn with_measure(mut self, phases: Arc<RunTimings>, processes: Arc<ProcessTimings>) -> Self {
+        self.measure = Some((phases, processes));
+        self
+    }
+
+    /// Records the result of every task in `results`, for `build --report`.
+    ///
+    /// Tasks inside parallel groups are recorded individually.
+    #[must_use]
+    pub fn with_results(mut self, results: Arc<TaskResults>) -> Self {
+        self.results = Some(results);
+        self
+    }
+
+    /// Records the commands every tool would run in `script`, for
+    /// `--emit-script`.
+    #[must_use]
+    pub fn with_script(mut self, script: Arc<Script>) -> Self {
+        self.script = Some(script);
+        self
+    }
+
     /// Sets clean flags.
     #[must_use]
     pub const fn with_clean_flags(mut self, flags: CleanFlags) -> Self {
@@ -100,6 +298,34 @@ impl TaskManager {
         self
     }
 
+    /// Sets the environment overlay passed to tasks.
+    #[must_use]
+    pub fn with_env(mut self, env: Env) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Sets the `CMAKE_INSTALL_MESSAGE` override passed to tasks.
+    #[must_use]
+    pub const fn with_install_message(mut self, message: Option<CmakeInstallMessage>) -> Self {
+        self.install_message = message;
+        self
+    }
+
+    /// Sets how parallel groups report failing children.
+    #[must_use]
+    pub const fn with_failure_mode(mut self, mode: FailureMode) -> Self {
+        self.failure_mode = mode;
+        self
+    }
+
+    /// Sets the install mode passed to tasks.
+    #[must_use]
+    pub const fn with_install_mode(mut self, mode: InstallMode) -> Self {
+        self.install_mode = mode;
+        self
+    }
+
     /// Enables the clean phase.
     #[must_use]
     pub const fn with_do_clean(mut self, enable: bool) -> Self {
@@ -132,6 +358,19 @@ impl TaskManager {
         self.tasks.len()
     }
 
+    /// Returns the repositories the added tasks would clone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.build` is not configured.
+    pub fn missing_repos(&self) -> Result<Vec<PathBuf>> {
+        let mut missing = Vec::new();
+        for task in &self.tasks {
+            missing.extend(task.missing_repos(&self.config)?);
+        }
+        Ok(missing)
+    }
+
     /// Returns whether cancellation has been requested.
     #[must_use]
     pub fn is_cancelled(&self) -> bool {
@@ -156,6 +395,18 @@ impl TaskManager {
         self.dry_run
     }
 
+    /// Returns whether the remaining tasks still run after one fails.
+    #[must_use]
+    pub const fn continue_on_error(&self) -> bool {
+        self.continue_on_error
+    }
+
+    /// Returns how long each top-level task may run, if limited.
+    #[must_use]
+    pub const fn task_timeout(&self) -> Option<Duration> {
+        self.task_timeout
+    }
+
     /// Returns the clean flags.
     #[must_use]
     pub const fn clean_flags(&self) -> CleanFlags {
@@ -168,6 +419,53 @@ impl TaskManager {
         &self.phases
     }
 
+    /// Returns the environment overlay.
+    #[must_use]
+    pub const fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Sets how many tasks of a parallel group fetch at once, also used as
+    /// `git submodule update --jobs`.
+    #[must_use]
+    pub const fn with_fetch_parallelism(mut self, jobs: NonZeroUsize) -> Self {
+        self.fetch_parallelism = jobs;
+        self
+    }
+
+    /// Sets the job count passed as `cmake --build --parallel N`, usually the
+    /// `--jobs` value that also sizes the concurrency semaphore.
+    #[must_use]
+    pub const fn with_build_jobs(mut self, jobs: Option<NonZeroUsize>) -> Self {
+        self.build_jobs = jobs;
+        self
+    }
+
+    /// Sets the archive mode passed to tasks.
+    #[must_use]
+    pub const fn with_archive_mode(mut self, mode: ArchiveMode) -> Self {
+        self.archive_mode = mode;
+        self
+    }
+
+    /// Returns the `CMAKE_INSTALL_MESSAGE` override, if any.
+    #[must_use]
+    pub const fn install_message(&self) -> Option<CmakeInstallMessage> {
+        self.install_message
+    }
+
+    /// Returns the install mode.
+    #[must_use]
+    pub const fn install_mode(&self) -> InstallMode {
+        self.install_mode
+    }
+
+    /// Returns how parallel groups report failing children.
+    #[must_use]
+    pub const fn failure_mode(&self) -> FailureMode {
+        self.failure_mode
+    }
+
     /// Triggers cancellation for all tasks.
     ///
     /// This signals all running tasks to stop gracefully.
@@ -179,22 +477,52 @@ impl TaskManager {
 
     /// Creates a `TaskContext` for task execution.
     fn create_context(&self) -> TaskContext {
-        TaskContext::new(Arc::clone(&self.config), self.cancel_token.clone())
+        let ctx = TaskContext::new(Arc::clone(&self.config), self.cancel_token.clone())
             .with_dry_run(self.dry_run)
             .with_clean_flags(self.clean_flags)
             .with_do_clean(self.phases.do_clean())
             .with_do_fetch(self.phases.do_fetch())
             .with_do_build(self.phases.do_build())
+            .with_env(self.env.clone())
+            .with_install_message(self.install_message)
+            .with_install_mode(self.install_mode)
+            .with_fetch_parallelism(self.fetch_parallelism)
+            .with_build_jobs(self.build_jobs)
+            .with_archive_mode(self.archive_mode)
+            .with_failure_mode(self.failure_mode)
+            .with_build_semaphore(Arc::clone(&self.concurrency_semaphore))
+            .with_fetch_semaphore(Arc::new(Semaphore::new(self.fetch_parallelism.get())))
+            .with_branch_cache(Arc::clone(&self.branch_cache))
+            .with_progress_group(self.progress.clone())
+            .with_priority(self.priority);
+        let ctx = match &self.measure {
+            Some((phases, processes)) => {
+                ctx.with_measure(Arc::clone(phases), Arc::clone(processes))
+            }
+            None => ctx,
+        };
+        let ctx = match &self.results {
+            Some(results) => ctx.with_results(Arc::clone(results)),
+            None => ctx,
+        };
+        let ctx = match &self.script {
+            Some(script) => ctx.with_script(Arc::clone(script)),
+            None => ctx,
+        };
+        match &self.plan {
+            Some(plan) => ctx.with_plan(Arc::clone(plan)),
+            None => ctx,
+        }
     }
 
-    /// Runs all tasks sequentially.
+    /// Runs all tasks in the order of their dependencies.
     ///
-    /// Tasks are executed in the order they were added.
-    /// Checks for cancellation between tasks.
+    /// Checks for cancellation between levels of independent tasks.
     ///
     /// # Errors
     ///
-    /// Returns an error if any task fails or if cancellation is requested.
+    /// Returns an error if any task fails, if the task dependencies form a
+    /// cycle, or if cancellation is requested.
     pub async fn run_all(&self) -> Result<()> {
         if self.tasks.is_empty() {
             tracing::debug!("No tasks to run");
@@ -203,28 +531,155 @@ impl TaskManager {
 
         tracing::info!(task_count = self.tasks.len(), "Starting task execution");
 
-        let ctx = self.create_context();
+        let timings = Arc::new(RunTimings::new());
+        let ctx = self.create_context().with_run_timings(Arc::clone(&timings));
+
+        let result = self.run_tasks(&ctx).await;
+        log_timing_summary(&timings.take());
+        result?;
+
+        tracing::info!("All tasks completed successfully");
+        Ok(())
+    }
 
-        for (i, task) in self.tasks.iter().enumerate() {
-            // Check for cancellation before each task
+    /// Runs every task with `ctx`, in the order of their dependencies.
+    ///
+    /// The independent tasks of one level run together, each with its own
+    /// timeout and result, and the level always finishes before the run
+    /// stops. Stops after the first failing level unless `continue_on_error`
+    /// is set, in which case failures are collected and returned together at
+    /// the end, and only the tasks depending on a failed task are skipped.
+    async fn run_tasks(&self, ctx: &TaskContext) -> Result<()> {
+        let graph = default_task_tree();
+        let nodes = schedule::nodes(&self.tasks, &graph);
+        let levels = schedule::levels(&nodes)?;
+        let total = levels.len();
+
+        let mut failures = Vec::new();
+        let mut failed: Vec<&str> = Vec::new();
+        for (i, level) in levels.iter().enumerate() {
+            // Check for cancellation before each level
             if self.is_cancelled() {
-                anyhow::bail!("Task execution interrupted before task {}", i + 1);
+                anyhow::bail!("Task execution interrupted before step {}", i + 1);
             }
 
-            tracing::info!(
-                task = %task.name(),
-                index = i + 1,
-                total = self.tasks.len(),
-                "Running task"
-            );
+            let mut runnable = Vec::new();
+            for &index in level {
+                let (task, node) = self
+                    .tasks
+                    .get(index)
+                    .zip(nodes.get(index))
+                    .with_context(|| format!("scheduled task {index} does not exist"))?;
+                if let Some(dependency) = node.requires_any(&failed) {
+                    let name = task.name();
+                    let error = TaskError::DependencyFailed {
+                        task: name.to_string(),
+                        dependency: dependency.to_string(),
+                    };
+                    tracing::error!(task = %name, %dependency, "Skipping task, dependency failed");
+                    failed.extend(&node.provides);
+                    failures.push((name.to_string(), error.into()));
+                } else {
+                    runnable.push((task, node));
+                }
+            }
 
-            task.run(&ctx)
-                .await
-                .with_context(|| format!("Task '{}' failed", task.name()))?;
+            let results = future::join_all(runnable.iter().map(|&(task, _)| async move {
+                tracing::info!(
+                    task = %task.name(),
+                    step = i + 1,
+                    total,
+                    "Running task"
+                );
+                self.run_task(task.name(), task, ctx).await
+            }))
+            .await;
+
+            let mut level_failures = Vec::new();
+            for ((task, node), result) in runnable.into_iter().zip(results) {
+                let Err(e) = result else {
+                    continue;
+                };
+                let name = task.name();
+                if self.continue_on_error && !self.is_cancelled() {
+                    tracing::error!(task = %name, error = %format!("{e:#}"), "Task failed, continuing");
+                    failed.extend(&node.provides);
+                    failures.push((name.to_string(), e));
+                } else {
+                    level_failures.push((name.to_string(), e));
+                }
+            }
+            self.level_result(level_failures)?;
         }
 
-        tracing::info!("All tasks completed successfully");
-        Ok(())
+        failures_result(failures)
+    }
+
+    /// Returns the error of the tasks of a level that failed without
+    /// `continue_on_error`.
+    ///
+    /// Fail-fast reports the first failure and logs the others; aggregate
+    /// (`--fail-fast=false`) lists every failure.
+    fn level_result(&self, failures: Vec<(String, anyhow::Error)>) -> Result<()> {
+        if self.failure_mode == FailureMode::Aggregate {
+            return failures_result(failures);
+        }
+        let mut failures = failures.into_iter();
+        let Some((name, first_error)) = failures.next() else {
+            return Ok(());
+        };
+        for (task, e) in failures {
+            tracing::error!(error = %format!("{e:#}"), task = %task, "Additional task error");
+        }
+        Err(first_error.context(format!("Task '{name}' failed")))
+    }
+
+    /// Runs `task`, cancelling it once the task timeout elapses.
+    ///
+    /// The task gets a child of the run's token, so a timeout only cancels
+    /// this task and not the ones after it.
+    async fn run_task(&self, label: &str, task: &Task, ctx: &TaskContext) -> Result<()> {
+        let Some(timeout) = self.task_timeout else {
+            return task.run(ctx).await;
+        };
+        let token = ctx.cancel_token().child_token();
+        let task_ctx = ctx.clone().with_cancel_token(token.clone());
+        run_with_timeout(label, timeout, &token, task.run(&task_ctx)).await
+    }
+}
+
+/// Awaits `run`, cancelling `token` if it takes longer than `timeout`.
+///
+/// After cancelling, `run` gets [`CANCEL_GRACE`] to stop its processes
+/// before it is dropped.
+async fn run_with_timeout(
+    name: &str,
+    timeout: Duration,
+    token: &CancellationToken,
+    run: impl Future<Output = Result<()>>,
+) -> Result<()> {
+    tokio::pin!(run);
+    if let Ok(result) = tokio::time::timeout(timeout, &mut run).await {
+        return result;
+    }
+
+    tracing::error!(task = %name, ?timeout, "Task timed out, cancelling");
+    token.cancel();
+    if tokio::time::timeout(CANCEL_GRACE, run).await.is_err() {
+        tracing::warn!(task = %name, "Task did not stop after cancellation");
+    }
+    Err(anyhow::Error::new(TaskError::Interrupted(name.to_string())))
+        .context(format!("timed out after {timeout:?}"))
+}
+
+/// Logs the per-task timing table of a run, also after a failed run.
+fn log_timing_summary(timings: &[PhaseTiming]) {
+    if timings.is_empty() {
+        return;
+    }
+    tracing::info!("Task timings:");
+    for line in format_summary(&summarize(timings)) {
+        tracing::info!("  {line}");
     }
 }
 
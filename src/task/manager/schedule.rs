@@ -0,0 +1,176 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Dependency ordering of the top-level tasks of a run.
+//!
+//! ```text
+//! top-level task --> provides: its name (group: every child's name;
+//!                              ModOrganizer: also its repository name)
+//!                --> requires: Taskable::dependencies of it or its children,
+//!                              followed through the default task tree
+//! Kahn's algorithm --> levels; each level only depends on earlier ones,
+//!                      tasks keep their insertion order within a level
+//! tasks left over --> TaskError::DependencyCycle
+//! ```
+//!
+//! Dependencies on tasks that are not part of the run are ignored, so
+//! `mob build modorganizer` does not pull in `usvfs`. Their own dependencies
+//! still count, so `mob build modorganizer uibase` builds `uibase` first even
+//! though `modorganizer` only depends on it through other projects.
+
+use std::collections::BTreeSet;
+
+use crate::error::TaskError;
+use crate::task::{Task, Taskable};
+
+/// One top-level task as seen by the scheduler.
+#[derive(Debug, Default)]
+pub(super) struct Node<'a> {
+    /// Name used in cycle errors.
+    pub(super) name: &'a str,
+
+    /// Names other tasks can depend on.
+    pub(super) provides: Vec<&'a str>,
+
+    /// Names of the tasks that must finish first.
+    pub(super) requires: Vec<&'a str>,
+}
+
+impl<'a> Node<'a> {
+    /// Creates the node of `task`, merging the children of a parallel group.
+    pub(super) fn of(task: &'a Task) -> Self {
+        let mut node = Self {
+            name: task.name(),
+            ..Self::default()
+        };
+        node.collect(task);
+        node
+    }
+
+    fn collect(&mut self, task: &'a Task) {
+        match task {
+            Task::Parallel(group) => {
+                for child in group.children() {
+                    self.collect(child);
+                }
+            }
+            Task::ModOrganizer(project) => {
+                self.provides.push(Taskable::name(project));
+                self.provides.push(project.repo_name());
+                self.requires.extend(Taskable::dependencies(project));
+            }
+            task => {
+                self.provides.push(task.name());
+                self.requires.extend(task.dependencies());
+            }
+        }
+    }
+
+    /// Returns the first required name that is in `names`, if any.
+    pub(super) fn requires_any(&self, names: &[&str]) -> Option<&'a str> {
+        self.requires
+            .iter()
+            .copied()
+            .find(|name| names.contains(name))
+    }
+}
+
+/// Creates the nodes of `tasks`, with the requirements of every task
+/// followed through the nodes of `graph`.
+pub(super) fn nodes<'a>(tasks: &'a [Task], graph: &'a [Task]) -> Vec<Node<'a>> {
+    let graph: Vec<_> = graph.iter().map(Node::of).collect();
+    let mut nodes: Vec<_> = tasks.iter().map(Node::of).collect();
+    for node in &mut nodes {
+        let mut pending = node.requires.clone();
+        while let Some(name) = pending.pop() {
+            for other in graph.iter().filter(|other| other.provides.contains(&name)) {
+                for &required in &other.requires {
+                    if !node.requires.contains(&required) {
+                        node.requires.push(required);
+                        pending.push(required);
+                    }
+                }
+            }
+        }
+    }
+    nodes
+}
+
+/// Groups the indices of `nodes` into levels that run one after the other.
+///
+/// Every node comes after the nodes providing its requirements; nodes of one
+/// level are independent of each other and keep their order in `nodes`.
+///
+/// # Errors
+///
+/// Returns [`TaskError::DependencyCycle`] if the requirements form a cycle.
+pub(super) fn levels(nodes: &[Node<'_>]) -> Result<Vec<Vec<usize>>, TaskError> {
+    let depends_on: Vec<BTreeSet<usize>> = nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| {
+            (0..nodes.len())
+                .filter(|&other| {
+                    other != index
+                        && node
+                            .requires
+                            .iter()
+                            .any(|name| nodes[other].provides.contains(name))
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut pending: Vec<usize> = depends_on.iter().map(BTreeSet::len).collect();
+    let mut done = vec![false; nodes.len()];
+    let mut levels = Vec::new();
+    let mut ready: Vec<usize> = (0..nodes.len()).filter(|&i| pending[i] == 0).collect();
+    while !ready.is_empty() {
+        for &index in &ready {
+            done[index] = true;
+        }
+        let mut next = Vec::new();
+        for (index, dependencies) in depends_on.iter().enumerate() {
+            if done[index] || pending[index] == 0 {
+                continue;
+            }
+            pending[index] -= ready.iter().filter(|i| dependencies.contains(i)).count();
+            if pending[index] == 0 {
+                next.push(index);
+            }
+        }
+        levels.push(std::mem::replace(&mut ready, next));
+    }
+
+    if let Some(start) = done.iter().position(|&done| !done) {
+        let cycle = find_cycle(nodes, &depends_on, &done, start);
+        return Err(TaskError::DependencyCycle(cycle));
+    }
+    Ok(levels)
+}
+
+/// Follows unfinished dependencies from `start` until a node repeats.
+///
+/// Every unfinished node still waits for another unfinished node, so the
+/// walk always ends in a cycle.
+fn find_cycle(
+    nodes: &[Node<'_>],
+    depends_on: &[BTreeSet<usize>],
+    done: &[bool],
+    start: usize,
+) -> Vec<String> {
+    let mut path = vec![start];
+    let mut current = start;
+    while let Some(&next) = depends_on[current].iter().find(|&&i| !done[i]) {
+        if let Some(position) = path.iter().position(|&i| i == next) {
+            path.drain(..position);
+            path.push(next);
+            break;
+        }
+        path.push(next);
+        current = next;
+    }
+    path.iter().map(|&i| nodes[i].name.to_string()).collect()
+}
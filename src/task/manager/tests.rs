@@ -5,6 +5,8 @@
 
 use std::sync::Arc;
 
+use tokio_util::sync::CancellationToken;
+
 use super::TaskManager;
 use crate::config::Config;
 use crate::task::{CleanFlags, ParallelTasks, Task};
@@ -16,7 +18,7 @@ fn test_config() -> Arc<Config> {
 #[test]
 fn test_task_manager_new() {
     let config = test_config();
-    let manager = TaskManager::new(config);
+    let manager = TaskManager::new(config, &CancellationToken::new());
 
     insta::assert_yaml_snapshot!(
         "task_manager_new",
@@ -32,7 +34,7 @@ fn test_task_manager_new() {
 #[test]
 fn test_task_manager_add() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     let counts: Vec<_> = [0, 1, 2]
         .into_iter()
@@ -48,17 +50,28 @@ fn test_task_manager_add() {
 #[test]
 fn test_task_manager_interrupt() {
     let config = test_config();
-    let manager = TaskManager::new(config);
+    let ctrl_c = CancellationToken::new();
+    let manager = TaskManager::new(config, &ctrl_c);
 
     assert!(!manager.is_cancelled());
     manager.interrupt_all();
     assert!(manager.is_cancelled());
+    assert!(!ctrl_c.is_cancelled());
+}
+
+#[test]
+fn test_task_manager_follows_ctrl_c_token() {
+    let ctrl_c = CancellationToken::new();
+    let manager = TaskManager::new(test_config(), &ctrl_c);
+
+    ctrl_c.cancel();
+    assert!(manager.is_cancelled());
 }
 
 #[test]
 fn test_task_manager_builder() {
     let config = test_config();
-    let manager = TaskManager::new(config)
+    let manager = TaskManager::new(config, &CancellationToken::new())
         .with_dry_run(true)
         .with_do_clean(true)
         .with_clean_flags(CleanFlags::REBUILD);
@@ -78,7 +91,7 @@ fn test_task_manager_builder() {
 #[test]
 fn test_task_manager_with_concurrency() {
     let config = test_config();
-    let manager = TaskManager::with_concurrency(config, 4);
+    let manager = TaskManager::with_concurrency(config, 4, &CancellationToken::new());
 
     insta::assert_yaml_snapshot!(
         "task_manager_concurrency",
@@ -91,7 +104,7 @@ fn test_task_manager_with_concurrency() {
 #[tokio::test]
 async fn test_task_manager_run_cancelled() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     // Add a task
     manager.add(Task::Parallel(ParallelTasks::new(vec![Task::Parallel(
@@ -106,3 +119,341 @@ async fn test_task_manager_run_cancelled() {
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("interrupted"));
 }
+
+#[tokio::test]
+async fn test_task_manager_continue_on_error() {
+    use crate::task::tasks::installer::InstallerTask;
+    use crate::task::tasks::licenses::LicensesTask;
+
+    // paths.install_licenses is missing, so the licenses task fails
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::default();
+    config.paths.licenses = Some(temp.path().to_path_buf());
+    let config = Arc::new(config);
+
+    let mut manager = TaskManager::new(Arc::clone(&config), &CancellationToken::new());
+    manager.add(Task::Licenses(LicensesTask::new()));
+    manager.add(Task::Licenses(LicensesTask::new()));
+    let error = manager.run_all().await.unwrap_err();
+    assert_eq!(error.to_string(), "Task 'licenses' failed");
+
+    // The installer depends on licenses and is skipped instead of run
+    let mut manager =
+        TaskManager::new(config, &CancellationToken::new()).with_continue_on_error(true);
+    assert!(manager.continue_on_error());
+    manager.add(Task::Installer(InstallerTask::new()));
+    manager.add(Task::Licenses(LicensesTask::new()));
+    let message = manager.run_all().await.unwrap_err().to_string();
+    assert!(
+        message.starts_with("2 task(s) failed:\n  licenses: "),
+        "{message}"
+    );
+    assert!(message.contains("paths.install_licenses"), "{message}");
+    assert!(
+        message.ends_with(
+            "\n  installer: task 'installer' failed because dependency 'licenses' failed"
+        ),
+        "{message}"
+    );
+}
+
+#[tokio::test]
+async fn test_task_manager_continue_on_error_only_skips_dependents_of_failed_tasks() {
+    use crate::task::registry::builtin::task_from_name;
+
+    // licenses fails next to modorganizer; translations only depends on
+    // modorganizer and still runs. Both are disabled, so they succeed.
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::parse(
+        "[tasks.modorganizer]\nenabled = false\n[tasks.translations]\nenabled = false\n",
+    )
+    .unwrap();
+    config.paths.licenses = Some(temp.path().to_path_buf());
+
+    let mut manager =
+        TaskManager::new(Arc::new(config), &CancellationToken::new()).with_continue_on_error(true);
+    for name in ["licenses", "modorganizer", "translations"] {
+        manager.add(task_from_name(name));
+    }
+    let message = manager.run_all().await.unwrap_err().to_string();
+    assert!(
+        message.starts_with("1 task(s) failed:\n  licenses: "),
+        "{message}"
+    );
+    assert!(!message.contains("translations"), "{message}");
+}
+
+#[tokio::test]
+async fn test_task_manager_plan_attributes_steps_to_tasks() {
+    use crate::task::plan::{Plan, PlannedAction};
+    use crate::task::tasks::stylesheets::StylesheetsTask;
+
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("downloads"));
+    config.paths.build = Some(temp.path().join("build"));
+    config.paths.install_stylesheets = Some(temp.path().join("install/stylesheets"));
+
+    let plan = Arc::new(Plan::new());
+    let mut manager = TaskManager::new(Arc::new(config), &CancellationToken::new())
+        .with_dry_run(true)
+        .with_do_build(false)
+        .with_plan(Arc::clone(&plan));
+    manager.add(Task::Parallel(ParallelTasks::new(vec![Task::Stylesheets(
+        StylesheetsTask::new(),
+    )])));
+    manager.run_all().await.unwrap();
+
+    let steps = plan.take();
+    assert!(!steps.is_empty());
+    assert!(steps.iter().all(|step| step.task() == "stylesheets"));
+    assert!(matches!(
+        steps[0].action(),
+        PlannedAction::Download { url, .. } if url.starts_with("https://")
+    ));
+    assert!(matches!(steps[1].action(), PlannedAction::Extract { .. }));
+}
+
+#[tokio::test]
+async fn test_task_manager_measure_records_phases() {
+    use crate::core::process::timing::ProcessTimings;
+    use crate::task::Phase;
+    use crate::task::tasks::stylesheets::StylesheetsTask;
+    use crate::task::timing::RunTimings;
+
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("downloads"));
+    config.paths.build = Some(temp.path().join("build"));
+    config.paths.install_stylesheets = Some(temp.path().join("install/stylesheets"));
+
+    let phases = Arc::new(RunTimings::new());
+    let processes = Arc::new(ProcessTimings::new());
+    let mut manager = TaskManager::new(Arc::new(config), &CancellationToken::new())
+        .with_dry_run(true)
+        .with_do_build(false)
+        .with_measure(Arc::clone(&phases), Arc::clone(&processes));
+    manager.add(Task::Stylesheets(StylesheetsTask::new()));
+    manager.run_all().await.unwrap();
+
+    let phases = phases.take();
+    assert_eq!(phases.len(), 1);
+    assert_eq!(phases[0].task(), "stylesheets");
+    assert_eq!(phases[0].phase(), Phase::Fetch);
+    // A dry run starts no process
+    assert!(processes.take().is_empty());
+}
+
+#[tokio::test]
+async fn test_task_manager_results_records_tasks() {
+    use crate::task::results::TaskResults;
+    use crate::task::tasks::stylesheets::StylesheetsTask;
+
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("downloads"));
+    config.paths.build = Some(temp.path().join("build"));
+    config.paths.install_stylesheets = Some(temp.path().join("install/stylesheets"));
+
+    let results = Arc::new(TaskResults::new());
+    let mut manager = TaskManager::new(Arc::new(config), &CancellationToken::new())
+        .with_dry_run(true)
+        .with_do_build(false)
+        .with_results(Arc::clone(&results));
+    manager.add(Task::Stylesheets(StylesheetsTask::new()));
+    manager.run_all().await.unwrap();
+
+    let results = results.take();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].task(), "stylesheets");
+    assert_eq!(results[0].failure(), None);
+}
+
+#[tokio::test]
+async fn test_task_manager_script_records_dry_run() {
+    use crate::core::process::script::{Script, ScriptStep};
+    use crate::task::tasks::stylesheets::StylesheetsTask;
+
+    let temp = tempfile::tempdir().expect("tempdir");
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("downloads"));
+    config.paths.build = Some(temp.path().join("build"));
+    config.paths.install_stylesheets = Some(temp.path().join("install/stylesheets"));
+
+    let script = Arc::new(Script::new());
+    let mut manager = TaskManager::new(Arc::new(config), &CancellationToken::new())
+        .with_dry_run(true)
+        .with_do_build(false)
+        .with_script(Arc::clone(&script));
+    manager.add(Task::Stylesheets(StylesheetsTask::new()));
+    manager.run_all().await.unwrap();
+
+    let steps = script.take();
+    assert!(matches!(steps.first(), Some(ScriptStep::Download { .. })));
+}
+
+#[test]
+fn test_task_manager_task_timeout() {
+    use std::time::Duration;
+
+    assert_eq!(
+        TaskManager::new(test_config(), &CancellationToken::new()).task_timeout(),
+        None
+    );
+
+    let config = Arc::new(Config::parse("[task]\ntimeout_secs = 90\n").unwrap());
+    let manager = TaskManager::with_concurrency(config, 2, &CancellationToken::new());
+    assert_eq!(manager.task_timeout(), Some(Duration::from_secs(90)));
+
+    let manager = manager.with_task_timeout(Duration::from_secs(5));
+    assert_eq!(manager.task_timeout(), Some(Duration::from_secs(5)));
+}
+
+#[tokio::test]
+async fn test_run_with_timeout_cancels_task() {
+    use std::time::Duration;
+
+    use super::run_with_timeout;
+    use crate::error::TaskError;
+
+    let token = CancellationToken::new();
+    let result = run_with_timeout("quick", Duration::from_secs(5), &token, async { Ok(()) }).await;
+    assert!(result.is_ok());
+    assert!(!token.is_cancelled());
+
+    let parent = CancellationToken::new();
+    let token = parent.child_token();
+    let stuck = async {
+        token.cancelled().await;
+        anyhow::bail!("stopped")
+    };
+    let error = run_with_timeout("stuck", Duration::from_millis(20), &token, stuck)
+        .await
+        .unwrap_err();
+    assert!(token.is_cancelled());
+    assert!(!parent.is_cancelled());
+    assert_eq!(error.to_string(), "timed out after 20ms");
+    assert!(matches!(
+        error.downcast_ref::<TaskError>(),
+        Some(TaskError::Interrupted(name)) if name == "stuck"
+    ));
+}
+
+fn node_names(tasks: &[Task]) -> Vec<Vec<String>> {
+    use super::schedule::{levels, nodes};
+    use crate::task::Taskable;
+    use crate::task::registry::builtin::default_task_tree;
+
+    let graph = default_task_tree();
+    let nodes = nodes(tasks, &graph);
+    levels(&nodes)
+        .unwrap()
+        .into_iter()
+        .map(|level| {
+            level
+                .into_iter()
+                .map(|i| tasks[i].name().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_schedule_default_task_tree() {
+    use crate::task::registry::builtin::default_task_tree;
+
+    let levels = node_names(&default_task_tree());
+    assert_eq!(levels.len(), 7, "{levels:?}");
+    assert_eq!(
+        levels[0],
+        [
+            "cmake_common",
+            "usvfs",
+            "stylesheets",
+            "explorerpp",
+            "licenses"
+        ]
+    );
+    assert_eq!(levels[1], ["modorganizer-uibase"]);
+    assert!(levels[2].contains(&"modorganizer-bsatk".to_string()));
+    assert!(levels[3].contains(&"modorganizer-bsapacker".to_string()));
+    assert!(levels[4].contains(&"modorganizer".to_string()));
+    assert_eq!(levels[5], ["translations"]);
+    assert_eq!(levels[6], ["installer"]);
+}
+
+#[test]
+fn test_schedule_ignores_missing_dependencies() {
+    use crate::task::registry::builtin::task_from_name;
+
+    // Short names still satisfy dependencies on the repository name
+    let tasks: Vec<_> = ["archive", "stylesheets", "uibase", "modorganizer"]
+        .into_iter()
+        .map(task_from_name)
+        .collect();
+    assert_eq!(
+        node_names(&tasks),
+        [
+            vec!["stylesheets", "uibase"],
+            vec!["archive"],
+            vec!["modorganizer"]
+        ]
+    );
+
+    // Missing tasks are not pulled in, but their dependencies still order
+    // the given ones
+    let tasks: Vec<_> = ["modorganizer", "uibase", "stylesheets"]
+        .into_iter()
+        .map(task_from_name)
+        .collect();
+    assert_eq!(
+        node_names(&tasks),
+        [vec!["uibase", "stylesheets"], vec!["modorganizer"]]
+    );
+}
+
+#[test]
+fn test_schedule_detects_cycles() {
+    use super::schedule::{Node, levels};
+    use crate::error::TaskError;
+
+    let nodes = [
+        Node {
+            name: "a",
+            provides: vec!["a"],
+            requires: vec![],
+        },
+        Node {
+            name: "b",
+            provides: vec!["b"],
+            requires: vec!["a", "d"],
+        },
+        Node {
+            name: "c",
+            provides: vec!["c"],
+            requires: vec!["b"],
+        },
+        Node {
+            name: "d",
+            provides: vec!["d"],
+            requires: vec!["c"],
+        },
+    ];
+    let error = levels(&nodes).unwrap_err();
+    assert!(matches!(&error, TaskError::DependencyCycle(cycle) if cycle == &["b", "d", "c", "b"]));
+    assert_eq!(error.to_string(), "task dependency cycle: b -> d -> c -> b");
+
+    let nodes = [
+        Node {
+            name: "a",
+            provides: vec!["a"],
+            requires: vec!["b"],
+        },
+        Node {
+            name: "b",
+            provides: vec!["b"],
+            requires: vec![],
+        },
+    ];
+    assert_eq!(levels(&nodes).unwrap(), [vec![1], vec![0]]);
+}
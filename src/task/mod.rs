@@ -25,6 +25,13 @@
 //!
 //! Task variants: Usvfs, ModOrganizer,
 //!   Stylesheets, Translations, ...
+//!
+//! Build phase: each non-group task waits for a permit of
+//! TaskContext::build_semaphore (--jobs N, default: logical CPUs); its fetch
+//! phase for one of TaskContext::fetch_semaphore (--fetch-parallelism N).
+//!
+//! Each phase of a task runs in a `task{task, phase}` span and each tool run
+//! in a nested `tool{tool}` span, so log lines say which task produced them.
 //! ```
 //!
 //! # Key Types
@@ -45,7 +52,9 @@
 //!
 //! - [`Taskable::name()`] - Returns the task name
 //! - [`Taskable::enabled()`] - Whether the task should run (default: `true`)
+//! - [`Taskable::dependencies()`] - Tasks that must finish first (default: none)
 //! - [`Taskable::do_clean()`] - Executes the clean phase
+//! - [`Taskable::clean_description()`] - What the clean phase would remove (default: nothing)
 //! - [`Taskable::do_fetch()`] - Executes the fetch phase
 //! - [`Taskable::do_build_and_install()`] - Executes the build and install phase
 //!
@@ -63,19 +72,41 @@
 
 pub mod helpers;
 pub mod manager;
+pub mod plan;
 pub mod registry;
+pub mod results;
 pub mod tasks;
+pub mod timing;
 pub mod tools;
 
 use bitflags::bitflags;
 use futures_util::future::BoxFuture;
+use futures_util::{FutureExt as _, StreamExt, stream};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 
 use crate::config::Config;
-use crate::error::Result;
+use crate::config::types::CmakeInstallMessage;
+use crate::core::env::container::Env;
+use crate::core::env::types::Arch;
+use crate::core::process::priority::ProcessPriority;
+use crate::core::process::script::Script;
+use crate::core::process::timing::ProcessTimings;
+use crate::error::{ConfigError, Result};
+use crate::git::query::is_git_repo;
+use crate::net::ProgressGroup;
 use crate::task::tools::ToolContext;
+use crate::task::tools::cmake::CmakeTool;
+use crate::task::tools::git::RemoteBranchCache;
 
+use plan::{Plan, PlanRecorder};
 use tasks::explorerpp::ExplorerPPTask;
 use tasks::installer::InstallerTask;
 use tasks::licenses::LicensesTask;
@@ -101,6 +132,63 @@ pub enum Phase {
     BuildAndInstall,
 }
 
+/// When `CMake` tasks run their install step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstallMode {
+    /// Always run `cmake --install`.
+    #[default]
+    Always,
+    /// Skip the install when no build output is newer than its installed copy
+    /// listed in `install_manifest.txt`.
+    OnlyChanged,
+}
+
+/// Whether download-and-extract tasks reuse what is already fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArchiveMode {
+    /// Download and extract as `global.redownload`/`global.reextract` say.
+    #[default]
+    FollowFlags,
+    /// Skip both when the cached archive matches its fetch stamp and the
+    /// extracted directory exists.
+    SkipExisting,
+}
+
+/// How a parallel group reports failing children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailureMode {
+    /// Clean and fetch stop at the first failing child; the build waits for
+    /// every child but returns only the first error and logs the others.
+    #[default]
+    FailFast,
+    /// Every child runs, and the returned error lists each failed child with
+    /// its error.
+    Aggregate,
+}
+
+/// Kind of external endpoint a task contacts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UrlKind {
+    /// Repository cloned or pulled with git.
+    Git,
+    /// Archive downloaded over HTTP.
+    Download,
+    /// Transifex API host.
+    Transifex,
+}
+
+impl UrlKind {
+    /// Returns the lowercase label used in listings.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Git => "git",
+            Self::Download => "download",
+            Self::Transifex => "transifex",
+        }
+    }
+}
+
 /// Controls which task phases are enabled.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct PhaseControl {
@@ -158,6 +246,18 @@ impl PhaseControl {
     pub const fn do_build(&self) -> bool {
         self.do_build
     }
+
+    /// Creates phase settings that enable exactly the given phases.
+    ///
+    /// Phases not present in `phases` are disabled.
+    #[must_use]
+    pub fn from_phases(phases: &[Phase]) -> Self {
+        Self {
+            do_clean: phases.contains(&Phase::Clean),
+            do_fetch: phases.contains(&Phase::Fetch),
+            do_build: phases.contains(&Phase::BuildAndInstall),
+        }
+    }
 }
 
 impl Phase {
@@ -178,6 +278,23 @@ impl Phase {
     }
 }
 
+impl std::str::FromStr for Phase {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "clean" => Ok(Self::Clean),
+            "fetch" => Ok(Self::Fetch),
+            "build" | "build_and_install" => Ok(Self::BuildAndInstall),
+            _ => Err(ConfigError::InvalidValue {
+                section: "build".to_string(),
+                key: "phases".to_string(),
+                message: format!("expected 'clean', 'fetch', or 'build', got '{s}'"),
+            }),
+        }
+    }
+}
+
 bitflags! {
     /// Flags controlling what gets cleaned during the Clean phase.
     ///
@@ -237,11 +354,27 @@ pub trait Taskable {
         true
     }
 
+    /// Returns the names of the tasks that must finish before this one.
+    ///
+    /// `TaskManager` orders its tasks by these; dependencies that are not
+    /// part of the run are ignored. Default implementation returns nothing.
+    fn dependencies(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
     /// Executes the clean phase.
     ///
     /// Clean flags are obtained from `ctx.clean_flags()`.
     fn do_clean<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>>;
 
+    /// Describes what `do_clean` would remove for `flags`, one line per
+    /// existing path, e.g. `delete directory C:/dev/build/usvfs`.
+    ///
+    /// Logged before a dry-run clean. Default implementation returns nothing.
+    fn clean_description(&self, _ctx: &TaskContext, _flags: CleanFlags) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Executes the fetch phase.
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>>;
 
@@ -268,6 +401,63 @@ pub struct TaskContext {
 
     /// Phase control toggles.
     phases: PhaseControl,
+
+    /// Extra environment variables passed on to tools.
+    env: Env,
+
+    /// Per-invocation `CMAKE_INSTALL_MESSAGE` override.
+    install_message: Option<CmakeInstallMessage>,
+
+    /// Whether unchanged installs are skipped.
+    install_mode: InstallMode,
+
+    /// Maximum concurrent fetches (clones, pulls, submodule jobs).
+    fetch_parallelism: NonZeroUsize,
+
+    /// Job count of each `cmake --build --parallel`, from `--jobs`.
+    build_jobs: Option<NonZeroUsize>,
+
+    /// Whether verified cached archives are reused.
+    archive_mode: ArchiveMode,
+
+    /// How parallel groups report failing children.
+    failure_mode: FailureMode,
+
+    /// Limits how many tasks build at once; unlimited if unset.
+    build_semaphore: Option<Arc<Semaphore>>,
+
+    /// Limits how many tasks fetch at once; unlimited if unset.
+    fetch_semaphore: Option<Arc<Semaphore>>,
+
+    /// Collects the duration of every finished phase for the run summary.
+    run_timings: Option<Arc<timing::RunTimings>>,
+
+    /// Collects the duration of every finished top-level phase for
+    /// `--measure`.
+    phase_timings: Option<Arc<timing::RunTimings>>,
+
+    /// Collects the duration of every process run for `--measure`.
+    process_timings: Option<Arc<ProcessTimings>>,
+
+    /// Collects the pass/fail result of every finished phase for
+    /// `build --report`.
+    results: Option<Arc<results::TaskResults>>,
+
+    /// Collects the commands of a dry run for `--emit-script`.
+    script: Option<Arc<Script>>,
+
+    /// Scheduling priority of the processes tools run (`--low-priority`).
+    priority: ProcessPriority,
+
+    /// Collects dry-run operations for `build --plan`, attributed to the
+    /// task this context is scoped to.
+    plan: Option<PlanRecorder>,
+
+    /// Remote branch checks shared by all tasks of the run.
+    branch_cache: Option<Arc<RemoteBranchCache>>,
+
+    /// Progress bars of the downloads of all tasks of the run.
+    progress: Option<ProgressGroup>,
 }
 
 impl TaskContext {
@@ -280,6 +470,24 @@ impl TaskContext {
             dry_run: false,
             clean_flags: CleanFlags::empty(),
             phases: PhaseControl::new(),
+            env: Env::new(),
+            install_message: None,
+            install_mode: InstallMode::Always,
+            fetch_parallelism: NonZeroUsize::MIN,
+            build_jobs: None,
+            archive_mode: ArchiveMode::FollowFlags,
+            failure_mode: FailureMode::FailFast,
+            build_semaphore: None,
+            fetch_semaphore: None,
+            run_timings: None,
+            phase_timings: None,
+            process_timings: None,
+            results: None,
+            script: None,
+            priority: ProcessPriority::Normal,
+            plan: None,
+            branch_cache: None,
+            progress: None,
         }
     }
 
@@ -313,6 +521,102 @@ impl TaskContext {
         self.phases
     }
 
+    /// Gets the environment overlay.
+    #[must_use]
+    pub const fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Gets the `CMAKE_INSTALL_MESSAGE` override, if any.
+    #[must_use]
+    pub const fn install_message(&self) -> Option<CmakeInstallMessage> {
+        self.install_message
+    }
+
+    /// Gets the install mode.
+    #[must_use]
+    pub const fn install_mode(&self) -> InstallMode {
+        self.install_mode
+    }
+
+    /// Gets the maximum number of concurrent fetches.
+    #[must_use]
+    pub const fn fetch_parallelism(&self) -> NonZeroUsize {
+        self.fetch_parallelism
+    }
+
+    /// Gets the job count of each `cmake --build --parallel`, if any.
+    #[must_use]
+    pub const fn build_jobs(&self) -> Option<NonZeroUsize> {
+        self.build_jobs
+    }
+
+    /// Gets the archive mode.
+    #[must_use]
+    pub const fn archive_mode(&self) -> ArchiveMode {
+        self.archive_mode
+    }
+
+    /// Gets how parallel groups report failing children.
+    #[must_use]
+    pub const fn failure_mode(&self) -> FailureMode {
+        self.failure_mode
+    }
+
+    /// Gets the semaphore limiting concurrent builds, if any.
+    #[must_use]
+    pub const fn build_semaphore(&self) -> Option<&Arc<Semaphore>> {
+        self.build_semaphore.as_ref()
+    }
+
+    /// Gets the semaphore limiting concurrent fetches, if any.
+    #[must_use]
+    pub const fn fetch_semaphore(&self) -> Option<&Arc<Semaphore>> {
+        self.fetch_semaphore.as_ref()
+    }
+
+    /// Gets the recorder of dry-run operations, if any.
+    #[must_use]
+    pub const fn plan(&self) -> Option<&PlanRecorder> {
+        self.plan.as_ref()
+    }
+
+    /// Gets the collector of phase durations, if any.
+    #[must_use]
+    pub const fn run_timings(&self) -> Option<&Arc<timing::RunTimings>> {
+        self.run_timings.as_ref()
+    }
+
+    /// Gets the collector of `--measure` phase durations, if any.
+    #[must_use]
+    pub const fn phase_timings(&self) -> Option<&Arc<timing::RunTimings>> {
+        self.phase_timings.as_ref()
+    }
+
+    /// Gets the collector of `--measure` process durations, if any.
+    #[must_use]
+    pub const fn process_timings(&self) -> Option<&Arc<ProcessTimings>> {
+        self.process_timings.as_ref()
+    }
+
+    /// Gets the scheduling priority of the processes tools run.
+    #[must_use]
+    pub const fn priority(&self) -> ProcessPriority {
+        self.priority
+    }
+
+    /// Gets the collector of `build --report` task results, if any.
+    #[must_use]
+    pub const fn results(&self) -> Option<&Arc<results::TaskResults>> {
+        self.results.as_ref()
+    }
+
+    /// Gets the `--emit-script` collector, if any.
+    #[must_use]
+    pub const fn script(&self) -> Option<&Arc<Script>> {
+        self.script.as_ref()
+    }
+
     /// Sets dry-run mode.
     #[must_use]
     pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
@@ -327,6 +631,138 @@ impl TaskContext {
         self
     }
 
+    /// Sets the archive mode.
+    #[must_use]
+    pub const fn with_archive_mode(mut self, mode: ArchiveMode) -> Self {
+        self.archive_mode = mode;
+        self
+    }
+
+    /// Sets how parallel groups report failing children.
+    #[must_use]
+    pub const fn with_failure_mode(mut self, mode: FailureMode) -> Self {
+        self.failure_mode = mode;
+        self
+    }
+
+    /// Sets the environment overlay.
+    #[must_use]
+    pub fn with_env(mut self, env: Env) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Replaces the cancellation token, e.g. with a child token that can be
+    /// cancelled on its own.
+    #[must_use]
+    pub fn with_cancel_token(mut self, cancel_token: CancellationToken) -> Self {
+        self.cancel_token = cancel_token;
+        self
+    }
+
+    /// Sets the semaphore limiting concurrent builds.
+    #[must_use]
+    pub fn with_build_semaphore(mut self, semaphore: Arc<Semaphore>) -> Self {
+        self.build_semaphore = Some(semaphore);
+        self
+    }
+
+    /// Sets the semaphore limiting concurrent fetches.
+    #[must_use]
+    pub fn with_fetch_semaphore(mut self, semaphore: Arc<Semaphore>) -> Self {
+        self.fetch_semaphore = Some(semaphore);
+        self
+    }
+
+    /// Sets the plan that collects the dry-run operations of every task.
+    #[must_use]
+    pub fn with_plan(mut self, plan: Arc<Plan>) -> Self {
+        self.plan = Some(PlanRecorder::new(plan, String::new()));
+        self
+    }
+
+    /// Sets the cache of remote branch checks shared by every task.
+    #[must_use]
+    pub fn with_branch_cache(mut self, cache: Arc<RemoteBranchCache>) -> Self {
+        self.branch_cache = Some(cache);
+        self
+    }
+
+    /// Sets the group of progress bars shared by every download of the run.
+    #[must_use]
+    pub fn with_progress_group(mut self, progress: ProgressGroup) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Sets the collector that records the duration of every finished phase.
+    #[must_use]
+    pub fn with_run_timings(mut self, timings: Arc<timing::RunTimings>) -> Self {
+        self.run_timings = Some(timings);
+        self
+    }
+
+    /// Sets the collectors of the `--measure` phase and process durations.
+    #[must_use]
+    pub fn with_measure(
+        mut self,
+        phases: Arc<timing::RunTimings>,
+        processes: Arc<ProcessTimings>,
+    ) -> Self {
+        self.phase_timings = Some(phases);
+        self.process_timings = Some(processes);
+        self
+    }
+
+    /// Sets the scheduling priority of the processes tools run.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: ProcessPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets the collector that records the result of every finished phase.
+    #[must_use]
+    pub fn with_results(mut self, results: Arc<results::TaskResults>) -> Self {
+        self.results = Some(results);
+        self
+    }
+
+    /// Sets the script that collects dry-run commands for `--emit-script`.
+    #[must_use]
+    pub fn with_script(mut self, script: Arc<Script>) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    /// Sets the maximum number of concurrent fetches.
+    #[must_use]
+    pub const fn with_fetch_parallelism(mut self, jobs: NonZeroUsize) -> Self {
+        self.fetch_parallelism = jobs;
+        self
+    }
+
+    /// Sets the job count of each `cmake --build --parallel`.
+    #[must_use]
+    pub const fn with_build_jobs(mut self, jobs: Option<NonZeroUsize>) -> Self {
+        self.build_jobs = jobs;
+        self
+    }
+
+    /// Sets the `CMAKE_INSTALL_MESSAGE` override.
+    #[must_use]
+    pub const fn with_install_message(mut self, message: Option<CmakeInstallMessage>) -> Self {
+        self.install_message = message;
+        self
+    }
+
+    /// Sets the install mode.
+    #[must_use]
+    pub const fn with_install_mode(mut self, mode: InstallMode) -> Self {
+        self.install_mode = mode;
+        self
+    }
+
     /// Enables the clean phase.
     #[must_use]
     pub const fn with_do_clean(mut self, enable: bool) -> Self {
@@ -354,14 +790,44 @@ impl TaskContext {
         self.cancel_token.is_cancelled()
     }
 
+    /// Creates the span a `phase` of `task` runs in.
+    ///
+    /// Log events inside it carry the `task` and `phase` fields.
+    #[must_use]
+    pub fn task_span(task: &str, phase: Phase) -> tracing::Span {
+        tracing::info_span!("task", task = %task, phase = phase.name())
+    }
+
     /// Creates a `ToolContext` from this `TaskContext`.
     #[must_use]
     pub fn tool_context(&self) -> ToolContext {
-        ToolContext::new(
+        let tool_ctx = ToolContext::new(
             Arc::clone(&self.config),
             self.cancel_token.clone(),
             self.dry_run,
         )
+        .with_env(self.env.clone())
+        .with_priority(self.priority);
+        let tool_ctx = match &self.branch_cache {
+            Some(cache) => tool_ctx.with_branch_cache(Arc::clone(cache)),
+            None => tool_ctx,
+        };
+        let tool_ctx = match &self.progress {
+            Some(progress) => tool_ctx.with_progress_group(progress.clone()),
+            None => tool_ctx,
+        };
+        let tool_ctx = match &self.process_timings {
+            Some(timings) => tool_ctx.with_process_timings(Arc::clone(timings)),
+            None => tool_ctx,
+        };
+        let tool_ctx = match &self.script {
+            Some(script) => tool_ctx.with_script(Arc::clone(script)),
+            None => tool_ctx,
+        };
+        match &self.plan {
+            Some(plan) => tool_ctx.with_plan(plan.clone()),
+            None => tool_ctx,
+        }
     }
 }
 
@@ -415,20 +881,58 @@ impl Taskable for ParallelTasks {
         Box::pin(async move {
             // For parallel tasks, clean children sequentially
             // (parallel execution happens in build phase)
+            let mut failures = Vec::new();
             for child in &self.children {
-                child.do_clean(ctx).await?;
+                let scoped = child.plan_context(ctx);
+                let started = Instant::now();
+                let result = child
+                    .do_clean(scoped.as_ref().unwrap_or(ctx))
+                    .instrument(child.phase_span(Phase::Clean))
+                    .await;
+                child.record_result(ctx, Phase::Clean, started, &result);
+                match result {
+                    Err(e) if ctx.failure_mode() == FailureMode::Aggregate => {
+                        failures.push((child.name().to_string(), e));
+                    }
+                    result => result?,
+                }
             }
-            Ok(())
+            failures_result(failures)
         })
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.children
+            .iter()
+            .flat_map(|child| child.clean_description(ctx, flags))
+            .collect()
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
-            // For parallel tasks, fetch children sequentially
-            for child in &self.children {
-                child.do_fetch(ctx).await?;
+            // Fetch up to `fetch_parallelism` children at once; 1 keeps them
+            // sequential. Unless failures are aggregated, the first error
+            // drops the fetches still running.
+            let pending: Vec<_> = self
+                .children
+                .iter()
+                .map(|child| {
+                    child
+                        .fetch_recorded(ctx)
+                        .map(move |result| (child.name(), result))
+                })
+                .collect();
+            let mut fetches = stream::iter(pending).buffer_unordered(ctx.fetch_parallelism().get());
+            let mut failures = Vec::new();
+            while let Some((name, result)) = fetches.next().await {
+                match result {
+                    Err(e) if ctx.failure_mode() == FailureMode::Aggregate => {
+                        failures.push((name.to_string(), e));
+                    }
+                    result => result?,
+                }
             }
-            Ok(())
+            failures_result(failures)
         })
     }
 
@@ -437,27 +941,57 @@ impl Taskable for ParallelTasks {
             use tokio::task::JoinSet;
             let mut set = JoinSet::new();
 
+            // Names are only looked up for panicked tasks, which return nothing.
+            let mut names = BTreeMap::new();
             for child in &self.children {
                 let child = child.clone();
                 let ctx = ctx.clone();
-                set.spawn(async move { child.do_build_and_install_owned(ctx).await });
+                let name = child.name().to_string();
+                let handle = set.spawn(async move {
+                    let name = child.name().to_string();
+                    let result = async {
+                        let ctx = child.plan_context(&ctx).unwrap_or(ctx);
+                        let _permit = child.build_permit(&ctx).await?;
+                        let started = Instant::now();
+                        let result = child
+                            .clone()
+                            .do_build_and_install_owned(ctx.clone())
+                            .instrument(child.phase_span(Phase::BuildAndInstall))
+                            .await;
+                        child.record_result(&ctx, Phase::BuildAndInstall, started, &result);
+                        result
+                    }
+                    .await;
+                    (name, result)
+                });
+                names.insert(handle.id(), name);
             }
 
             // Wait for all and collect errors
-            let mut errors = Vec::new();
+            let mut failures = Vec::new();
             while let Some(result) = set.join_next().await {
                 match result {
-                    Ok(Ok(())) => {}
-                    Ok(Err(e)) => errors.push(e),
-                    Err(e) => errors.push(anyhow::anyhow!("Task panicked: {e}")),
+                    Ok((_, Ok(()))) => {}
+                    Ok((name, Err(e))) => failures.push((name, e)),
+                    Err(e) => failures.push((
+                        names
+                            .get(&e.id())
+                            .cloned()
+                            .unwrap_or_else(|| "<unknown>".to_string()),
+                        anyhow::anyhow!("Task panicked: {e}"),
+                    )),
                 }
             }
 
-            if let Some(first_error) = errors.first() {
-                for (i, e) in errors.iter().enumerate().skip(1) {
-                    tracing::error!(error = %e, task_index = i + 1, "Additional parallel task error");
+            if ctx.failure_mode() == FailureMode::Aggregate {
+                return failures_result(failures);
+            }
+            let mut failures = failures.into_iter().enumerate();
+            if let Some((_, (_, first_error))) = failures.next() {
+                for (i, (task, e)) in failures {
+                    tracing::error!(error = %e, task = %task, task_index = i + 1, "Additional parallel task error");
                 }
-                return Err(anyhow::anyhow!("{first_error}"));
+                return Err(first_error);
             }
 
             Ok(())
@@ -465,6 +999,27 @@ impl Taskable for ParallelTasks {
     }
 }
 
+/// Returns an error listing every failure, or `Ok` if there is none.
+///
+/// The first failure stays the source of the returned error, so its typed
+/// chain still decides the exit code.
+fn failures_result(failures: Vec<(String, anyhow::Error)>) -> Result<()> {
+    let message = format_failures(&failures);
+    match failures.into_iter().next() {
+        Some((_, first_error)) => Err(first_error.context(message)),
+        None => Ok(()),
+    }
+}
+
+/// Lists every failed task with its error chain.
+fn format_failures(failures: &[(String, anyhow::Error)]) -> String {
+    let mut message = format!("{} task(s) failed:", failures.len());
+    for (task, error) in failures {
+        let _ = write!(message, "\n  {task}: {error:#}");
+    }
+    message
+}
+
 /// A build task.
 ///
 /// This enum uses compile-time dispatch for zero-cost abstraction.
@@ -502,6 +1057,8 @@ impl Task {
             tracing::debug!(task = %Taskable::name(self), "Skipping disabled task");
             return Ok(());
         }
+        let scoped = self.plan_context(ctx);
+        let ctx = scoped.as_ref().unwrap_or(ctx);
 
         // Clean phase
         if ctx.phases().do_clean() && !ctx.clean_flags().is_empty() {
@@ -511,7 +1068,18 @@ impl Task {
                     Taskable::name(self)
                 );
             }
-            Taskable::do_clean(self, ctx).await?;
+            if ctx.is_dry_run() {
+                for line in Taskable::clean_description(self, ctx, ctx.clean_flags()) {
+                    tracing::info!(task = %Taskable::name(self), "[DRY-RUN] clean would {line}");
+                }
+            }
+            let started = Instant::now();
+            let result = Taskable::do_clean(self, ctx)
+                .instrument(self.phase_span(Phase::Clean))
+                .await;
+            self.record_result(ctx, Phase::Clean, started, &result);
+            result?;
+            self.record_timing(ctx, Phase::Clean, started);
         }
 
         // Fetch phase
@@ -522,7 +1090,15 @@ impl Task {
                     Taskable::name(self)
                 );
             }
-            Taskable::do_fetch(self, ctx).await?;
+            let permit = self.fetch_permit(ctx).await?;
+            let started = Instant::now();
+            let result = Taskable::do_fetch(self, ctx)
+                .instrument(self.phase_span(Phase::Fetch))
+                .await;
+            drop(permit);
+            self.record_result(ctx, Phase::Fetch, started, &result);
+            result?;
+            self.record_timing(ctx, Phase::Fetch, started);
         }
 
         // Build and install phase
@@ -533,12 +1109,248 @@ impl Task {
                     Taskable::name(self)
                 );
             }
-            Taskable::do_build_and_install(self, ctx).await?;
+            let permit = self.build_permit(ctx).await?;
+            let started = Instant::now();
+            let result = Taskable::do_build_and_install(self, ctx)
+                .instrument(self.phase_span(Phase::BuildAndInstall))
+                .await;
+            drop(permit);
+            self.record_result(ctx, Phase::BuildAndInstall, started, &result);
+            result?;
+            self.record_timing(ctx, Phase::BuildAndInstall, started);
         }
 
         Ok(())
     }
 
+    /// Returns the span `phase` of this task runs in.
+    ///
+    /// Parallel groups get none; their children open their own.
+    fn phase_span(&self, phase: Phase) -> tracing::Span {
+        if matches!(self, Self::Parallel(_)) {
+            tracing::Span::none()
+        } else {
+            TaskContext::task_span(Taskable::name(self), phase)
+        }
+    }
+
+    /// Records how long `phase` of this task ran since `started`, if `ctx`
+    /// collects `--measure` timings.
+    fn record_timing(&self, ctx: &TaskContext, phase: Phase, started: Instant) {
+        if let Some(timings) = ctx.phase_timings() {
+            timings.record(timing::PhaseTiming::new(
+                Taskable::name(self).to_string(),
+                phase,
+                started.elapsed(),
+            ));
+        }
+    }
+
+    /// Returns the `CMake` configure steps this task would run.
+    ///
+    /// Tasks without a `CMake` configure step return an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the paths needed by a configure step are not configured.
+    pub fn cmake_configure_tools(&self, config: &Config) -> Result<Vec<CmakeTool>> {
+        match self {
+            Self::Parallel(p) => {
+                let mut tools = Vec::new();
+                for child in p.children() {
+                    tools.extend(child.cmake_configure_tools(config)?);
+                }
+                Ok(tools)
+            }
+            Self::ModOrganizer(t) => Ok(vec![t.cmake_configure_tool(config)?]),
+            Self::Usvfs(_) => [Arch::X64, Arch::X86]
+                .into_iter()
+                .map(|arch| UsvfsTask::cmake_configure_tool(config, arch))
+                .collect(),
+            Self::Stylesheets(_)
+            | Self::ExplorerPP(_)
+            | Self::Licenses(_)
+            | Self::Translations(_)
+            | Self::Installer(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns every external URL the fetch phase of this task may contact.
+    ///
+    /// Built from the same functions the fetch phase uses; nothing is
+    /// downloaded. Licenses are copied locally and contact nothing.
+    #[must_use]
+    pub fn external_urls(&self, config: &Config) -> Vec<(UrlKind, String)> {
+        match self {
+            Self::Parallel(p) => p
+                .children()
+                .iter()
+                .flat_map(|child| child.external_urls(config))
+                .collect(),
+            Self::ModOrganizer(t) => vec![(UrlKind::Git, t.git_url(config))],
+            Self::Usvfs(_) => vec![(UrlKind::Git, UsvfsTask::git_url(config))],
+            Self::Installer(_) => vec![(UrlKind::Git, InstallerTask::git_url(config))],
+            Self::Stylesheets(_) => StylesheetsTask::download_urls(config)
+                .into_iter()
+                .map(|url| (UrlKind::Download, url))
+                .collect(),
+            Self::ExplorerPP(_) => vec![(UrlKind::Download, ExplorerPPTask::download_url(config))],
+            Self::Translations(_) => vec![(UrlKind::Transifex, config.transifex.url.clone())],
+            Self::Licenses(_) => Vec::new(),
+        }
+    }
+
+    /// Returns the source paths of this task that are not present locally.
+    ///
+    /// Git-cloned sources (`ModOrganizer`, USVFS, installer) count as present
+    /// only when they are a git repository; downloaded stylesheets only need to
+    /// exist. Tasks without a local source return an empty list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.build` is not configured.
+    pub fn missing_sources(&self, config: &Config) -> Result<Vec<PathBuf>> {
+        match self {
+            Self::Parallel(p) => {
+                let mut missing = Vec::new();
+                for child in p.children() {
+                    missing.extend(child.missing_sources(config)?);
+                }
+                Ok(missing)
+            }
+            Self::ModOrganizer(_) | Self::Usvfs(_) | Self::Installer(_) => {
+                self.missing_repos(config)
+            }
+            Self::Stylesheets(_) => Ok(StylesheetsTask::build_paths(config)?
+                .into_iter()
+                .filter(|path| !path.exists())
+                .collect()),
+            Self::ExplorerPP(_) | Self::Licenses(_) | Self::Translations(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the git-cloned source paths of this task that are not a git
+    /// repository yet, i.e. the repositories the fetch phase would clone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.build` is not configured.
+    pub fn missing_repos(&self, config: &Config) -> Result<Vec<PathBuf>> {
+        let missing_repo = |path: PathBuf| {
+            if is_git_repo(&path) {
+                vec![]
+            } else {
+                vec![path]
+            }
+        };
+
+        match self {
+            Self::Parallel(p) => {
+                let mut missing = Vec::new();
+                for child in p.children() {
+                    missing.extend(child.missing_repos(config)?);
+                }
+                Ok(missing)
+            }
+            Self::ModOrganizer(t) => Ok(missing_repo(t.source_path(config)?)),
+            Self::Usvfs(_) => Ok(missing_repo(UsvfsTask::source_path(config)?)),
+            Self::Installer(_) => Ok(missing_repo(InstallerTask::source_path(config)?)),
+            Self::Stylesheets(_)
+            | Self::ExplorerPP(_)
+            | Self::Licenses(_)
+            | Self::Translations(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Records the result of `phase` that ran since `started` in `ctx`'s
+    /// results and, if it succeeded, its duration in `ctx`'s run timings.
+    ///
+    /// Parallel groups are skipped; their children record themselves.
+    fn record_result(
+        &self,
+        ctx: &TaskContext,
+        phase: Phase,
+        started: Instant,
+        result: &Result<()>,
+    ) {
+        if matches!(self, Self::Parallel(_)) {
+            return;
+        }
+        let elapsed = started.elapsed();
+        if let Some(results) = ctx.results() {
+            results.record(Taskable::name(self), elapsed, result);
+        }
+        if result.is_err() {
+            return;
+        }
+        tracing::debug!(
+            task = %Taskable::name(self),
+            phase = phase.name(),
+            elapsed_ms = elapsed.as_millis(),
+            "Phase finished"
+        );
+        if let Some(timings) = ctx.run_timings() {
+            timings.record(timing::PhaseTiming::new(
+                Taskable::name(self).to_string(),
+                phase,
+                elapsed,
+            ));
+        }
+    }
+
+    /// Returns `ctx` with its plan recorder scoped to this task, or `None`
+    /// without a plan and for parallel groups, whose children scope themselves.
+    fn plan_context(&self, ctx: &TaskContext) -> Option<TaskContext> {
+        match ctx.plan() {
+            Some(plan) if !matches!(self, Self::Parallel(_)) => {
+                let mut scoped = ctx.clone();
+                scoped.plan = Some(plan.for_task(Taskable::name(self)));
+                Some(scoped)
+            }
+            _ => None,
+        }
+    }
+
+    /// Waits for a build slot of `ctx`'s build semaphore.
+    ///
+    /// Parallel groups don't take a slot, so a nested group never holds one
+    /// while its children wait for theirs.
+    async fn build_permit(&self, ctx: &TaskContext) -> Result<Option<OwnedSemaphorePermit>> {
+        match ctx.build_semaphore() {
+            Some(semaphore) if !matches!(self, Self::Parallel(_)) => {
+                Ok(Some(Arc::clone(semaphore).acquire_owned().await?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Waits for a fetch slot of `ctx`'s fetch semaphore.
+    ///
+    /// Like [`Self::build_permit`], parallel groups don't take a slot.
+    async fn fetch_permit(&self, ctx: &TaskContext) -> Result<Option<OwnedSemaphorePermit>> {
+        match ctx.fetch_semaphore() {
+            Some(semaphore) if !matches!(self, Self::Parallel(_)) => {
+                Ok(Some(Arc::clone(semaphore).acquire_owned().await?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Runs the fetch phase and records its result.
+    fn fetch_recorded<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let scoped = self.plan_context(ctx);
+            let _permit = self.fetch_permit(ctx).await?;
+            let started = Instant::now();
+            let result = self
+                .do_fetch(scoped.as_ref().unwrap_or(ctx))
+                .instrument(self.phase_span(Phase::Fetch))
+                .await;
+            self.record_result(ctx, Phase::Fetch, started, &result);
+            result
+        })
+    }
+
     /// Owned version of `do_build_and_install` for spawning tasks.
     /// Takes owned `TaskContext` to avoid lifetime issues with `tokio::spawn`.
     pub(crate) fn do_build_and_install_owned(
@@ -556,7 +1368,18 @@ impl Task {
 
                     for child in p.into_children() {
                         let ctx = ctx.clone();
-                        set.spawn(async move { child.do_build_and_install_owned(ctx).await });
+                        set.spawn(async move {
+                            let ctx = child.plan_context(&ctx).unwrap_or(ctx);
+                            let _permit = child.build_permit(&ctx).await?;
+                            let started = Instant::now();
+                            let result = child
+                                .clone()
+                                .do_build_and_install_owned(ctx.clone())
+                                .instrument(child.phase_span(Phase::BuildAndInstall))
+                                .await;
+                            child.record_result(&ctx, Phase::BuildAndInstall, started, &result);
+                            result
+                        });
                     }
 
                     // Wait for all and collect errors
@@ -569,13 +1392,13 @@ impl Task {
                         }
                     }
 
-                    if let Some(first_error) = errors.first() {
+                    let mut errors = errors.into_iter().enumerate();
+                    if let Some((_, first_error)) = errors.next() {
                         // Log additional errors beyond the first
-                        for (i, e) in errors.iter().enumerate().skip(1) {
+                        for (i, e) in errors {
                             tracing::error!(error = %e, task_index = i + 1, "Additional parallel task error");
                         }
-                        // Safe: we just checked errors.first() is Some
-                        return Err(anyhow::anyhow!("{first_error}"));
+                        return Err(first_error);
                     }
                 }
                 Self::ModOrganizer(t) => {
@@ -622,12 +1445,24 @@ macro_rules! impl_taskable_for_task {
                 }
             }
 
+            fn dependencies(&self) -> Vec<&str> {
+                match self {
+                    $(Task::$variant(t) => Taskable::dependencies(t),)+
+                }
+            }
+
             fn do_clean<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
                 match self {
                     $(Task::$variant(t) => Taskable::do_clean(t, ctx),)+
                 }
             }
 
+            fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+                match self {
+                    $(Task::$variant(t) => Taskable::clean_description(t, ctx, flags),)+
+                }
+            }
+
             fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
                 match self {
                     $(Task::$variant(t) => Taskable::do_fetch(t, ctx),)+
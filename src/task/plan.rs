@@ -0,0 +1,197 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Operations a dry run would perform, collected per task for `build --plan`.
+//!
+//! ```text
+//! TaskManager::with_plan(plan)
+//!       |
+//! Task::run()  --> TaskContext scoped to the task name
+//!       |
+//! tool dry-run branch
+//!   ToolContext::record_command(&ProcessBuilder)   also --emit-script
+//!   ToolContext::record_download(url, file)        also --emit-script
+//!   ToolContext::record_plan(Extract { .. })
+//!                           |
+//!                           v
+//!                 Plan (shared by the run, in order)
+//!                           |
+//!                           v
+//!          Plan::take() --> format_plan() --> tree per task
+//! ```
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// One operation of a dry run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlannedAction {
+    /// A process invocation, such as `git clone` or a `cmake` configure.
+    Command {
+        /// Executable to run.
+        program: PathBuf,
+        /// Arguments, unquoted.
+        args: Vec<String>,
+        /// Working directory, if set.
+        cwd: Option<PathBuf>,
+    },
+    /// An HTTP download.
+    Download {
+        /// Source URL.
+        url: String,
+        /// Destination file.
+        file: PathBuf,
+    },
+    /// An archive extraction.
+    Extract {
+        /// Archive to extract.
+        archive: PathBuf,
+        /// Directory the archive is extracted into.
+        output: PathBuf,
+    },
+}
+
+impl std::fmt::Display for PlannedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Command { program, args, cwd } => {
+                write!(f, "run {}", program.display())?;
+                for arg in args {
+                    write!(f, " {arg}")?;
+                }
+                if let Some(cwd) = cwd {
+                    write!(f, " (in {})", cwd.display())?;
+                }
+                Ok(())
+            }
+            Self::Download { url, file } => {
+                write!(f, "download {url} -> {}", file.display())
+            }
+            Self::Extract { archive, output } => {
+                write!(f, "extract {} -> {}", archive.display(), output.display())
+            }
+        }
+    }
+}
+
+/// An operation and the task that would perform it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedStep {
+    task: String,
+    action: PlannedAction,
+}
+
+impl PlannedStep {
+    /// Creates a new step.
+    #[must_use]
+    pub const fn new(task: String, action: PlannedAction) -> Self {
+        Self { task, action }
+    }
+
+    /// Returns the task name.
+    #[must_use]
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    /// Returns the operation.
+    #[must_use]
+    pub const fn action(&self) -> &PlannedAction {
+        &self.action
+    }
+}
+
+/// Planned steps of one `TaskManager` run, shared by all of its tasks.
+#[derive(Debug, Default)]
+pub struct Plan {
+    steps: Mutex<Vec<PlannedStep>>,
+}
+
+impl Plan {
+    /// Creates an empty plan.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `step`.
+    pub fn record(&self, step: PlannedStep) {
+        self.steps
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(step);
+    }
+
+    /// Returns and clears the recorded steps.
+    #[must_use]
+    pub fn take(&self) -> Vec<PlannedStep> {
+        std::mem::take(
+            &mut *self
+                .steps
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+}
+
+/// A [`Plan`] together with the task whose steps are recorded into it.
+#[derive(Debug, Clone)]
+pub struct PlanRecorder {
+    plan: Arc<Plan>,
+    task: String,
+}
+
+impl PlanRecorder {
+    /// Creates a recorder for `task`.
+    #[must_use]
+    pub const fn new(plan: Arc<Plan>, task: String) -> Self {
+        Self { plan, task }
+    }
+
+    /// Returns a recorder into the same plan for `task`.
+    #[must_use]
+    pub fn for_task(&self, task: &str) -> Self {
+        Self::new(Arc::clone(&self.plan), task.to_string())
+    }
+
+    /// Records `action` for this recorder's task.
+    pub fn record(&self, action: PlannedAction) {
+        self.plan
+            .record(PlannedStep::new(self.task.clone(), action));
+    }
+}
+
+/// Formats `steps` as a tree with one branch per task, in first-seen order.
+#[must_use]
+pub fn format_plan(steps: &[PlannedStep]) -> String {
+    let mut tasks: Vec<(&str, Vec<&PlannedAction>)> = Vec::new();
+    for step in steps {
+        match tasks.iter_mut().find(|(task, _)| *task == step.task) {
+            Some((_, actions)) => actions.push(&step.action),
+            None => tasks.push((&step.task, vec![&step.action])),
+        }
+    }
+
+    let mut out = format!("Build plan ({} steps)\n", steps.len());
+    for (index, (task, actions)) in tasks.iter().enumerate() {
+        let last_task = index + 1 == tasks.len();
+        let (branch, indent) = if last_task {
+            ("└─", "   ")
+        } else {
+            ("├─", "│  ")
+        };
+        let _ = writeln!(out, "{branch} {task}");
+        for (index, action) in actions.iter().enumerate() {
+            let leaf = if index + 1 == actions.len() {
+                "└─"
+            } else {
+                "├─"
+            };
+            let _ = writeln!(out, "{indent}{leaf} {action}");
+        }
+    }
+    out
+}
@@ -0,0 +1,255 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Canonical task lists shared by `build`, `list` and `release official`.
+//!
+//! ```text
+//! BUILTIN_TASKS (build order) --> builtin_tasks(config)   release pipeline
+//! DEFAULT_MO_PROJECTS + builtins --> default_task_tree()  build, no task given
+//!   (project dependencies from the build groups of DEFAULT_MO_PROJECTS)
+//! TaskRegistry::for_config(config): config tasks, MO projects, builtins
+//! task_from_name(name) --> Task (alternate names resolved)
+//! ```
+
+use crate::config::Config;
+use crate::task::Task;
+use crate::task::tasks::explorerpp::ExplorerPPTask;
+use crate::task::tasks::installer::InstallerTask;
+use crate::task::tasks::licenses::LicensesTask;
+use crate::task::tasks::modorganizer::ModOrganizerTask;
+use crate::task::tasks::stylesheets::StylesheetsTask;
+use crate::task::tasks::translations::TranslationsTask;
+use crate::task::tasks::usvfs::UsvfsTask;
+
+use super::TaskRegistry;
+
+/// Built-in task names in build order.
+///
+/// Apart from `modorganizer`, each has a dedicated task type instead of
+/// `ModOrganizerTask`.
+pub const BUILTIN_TASKS: &[&str] = &[
+    "usvfs",
+    "modorganizer",
+    "stylesheets",
+    "explorerpp",
+    "licenses",
+    "translations",
+    "installer",
+];
+
+/// Default `ModOrganizer` sub-projects in the build groups of C++ mob's
+/// `add_tasks()` in `main.cpp`.
+///
+/// These are the `modorganizer-*` repos that C++ mob hardcodes. Without this list,
+/// mob-rs only knows about `BUILTIN_TASKS` (7 items) and whatever `mob.toml` defines,
+/// leaving ~25 sub-projects unregistered and therefore never cloned or built.
+///
+/// Each project depends on every task of the group before it. Each entry is
+/// `(canonical_name, &[alternate_names])`. Alternate names come from transifex
+/// slugs which sometimes differ from project names.
+const DEFAULT_MO_PROJECTS: &[&[(&str, &[&str])]] = &[
+    // Group 1 (with usvfs)
+    &[("cmake_common", &[])],
+    // Group 2
+    &[("modorganizer-uibase", &[])],
+    // Group 3
+    &[
+        ("modorganizer-archive", &[]),
+        ("modorganizer-lootcli", &[]),
+        ("modorganizer-esptk", &[]),
+        ("modorganizer-bsatk", &[]),
+        ("modorganizer-nxmhandler", &[]),
+        ("modorganizer-helper", &[]),
+        ("modorganizer-game_bethesda", &[]),
+    ],
+    // Group 4
+    &[
+        ("modorganizer-bsapacker", &["bsa_packer"]),
+        ("modorganizer-tool_inieditor", &["inieditor"]),
+        ("modorganizer-tool_inibakery", &["inibakery"]),
+        ("modorganizer-preview_bsa", &[]),
+        ("modorganizer-preview_base", &[]),
+        ("modorganizer-diagnose_basic", &[]),
+        ("modorganizer-check_fnis", &[]),
+        ("modorganizer-installer_bain", &[]),
+        ("modorganizer-installer_manual", &[]),
+        ("modorganizer-installer_bundle", &[]),
+        ("modorganizer-installer_quick", &[]),
+        ("modorganizer-installer_fomod", &[]),
+        ("modorganizer-installer_fomod_csharp", &[]),
+        ("modorganizer-installer_omod", &[]),
+        ("modorganizer-installer_wizard", &[]),
+        ("modorganizer-bsa_extractor", &[]),
+        ("modorganizer-plugin_python", &[]),
+    ],
+    // Group 5 (with modorganizer, stylesheets, licenses, explorerpp)
+    &[
+        ("modorganizer-tool_configurator", &["pycfg"]),
+        ("modorganizer-fnistool", &[]),
+        ("modorganizer-basic_games", &[]),
+        (
+            "modorganizer-script_extender_plugin_checker",
+            &["scriptextenderpluginchecker"],
+        ),
+        ("modorganizer-form43_checker", &["form43checker"]),
+        ("modorganizer-preview_dds", &["ddspreview"]),
+        // "modorganizer" itself has alternate name "organizer" — handled in BUILTIN_TASKS
+    ],
+];
+
+impl TaskRegistry {
+    /// Creates a registry with every task `config` knows about: `[tasks.*]`
+    /// sections, the default `ModOrganizer` projects and the built-in tasks.
+    #[must_use]
+    pub fn for_config(config: &Config) -> Self {
+        let mut registry = Self::new(config.aliases.clone());
+        register_config_tasks(&mut registry, config);
+        register_default_projects(&mut registry);
+        registry.register_all(BUILTIN_TASKS.iter().map(ToString::to_string));
+        // Register alternate name for modorganizer
+        registry.register("organizer");
+        registry
+    }
+}
+
+/// Returns the built-in tasks in build order, leaving out the ones disabled
+/// in `config`.
+#[must_use]
+pub fn builtin_tasks(config: &Config) -> Vec<Task> {
+    BUILTIN_TASKS
+        .iter()
+        .filter(|name| config.task_config(name).enabled)
+        .map(|name| task_from_name(name))
+        .collect()
+}
+
+/// Registers the `[tasks.*]` sections of `config` with their alternate names.
+fn register_config_tasks(registry: &mut TaskRegistry, config: &Config) {
+    for name in config.tasks.keys() {
+        // Skip alias names (e.g., "super", "plugins") — they are config override
+        // scopes, not actual buildable tasks. In C++ mob, `[super:task]` applies
+        // overrides to all tasks in the `super` alias group.
+        if config.aliases.contains_key(name) {
+            continue;
+        }
+
+        // Skip built-in task names — they have their own task types and should
+        // not be registered as modorganizer-* repos.
+        if BUILTIN_TASKS.contains(&name.as_str()) {
+            continue;
+        }
+
+        registry.register(name.clone());
+        if let Some(short) = name.strip_prefix("modorganizer-") {
+            registry.register(short.to_string());
+        } else if name != "modorganizer" {
+            registry.register(format!("modorganizer-{name}"));
+        }
+    }
+}
+
+/// Registers all default `ModOrganizer` sub-projects and their alternate names.
+fn register_default_projects(registry: &mut TaskRegistry) {
+    for &(canonical, alternates) in DEFAULT_MO_PROJECTS.iter().copied().flatten() {
+        registry.register(canonical.to_string());
+
+        // Also register the short name (without `modorganizer-` prefix)
+        if let Some(short) = canonical.strip_prefix("modorganizer-") {
+            registry.register(short.to_string());
+        }
+
+        // Register alternate names (transifex slugs, etc.)
+        for &alt in alternates {
+            registry.register(alt.to_string());
+        }
+    }
+}
+
+/// Returns every task of a full build: the built-in tasks and the default
+/// `ModOrganizer` projects.
+///
+/// `TaskManager` orders them by their dependencies, which reproduces the
+/// groups of C++ mob's `add_tasks()`:
+///
+/// ```text
+/// usvfs, cmake_common, stylesheets, explorerpp, licenses
+///   --> modorganizer-uibase --> archive, lootcli, ... game_bethesda
+///   --> bsapacker, tool_inieditor, ... plugin_python
+///   --> modorganizer, pycfg, ... preview_dds --> translations --> installer
+/// ```
+#[must_use]
+pub fn default_task_tree() -> Vec<Task> {
+    let mut tasks: Vec<Task> = DEFAULT_MO_PROJECTS
+        .iter()
+        .copied()
+        .flatten()
+        .map(|&(name, _)| mo(name))
+        .collect();
+    tasks.extend(BUILTIN_TASKS.iter().map(|name| task_from_name(name)));
+    tasks
+}
+
+/// Shorthand for creating a `ModOrganizerTask` wrapped in `Task`, with the
+/// dependencies of its build group.
+fn mo(name: &str) -> Task {
+    let task = ModOrganizerTask::new(name.to_string());
+    let dependencies = project_dependencies(task.name(), task.repo_name());
+    Task::ModOrganizer(task.with_dependencies(dependencies))
+}
+
+/// Returns the tasks a `ModOrganizer` project depends on.
+///
+/// Default projects depend on the group before theirs, `modorganizer` on the
+/// last but one group and `usvfs`; projects only known from `[tasks.*]` build
+/// against `modorganizer-uibase`.
+fn project_dependencies(name: &str, repo_name: &str) -> Vec<&'static str> {
+    let group = if name == "modorganizer" {
+        Some(DEFAULT_MO_PROJECTS.len() - 1)
+    } else {
+        DEFAULT_MO_PROJECTS.iter().position(|group| {
+            group
+                .iter()
+                .any(|&(project, _)| project == name || project == repo_name)
+        })
+    };
+    let Some(group) = group else {
+        return vec!["modorganizer-uibase"];
+    };
+    let Some(previous) = group.checked_sub(1) else {
+        return Vec::new();
+    };
+
+    let mut dependencies: Vec<_> = DEFAULT_MO_PROJECTS[previous]
+        .iter()
+        .map(|&(project, _)| project)
+        .collect();
+    if previous == 0 || name == "modorganizer" {
+        dependencies.push("usvfs");
+    }
+    dependencies
+}
+
+/// Creates the task for `name`, mapping alternate names to their project.
+#[must_use]
+pub fn task_from_name(name: &str) -> Task {
+    match name {
+        "usvfs" => Task::Usvfs(UsvfsTask::new()),
+        "stylesheets" | "ss" => Task::Stylesheets(StylesheetsTask::new()),
+        "explorerpp" | "explorer++" => Task::ExplorerPP(ExplorerPPTask::new()),
+        "licenses" => Task::Licenses(LicensesTask::new()),
+        "translations" => Task::Translations(TranslationsTask::new()),
+        "installer" => Task::Installer(InstallerTask::new()),
+        // Resolve alternate names to their canonical modorganizer-* form
+        "organizer" => mo("modorganizer"),
+        "bsa_packer" => mo("modorganizer-bsapacker"),
+        "inieditor" => mo("modorganizer-tool_inieditor"),
+        "inibakery" => mo("modorganizer-tool_inibakery"),
+        "pycfg" => mo("modorganizer-tool_configurator"),
+        "scriptextenderpluginchecker" => mo("modorganizer-script_extender_plugin_checker"),
+        "form43checker" => mo("modorganizer-form43_checker"),
+        "ddspreview" => mo("modorganizer-preview_dds"),
+        _ => mo(name),
+    }
+}
@@ -10,8 +10,14 @@
 //!   resolve_aliases: "super" --> [usvfs, modorganizer, ...]
 //!   match_pattern:   "mod*"  --> [modorganizer, modorganizer-archive, ...]
 //!   dedupe + preserve order
+//!
+//! TaskFilter (--only / --skip globs on task names)
+//!   --only keeps matching tasks, --skip then drops matching tasks
+//!   parallel groups left without children are dropped
 //! ```
 
+pub mod builtin;
+
 use std::collections::BTreeSet;
 
 use crate::error::Result;
@@ -19,6 +25,7 @@ use anyhow::Context;
 use wax::{Glob, Program};
 
 use crate::config::types::Aliases;
+use crate::task::{ParallelTasks, Task, Taskable};
 
 /// Registry for looking up tasks by name or pattern.
 pub struct TaskRegistry {
@@ -148,5 +155,88 @@ impl TaskRegistry {
     }
 }
 
+/// Glob filter over task names from `build --only` and `--skip`.
+pub struct TaskFilter {
+    /// Patterns a task must match one of, with their source text.
+    only: Vec<(String, Glob<'static>)>,
+
+    /// Patterns that drop a task.
+    skip: Vec<Glob<'static>>,
+}
+
+impl TaskFilter {
+    /// Compiles the `--only` and `--skip` patterns.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern is not a valid glob.
+    pub fn new(only: &[String], skip: &[String]) -> Result<Self> {
+        let only = only
+            .iter()
+            .map(|pattern| Ok((pattern.clone(), compile_glob(pattern)?)))
+            .collect::<Result<_>>()?;
+        let skip = skip
+            .iter()
+            .map(|pattern| compile_glob(pattern))
+            .collect::<Result<_>>()?;
+        Ok(Self { only, skip })
+    }
+
+    /// Returns whether `name` passes the filter.
+    #[must_use]
+    pub fn keeps(&self, name: &str) -> bool {
+        (self.only.is_empty() || self.only.iter().any(|(_, glob)| glob.is_match(name)))
+            && !self.skip.iter().any(|glob| glob.is_match(name))
+    }
+
+    /// Removes the tasks that don't pass the filter from `tasks`, descending
+    /// into parallel groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an `--only` pattern matches none of the tasks,
+    /// which usually is a typo.
+    pub fn apply(&self, tasks: Vec<Task>) -> Result<Vec<Task>> {
+        let mut names = Vec::new();
+        collect_names(&tasks, &mut names);
+        for (pattern, glob) in &self.only {
+            if !names.iter().any(|name| glob.is_match(*name)) {
+                anyhow::bail!("--only '{pattern}' matched no task");
+            }
+        }
+        Ok(self.retain(tasks))
+    }
+
+    fn retain(&self, tasks: Vec<Task>) -> Vec<Task> {
+        tasks
+            .into_iter()
+            .filter_map(|task| match task {
+                Task::Parallel(group) => {
+                    let children = self.retain(group.into_children());
+                    (!children.is_empty()).then(|| Task::Parallel(ParallelTasks::new(children)))
+                }
+                task => self.keeps(task.name()).then_some(task),
+            })
+            .collect()
+    }
+}
+
+/// Parses `pattern` as a task name glob.
+fn compile_glob(pattern: &str) -> Result<Glob<'static>> {
+    Ok(Glob::new(pattern)
+        .with_context(|| format!("Invalid glob pattern: {pattern}"))?
+        .into_owned())
+}
+
+/// Collects the names of `tasks`, with parallel groups replaced by their children.
+fn collect_names<'a>(tasks: &'a [Task], names: &mut Vec<&'a str>) {
+    for task in tasks {
+        match task {
+            Task::Parallel(group) => collect_names(group.children(), names),
+            task => names.push(task.name()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -133,3 +133,134 @@ fn test_resolve_deduplicates() {
     // Should contain "modorganizer" only once (deduplicated)
     insta::assert_yaml_snapshot!("resolve_deduplicates", result);
 }
+
+fn filter_names(tasks: &[crate::task::Task]) -> Vec<String> {
+    use crate::task::{Task, Taskable};
+
+    tasks
+        .iter()
+        .map(|task| match task {
+            Task::Parallel(group) => format!("[{}]", filter_names(group.children()).join(", ")),
+            task => task.name().to_string(),
+        })
+        .collect()
+}
+
+fn filter_tasks() -> Vec<crate::task::Task> {
+    use crate::task::tasks::modorganizer::ModOrganizerTask;
+    use crate::task::tasks::translations::TranslationsTask;
+    use crate::task::tasks::usvfs::UsvfsTask;
+    use crate::task::{ParallelTasks, Task};
+
+    let mo = |name: &str| Task::ModOrganizer(ModOrganizerTask::new(name.to_string()));
+    vec![
+        Task::Parallel(ParallelTasks::new(vec![
+            Task::Usvfs(UsvfsTask::new()),
+            mo("cmake_common"),
+        ])),
+        mo("modorganizer-uibase"),
+        Task::Parallel(ParallelTasks::new(vec![
+            mo("modorganizer-installer_bain"),
+            mo("modorganizer-installer_omod"),
+        ])),
+        Task::Translations(TranslationsTask::new()),
+    ]
+}
+
+#[test]
+fn test_task_filter_only_and_skip() {
+    use super::TaskFilter;
+
+    let strings = |patterns: &[&str]| patterns.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    let filter = TaskFilter::new(&[], &[]).unwrap();
+    assert_eq!(
+        filter_names(&filter.apply(filter_tasks()).unwrap()).len(),
+        4
+    );
+
+    let filter = TaskFilter::new(&strings(&["modorganizer-uibase"]), &[]).unwrap();
+    assert_eq!(
+        filter_names(&filter.apply(filter_tasks()).unwrap()),
+        ["modorganizer-uibase"]
+    );
+
+    let filter = TaskFilter::new(&[], &strings(&["translations", "usvfs"])).unwrap();
+    assert_eq!(
+        filter_names(&filter.apply(filter_tasks()).unwrap()),
+        [
+            "[cmake_common]",
+            "modorganizer-uibase",
+            "[modorganizer-installer_bain, modorganizer-installer_omod]",
+        ]
+    );
+
+    let filter = TaskFilter::new(
+        &strings(&["modorganizer-installer_*", "usvfs"]),
+        &strings(&["*_omod"]),
+    )
+    .unwrap();
+    assert_eq!(
+        filter_names(&filter.apply(filter_tasks()).unwrap()),
+        ["[usvfs]", "[modorganizer-installer_bain]"]
+    );
+}
+
+#[test]
+fn test_task_filter_only_matching_nothing() {
+    use super::TaskFilter;
+
+    let filter = TaskFilter::new(&["usvfs".to_string(), "uibsae".to_string()], &[]).unwrap();
+    let error = filter.apply(filter_tasks()).unwrap_err();
+    assert_eq!(error.to_string(), "--only 'uibsae' matched no task");
+
+    assert!(TaskFilter::new(&[], &["[".to_string()]).is_err());
+}
+
+#[test]
+fn test_builtin_tasks_order() {
+    use super::builtin::builtin_tasks;
+    use crate::config::Config;
+
+    let names = |config: &Config| {
+        builtin_tasks(config)
+            .iter()
+            .map(|task| crate::task::Taskable::name(task).to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(&Config::default()),
+        [
+            "usvfs",
+            "modorganizer",
+            "stylesheets",
+            "explorerpp",
+            "licenses",
+            "translations",
+            "installer",
+        ]
+    );
+
+    let config = Config::parse("[tasks.translations]\nenabled = false\n").unwrap();
+    assert!(!names(&config).contains(&"translations".to_string()));
+    assert_eq!(names(&config).len(), 6);
+}
+
+#[test]
+fn test_registry_for_config() {
+    use crate::config::Config;
+
+    let config = Config::parse("[tasks.modorganizer-custom]\nmo_branch = \"dev\"\n").unwrap();
+    let registry = TaskRegistry::for_config(&config);
+    for name in [
+        "usvfs",
+        "organizer",
+        "modorganizer-uibase",
+        "uibase",
+        "pycfg",
+        "modorganizer-custom",
+        "custom",
+    ] {
+        assert!(registry.all_tasks().contains(name), "{name}");
+    }
+}
@@ -0,0 +1,114 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-task pass/fail results, recorded for `build --report`.
+//!
+//! ```text
+//! TaskManager::with_results(results)
+//!       |
+//! Task::run() / ParallelTasks children
+//!   each phase finished --> TaskContext::results
+//!                             --> TaskResults::record(task, elapsed, Ok | Err)
+//!                                  |
+//!                                  v
+//!        TaskResults::take() --> one TaskResult per task (phases summed)
+//! ```
+//!
+//! Unlike `timing`, tasks inside a parallel group are recorded individually
+//! and failed phases are kept.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::error::Result;
+
+/// Outcome of one task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskResult {
+    task: String,
+    duration: Duration,
+    failure: Option<String>,
+}
+
+impl TaskResult {
+    /// Creates a new result; `failure` is the error message of a failed task.
+    #[must_use]
+    pub const fn new(task: String, duration: Duration, failure: Option<String>) -> Self {
+        Self {
+            task,
+            duration,
+            failure,
+        }
+    }
+
+    /// Returns the task name.
+    #[must_use]
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    /// Returns how long the task ran.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the error message if the task failed.
+    #[must_use]
+    pub fn failure(&self) -> Option<&str> {
+        self.failure.as_deref()
+    }
+}
+
+/// Results of the phases finished during one run, shared by all tasks.
+#[derive(Debug, Default)]
+pub struct TaskResults {
+    results: Mutex<Vec<TaskResult>>,
+}
+
+impl TaskResults {
+    /// Creates an empty collection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores the result of one phase of `task`.
+    pub fn record(&self, task: &str, duration: Duration, result: &Result<()>) {
+        let failure = result.as_ref().err().map(|e| format!("{e:#}"));
+        self.results
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(TaskResult::new(task.to_string(), duration, failure));
+    }
+
+    /// Returns and clears all recorded results, one per task in first-run
+    /// order.
+    ///
+    /// Durations of a task's phases are summed and its first failure is kept.
+    #[must_use]
+    pub fn take(&self) -> Vec<TaskResult> {
+        let recorded = std::mem::take(
+            &mut *self
+                .results
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+
+        let mut merged: Vec<TaskResult> = Vec::new();
+        for result in recorded {
+            match merged.iter_mut().find(|r| r.task == result.task) {
+                Some(existing) => {
+                    existing.duration += result.duration;
+                    if existing.failure.is_none() {
+                        existing.failure = result.failure;
+                    }
+                }
+                None => merged.push(result),
+            }
+        }
+        merged
+    }
+}
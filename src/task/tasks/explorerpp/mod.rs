@@ -7,19 +7,27 @@
 //!
 //! ```text
 //! ExplorerPPTask
-//! URL → cache/explorerpp_x64.zip → build/explorer++ → install/bin/explorer++
+//! URL → downloads/explorerpp_x64-<version>.zip → build/explorer++-<version>
+//!     → install/bin/explorer++
+//! versions.explorerpp_sha256: archive checksum, verified after download
 //! ```
 //!
+//! The archive and the extracted directory are named after
+//! `versions.explorerpp`, so changing the version fetches and extracts the new
+//! release instead of reusing the old one. Fetching removes the archives and
+//! directories of other versions, and installing replaces
+//! `install/bin/explorer++` so no files of an older release are left behind.
+//!
 //! This task downloads and installs Explorer++ - a file manager replacement
 //! that MO2 can launch for browsing mod files.
 //!
 //! # Process
 //!
 //! 1. **Fetch**: Download zip from explorerplusplus.com
-//! 2. **Extract**: Unpack to build/explorer++/
+//! 2. **Extract**: Unpack to build/explorer++-<version>/
 //! 3. **Install**: Copy to install/bin/explorer++/
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::Result;
 use anyhow::Context;
@@ -28,12 +36,18 @@ use tokio::fs;
 use tracing::info;
 
 use crate::config::Config;
+use crate::task::helpers::fetch_archive;
 use crate::task::tools::Tool;
 use crate::task::tools::downloader::DownloaderTool;
-use crate::task::tools::extractor::ExtractorTool;
 use crate::task::{CleanFlags, TaskContext, Taskable};
 use crate::utility::fs::copy::copy_files_async;
 
+/// File name prefix of the cached archives, followed by the version.
+const ARCHIVE_PREFIX: &str = "explorerpp_x64-";
+
+/// Directory name prefix of the extracted releases, followed by the version.
+const SOURCE_PREFIX: &str = "explorer++-";
+
 /// Explorer++ task for downloading prebuilt Explorer++.
 #[derive(Debug, Clone)]
 pub struct ExplorerPPTask {
@@ -68,19 +82,30 @@ impl ExplorerPPTask {
     }
 
     /// Get the download URL.
-    fn download_url(config: &Config) -> String {
+    pub(crate) fn download_url(config: &Config) -> String {
         let version = Self::version(config);
         format!("https://download.explorerplusplus.com/stable/{version}/explorerpp_x64.zip")
     }
 
     /// Get the cache file path.
     fn cache_file(config: &Config) -> Result<PathBuf> {
-        let cache = config
+        let downloads = config
             .paths
-            .cache
-            .as_ref()
+            .downloads_dir()
             .context("paths.cache not configured")?;
-        Ok(cache.join("explorerpp_x64.zip"))
+        Ok(downloads.join(format!("{ARCHIVE_PREFIX}{}.zip", Self::version(config))))
+    }
+
+    /// Get the `(url, cache file)` pair of the Explorer++ archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.cache` is not configured.
+    pub fn downloads(config: &Config) -> Result<Vec<(String, PathBuf)>> {
+        Ok(vec![(
+            Self::download_url(config),
+            Self::cache_file(config)?,
+        )])
     }
 
     /// Get the source/build path (where it's extracted).
@@ -90,7 +115,7 @@ impl ExplorerPPTask {
             .build
             .as_ref()
             .context("paths.build not configured")?;
-        Ok(build.join("explorer++"))
+        Ok(build.join(format!("{SOURCE_PREFIX}{}", Self::version(config))))
     }
 
     /// Get the install path.
@@ -103,55 +128,99 @@ impl ExplorerPPTask {
         Ok(install_bin.join("explorer++"))
     }
 
+    /// Describes what [`do_clean`](Self::do_clean) would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let config = ctx.config();
+        let mut lines = Vec::new();
+        if flags.contains(CleanFlags::REDOWNLOAD)
+            && let Ok(cache_file) = Self::cache_file(config)
+        {
+            lines.extend(
+                listed_versions(&cache_file, ARCHIVE_PREFIX)
+                    .iter()
+                    .map(|file| format!("delete file {}", file.display())),
+            );
+        }
+        if flags.contains(CleanFlags::REEXTRACT)
+            && let Ok(source_path) = Self::source_path(config)
+        {
+            lines.extend(
+                listed_versions(&source_path, SOURCE_PREFIX)
+                    .iter()
+                    .map(|dir| format!("delete directory {}", dir.display())),
+            );
+        }
+        lines
+    }
+
     /// Execute the clean phase.
     ///
+    /// Removes the archives and extracted directories of every version, not
+    /// only the configured one.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the cached archive or the extracted directory
-    /// cannot be removed.
+    /// Returns an error if a cached archive or an extracted directory cannot
+    /// be removed.
     pub async fn do_clean(&self, ctx: &TaskContext, flags: CleanFlags) -> Result<()> {
         let config = &ctx.config;
-        let tool_ctx = ctx.tool_context();
 
-        // Redownload: delete cached archive
+        // Redownload: delete cached archives
         if flags.contains(CleanFlags::REDOWNLOAD) {
             let cache_file = Self::cache_file(config)?;
-
-            if cache_file.exists() {
-                let downloader = DownloaderTool::new().file(&cache_file).clean_op();
-
-                if ctx.dry_run {
-                    info!(
-                        file = %cache_file.display(),
-                        "[DRY-RUN] would delete cached archive"
-                    );
-                } else {
-                    downloader.run(&tool_ctx).await.with_context(|| {
-                        format!("failed to clean cache file: {}", cache_file.display())
-                    })?;
-                }
-            }
+            let archives = versions_of(&cache_file, ARCHIVE_PREFIX).await?;
+            Self::delete_archives(ctx, &archives).await?;
         }
 
-        // Reextract: delete extracted directory
+        // Reextract: delete extracted directories
         if flags.contains(CleanFlags::REEXTRACT) {
             let source_path = Self::source_path(config)?;
+            let sources = versions_of(&source_path, SOURCE_PREFIX).await?;
+            Self::delete_sources(ctx, &sources).await?;
+        }
+
+        Ok(())
+    }
 
-            if source_path.exists() {
-                if ctx.dry_run {
-                    info!(
-                        path = %source_path.display(),
-                        "[DRY-RUN] would delete extracted directory"
-                    );
-                } else {
-                    info!(path = %source_path.display(), "Deleting extracted directory");
-                    fs::remove_dir_all(&source_path)
-                        .await
-                        .with_context(|| format!("failed to delete {}", source_path.display()))?;
-                }
+    /// Deletes the given cached archives.
+    async fn delete_archives(ctx: &TaskContext, archives: &[PathBuf]) -> Result<()> {
+        let tool_ctx = ctx.tool_context();
+        for archive in archives {
+            if ctx.dry_run {
+                info!(
+                    file = %archive.display(),
+                    "[DRY-RUN] would delete cached archive"
+                );
+            } else {
+                DownloaderTool::new()
+                    .file(archive)
+                    .clean_op()
+                    .run(&tool_ctx)
+                    .await
+                    .with_context(|| {
+                        format!("failed to clean cache file: {}", archive.display())
+                    })?;
             }
         }
+        Ok(())
+    }
 
+    /// Deletes the given extracted directories.
+    async fn delete_sources(ctx: &TaskContext, sources: &[PathBuf]) -> Result<()> {
+        for source in sources {
+            if ctx.dry_run {
+                info!(
+                    path = %source.display(),
+                    "[DRY-RUN] would delete extracted directory"
+                );
+            } else {
+                info!(path = %source.display(), "Deleting extracted directory");
+                fs::remove_dir_all(source)
+                    .await
+                    .with_context(|| format!("failed to delete {}", source.display()))?;
+            }
+        }
         Ok(())
     }
 
@@ -162,7 +231,6 @@ impl ExplorerPPTask {
     /// Returns an error if the download or extraction fails.
     pub async fn do_fetch(&self, ctx: &TaskContext) -> Result<()> {
         let config = &ctx.config;
-        let tool_ctx = ctx.tool_context();
 
         let url = Self::download_url(config);
         let cache_file = Self::cache_file(config)?;
@@ -173,29 +241,24 @@ impl ExplorerPPTask {
             "Fetching Explorer++"
         );
 
-        // Download
-        let downloader = DownloaderTool::new()
-            .url(&url)
-            .file(&cache_file)
-            .force(config.global.clean_download_actions.redownload);
-
-        downloader
-            .run(&tool_ctx)
-            .await
-            .context("failed to download Explorer++")?;
-
-        // Extract
-        let extractor = ExtractorTool::new()
-            .archive(&cache_file)
-            .output(&source_path)
-            .force(config.global.clean_download_actions.reextract);
-
-        extractor
-            .run(&tool_ctx)
-            .await
-            .context("failed to extract Explorer++")?;
-
-        Ok(())
+        fetch_archive(
+            ctx,
+            &[url],
+            &cache_file,
+            &source_path,
+            "Explorer++",
+            config.versions.explorerpp_sha256.as_deref(),
+        )
+        .await?;
+
+        // Drop the archives and directories of other versions
+        let mut archives = versions_of(&cache_file, ARCHIVE_PREFIX).await?;
+        archives.retain(|archive| *archive != cache_file);
+        Self::delete_archives(ctx, &archives).await?;
+
+        let mut sources = versions_of(&source_path, SOURCE_PREFIX).await?;
+        sources.retain(|source| *source != source_path);
+        Self::delete_sources(ctx, &sources).await
     }
 
     /// Execute the build and install phase.
@@ -203,6 +266,7 @@ impl ExplorerPPTask {
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The previous install directory cannot be removed.
     /// - The install directory cannot be created.
     /// - Files cannot be copied to the install directory.
     pub async fn do_build_and_install(&self, ctx: &TaskContext) -> Result<()> {
@@ -218,18 +282,21 @@ impl ExplorerPPTask {
             return Ok(());
         }
 
-        // Create install directory if needed
-        if !install_path.exists() {
-            if ctx.dry_run {
-                info!(
-                    path = %install_path.display(),
-                    "[DRY-RUN] would create explorer++ directory"
-                );
-            } else {
-                fs::create_dir_all(&install_path)
+        // Replace the install directory so files of older releases go away
+        if ctx.dry_run {
+            info!(
+                path = %install_path.display(),
+                "[DRY-RUN] would recreate explorer++ directory"
+            );
+        } else {
+            if fs::try_exists(&install_path).await.unwrap_or(false) {
+                fs::remove_dir_all(&install_path)
                     .await
-                    .with_context(|| format!("failed to create {}", install_path.display()))?;
+                    .with_context(|| format!("failed to delete {}", install_path.display()))?;
             }
+            fs::create_dir_all(&install_path)
+                .await
+                .with_context(|| format!("failed to create {}", install_path.display()))?;
         }
 
         info!("Installing Explorer++");
@@ -249,6 +316,59 @@ impl ExplorerPPTask {
     }
 }
 
+/// Lists the siblings of `current` named `<prefix><version>`, sorted.
+///
+/// A missing parent directory has none.
+async fn versions_of(current: &Path, prefix: &str) -> Result<Vec<PathBuf>> {
+    let Some(dir) = current.parent() else {
+        return Ok(Vec::new());
+    };
+    let mut entries = match fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", dir.display())),
+    };
+
+    let mut versions = Vec::new();
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .with_context(|| format!("failed to read entry in {}", dir.display()))?
+    {
+        if is_version(&entry.path(), prefix) {
+            versions.push(entry.path());
+        }
+    }
+
+    // Sort for deterministic order
+    versions.sort();
+    Ok(versions)
+}
+
+/// Synchronous [`versions_of`] for clean descriptions, skipping unreadable
+/// directories.
+fn listed_versions(current: &Path, prefix: &str) -> Vec<PathBuf> {
+    let mut versions: Vec<_> = current
+        .parent()
+        .and_then(|dir| std::fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_version(path, prefix))
+        .collect();
+    versions.sort();
+    versions
+}
+
+/// Returns true if the file name of `path` is `prefix` followed by a version.
+fn is_version(path: &Path, prefix: &str) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix(prefix))
+        .is_some_and(|version| !version.is_empty())
+}
+
 impl Taskable for ExplorerPPTask {
     fn name(&self) -> &str {
         &self.name
@@ -258,6 +378,10 @@ impl Taskable for ExplorerPPTask {
         Box::pin(self.do_clean(ctx, ctx.clean_flags))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
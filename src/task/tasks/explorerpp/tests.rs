@@ -5,7 +5,10 @@
 
 use super::ExplorerPPTask;
 use crate::config::Config;
+use crate::task::{CleanFlags, TaskContext};
 use std::path::PathBuf;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 #[test]
 fn test_explorerpp_task_info() {
@@ -42,6 +45,139 @@ fn test_cache_file_path() {
     insta::assert_debug_snapshot!("explorerpp_cache_file_path", path);
 }
 
+#[test]
+fn test_cache_file_prefers_downloads() {
+    let mut config = Config::default();
+    config.paths.cache = Some(PathBuf::from("/test/cache"));
+    config.paths.downloads = Some(PathBuf::from("/test/downloads"));
+
+    let path = ExplorerPPTask::cache_file(&config).unwrap();
+    assert_eq!(
+        path,
+        PathBuf::from("/test/downloads").join("explorerpp_x64-1.4.0.zip")
+    );
+}
+
+#[test]
+fn test_paths_follow_version() {
+    let mut config = Config::default();
+    config.paths.cache = Some(PathBuf::from("/test/cache"));
+    config.paths.build = Some(PathBuf::from("/test/build"));
+    let old_cache = ExplorerPPTask::cache_file(&config).unwrap();
+    let old_source = ExplorerPPTask::source_path(&config).unwrap();
+
+    config.versions.explorerpp = "1.5.0".to_string();
+    assert_eq!(
+        ExplorerPPTask::cache_file(&config).unwrap(),
+        PathBuf::from("/test/cache").join("explorerpp_x64-1.5.0.zip")
+    );
+    assert_eq!(
+        ExplorerPPTask::source_path(&config).unwrap(),
+        PathBuf::from("/test/build").join("explorer++-1.5.0")
+    );
+    assert_ne!(ExplorerPPTask::cache_file(&config).unwrap(), old_cache);
+    assert_ne!(ExplorerPPTask::source_path(&config).unwrap(), old_source);
+}
+
+#[test]
+fn test_clean_description() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("cache"));
+    config.paths.build = Some(temp.path().join("build"));
+    let config = Arc::new(config);
+    let ctx = TaskContext::new(Arc::clone(&config), CancellationToken::new());
+    let task = ExplorerPPTask::new();
+    let flags = CleanFlags::REDOWNLOAD | CleanFlags::REEXTRACT;
+
+    // Nothing exists yet, so nothing would be removed
+    assert!(task.clean_description(&ctx, flags).is_empty());
+
+    let cache_file = ExplorerPPTask::cache_file(&config).unwrap();
+    let source_path = ExplorerPPTask::source_path(&config).unwrap();
+    std::fs::create_dir_all(cache_file.parent().unwrap()).unwrap();
+    std::fs::write(&cache_file, b"zip").unwrap();
+    std::fs::create_dir_all(&source_path).unwrap();
+    assert_eq!(
+        task.clean_description(&ctx, flags),
+        vec![
+            format!("delete file {}", cache_file.display()),
+            format!("delete directory {}", source_path.display()),
+        ]
+    );
+    assert_eq!(
+        task.clean_description(&ctx, CleanFlags::REEXTRACT),
+        vec![format!("delete directory {}", source_path.display())]
+    );
+
+    // Other versions are removed as well
+    let old_source = source_path.with_file_name("explorer++-1.3.5");
+    std::fs::create_dir_all(&old_source).unwrap();
+    assert_eq!(
+        task.clean_description(&ctx, CleanFlags::REEXTRACT),
+        vec![
+            format!("delete directory {}", old_source.display()),
+            format!("delete directory {}", source_path.display()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_clean_removes_every_version() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("cache"));
+    config.paths.build = Some(temp.path().join("build"));
+    let config = Arc::new(config);
+    let ctx = TaskContext::new(Arc::clone(&config), CancellationToken::new());
+
+    let cache_file = ExplorerPPTask::cache_file(&config).unwrap();
+    let old_cache = cache_file.with_file_name("explorerpp_x64-1.3.5.zip");
+    let unrelated = cache_file.with_file_name("7z-24.09.zip");
+    std::fs::create_dir_all(cache_file.parent().unwrap()).unwrap();
+    for file in [&cache_file, &old_cache, &unrelated] {
+        std::fs::write(file, b"zip").unwrap();
+    }
+    let source_path = ExplorerPPTask::source_path(&config).unwrap();
+    let old_source = source_path.with_file_name("explorer++-1.3.5");
+    std::fs::create_dir_all(&source_path).unwrap();
+    std::fs::create_dir_all(&old_source).unwrap();
+
+    ExplorerPPTask::new()
+        .do_clean(&ctx, CleanFlags::REDOWNLOAD | CleanFlags::REEXTRACT)
+        .await
+        .unwrap();
+    assert!(!cache_file.exists());
+    assert!(!old_cache.exists());
+    assert!(unrelated.exists());
+    assert!(!source_path.exists());
+    assert!(!old_source.exists());
+}
+
+#[tokio::test]
+async fn test_install_replaces_previous_release() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.paths.build = Some(temp.path().join("build"));
+    config.paths.install_bin = Some(temp.path().join("install").join("bin"));
+    let config = Arc::new(config);
+    let ctx = TaskContext::new(Arc::clone(&config), CancellationToken::new());
+
+    let source_path = ExplorerPPTask::source_path(&config).unwrap();
+    std::fs::create_dir_all(&source_path).unwrap();
+    std::fs::write(source_path.join("Explorer++.exe"), b"new").unwrap();
+    let install_path = ExplorerPPTask::install_path(&config).unwrap();
+    std::fs::create_dir_all(&install_path).unwrap();
+    std::fs::write(install_path.join("removed.dll"), b"old").unwrap();
+
+    ExplorerPPTask::new()
+        .do_build_and_install(&ctx)
+        .await
+        .unwrap();
+    assert!(install_path.join("Explorer++.exe").exists());
+    assert!(!install_path.join("removed.dll").exists());
+}
+
 #[test]
 fn test_source_path() {
     let mut config = Config::default();
@@ -63,3 +199,23 @@ fn test_install_path() {
     let path = ExplorerPPTask::install_path(&config).unwrap();
     insta::assert_debug_snapshot!("explorerpp_install_path", path);
 }
+
+#[test]
+fn test_downloads() {
+    let mut config = Config::default();
+    config.paths.cache = Some(PathBuf::from("/test/cache"));
+
+    let downloads = ExplorerPPTask::downloads(&config).unwrap();
+    assert_eq!(
+        downloads,
+        vec![(
+            ExplorerPPTask::download_url(&config),
+            ExplorerPPTask::cache_file(&config).unwrap()
+        )]
+    );
+}
+
+#[test]
+fn test_downloads_requires_cache() {
+    assert!(ExplorerPPTask::downloads(&Config::default()).is_err());
+}
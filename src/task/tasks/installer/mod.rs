@@ -32,6 +32,7 @@ use futures_util::future::BoxFuture;
 use tracing::{debug, info, warn};
 
 use crate::config::Config;
+use crate::task::helpers::{FETCH_RETRY_DELAY, retry_async};
 use crate::task::tools::git::{GitTool, remote_branch_exists_ctx};
 use crate::task::tools::{Tool, ToolContext};
 use crate::task::{CleanFlags, TaskContext, Taskable};
@@ -70,7 +71,7 @@ impl InstallerTask {
     /// Get the source path for the installer repository.
     ///
     /// This is `build/modorganizer_super/installer`.
-    fn source_path(config: &Config) -> Result<PathBuf> {
+    pub(crate) fn source_path(config: &Config) -> Result<PathBuf> {
         let build = config
             .paths
             .build
@@ -89,7 +90,7 @@ impl InstallerTask {
     }
 
     /// Build the git URL for the installer repository.
-    fn git_url(config: &Config) -> String {
+    pub(crate) fn git_url(config: &Config) -> String {
         format!(
             "{}{}/modorganizer-Installer.git",
             config.task.git_url_prefix, config.task.mo_org
@@ -167,6 +168,26 @@ impl InstallerTask {
         Ok(selected)
     }
 
+    /// Describes what [`Self::do_clean`] would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let config = ctx.config();
+        let mut lines = Vec::new();
+        if flags.contains(CleanFlags::REEXTRACT)
+            && let Ok(source) = Self::source_path(config)
+            && source.exists()
+        {
+            lines.push(format!("delete directory {}", source.display()));
+        }
+        if flags.contains(CleanFlags::REBUILD)
+            && let Ok(install) = Self::install_path(config)
+            && install.exists()
+        {
+            lines.push(format!("delete directory {}", install.display()));
+        }
+        lines
+    }
+
     /// Execute the clean phase.
     ///
     /// # Errors
@@ -253,11 +274,21 @@ impl InstallerTask {
                 "Pulling updates"
             );
 
-            let git = GitTool::new().path(&source_path).branch(&branch).pull_op();
-
-            git.run(&tool_ctx)
-                .await
-                .context("failed to pull modorganizer-Installer")?;
+            let git = GitTool::new()
+                .path(&source_path)
+                .branch(&branch)
+                .remote(&task_config.git_behavior.pull_remote)
+                .lfs(task_config.git_behavior.git_lfs)
+                .pull_op();
+
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .context("failed to pull modorganizer-Installer")?;
         } else {
             // Clone new repo
             info!(
@@ -287,15 +318,24 @@ impl InstallerTask {
                 .url(&git_url)
                 .path(&source_path)
                 .branch(&branch)
+                .lfs(task_config.git_behavior.git_lfs)
                 .clone_op();
 
             if task_config.git_clone.git_shallow {
                 git = git.shallow(true);
             }
+            if task_config.git_clone.git_single_branch {
+                git = git.single_branch(true);
+            }
 
-            git.run(&tool_ctx)
-                .await
-                .context("failed to clone modorganizer-Installer")?;
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .context("failed to clone modorganizer-Installer")?;
         }
 
         Ok(())
@@ -376,10 +416,26 @@ impl Taskable for InstallerTask {
         ctx.config().task_config(&self.name).enabled && cfg!(windows)
     }
 
+    fn dependencies(&self) -> Vec<&str> {
+        // Packages everything the other built-in tasks install
+        vec![
+            "usvfs",
+            "modorganizer",
+            "stylesheets",
+            "explorerpp",
+            "licenses",
+            "translations",
+        ]
+    }
+
     fn do_clean<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_clean(ctx, ctx.clean_flags()))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
@@ -24,11 +24,14 @@ use tracing::{debug, info};
 use crate::config::Config;
 use crate::git::cmd::init_repo;
 use crate::git::query::is_git_repo;
-use crate::task::helpers::safe_remove_source;
+use crate::task::helpers::{
+    FETCH_RETRY_DELAY, commit_staged_install, install_is_current, reset_staged_install,
+    retry_async, safe_remove_source, staging_install_prefix,
+};
 use crate::task::tools::Tool;
-use crate::task::tools::cmake::{CmakeArchitecture, CmakeGenerator, CmakeTool};
+use crate::task::tools::cmake::CmakeTool;
 use crate::task::tools::git::GitTool;
-use crate::task::{CleanFlags, TaskContext, Taskable};
+use crate::task::{CleanFlags, InstallMode, TaskContext, Taskable};
 
 /// Static initializer for the super repository.
 /// Ensures the super repo is initialized only once across all `ModOrganizer` tasks.
@@ -53,6 +56,9 @@ pub struct ModOrganizerTask {
 
     /// Full repository name (e.g., "modorganizer-archive")
     repo_name: String,
+
+    /// Repositories that must be built first (e.g., "modorganizer-uibase")
+    dependencies: Vec<&'static str>,
 }
 
 impl ModOrganizerTask {
@@ -67,7 +73,18 @@ impl ModOrganizerTask {
             format!("modorganizer-{name}")
         };
 
-        Self { name, repo_name }
+        Self {
+            name,
+            repo_name,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Sets the repositories that must be built before this project.
+    #[must_use]
+    pub fn with_dependencies(mut self, dependencies: Vec<&'static str>) -> Self {
+        self.dependencies = dependencies;
+        self
     }
 
     /// Returns the task name.
@@ -83,7 +100,7 @@ impl ModOrganizerTask {
     }
 
     /// Returns the git URL for this project.
-    fn git_url(&self, config: &Config) -> String {
+    pub(crate) fn git_url(&self, config: &Config) -> String {
         format!(
             "{}{}/{}.git",
             config.task.git_url_prefix, config.task.mo_org, self.repo_name
@@ -91,7 +108,7 @@ impl ModOrganizerTask {
     }
 
     /// Returns the source directory path.
-    fn source_path(&self, config: &Config) -> Result<PathBuf> {
+    pub(crate) fn source_path(&self, config: &Config) -> Result<PathBuf> {
         let build_dir = config
             .paths
             .build
@@ -198,6 +215,65 @@ impl ModOrganizerTask {
         source_path.join("CMakePresets.json").exists()
     }
 
+    /// Builds the `CMake` configure step for this project.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.install` or the super repo path cannot be resolved.
+    pub fn cmake_configure_tool(&self, config: &Config) -> Result<CmakeTool> {
+        let source_path = self.source_path(config)?;
+        let install_prefix = config
+            .paths
+            .install
+            .as_ref()
+            .context("paths.install not configured")?;
+        let cmake_prefix_path = Self::cmake_prefix_path(config)?;
+        let task_config = config.task_config(&self.name);
+
+        let generator = task_config.cmake_generator;
+        let mut cmake = CmakeTool::new()
+            .source_dir(&source_path)
+            .build_dir(&source_path)
+            .generator(generator);
+        // Ninja and JOM build a single architecture chosen by the compiler
+        // environment, so `-A` only goes to Visual Studio.
+        if generator.supports_architecture() {
+            cmake = cmake.architecture(task_config.architecture.into());
+        }
+
+        if let Some(toolchain) = config.paths.vcpkg_toolchain() {
+            cmake = cmake.toolchain_file(toolchain);
+        }
+
+        Ok(cmake
+            .definition("CMAKE_INSTALL_PREFIX", install_prefix.display().to_string())
+            .definition("CMAKE_PREFIX_PATH", &cmake_prefix_path)
+            .configuration(task_config.configuration)
+            .export_compile_commands(config.cmake.export_compile_commands)
+            .configure_op())
+    }
+
+    /// Describes what [`Self::do_clean`] would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let Ok(source_path) = self.source_path(ctx.config()) else {
+            return Vec::new();
+        };
+        if !source_path.exists() {
+            return Vec::new();
+        }
+        if flags.contains(CleanFlags::REEXTRACT) {
+            return vec![format!("delete directory {}", source_path.display())];
+        }
+        if flags.contains(CleanFlags::RECONFIGURE) {
+            return vec![format!(
+                "delete CMakeCache.txt and CMakeFiles in {}",
+                source_path.display()
+            )];
+        }
+        Vec::new()
+    }
+
     /// Execute the clean phase.
     ///
     /// # Errors
@@ -220,7 +296,7 @@ impl ModOrganizerTask {
             let cmake = CmakeTool::new()
                 .source_dir(&source_path)
                 .build_dir(&source_path)
-                .clean_op();
+                .clean_cache_op();
 
             if ctx.is_dry_run() {
                 info!(
@@ -273,11 +349,21 @@ impl ModOrganizerTask {
                 "Pulling updates"
             );
 
-            let git = GitTool::new().path(&source_path).branch(&branch).pull_op();
-
-            git.run(&tool_ctx)
-                .await
-                .with_context(|| format!("failed to pull {}", self.repo_name))?;
+            let git = GitTool::new()
+                .path(&source_path)
+                .branch(&branch)
+                .remote(&task_config.git_behavior.pull_remote)
+                .lfs(task_config.git_behavior.git_lfs)
+                .pull_op();
+
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .with_context(|| format!("failed to pull {}", self.repo_name))?;
         } else {
             // Clone new repo
             info!(
@@ -291,15 +377,24 @@ impl ModOrganizerTask {
                 .url(&git_url)
                 .path(&source_path)
                 .branch(&branch)
+                .lfs(task_config.git_behavior.git_lfs)
                 .clone_op();
 
             if task_config.git_clone.git_shallow {
                 git = git.shallow(true);
             }
+            if task_config.git_clone.git_single_branch {
+                git = git.single_branch(true);
+            }
 
-            git.run(&tool_ctx)
-                .await
-                .with_context(|| format!("failed to clone {}", self.repo_name))?;
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .with_context(|| format!("failed to clone {}", self.repo_name))?;
         }
 
         // Update submodules if present
@@ -307,7 +402,10 @@ impl ModOrganizerTask {
         if gitmodules.exists() {
             debug!(repo = %self.repo_name, "Updating submodules");
 
-            let git = GitTool::new().path(&source_path).submodule_update_op();
+            let git = GitTool::new()
+                .path(&source_path)
+                .jobs(ctx.fetch_parallelism())
+                .submodule_update_op();
 
             git.run(&tool_ctx)
                 .await
@@ -354,7 +452,6 @@ impl ModOrganizerTask {
             .as_ref()
             .context("paths.install not configured")?;
 
-        let cmake_prefix_path = Self::cmake_prefix_path(config)?;
         let configuration = task_config.configuration;
 
         let tool_ctx = ctx.tool_context();
@@ -366,16 +463,10 @@ impl ModOrganizerTask {
             "Configuring with CMake"
         );
 
-        let cmake_configure = CmakeTool::new()
-            .source_dir(&source_path)
-            .build_dir(&source_path)
-            .generator(CmakeGenerator::VisualStudio)
-            .architecture(CmakeArchitecture::X64)
-            .definition("CMAKE_INSTALL_PREFIX", install_prefix.display().to_string())
-            .definition("CMAKE_PREFIX_PATH", &cmake_prefix_path)
-            .configuration(configuration)
-            .configure_op();
-
+        let mut cmake_configure = self.cmake_configure_tool(config)?;
+        if let Some(message) = ctx.install_message() {
+            cmake_configure = cmake_configure.install_message(message);
+        }
         cmake_configure
             .run(&tool_ctx)
             .await
@@ -392,6 +483,7 @@ impl ModOrganizerTask {
             .source_dir(&source_path)
             .build_dir(&source_path)
             .configuration(configuration)
+            .parallel_jobs(ctx.build_jobs())
             .build_op();
 
         cmake_build
@@ -399,6 +491,19 @@ impl ModOrganizerTask {
             .await
             .with_context(|| format!("failed to build {}", self.repo_name))?;
 
+        let staging = config
+            .cmake
+            .staged_install
+            .then(|| staging_install_prefix(install_prefix, &self.name));
+
+        if ctx.install_mode() == InstallMode::OnlyChanged
+            && !ctx.clean_flags().contains(CleanFlags::REBUILD)
+            && install_is_current(&source_path, install_prefix, staging.as_deref()).await
+        {
+            info!(repo = %self.repo_name, "Install is up to date, skipping");
+            return Ok(());
+        }
+
         // CMake install
         info!(
             repo = %self.repo_name,
@@ -406,17 +511,30 @@ impl ModOrganizerTask {
             "Installing"
         );
 
-        let cmake_install = CmakeTool::new()
+        let mut cmake_install = CmakeTool::new()
             .source_dir(&source_path)
             .build_dir(&source_path)
-            .configuration(configuration)
-            .install_op();
+            .configuration(configuration);
+
+        // Staged install: install into a staging prefix, only replace the
+        // real install tree once cmake --install succeeded
+        if let Some(ref staging) = staging {
+            reset_staged_install(ctx, staging, install_prefix).await?;
+            cmake_install = cmake_install.install_prefix(staging);
+        }
 
         cmake_install
+            .install_op()
             .run(&tool_ctx)
             .await
             .with_context(|| format!("failed to install {}", self.repo_name))?;
 
+        if let Some(ref staging) = staging {
+            commit_staged_install(ctx, staging, install_prefix)
+                .await
+                .with_context(|| format!("failed to install {}", self.repo_name))?;
+        }
+
         Ok(())
     }
 }
@@ -430,10 +548,18 @@ impl Taskable for ModOrganizerTask {
         ctx.config().task_config(&self.name).enabled
     }
 
+    fn dependencies(&self) -> Vec<&str> {
+        self.dependencies.clone()
+    }
+
     fn do_clean<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_clean(ctx, ctx.clean_flags()))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
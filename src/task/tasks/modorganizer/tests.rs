@@ -66,6 +66,35 @@ fn test_cmake_prefix_path() {
     assert!(prefix_path.contains("cmake"));
 }
 
+#[tokio::test]
+async fn test_cmake_configure_tool_generator() {
+    let task = ModOrganizerTask::new("modorganizer-uibase");
+    let vs_args = task
+        .cmake_configure_tool(&test_config())
+        .unwrap()
+        .configure_args(&test_config())
+        .await
+        .unwrap();
+    assert!(
+        vs_args
+            .windows(2)
+            .any(|w| w == ["-G", "Visual Studio 17 2022"])
+    );
+    assert!(vs_args.windows(2).any(|w| w == ["-A", "x64"]));
+
+    let mut config =
+        Config::parse("[tasks.modorganizer-uibase]\ncmake_generator = \"ninja\"\n").unwrap();
+    config.paths = test_config().paths.clone();
+    let ninja_args = task
+        .cmake_configure_tool(&config)
+        .unwrap()
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(ninja_args.windows(2).any(|w| w == ["-G", "Ninja"]));
+    assert!(!ninja_args.contains(&"-A".to_string()));
+}
+
 #[test]
 fn test_enabled() {
     let config = test_config();
@@ -7,27 +7,34 @@
 //!
 //! ```text
 //! StylesheetsTask
-//! 9 theme releases (6788-00 + Trosski)
+//! [[stylesheets.release]] from config, or 9 built-in releases (6788-00 + Trosski)
 //! Pipeline: GitHub .7z → cache → build/stylesheets → install/bin/stylesheets
+//!           (mirrors tried in order when the GitHub download fails)
+//! Fetch: one JoinSet job per release, each holding a --jobs permit
 //! ```
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::error::Result;
 use anyhow::Context;
 use futures_util::future::BoxFuture;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use tracing::info;
 
 use crate::config::Config;
+use crate::config::types::StylesheetRelease;
+use crate::task::helpers::fetch_archive;
 use crate::task::tools::Tool;
 use crate::task::tools::downloader::DownloaderTool;
-use crate::task::tools::extractor::ExtractorTool;
 use crate::task::{CleanFlags, TaskContext, Taskable};
 use crate::utility::fs::copy::copy_dir_contents_async;
 
-/// A stylesheet release definition.
+/// A built-in stylesheet release, used when `[[stylesheets.release]]` is empty.
 #[derive(Debug, Clone)]
-struct StylesheetRelease {
+struct BuiltinRelease {
     /// GitHub username
     user: &'static str,
     /// GitHub repository name
@@ -38,9 +45,11 @@ struct StylesheetRelease {
     file: &'static str,
     /// Top-level folder inside archive (empty if files are at root)
     top_level_folder: &'static str,
+    /// Known SHA-256 of the archive, checked after download if set
+    sha256: Option<&'static str>,
 }
 
-impl StylesheetRelease {
+impl BuiltinRelease {
     const fn new(
         user: &'static str,
         repo: &'static str,
@@ -54,69 +63,85 @@ impl StylesheetRelease {
             version_key,
             file,
             top_level_folder,
+            sha256: None,
+        }
+    }
+
+    fn to_release(&self) -> StylesheetRelease {
+        StylesheetRelease {
+            user: self.user.to_string(),
+            repo: self.repo.to_string(),
+            version_key: self.version_key.to_string(),
+            file: self.file.to_string(),
+            top_level_folder: self.top_level_folder.to_string(),
+            sha256: self.sha256.map(str::to_string),
+            mirrors: Vec::new(),
         }
     }
 }
 
-/// All stylesheet releases.
-const RELEASES: &[StylesheetRelease] = &[
-    StylesheetRelease::new(
+/// Replaced with the release version in mirror URLs.
+const VERSION_PLACEHOLDER: &str = "{version}";
+
+/// Built-in stylesheet releases.
+const RELEASES: &[BuiltinRelease] = &[
+    BuiltinRelease::new(
         "6788-00",
         "paper-light-and-dark",
         "ss_paper_lad_6788",
         "paper-light-and-dark",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "6788-00",
         "paper-automata",
         "ss_paper_automata_6788",
         "paper-automata",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "6788-00",
         "paper-mono",
         "ss_paper_mono_6788",
         "paper-mono",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "6788-00",
         "1809-dark-mode",
         "ss_dark_mode_1809_6788",
         "1809",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "Trosski",
         "ModOrganizer_Style_Morrowind",
         "ss_morrowind_trosski",
         "Morrowind-MO2-Stylesheet",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "Trosski",
         "Mod-Organizer-2-Skyrim-Stylesheet",
         "ss_skyrim_trosski",
         "Skyrim-MO2-Stylesheet",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "Trosski",
         "ModOrganizer_Style_Fallout3",
         "ss_fallout3_trosski",
         "Fallout3-MO2-Stylesheet",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "Trosski",
         "Mod-Organizer2-Fallout-4-Stylesheet",
         "ss_fallout4_trosski",
         "Fallout4-MO2-Stylesheet",
         "",
     ),
-    StylesheetRelease::new(
+    BuiltinRelease::new(
         "Trosski",
         "Starfield_MO2_Stylesheet",
         "ss_starfield_trosski",
@@ -153,12 +178,22 @@ impl StylesheetsTask {
         &self.name
     }
 
+    /// Returns the configured releases, or the built-in ones if none are configured.
+    #[must_use]
+    pub fn releases(config: &Config) -> Vec<StylesheetRelease> {
+        if config.stylesheets.release.is_empty() {
+            RELEASES.iter().map(BuiltinRelease::to_release).collect()
+        } else {
+            config.stylesheets.release.clone()
+        }
+    }
+
     /// Get the version for a release from config.
     fn get_version(config: &Config, release: &StylesheetRelease) -> String {
         config
             .versions
             .stylesheets
-            .get(release.version_key)
+            .get(&release.version_key)
             .cloned()
             .unwrap_or_else(|| "latest".to_string())
     }
@@ -172,14 +207,43 @@ impl StylesheetsTask {
         )
     }
 
+    /// Get the GitHub URL followed by the mirrors of a release.
+    fn candidate_urls(config: &Config, release: &StylesheetRelease) -> Vec<String> {
+        let version = Self::get_version(config, release);
+        std::iter::once(Self::download_url(config, release))
+            .chain(
+                release
+                    .mirrors
+                    .iter()
+                    .map(|mirror| mirror.replace(VERSION_PLACEHOLDER, &version)),
+            )
+            .collect()
+    }
+
     /// Get the cache file path for a release.
     fn cache_file(config: &Config, release: &StylesheetRelease) -> Result<PathBuf> {
-        let cache = config
+        let downloads = config
             .paths
-            .cache
-            .as_ref()
+            .downloads_dir()
             .context("paths.cache not configured")?;
-        Ok(cache.join(format!("{}.7z", release.repo)))
+        Ok(downloads.join(format!("{}.7z", release.repo)))
+    }
+
+    /// Get the `(url, cache file)` pair of every release archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.cache` is not configured.
+    pub fn downloads(config: &Config) -> Result<Vec<(String, PathBuf)>> {
+        Self::releases(config)
+            .iter()
+            .map(|release| {
+                Ok((
+                    Self::download_url(config, release),
+                    Self::cache_file(config, release)?,
+                ))
+            })
+            .collect()
     }
 
     /// Get the build path for a release (where it's extracted).
@@ -195,6 +259,22 @@ impl StylesheetsTask {
             .join(format!("{}-{}", release.repo, version)))
     }
 
+    /// Returns the download URL of every release.
+    pub(crate) fn download_urls(config: &Config) -> Vec<String> {
+        Self::releases(config)
+            .iter()
+            .map(|release| Self::download_url(config, release))
+            .collect()
+    }
+
+    /// Get the build paths of all releases.
+    pub(crate) fn build_paths(config: &Config) -> Result<Vec<PathBuf>> {
+        Self::releases(config)
+            .iter()
+            .map(|release| Self::build_path(config, release))
+            .collect()
+    }
+
     /// Get the install directory for stylesheets.
     fn install_path(config: &Config) -> Result<PathBuf> {
         config
@@ -204,6 +284,28 @@ impl StylesheetsTask {
             .context("paths.install_stylesheets not configured")
     }
 
+    /// Describes what [`Self::do_clean`] would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let config = ctx.config();
+        let mut lines = Vec::new();
+        for release in &Self::releases(config) {
+            if flags.contains(CleanFlags::REDOWNLOAD)
+                && let Ok(cache_file) = Self::cache_file(config, release)
+                && cache_file.exists()
+            {
+                lines.push(format!("delete file {}", cache_file.display()));
+            }
+            if flags.contains(CleanFlags::REEXTRACT)
+                && let Ok(build_path) = Self::build_path(config, release)
+                && build_path.exists()
+            {
+                lines.push(format!("delete directory {}", build_path.display()));
+            }
+        }
+        lines
+    }
+
     /// Execute the clean phase.
     ///
     /// # Errors
@@ -215,7 +317,7 @@ impl StylesheetsTask {
 
         // Redownload: delete cached archives
         if flags.contains(CleanFlags::REDOWNLOAD) {
-            for release in RELEASES {
+            for release in &Self::releases(config) {
                 let cache_file = Self::cache_file(config, release)?;
 
                 if cache_file.exists() {
@@ -237,7 +339,7 @@ impl StylesheetsTask {
 
         // Reextract: delete extracted directories
         if flags.contains(CleanFlags::REEXTRACT) {
-            for release in RELEASES {
+            for release in &Self::releases(config) {
                 let build_path = Self::build_path(config, release)?;
 
                 if build_path.exists() {
@@ -263,45 +365,81 @@ impl StylesheetsTask {
 
     /// Execute the fetch phase (download and extract).
     ///
+    /// Releases are fetched concurrently, each holding a permit of the
+    /// `--jobs` semaphore, or of a `fetch_parallelism` sized one when the
+    /// context has none. Releases still waiting for a permit give up once the
+    /// run is cancelled.
+    ///
     /// # Errors
     ///
-    /// Returns an error if any download or extraction fails.
+    /// Returns the error of the first failed release in release order; the
+    /// others are logged.
     pub async fn do_fetch(&self, ctx: &TaskContext) -> Result<()> {
         let config = &ctx.config;
-        let tool_ctx = ctx.tool_context();
-
-        for release in RELEASES {
-            let url = Self::download_url(config, release);
-            let cache_file = Self::cache_file(config, release)?;
-            let build_path = Self::build_path(config, release)?;
-
-            info!(
-                repo = release.repo,
-                version = %Self::get_version(config, release),
-                "Fetching stylesheet"
-            );
-
-            // Download
-            let downloader = DownloaderTool::new()
-                .url(&url)
-                .file(&cache_file)
-                .force(config.global.clean_download_actions.redownload);
-
-            downloader
-                .run(&tool_ctx)
+        let semaphore = ctx.build_semaphore().map_or_else(
+            || Arc::new(Semaphore::new(ctx.fetch_parallelism().get())),
+            Arc::clone,
+        );
+
+        let mut set = JoinSet::new();
+        // Task ids map back to release indices so errors are reported in
+        // release order, whichever download finishes first.
+        let mut indices = BTreeMap::new();
+        for (index, release) in Self::releases(config).into_iter().enumerate() {
+            let urls = Self::candidate_urls(config, &release);
+            let cache_file = Self::cache_file(config, &release)?;
+            let build_path = Self::build_path(config, &release)?;
+            let version = Self::get_version(config, &release);
+            let semaphore = Arc::clone(&semaphore);
+            let ctx = ctx.clone();
+
+            let handle = set.spawn(async move {
+                let _permit = tokio::select! {
+                    biased;
+                    () = ctx.cancel_token().cancelled() => {
+                        anyhow::bail!("stylesheet {} interrupted before fetch", release.repo);
+                    }
+                    permit = semaphore.acquire_owned() => permit?,
+                };
+
+                info!(repo = %release.repo, %version, "Fetching stylesheet");
+
+                fetch_archive(
+                    &ctx,
+                    &urls,
+                    &cache_file,
+                    &build_path,
+                    &release.repo,
+                    release.sha256.as_deref(),
+                )
                 .await
-                .with_context(|| format!("failed to download {}", release.repo))?;
+            });
+            indices.insert(handle.id(), index);
+        }
 
-            // Extract
-            let extractor = ExtractorTool::new()
-                .archive(&cache_file)
-                .output(&build_path)
-                .force(config.global.clean_download_actions.reextract);
+        // Wait for all and collect errors by release index
+        let mut errors = BTreeMap::new();
+        while let Some(result) = set.join_next_with_id().await {
+            match result {
+                Ok((_, Ok(()))) => {}
+                Ok((id, Err(e))) => {
+                    errors.insert(indices.get(&id).copied().unwrap_or(usize::MAX), e);
+                }
+                Err(e) => {
+                    errors.insert(
+                        indices.get(&e.id()).copied().unwrap_or(usize::MAX),
+                        anyhow::anyhow!("Stylesheet fetch panicked: {e}"),
+                    );
+                }
+            }
+        }
 
-            extractor
-                .run(&tool_ctx)
-                .await
-                .with_context(|| format!("failed to extract {}", release.repo))?;
+        let mut errors = errors.into_values();
+        if let Some(first_error) = errors.next() {
+            for e in errors {
+                tracing::error!(error = %e, "Additional stylesheet fetch error");
+            }
+            return Err(first_error);
         }
 
         Ok(())
@@ -331,14 +469,14 @@ impl StylesheetsTask {
             }
         }
 
-        for release in RELEASES {
+        for release in &Self::releases(config) {
             let build_path = Self::build_path(config, release)?;
 
             // Determine source directory (with or without top-level folder)
             let source_path = if release.top_level_folder.is_empty() {
                 build_path.clone()
             } else {
-                build_path.join(release.top_level_folder)
+                build_path.join(&release.top_level_folder)
             };
 
             if !source_path.exists() {
@@ -349,7 +487,7 @@ impl StylesheetsTask {
                 continue;
             }
 
-            info!(repo = release.repo, "Installing stylesheet");
+            info!(repo = %release.repo, "Installing stylesheet");
 
             // Copy all files and directories from source to install
             if ctx.dry_run {
@@ -376,6 +514,10 @@ impl Taskable for StylesheetsTask {
         Box::pin(self.do_clean(ctx, ctx.clean_flags))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
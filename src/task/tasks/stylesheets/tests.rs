@@ -3,7 +3,7 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{RELEASES, StylesheetsTask};
+use super::StylesheetsTask;
 use crate::config::Config;
 use std::path::PathBuf;
 
@@ -14,7 +14,7 @@ fn test_stylesheets_task_info() {
         "stylesheets_task_info",
         serde_json::json!({
             "name": task.name(),
-            "releases_count": RELEASES.len(),
+            "releases_count": StylesheetsTask::releases(&Config::default()).len(),
         })
     );
 }
@@ -23,7 +23,8 @@ fn test_stylesheets_task_info() {
 fn test_download_url_format() {
     let config = Config::default();
     let _task = StylesheetsTask::new();
-    let release = &RELEASES[0]; // paper-light-and-dark
+    let releases = StylesheetsTask::releases(&config);
+    let release = &releases[0]; // paper-light-and-dark
 
     let url = StylesheetsTask::download_url(&config, release);
     assert!(url.contains("github.com"));
@@ -38,7 +39,8 @@ fn test_cache_file_path() {
     config.paths.cache = Some(PathBuf::from("/test/cache"));
 
     let _task = StylesheetsTask::new();
-    let release = &RELEASES[0];
+    let releases = StylesheetsTask::releases(&config);
+    let release = &releases[0];
 
     let path = StylesheetsTask::cache_file(&config, release).unwrap();
     insta::assert_debug_snapshot!("stylesheets_cache_file_path", path);
@@ -50,7 +52,8 @@ fn test_build_path() {
     config.paths.build = Some(PathBuf::from("/test/build"));
 
     let _task = StylesheetsTask::new();
-    let release = &RELEASES[0];
+    let releases = StylesheetsTask::releases(&config);
+    let release = &releases[0];
 
     let path = StylesheetsTask::build_path(&config, release).unwrap();
     insta::assert_debug_snapshot!("stylesheets_build_path", path);
@@ -61,7 +64,7 @@ fn test_all_releases_have_valid_config() {
     let config = Config::default();
     let _task = StylesheetsTask::new();
 
-    for release in RELEASES {
+    for release in &StylesheetsTask::releases(&config) {
         let version = StylesheetsTask::get_version(&config, release);
         // Should get version from config, not "latest" fallback
         assert_ne!(
@@ -71,3 +74,79 @@ fn test_all_releases_have_valid_config() {
         );
     }
 }
+
+#[test]
+fn test_configured_releases_replace_builtins() {
+    let toml = r#"
+[versions]
+ss_custom = "2.0"
+
+[[stylesheets.release]]
+user = "someone"
+repo = "custom-theme"
+version_key = "ss_custom"
+file = "Custom-Theme"
+top_level_folder = "Custom"
+"#;
+    let config = Config::parse(toml).unwrap();
+
+    let releases = StylesheetsTask::releases(&config);
+    assert_eq!(releases.len(), 1);
+    assert_eq!(releases[0].top_level_folder, "Custom");
+    assert_eq!(releases[0].sha256, None);
+    assert_eq!(
+        StylesheetsTask::download_urls(&config),
+        ["https://github.com/someone/custom-theme/releases/download/2.0/Custom-Theme.7z"]
+    );
+    assert!(Config::parse("[[stylesheets.release]]\nuser = \"someone\"\n").is_err());
+}
+
+#[test]
+fn test_candidate_urls_include_mirrors() {
+    let toml = r#"
+[versions]
+ss_custom = "2.0"
+
+[[stylesheets.release]]
+user = "someone"
+repo = "custom-theme"
+version_key = "ss_custom"
+file = "Custom-Theme"
+mirrors = ["https://mirror.example.com/{version}/Custom-Theme.7z"]
+"#;
+    let config = Config::parse(toml).unwrap();
+    let releases = StylesheetsTask::releases(&config);
+
+    assert_eq!(
+        StylesheetsTask::candidate_urls(&config, &releases[0]),
+        [
+            "https://github.com/someone/custom-theme/releases/download/2.0/Custom-Theme.7z",
+            "https://mirror.example.com/2.0/Custom-Theme.7z",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_fetch_stops_when_cancelled() {
+    use crate::task::TaskContext;
+    use std::sync::Arc;
+    use tokio_util::sync::CancellationToken;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.paths.cache = Some(temp.path().join("downloads"));
+    config.paths.build = Some(temp.path().join("build"));
+
+    let first = StylesheetsTask::releases(&config)[0].repo.clone();
+
+    let cancel_token = CancellationToken::new();
+    cancel_token.cancel();
+    let ctx = TaskContext::new(Arc::new(config), cancel_token).with_dry_run(true);
+
+    // Every release fails; the first one in release order is reported
+    let error = StylesheetsTask::new().do_fetch(&ctx).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!("stylesheet {first} interrupted before fetch")
+    );
+}
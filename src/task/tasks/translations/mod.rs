@@ -10,7 +10,8 @@
 //! # Process
 //!
 //! 1. **Fetch**: Initialize transifex, configure, and pull translations from Transifex
-//! 2. **Build**: Compile .ts files to .qm using lrelease
+//! 2. **Build**: Compile .ts files to .qm using lrelease, skipping those whose
+//!    .qm is newer than the .ts
 //! 3. **Install**: Copy Qt builtin translations
 //!
 //! # Directory Structure
@@ -29,8 +30,9 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::config::Config;
 use crate::error::Result;
-use crate::task::helpers::{copy_file_if_newer, ensure_dir};
+use crate::task::helpers::{copy_file_if_newer, ensure_dir, is_outdated};
 use crate::task::tools::Tool;
 use crate::task::tools::lrelease::LreleaseTool;
 use crate::task::tools::transifex::TransifexTool;
@@ -68,9 +70,12 @@ impl TranslationsTask {
     }
 
     /// Get the source path for transifex directory.
-    fn source_path(ctx: &TaskContext) -> Result<PathBuf> {
-        let build = ctx
-            .config
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.build` is not configured.
+    pub fn source_path(config: &Config) -> Result<PathBuf> {
+        let build = config
             .paths
             .build
             .as_ref()
@@ -80,7 +85,7 @@ impl TranslationsTask {
 
     /// Get the translations subdirectory inside `source_path`.
     fn translations_path(ctx: &TaskContext) -> Result<PathBuf> {
-        Ok(Self::source_path(ctx)?.join("translations"))
+        Ok(Self::source_path(ctx.config())?.join("translations"))
     }
 
     /// Get the install path for translations (.qm files).
@@ -97,6 +102,25 @@ impl TranslationsTask {
         ctx.config().paths.qt_translations.clone()
     }
 
+    /// Describes what [`Self::do_clean`] would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let mut lines = Vec::new();
+        if flags.contains(CleanFlags::REDOWNLOAD)
+            && let Ok(source) = Self::source_path(ctx.config())
+            && source.exists()
+        {
+            lines.push(format!("delete directory {}", source.display()));
+        }
+        if flags.contains(CleanFlags::REBUILD)
+            && let Ok(install) = Self::install_path(ctx)
+            && install.exists()
+        {
+            lines.push(format!("delete .qm files in {}", install.display()));
+        }
+        lines
+    }
+
     /// Execute the clean phase.
     ///
     /// # Errors
@@ -105,7 +129,7 @@ impl TranslationsTask {
     pub async fn do_clean(&self, ctx: &TaskContext, flags: CleanFlags) -> Result<()> {
         // Redownload: delete entire transifex directory
         if flags.contains(CleanFlags::REDOWNLOAD) {
-            let source = Self::source_path(ctx)?;
+            let source = Self::source_path(ctx.config())?;
             if source.exists() {
                 if ctx.dry_run {
                     info!(
@@ -147,7 +171,7 @@ impl TranslationsTask {
     pub async fn do_fetch(&self, ctx: &TaskContext) -> Result<()> {
         let config = &ctx.config;
         let tool_ctx = ctx.tool_context();
-        let source = Self::source_path(ctx)?;
+        let source = Self::source_path(ctx.config())?;
 
         // Check for API key
         let api_key = if !config.transifex.key.is_empty() {
@@ -200,6 +224,7 @@ impl TranslationsTask {
                 .api_key(&api_key)
                 .minimum(config.transifex.minimum)
                 .force(config.transifex.actions.force)
+                .pull_mode(config.transifex.actions.pull_mode)
                 .pull_op();
             pull_tool
                 .run(&tool_ctx)
@@ -255,17 +280,28 @@ impl TranslationsTask {
                     .and_then(|s| s.to_str())
                     .unwrap_or("unknown");
 
+                let lrelease = LreleaseTool::new()
+                    .project(project.name())
+                    .add_source(ts_file)
+                    .output_dir(&install);
+
+                let qm_path = lrelease.qm_path()?;
+                if !is_outdated(ts_file, &qm_path).await? {
+                    debug!(
+                        project = %project.name(),
+                        lang,
+                        qm = %qm_path.display(),
+                        "Translation up to date, skipping"
+                    );
+                    continue;
+                }
+
                 debug!(
                     project = %project.name(),
                     lang,
                     "Compiling translation"
                 );
 
-                let lrelease = LreleaseTool::new()
-                    .project(project.name())
-                    .add_source(ts_file)
-                    .output_dir(&install);
-
                 lrelease
                     .run(&tool_ctx)
                     .await
@@ -361,10 +397,19 @@ impl Taskable for TranslationsTask {
         ctx.config().task_config(&self.name).enabled && ctx.config().transifex.enabled
     }
 
+    fn dependencies(&self) -> Vec<&str> {
+        // Compiled translations go next to the installed binaries
+        vec!["modorganizer"]
+    }
+
     fn do_clean<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_clean(ctx, ctx.clean_flags))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
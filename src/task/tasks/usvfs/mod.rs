@@ -16,13 +16,16 @@
 //!
 //! USVFS must be built for both x86 and x64 architectures, as the 32-bit
 //! version is needed for 32-bit applications and the 64-bit version for
-//! 64-bit applications.
+//! 64-bit applications. `usvfs_arch = "x64"` or `"x86"` builds only one of
+//! them for local iteration; releases refuse such a configuration.
 //!
 //! # Build Process
 //!
 //! 1. **Fetch**: Clone the usvfs repository
 //! 2. **Configure**: Run `CMake` with VS generator for both architectures
 //! 3. **Build**: Use `MSBuild` to build both architectures
+//! 4. **Staged install** (`cmake.staged_install`): `CMAKE_INSTALL_PREFIX` points
+//!    at a staging tree that is moved into `paths.install` after both builds
 //!
 //! # Phases
 //!
@@ -30,7 +33,7 @@
 //! - **Fetch**: Git clone/pull the repository
 //! - **`BuildAndInstall`**: `CMake` configure + `MSBuild` for x86 and x64
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::Result;
 use anyhow::Context;
@@ -38,10 +41,15 @@ use futures_util::future::BoxFuture;
 use tracing::{debug, info};
 
 use crate::config::Config;
+use crate::config::types::CmakeGenerator;
+use crate::config::types::UsvfsArch;
 use crate::core::env::types::Arch;
-use crate::task::helpers::safe_remove_source;
+use crate::task::helpers::{
+    FETCH_RETRY_DELAY, commit_staged_install, reset_staged_install, retry_async,
+    safe_remove_source, staging_install_prefix,
+};
 use crate::task::tools::Tool;
-use crate::task::tools::cmake::{CmakeGenerator, CmakeTool};
+use crate::task::tools::cmake::CmakeTool;
 use crate::task::tools::git::GitTool;
 use crate::task::tools::msbuild::MsBuildTool;
 use crate::task::{CleanFlags, TaskContext, Taskable};
@@ -86,7 +94,7 @@ impl UsvfsTask {
     }
 
     /// Returns the git URL for the USVFS repository.
-    fn git_url(config: &Config) -> String {
+    pub(crate) fn git_url(config: &Config) -> String {
         format!(
             "{}{}/usvfs.git",
             config.task.git_url_prefix, config.task.mo_org
@@ -94,7 +102,7 @@ impl UsvfsTask {
     }
 
     /// Returns the source directory path.
-    fn source_path(config: &Config) -> Result<PathBuf> {
+    pub(crate) fn source_path(config: &Config) -> Result<PathBuf> {
         let build_dir = config
             .paths
             .build
@@ -119,6 +127,22 @@ impl UsvfsTask {
         Ok(build_dir.join("usvfs.sln"))
     }
 
+    /// Ensures `usvfs_arch` builds both architectures, as releases ship both.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `usvfs_arch` selects a single architecture.
+    pub fn ensure_all_arches(config: &Config) -> Result<()> {
+        let usvfs_arch = config.task_config("usvfs").usvfs_arch;
+        if usvfs_arch != UsvfsArch::Both {
+            anyhow::bail!(
+                "usvfs_arch is \"{usvfs_arch}\" but releases need both architectures; \
+                 set usvfs_arch = \"both\" in [tasks.usvfs]"
+            );
+        }
+        Ok(())
+    }
+
     /// Returns the version/branch to use.
     fn version(config: &Config) -> String {
         config.versions.usvfs.clone()
@@ -132,6 +156,86 @@ impl UsvfsTask {
         }
     }
 
+    /// Returns the configured install prefix.
+    fn install_path(config: &Config) -> Result<&Path> {
+        config
+            .paths
+            .install
+            .as_deref()
+            .context("paths.install not configured")
+    }
+
+    /// Returns the staging prefix used when `cmake.staged_install` is set.
+    fn staging_path(config: &Config) -> Result<PathBuf> {
+        Ok(staging_install_prefix(Self::install_path(config)?, "usvfs"))
+    }
+
+    /// Builds the `CMake` configure step for one architecture.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `paths.build` or `paths.install` is not configured.
+    pub fn cmake_configure_tool(config: &Config, arch: Arch) -> Result<CmakeTool> {
+        let source_path = Self::source_path(config)?;
+        let build_dir = Self::build_dir(config, arch)?;
+        let install_prefix = if config.cmake.staged_install {
+            Self::staging_path(config)?
+        } else {
+            Self::install_path(config)?.to_path_buf()
+        };
+
+        let mut cmake = CmakeTool::new()
+            .source_dir(&source_path)
+            .build_dir(&build_dir)
+            .generator(CmakeGenerator::VisualStudio)
+            .preset(Self::cmake_preset(arch));
+        if let Some(toolchain) = config.paths.vcpkg_toolchain() {
+            cmake = cmake.toolchain_file(toolchain);
+        }
+
+        Ok(cmake
+            .definition("CMAKE_INSTALL_PREFIX", install_prefix.display().to_string())
+            .definition("BUILD_TESTING", "OFF")
+            .configure_op())
+    }
+
+    /// Describes what [`Self::do_clean`] would remove for `flags`.
+    #[must_use]
+    pub fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        let config = ctx.config();
+        let Ok(source_path) = Self::source_path(config) else {
+            return Vec::new();
+        };
+        if flags.contains(CleanFlags::REEXTRACT) {
+            return if source_path.exists() {
+                vec![format!("delete directory {}", source_path.display())]
+            } else {
+                Vec::new()
+            };
+        }
+
+        let arches = config.task_config(&self.name).usvfs_arch.arches();
+        let mut lines = Vec::new();
+        for &arch in arches {
+            if flags.contains(CleanFlags::RECONFIGURE)
+                && let Ok(build_dir) = Self::build_dir(config, arch)
+                && build_dir.exists()
+            {
+                lines.push(format!(
+                    "delete CMakeCache.txt and CMakeFiles in {}",
+                    build_dir.display()
+                ));
+            }
+            if flags.contains(CleanFlags::REBUILD)
+                && let Ok(solution) = Self::solution_path(config, arch)
+                && solution.exists()
+            {
+                lines.push(format!("run MSBuild Clean on {}", solution.display()));
+            }
+        }
+        lines
+    }
+
     /// Execute the clean phase.
     ///
     /// # Errors
@@ -150,15 +254,15 @@ impl UsvfsTask {
 
         let tool_ctx = ctx.tool_context();
 
-        // Reconfigure: clean cmake cache for both architectures
+        // Reconfigure: clean cmake cache for the selected architectures
         if flags.contains(CleanFlags::RECONFIGURE) {
-            for arch in [Arch::X64, Arch::X86] {
+            for &arch in config.task_config(&self.name).usvfs_arch.arches() {
                 let build_dir = Self::build_dir(config, arch)?;
                 if build_dir.exists() {
                     let cmake = CmakeTool::new()
                         .source_dir(&source_path)
                         .build_dir(&build_dir)
-                        .clean_op();
+                        .clean_cache_op();
 
                     if ctx.is_dry_run() {
                         info!(
@@ -176,11 +280,11 @@ impl UsvfsTask {
             }
         }
 
-        // Rebuild: clean with MSBuild for both architectures
+        // Rebuild: clean with MSBuild for the selected architectures
         if flags.contains(CleanFlags::REBUILD) {
             let task_config = config.task_config(&self.name);
 
-            for arch in [Arch::X64, Arch::X86] {
+            for &arch in task_config.usvfs_arch.arches() {
                 let solution = Self::solution_path(config, arch)?;
                 if solution.exists() {
                     let msbuild = MsBuildTool::new()
@@ -229,9 +333,21 @@ impl UsvfsTask {
                 "Pulling updates"
             );
 
-            let git = GitTool::new().path(&source_path).branch(&branch).pull_op();
-
-            git.run(&tool_ctx).await.context("failed to pull usvfs")?;
+            let git = GitTool::new()
+                .path(&source_path)
+                .branch(&branch)
+                .remote(&task_config.git_behavior.pull_remote)
+                .lfs(task_config.git_behavior.git_lfs)
+                .pull_op();
+
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .context("failed to pull usvfs")?;
         } else {
             // Clone new repo
             info!(
@@ -245,13 +361,24 @@ impl UsvfsTask {
                 .url(&git_url)
                 .path(&source_path)
                 .branch(&branch)
+                .lfs(task_config.git_behavior.git_lfs)
                 .clone_op();
 
             if task_config.git_clone.git_shallow {
                 git = git.shallow(true);
             }
+            if task_config.git_clone.git_single_branch {
+                git = git.single_branch(true);
+            }
 
-            git.run(&tool_ctx).await.context("failed to clone usvfs")?;
+            retry_async(
+                ctx,
+                task_config.git_behavior.fetch_attempts,
+                FETCH_RETRY_DELAY,
+                || git.run(&tool_ctx),
+            )
+            .await
+            .context("failed to clone usvfs")?;
         }
 
         // Update submodules if present
@@ -259,7 +386,10 @@ impl UsvfsTask {
         if gitmodules.exists() {
             debug!(repo = "usvfs", "Updating submodules");
 
-            let git = GitTool::new().path(&source_path).submodule_update_op();
+            let git = GitTool::new()
+                .path(&source_path)
+                .jobs(ctx.fetch_parallelism())
+                .submodule_update_op();
 
             git.run(&tool_ctx)
                 .await
@@ -278,45 +408,39 @@ impl UsvfsTask {
     pub async fn do_build_and_install(&self, ctx: &TaskContext) -> Result<()> {
         let config = ctx.config();
         let task_config = config.task_config(&self.name);
-        let source_path = Self::source_path(config)?;
-        let install_prefix = config
-            .paths
-            .install
-            .as_ref()
-            .context("paths.install not configured")?;
-
         let tool_ctx = ctx.tool_context();
 
-        // Configure and build for both architectures
-        for arch in [Arch::X64, Arch::X86] {
-            let build_dir = Self::build_dir(config, arch)?;
-            let preset = Self::cmake_preset(arch);
+        if config.cmake.staged_install {
+            reset_staged_install(
+                ctx,
+                &Self::staging_path(config)?,
+                Self::install_path(config)?,
+            )
+            .await?;
+        }
 
+        // Configure and build for the selected architectures
+        for &arch in task_config.usvfs_arch.arches() {
             // CMake configure
             info!(
                 repo = "usvfs",
                 arch = ?arch,
-                preset = preset,
+                preset = Self::cmake_preset(arch),
                 "Configuring with CMake"
             );
 
-            let cmake_configure = CmakeTool::new()
-                .source_dir(&source_path)
-                .build_dir(&build_dir)
-                .generator(CmakeGenerator::VisualStudio)
-                .preset(preset)
-                .definition("CMAKE_INSTALL_PREFIX", install_prefix.display().to_string())
-                .definition("BUILD_TESTING", "OFF")
-                .configure_op();
-
+            let mut cmake_configure = Self::cmake_configure_tool(config, arch)?;
+            if let Some(message) = ctx.install_message() {
+                cmake_configure = cmake_configure.install_message(message);
+            }
             cmake_configure
                 .run(&tool_ctx)
                 .await
                 .with_context(|| format!("failed to configure usvfs for {arch:?}"))?;
         }
 
-        // Build with MSBuild for both architectures
-        for arch in [Arch::X64, Arch::X86] {
+        // Build with MSBuild for the selected architectures
+        for &arch in task_config.usvfs_arch.arches() {
             let solution = Self::solution_path(config, arch)?;
 
             info!(
@@ -339,6 +463,17 @@ impl UsvfsTask {
                 .with_context(|| format!("failed to build usvfs for {arch:?}"))?;
         }
 
+        // All architectures built: move the staged install into place
+        if config.cmake.staged_install {
+            commit_staged_install(
+                ctx,
+                &Self::staging_path(config)?,
+                Self::install_path(config)?,
+            )
+            .await
+            .context("failed to install usvfs")?;
+        }
+
         Ok(())
     }
 }
@@ -356,6 +491,10 @@ impl Taskable for UsvfsTask {
         Box::pin(self.do_clean(ctx, ctx.clean_flags()))
     }
 
+    fn clean_description(&self, ctx: &TaskContext, flags: CleanFlags) -> Vec<String> {
+        self.clean_description(ctx, flags)
+    }
+
     fn do_fetch<'a>(&'a self, ctx: &'a TaskContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(self.do_fetch(ctx))
     }
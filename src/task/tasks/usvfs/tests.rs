@@ -11,7 +11,7 @@ use tokio_util::sync::CancellationToken;
 use super::UsvfsTask;
 use crate::config::Config;
 use crate::core::env::types::Arch;
-use crate::task::{TaskContext, Taskable};
+use crate::task::{CleanFlags, ParallelTasks, Task, TaskContext, Taskable};
 
 fn test_config() -> Arc<Config> {
     let mut config = Config::default();
@@ -85,6 +85,33 @@ fn test_cmake_presets() {
     insta::assert_snapshot!("usvfs_cmake_preset_x86", UsvfsTask::cmake_preset(Arch::X86));
 }
 
+#[tokio::test]
+async fn test_cmake_configure_args() {
+    let config = test_config();
+    let args = UsvfsTask::cmake_configure_tool(&config, Arch::X86)
+        .unwrap()
+        .configure_args(&config)
+        .await
+        .unwrap();
+    insta::assert_debug_snapshot!("usvfs_cmake_configure_args_x86", args);
+}
+
+#[tokio::test]
+async fn test_cmake_configure_args_staged_install() {
+    let mut config = Config::clone(&test_config());
+    config.cmake.staged_install = true;
+    let args = UsvfsTask::cmake_configure_tool(&config, Arch::X64)
+        .unwrap()
+        .configure_args(&config)
+        .await
+        .unwrap();
+    let prefix = args
+        .iter()
+        .find(|arg| arg.starts_with("-DCMAKE_INSTALL_PREFIX="))
+        .unwrap();
+    assert!(prefix.contains("install.staging"), "{prefix}");
+}
+
 #[test]
 fn test_enabled() {
     let config = test_config();
@@ -104,3 +131,64 @@ fn test_version_configured() {
     let version = UsvfsTask::version(&config);
     insta::assert_snapshot!("usvfs_version_configured", version);
 }
+
+#[test]
+fn test_clean_description() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.paths.build = Some(temp.path().to_path_buf());
+    let ctx = test_ctx(Arc::new(config));
+    let task = UsvfsTask::new();
+    let source = temp.path().join("usvfs");
+
+    // Nothing exists yet, so nothing would be removed
+    assert!(
+        task.clean_description(&ctx, CleanFlags::REEXTRACT)
+            .is_empty()
+    );
+
+    std::fs::create_dir_all(source.join("vsbuild64")).unwrap();
+    assert_eq!(
+        task.clean_description(&ctx, CleanFlags::REEXTRACT),
+        vec![format!("delete directory {}", source.display())]
+    );
+    assert_eq!(
+        task.clean_description(&ctx, CleanFlags::RECONFIGURE),
+        vec![format!(
+            "delete CMakeCache.txt and CMakeFiles in {}",
+            source.join("vsbuild64").display()
+        )]
+    );
+
+    // Parallel groups report their children's lines
+    let group = Task::Parallel(ParallelTasks::new(vec![Task::Usvfs(task)]));
+    assert_eq!(
+        Taskable::clean_description(&group, &ctx, CleanFlags::REEXTRACT).len(),
+        1
+    );
+}
+
+#[test]
+fn test_usvfs_arch_limits_architectures() {
+    let temp = tempfile::tempdir().unwrap();
+    let toml = format!(
+        "[paths]\nbuild = '{}'\n\n[tasks.usvfs]\nusvfs_arch = \"x86\"\n",
+        temp.path().display()
+    );
+    let config = Config::parse(&toml).unwrap();
+    let source = temp.path().join("usvfs");
+    std::fs::create_dir_all(source.join("vsbuild64")).unwrap();
+    std::fs::create_dir_all(source.join("vsbuild32")).unwrap();
+
+    assert!(UsvfsTask::ensure_all_arches(&config).is_err());
+    assert!(UsvfsTask::ensure_all_arches(&Config::default()).is_ok());
+
+    let ctx = test_ctx(Arc::new(config));
+    assert_eq!(
+        UsvfsTask::new().clean_description(&ctx, CleanFlags::RECONFIGURE),
+        vec![format!(
+            "delete CMakeCache.txt and CMakeFiles in {}",
+            source.join("vsbuild32").display()
+        )]
+    );
+}
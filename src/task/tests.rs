@@ -3,8 +3,9 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{CleanFlags, ParallelTasks, Phase, Task, TaskContext, Taskable};
+use super::{CleanFlags, ParallelTasks, Phase, PhaseControl, Task, TaskContext, Taskable};
 use crate::config::Config;
+use crate::core::env::container::Env;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
@@ -24,6 +25,26 @@ fn test_phase_name() {
     insta::assert_debug_snapshot!("test_phase_names", names);
 }
 
+#[test]
+fn test_phase_from_str() {
+    let parsed: Vec<_> = ["clean", "fetch", "build", "Build_And_Install", "install"]
+        .into_iter()
+        .map(|input| (input, input.parse::<Phase>().ok()))
+        .collect();
+    insta::assert_debug_snapshot!("test_phase_from_str", parsed);
+}
+
+#[test]
+fn test_phase_control_from_phases() {
+    let fetch_only = PhaseControl::from_phases(&[Phase::Fetch]);
+    assert!(!fetch_only.do_clean());
+    assert!(fetch_only.do_fetch());
+    assert!(!fetch_only.do_build());
+
+    let all = PhaseControl::from_phases(Phase::all());
+    assert!(all.do_clean() && all.do_fetch() && all.do_build());
+}
+
 #[test]
 fn test_clean_flags() {
     let flags = CleanFlags::REDOWNLOAD | CleanFlags::REBUILD;
@@ -64,7 +85,8 @@ fn test_task_context_builder() {
     let ctx = TaskContext::new(config, token)
         .with_dry_run(true)
         .with_do_clean(true)
-        .with_clean_flags(CleanFlags::REBUILD);
+        .with_clean_flags(CleanFlags::REBUILD)
+        .with_fetch_parallelism(std::num::NonZeroUsize::new(8).unwrap());
 
     insta::assert_debug_snapshot!(
         "test_task_context_builder",
@@ -74,10 +96,24 @@ fn test_task_context_builder() {
             ctx.phases().do_fetch(),
             ctx.phases().do_build(),
             ctx.clean_flags(),
+            ctx.fetch_parallelism(),
         )
     );
 }
 
+#[test]
+fn test_task_context_env_propagates_to_tool_context() {
+    let config = test_config();
+    let mut env = Env::new();
+    env.set("VCPKG_ROOT", "C:/vcpkg");
+
+    let ctx = TaskContext::new(config, CancellationToken::new()).with_env(env);
+    let tool_ctx = ctx.tool_context();
+
+    assert_eq!(ctx.env().get("VCPKG_ROOT"), Some("C:/vcpkg"));
+    assert_eq!(tool_ctx.env().get("VCPKG_ROOT"), Some("C:/vcpkg"));
+}
+
 #[test]
 fn test_task_context_cancellation() {
     let config = test_config();
@@ -89,6 +125,28 @@ fn test_task_context_cancellation() {
     assert!(ctx.is_cancelled());
 }
 
+#[tokio::test]
+async fn test_build_permit_only_for_leaf_tasks() {
+    use super::tasks::usvfs::UsvfsTask;
+    use tokio::sync::Semaphore;
+
+    let semaphore = Arc::new(Semaphore::new(1));
+    let ctx = TaskContext::new(test_config(), CancellationToken::new())
+        .with_build_semaphore(Arc::clone(&semaphore));
+    let group = Task::Parallel(ParallelTasks::new(vec![]));
+    let leaf = Task::Usvfs(UsvfsTask::new());
+
+    let permit = leaf.build_permit(&ctx).await.expect("acquire");
+    assert!(permit.is_some());
+    assert_eq!(semaphore.available_permits(), 0);
+
+    // Groups never wait, so nested groups can't starve their children.
+    assert!(group.build_permit(&ctx).await.expect("acquire").is_none());
+
+    drop(permit);
+    assert_eq!(semaphore.available_permits(), 1);
+}
+
 #[test]
 fn test_parallel_tasks() {
     let parallel = ParallelTasks::new(vec![]);
@@ -101,3 +159,246 @@ fn test_parallel_tasks() {
         })
     );
 }
+
+#[tokio::test]
+async fn test_parallel_failure_mode() {
+    use super::FailureMode;
+    use super::tasks::licenses::LicensesTask;
+
+    // paths.install_licenses is missing, so both licenses tasks fail
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let mut config = Config::default();
+    config.paths.licenses = Some(temp.path().to_path_buf());
+    let ctx = TaskContext::new(Arc::new(config), CancellationToken::new());
+    let parallel = ParallelTasks::new(vec![
+        Task::Licenses(LicensesTask::new()),
+        Task::Licenses(LicensesTask::new()),
+    ]);
+
+    let message = parallel
+        .do_build_and_install(&ctx)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(!message.contains("task(s) failed"), "{message}");
+    assert!(message.contains("paths.install_licenses"), "{message}");
+
+    let ctx = ctx.with_failure_mode(FailureMode::Aggregate);
+    let message = parallel
+        .do_build_and_install(&ctx)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        message.starts_with("2 task(s) failed:\n  licenses: "),
+        "{message}"
+    );
+    assert_eq!(message.matches("\n  licenses: ").count(), 2, "{message}");
+}
+
+#[test]
+fn test_failures_result_keeps_first_error() {
+    use crate::error::{ConfigError, exit_code, exit_codes};
+
+    assert!(super::failures_result(Vec::new()).is_ok());
+
+    let error = super::failures_result(vec![
+        (
+            "usvfs".to_string(),
+            anyhow::Error::new(ConfigError::MissingKey {
+                section: "paths".to_string(),
+                key: "prefix".to_string(),
+            }),
+        ),
+        ("licenses".to_string(), anyhow::anyhow!("no licenses")),
+    ])
+    .unwrap_err();
+    assert_eq!(exit_code(&error), exit_codes::CONFIG);
+    assert_eq!(
+        error.to_string(),
+        "2 task(s) failed:\n  usvfs: missing required config key 'prefix' in section '[paths]'\n  licenses: no licenses"
+    );
+}
+
+#[test]
+fn test_missing_sources() {
+    use super::tasks::licenses::LicensesTask;
+    use super::tasks::modorganizer::ModOrganizerTask;
+    use super::tasks::usvfs::UsvfsTask;
+
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let mut config = Config::default();
+    config.paths.build = Some(temp.path().to_path_buf());
+
+    // A plain directory is not a cloned repository
+    std::fs::create_dir_all(temp.path().join("usvfs")).expect("create usvfs");
+    let uibase_path = temp.path().join("modorganizer-uibase");
+    std::fs::create_dir_all(&uibase_path).expect("create uibase");
+    crate::git::cmd::init_repo(&uibase_path).expect("init repo");
+
+    let usvfs = Task::Usvfs(UsvfsTask::new());
+    let uibase = Task::ModOrganizer(ModOrganizerTask::new("modorganizer-uibase"));
+    let licenses = Task::Licenses(LicensesTask::new());
+    let parallel = Task::Parallel(ParallelTasks::new(vec![usvfs.clone(), uibase.clone()]));
+
+    assert_eq!(
+        usvfs.missing_sources(&config).expect("resolve"),
+        vec![temp.path().join("usvfs")]
+    );
+    assert!(uibase.missing_sources(&config).expect("resolve").is_empty());
+    assert!(
+        licenses
+            .missing_sources(&config)
+            .expect("resolve")
+            .is_empty()
+    );
+    assert_eq!(parallel.missing_sources(&config).expect("resolve").len(), 1);
+}
+
+#[test]
+fn test_missing_repos_skips_downloads() {
+    use super::tasks::stylesheets::StylesheetsTask;
+    use super::tasks::usvfs::UsvfsTask;
+
+    let temp = tempfile::tempdir().expect("failed to create temp dir");
+    let mut config = Config::default();
+    config.paths.build = Some(temp.path().to_path_buf());
+
+    let stylesheets = Task::Stylesheets(StylesheetsTask::new());
+    let parallel = Task::Parallel(ParallelTasks::new(vec![
+        Task::Usvfs(UsvfsTask::new()),
+        stylesheets.clone(),
+    ]));
+
+    assert!(
+        !stylesheets
+            .missing_sources(&config)
+            .expect("resolve")
+            .is_empty()
+    );
+    assert!(
+        stylesheets
+            .missing_repos(&config)
+            .expect("resolve")
+            .is_empty()
+    );
+    assert_eq!(
+        parallel.missing_repos(&config).expect("resolve"),
+        vec![temp.path().join("usvfs")]
+    );
+}
+
+#[test]
+fn test_external_urls() {
+    use super::UrlKind;
+    use super::tasks::explorerpp::ExplorerPPTask;
+    use super::tasks::licenses::LicensesTask;
+    use super::tasks::modorganizer::ModOrganizerTask;
+    use super::tasks::usvfs::UsvfsTask;
+
+    let mut config = Config::default();
+    config.task.mo_org = "ModOrganizer2".to_string();
+
+    let uibase = Task::ModOrganizer(ModOrganizerTask::new("modorganizer-uibase"));
+    let parallel = Task::Parallel(ParallelTasks::new(vec![
+        Task::Usvfs(UsvfsTask::new()),
+        uibase.clone(),
+    ]));
+
+    assert_eq!(
+        uibase.external_urls(&config),
+        [(
+            UrlKind::Git,
+            "https://github.com/ModOrganizer2/modorganizer-uibase.git".to_string()
+        )]
+    );
+    assert_eq!(parallel.external_urls(&config).len(), 2);
+    assert!(matches!(
+        Task::ExplorerPP(ExplorerPPTask::new()).external_urls(&config)[..],
+        [(UrlKind::Download, ref url)] if url.starts_with("https://download.explorerplusplus.com/")
+    ));
+    assert!(
+        Task::Licenses(LicensesTask::new())
+            .external_urls(&config)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_timing_summary_sorted_by_total() {
+    use super::timing::{PhaseTiming, RunTimings, format_summary, summarize};
+    use std::time::Duration;
+
+    let timings = RunTimings::new();
+    for (task, phase, secs) in [
+        ("stylesheets", Phase::Fetch, 2),
+        ("modorganizer-uibase", Phase::Fetch, 1),
+        ("modorganizer-uibase", Phase::BuildAndInstall, 40),
+        ("stylesheets", Phase::BuildAndInstall, 1),
+        ("usvfs", Phase::Clean, 3),
+    ] {
+        timings.record(PhaseTiming::new(
+            task.to_string(),
+            phase,
+            Duration::from_secs(secs),
+        ));
+    }
+
+    let summary = summarize(&timings.take());
+    assert!(timings.take().is_empty());
+    let tasks: Vec<_> = summary
+        .iter()
+        .map(super::timing::TaskTiming::task)
+        .collect();
+    assert_eq!(tasks, ["modorganizer-uibase", "stylesheets", "usvfs"]);
+    assert_eq!(summary[0].phase(Phase::Fetch), Duration::from_secs(1));
+    assert_eq!(summary[0].total(), Duration::from_secs(41));
+
+    let lines = format_summary(&summary);
+    assert_eq!(
+        lines[0],
+        "task                    clean     fetch     build     total"
+    );
+    assert_eq!(
+        lines[1],
+        "modorganizer-uibase      0.0s      1.0s     40.0s     41.0s"
+    );
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn test_format_plan_groups_steps_by_task() {
+    use super::plan::{Plan, PlanRecorder, PlannedAction, format_plan};
+    use std::path::PathBuf;
+
+    let plan = Arc::new(Plan::new());
+    let usvfs = PlanRecorder::new(Arc::clone(&plan), "usvfs".to_string());
+    let stylesheets = usvfs.for_task("stylesheets");
+    usvfs.record(PlannedAction::Command {
+        program: PathBuf::from("git"),
+        args: vec![
+            "clone".to_string(),
+            "https://example.com/usvfs.git".to_string(),
+        ],
+        cwd: Some(PathBuf::from("build")),
+    });
+    stylesheets.record(PlannedAction::Download {
+        url: "https://example.com/a.7z".to_string(),
+        file: PathBuf::from("downloads/a.7z"),
+    });
+    usvfs.record(PlannedAction::Extract {
+        archive: PathBuf::from("downloads/b.7z"),
+        output: PathBuf::from("build/b"),
+    });
+
+    let text = format_plan(&plan.take());
+    assert_eq!(
+        text,
+        "Build plan (3 steps)\n\
+         ├─ usvfs\n\
+         │  ├─ run git clone https://example.com/usvfs.git (in build)\n\
+         │  └─ extract downloads/b.7z -> build/b\n\
+         └─ stylesheets\n   \
+         └─ download https://example.com/a.7z -> downloads/a.7z\n"
+    );
+}
@@ -0,0 +1,200 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Wall-clock timings of task phases, recorded for `--measure`.
+//!
+//! ```text
+//! TaskManager::with_measure(phases, processes)
+//!       |
+//! Task::run()
+//!   each phase finished --> TaskContext::phase_timings
+//!                             --> RunTimings::record(task, phase, elapsed)
+//!                                  |
+//!                                  v
+//!                     RunTimings::take() --> build profile
+//! ```
+//!
+//! Parallel groups are recorded once under `parallel` with the wall time of
+//! the whole group.
+//!
+//! Independently, every `TaskManager::run_all` collects a [`RunTimings`] that
+//! records tasks inside parallel groups individually and is printed as a
+//! per-task summary, slowest first:
+//!
+//! ```text
+//! TaskContext::run_timings --> RunTimings::record(task, phase, elapsed)
+//!                                  |
+//!                                  v
+//!                     summarize() --> format_summary() --> INFO log
+//! ```
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::Phase;
+
+/// Header of the task column in the run summary.
+const TASK_HEADER: &str = "task";
+
+/// Wall-clock duration of one phase of one task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseTiming {
+    task: String,
+    phase: Phase,
+    duration: Duration,
+}
+
+impl PhaseTiming {
+    /// Creates a new timing record.
+    #[must_use]
+    pub const fn new(task: String, phase: Phase, duration: Duration) -> Self {
+        Self {
+            task,
+            phase,
+            duration,
+        }
+    }
+
+    /// Returns the task name.
+    #[must_use]
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    /// Returns the phase.
+    #[must_use]
+    pub const fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns how long the phase ran.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Phase timings of one `TaskManager` run, shared by all of its tasks.
+#[derive(Debug, Default)]
+pub struct RunTimings {
+    timings: Mutex<Vec<PhaseTiming>>,
+}
+
+impl RunTimings {
+    /// Creates an empty collection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `timing`.
+    pub fn record(&self, timing: PhaseTiming) {
+        self.timings
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(timing);
+    }
+
+    /// Returns and clears the recorded timings.
+    #[must_use]
+    pub fn take(&self) -> Vec<PhaseTiming> {
+        std::mem::take(
+            &mut *self
+                .timings
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+}
+
+/// Summed phase durations of one task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskTiming {
+    task: String,
+    clean: Duration,
+    fetch: Duration,
+    build: Duration,
+}
+
+impl TaskTiming {
+    /// Returns the task name.
+    #[must_use]
+    pub fn task(&self) -> &str {
+        &self.task
+    }
+
+    /// Returns how long `phase` ran.
+    #[must_use]
+    pub const fn phase(&self, phase: Phase) -> Duration {
+        match phase {
+            Phase::Clean => self.clean,
+            Phase::Fetch => self.fetch,
+            Phase::BuildAndInstall => self.build,
+        }
+    }
+
+    /// Returns the duration of all phases.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.clean + self.fetch + self.build
+    }
+}
+
+/// Merges `timings` into one entry per task, slowest first.
+///
+/// Tasks with the same total keep their first-run order.
+#[must_use]
+pub fn summarize(timings: &[PhaseTiming]) -> Vec<TaskTiming> {
+    let mut summary: Vec<TaskTiming> = Vec::new();
+    for timing in timings {
+        let index = summary
+            .iter()
+            .position(|t| t.task == timing.task)
+            .unwrap_or_else(|| {
+                summary.push(TaskTiming {
+                    task: timing.task.clone(),
+                    clean: Duration::ZERO,
+                    fetch: Duration::ZERO,
+                    build: Duration::ZERO,
+                });
+                summary.len() - 1
+            });
+        let entry = &mut summary[index];
+        match timing.phase {
+            Phase::Clean => entry.clean += timing.duration,
+            Phase::Fetch => entry.fetch += timing.duration,
+            Phase::BuildAndInstall => entry.build += timing.duration,
+        }
+    }
+    summary.sort_by_key(|t| std::cmp::Reverse(t.total()));
+    summary
+}
+
+/// Formats `summary` as table lines with a header row.
+#[must_use]
+pub fn format_summary(summary: &[TaskTiming]) -> Vec<String> {
+    let width = summary
+        .iter()
+        .map(|t| t.task.len())
+        .chain([TASK_HEADER.len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut lines = vec![format!(
+        "{TASK_HEADER:<width$} {:>9} {:>9} {:>9} {:>9}",
+        "clean", "fetch", "build", "total"
+    )];
+    for t in summary {
+        lines.push(format!(
+            "{:<width$} {:>8.1}s {:>8.1}s {:>8.1}s {:>8.1}s",
+            t.task,
+            t.clean.as_secs_f64(),
+            t.fetch.as_secs_f64(),
+            t.build.as_secs_f64(),
+            t.total().as_secs_f64(),
+        ));
+    }
+    lines
+}
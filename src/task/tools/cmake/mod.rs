@@ -7,44 +7,44 @@
 //!
 //! ```text
 //! CmakeTool
-//! Operations: Configure | Build | Install | Clean
+//! Operations: Configure | Build | Install | Clean | CleanCache
+//! CleanCache: CMakeCache.txt + CMakeFiles/ only, rest of build_dir kept
 //! Builder: source_dir/build_dir/generator/architecture/definition
-//! Generators: VisualStudio 17, Ninja, NMake JOM
-//! Architectures: X86 (Win32), X64
+//! export_compile_commands: -DCMAKE_EXPORT_COMPILE_COMMANDS=ON, then
+//!   build_dir/compile_commands.json copied to source_dir
+//! toolchain_file: -DCMAKE_TOOLCHAIN_FILE=..., unless the preset (or a preset
+//!   it inherits) in source_dir/CMake[User]Presets.json sets a toolchain
+//! configure_args(): resolved configure argument vector (no process spawned)
+//! Generators: VisualStudio 17, Ninja, NMake JOM (config::types::CmakeGenerator)
+//! cmake.compiler_launcher: -DCMAKE_{C,CXX}_COMPILER_LAUNCHER=... with Ninja
+//!   and JOM, warns if the launcher is not found or the generator is VS
+//! -A is rejected for generators other than Visual Studio; Ninja and JOM get
+//!   -DCMAKE_BUILD_TYPE=<configuration> and no -T host=...
+//! versions.vs_version with the VS generator: -DCMAKE_GENERATOR_INSTANCE=<path>
+//!   of the pinned installation, rejected unless its major version is the
+//!   generator's (17 for Visual Studio 17 2022)
+//! Architectures: X86 (Win32), X64 (From<Arch>)
+//! Output: warning/error counts logged after configure/build/install
 //! ```
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 
 use crate::error::Result;
 use anyhow::Context;
 use tokio::fs;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use super::{BoxFuture, Tool, ToolContext};
-use crate::config::types::BuildConfiguration;
+use super::{BoxFuture, Tool, ToolContext, resolve_executable, run_with_diagnostics};
+use crate::config::Config;
+use crate::config::types::{BuildConfiguration, CmakeGenerator, CmakeInstallMessage};
+use crate::core::env::types::Arch;
 use crate::core::process::builder::ProcessBuilder;
-
-/// `CMake` generator to use for configuration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum CmakeGenerator {
-    /// Visual Studio generator.
-    VisualStudio,
-    /// Ninja generator.
-    Ninja,
-    /// `NMake` JOM generator.
-    NMakeJom,
-}
-
-impl CmakeGenerator {
-    const fn as_str(self) -> &'static str {
-        match self {
-            Self::VisualStudio => "Visual Studio 17 2022",
-            Self::Ninja => "Ninja",
-            Self::NMakeJom => "NMake Makefiles JOM",
-        }
-    }
-}
+#[cfg(windows)]
+use crate::core::vs::VsInstallation;
+#[cfg(windows)]
+use crate::task::tools::vs::VsHelper;
 
 /// Target architecture for `CMake` (-A option).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -64,6 +64,15 @@ impl CmakeArchitecture {
     }
 }
 
+impl From<Arch> for CmakeArchitecture {
+    fn from(arch: Arch) -> Self {
+        match arch {
+            Arch::X86 => Self::X86,
+            Arch::X64 => Self::X64,
+        }
+    }
+}
+
 /// `CMake` operation to perform.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CmakeOperation {
@@ -76,6 +85,8 @@ pub enum CmakeOperation {
     Install,
     /// Clean the build directory.
     Clean,
+    /// Remove only the `CMake` cache, keeping other build outputs.
+    CleanCache,
 }
 
 /// `CMake` tool for configure/build/install operations.
@@ -84,6 +95,7 @@ pub struct CmakeTool {
     source_dir: Option<PathBuf>,
     build_dir: Option<PathBuf>,
     install_prefix: Option<PathBuf>,
+    install_message: Option<CmakeInstallMessage>,
     generator: Option<CmakeGenerator>,
     architecture: Option<CmakeArchitecture>,
     configuration: Option<BuildConfiguration>,
@@ -92,6 +104,9 @@ pub struct CmakeTool {
     target: Option<String>,
     targets: Vec<String>,
     preset: Option<String>,
+    toolchain_file: Option<PathBuf>,
+    export_compile_commands: bool,
+    parallel_jobs: Option<NonZeroUsize>,
     operation: CmakeOperation,
 }
 
@@ -103,6 +118,7 @@ impl CmakeTool {
             source_dir: None,
             build_dir: None,
             install_prefix: None,
+            install_message: None,
             generator: None,
             architecture: None,
             configuration: None,
@@ -111,6 +127,9 @@ impl CmakeTool {
             target: None,
             targets: Vec::new(),
             preset: None,
+            toolchain_file: None,
+            export_compile_commands: false,
+            parallel_jobs: None,
             operation: CmakeOperation::Configure,
         }
     }
@@ -133,6 +152,13 @@ impl CmakeTool {
         self
     }
 
+    /// Overrides `CMAKE_INSTALL_MESSAGE` (default: `cmake.install_message`).
+    #[must_use]
+    pub const fn install_message(mut self, message: CmakeInstallMessage) -> Self {
+        self.install_message = Some(message);
+        self
+    }
+
     #[must_use]
     pub const fn generator(mut self, generator: CmakeGenerator) -> Self {
         self.generator = Some(generator);
@@ -192,6 +218,36 @@ impl CmakeTool {
         self
     }
 
+    /// Sets `CMAKE_TOOLCHAIN_FILE`, such as vcpkg's `vcpkg.cmake`.
+    ///
+    /// Ignored when the configure preset already supplies a toolchain.
+    #[must_use]
+    pub fn toolchain_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.toolchain_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets `CMAKE_EXPORT_COMPILE_COMMANDS=ON` and, after a successful
+    /// configure, copies `compile_commands.json` from the build directory to
+    /// the source directory so editors find it.
+    ///
+    /// Visual Studio generators ignore the definition and write no database.
+    #[must_use]
+    pub const fn export_compile_commands(mut self, export: bool) -> Self {
+        self.export_compile_commands = export;
+        self
+    }
+
+    /// Sets the job count passed as `--parallel N` to the build step.
+    ///
+    /// `cmake.parallel_level` takes precedence; with neither, a bare
+    /// `--parallel` lets the generator decide.
+    #[must_use]
+    pub const fn parallel_jobs(mut self, jobs: Option<NonZeroUsize>) -> Self {
+        self.parallel_jobs = jobs;
+        self
+    }
+
     #[must_use]
     pub const fn configure_op(mut self) -> Self {
         self.operation = CmakeOperation::Configure;
@@ -216,6 +272,12 @@ impl CmakeTool {
         self
     }
 
+    #[must_use]
+    pub const fn clean_cache_op(mut self) -> Self {
+        self.operation = CmakeOperation::CleanCache;
+        self
+    }
+
     fn build_dir_required(&self) -> Result<&Path> {
         self.build_dir
             .as_deref()
@@ -247,12 +309,125 @@ impl CmakeTool {
         Some(value)
     }
 
-    fn cmake_builder(ctx: &ToolContext) -> Result<ProcessBuilder> {
-        if ctx.config().tools.cmake.as_os_str().is_empty() {
-            ProcessBuilder::which("cmake").context("cmake executable not found")
+    /// Returns whether the configure preset sets `toolchainFile` or a
+    /// `CMAKE_TOOLCHAIN_FILE` cache variable, directly or through `inherits`.
+    ///
+    /// Presets are read from `CMakePresets.json` and `CMakeUserPresets.json`
+    /// in `source_dir` and from every file they `include`; unreadable files
+    /// count as having no toolchain.
+    async fn preset_has_toolchain(&self) -> bool {
+        let (Some(preset), Some(source)) = (&self.preset, &self.source_dir) else {
+            return false;
+        };
+        let presets = read_configure_presets(source).await;
+
+        let mut pending = vec![preset.clone()];
+        let mut seen = BTreeSet::new();
+        while let Some(name) = pending.pop() {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            let Some(entry) = presets.get(&name) else {
+                continue;
+            };
+            if entry.get("toolchainFile").is_some()
+                || entry
+                    .get("cacheVariables")
+                    .and_then(|vars| vars.get("CMAKE_TOOLCHAIN_FILE"))
+                    .is_some()
+            {
+                return true;
+            }
+            match entry.get("inherits") {
+                Some(serde_json::Value::String(parent)) => pending.push(parent.clone()),
+                Some(serde_json::Value::Array(parents)) => pending.extend(
+                    parents
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(str::to_string),
+                ),
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Returns the `cmake` program that will be invoked.
+    ///
+    /// Uses `tools.cmake` from the configuration, falling back to `cmake` on PATH.
+    #[must_use]
+    pub fn program(config: &Config) -> PathBuf {
+        if config.tools.cmake.as_os_str().is_empty() {
+            ProcessBuilder::find("cmake").unwrap_or_else(|| PathBuf::from("cmake"))
+        } else {
+            config.tools.cmake.clone()
+        }
+    }
+
+    fn cmake_builder(&self, ctx: &ToolContext) -> Result<ProcessBuilder> {
+        Ok(ProcessBuilder::new(self.resolved_path(ctx)?))
+    }
+
+    /// Copies `compile_commands.json` from the build directory to the source
+    /// directory, if both are set and differ.
+    async fn copy_compile_commands(&self) -> Result<()> {
+        let (Some(source), Some(build)) = (&self.source_dir, &self.build_dir) else {
+            return Ok(());
+        };
+        if source == build {
+            return Ok(());
+        }
+
+        let generated = build.join("compile_commands.json");
+        if !generated.exists() {
+            debug!(
+                path = %generated.display(),
+                "compile_commands.json not generated, skipping copy"
+            );
+            return Ok(());
+        }
+
+        let target = source.join("compile_commands.json");
+        fs::copy(&generated, &target).await.with_context(|| {
+            format!(
+                "failed to copy {} to {}",
+                generated.display(),
+                target.display()
+            )
+        })?;
+        debug!(path = %target.display(), "Copied compile_commands.json");
+        Ok(())
+    }
+
+    /// Records the `cmake` call with `args` for `--emit-script`.
+    fn record_dry_run(&self, ctx: &ToolContext, args: Vec<String>) {
+        let builder = ProcessBuilder::new(self.script_program(ctx, "cmake"))
+            .args(args)
+            .env_overlay(ctx.env());
+        ctx.record_command(&builder);
+    }
+
+    fn install_args(&self) -> Result<Vec<String>> {
+        let mut args = vec!["--install".to_string()];
+
+        if let Some(ref preset) = self.preset {
+            args.push("--preset".to_string());
+            args.push(preset.clone());
         } else {
-            Ok(ProcessBuilder::new(&ctx.config().tools.cmake))
+            args.push(self.build_dir_required()?.display().to_string());
+        }
+
+        if let Some(configuration) = self.configuration {
+            args.push("--config".to_string());
+            args.push(configuration.to_string());
+        }
+
+        if let Some(ref prefix) = self.install_prefix {
+            args.push("--prefix".to_string());
+            args.push(prefix.display().to_string());
         }
+
+        Ok(args)
     }
 
     fn combined_targets(&self) -> Vec<String> {
@@ -266,12 +441,21 @@ impl CmakeTool {
         targets.into_iter().collect()
     }
 
-    async fn do_configure(&self, ctx: &ToolContext) -> Result<()> {
+    /// `preset_toolchain` is the result of [`Self::preset_has_toolchain`].
+    fn configure_definitions(
+        &self,
+        config: &Config,
+        preset_toolchain: bool,
+    ) -> BTreeMap<String, String> {
         let mut definitions = self.definitions.clone();
 
-        definitions
-            .entry("CMAKE_INSTALL_MESSAGE".to_string())
-            .or_insert_with(|| ctx.config().cmake.install_message.to_string());
+        if let Some(message) = self.install_message {
+            definitions.insert("CMAKE_INSTALL_MESSAGE".to_string(), message.to_string());
+        } else {
+            definitions
+                .entry("CMAKE_INSTALL_MESSAGE".to_string())
+                .or_insert_with(|| config.cmake.install_message.to_string());
+        }
 
         if let Some(ref prefix) = self.install_prefix {
             definitions
@@ -285,52 +469,236 @@ impl CmakeTool {
                 .or_insert(prefix_path);
         }
 
-        if ctx.is_dry_run() {
-            info!(
-                source = ?self.source_dir,
-                build = ?self.build_dir,
-                generator = self.generator.map(CmakeGenerator::as_str),
-                architecture = self.architecture.map(CmakeArchitecture::as_str),
-                preset = ?self.preset,
-                definitions = ?definitions,
-                "[dry-run] Would configure CMake"
-            );
-            return Ok(());
+        if let Some(ref toolchain) = self.toolchain_file
+            && !preset_toolchain
+        {
+            definitions
+                .entry("CMAKE_TOOLCHAIN_FILE".to_string())
+                .or_insert_with(|| toolchain.display().to_string());
         }
 
-        let mut builder = Self::cmake_builder(ctx)?;
+        if self.export_compile_commands {
+            definitions
+                .entry("CMAKE_EXPORT_COMPILE_COMMANDS".to_string())
+                .or_insert_with(|| "ON".to_string());
+        }
+
+        if let Some(configuration) = self.configuration
+            && self.is_single_config()
+        {
+            definitions
+                .entry("CMAKE_BUILD_TYPE".to_string())
+                .or_insert_with(|| configuration.to_string());
+        }
+
+        if let Some(launcher) = &config.cmake.compiler_launcher
+            && self.is_single_config()
+        {
+            for key in ["CMAKE_C_COMPILER_LAUNCHER", "CMAKE_CXX_COMPILER_LAUNCHER"] {
+                definitions
+                    .entry(key.to_string())
+                    .or_insert_with(|| launcher.clone());
+            }
+        }
+
+        definitions
+    }
+
+    /// Whether the generator is a single-config one (Ninja, `NMake` JOM).
+    ///
+    /// These ignore `--config` and `-A`, but honour `CMAKE_BUILD_TYPE` and the
+    /// compiler launchers, which the Visual Studio generators ignore. Without a
+    /// generator, `CMake` picks Visual Studio on Windows.
+    fn is_single_config(&self) -> bool {
+        self.generator
+            .is_some_and(|generator| !generator.supports_architecture())
+    }
+
+    /// Returns the arguments passed to `cmake` for the configure step.
+    ///
+    /// Includes the preset or source/build directories, generator,
+    /// architecture, toolset host and every `-D` definition. Single-config
+    /// generators get `CMAKE_BUILD_TYPE` from the configuration and no toolset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no preset is set and `source_dir` or `build_dir` is
+    /// missing, or if an architecture is combined with a generator other than
+    /// Visual Studio.
+    pub async fn configure_args(&self, config: &Config) -> Result<Vec<String>> {
+        self.resolved_configure_args(config, self.preset_has_toolchain().await)
+    }
+
+    /// Returns [`Self::configure_args`] for a preset that sets a toolchain if
+    /// `preset_toolchain` is true.
+    fn resolved_configure_args(
+        &self,
+        config: &Config,
+        preset_toolchain: bool,
+    ) -> Result<Vec<String>> {
+        let mut args = Vec::new();
 
         if let Some(ref preset) = self.preset {
-            builder = builder.arg("--preset").arg(preset);
+            args.push("--preset".to_string());
+            args.push(preset.clone());
         } else {
             let source = self.source_dir_required()?;
             let build = self.build_dir_required()?;
 
-            builder = builder.arg("-S").arg(source).arg("-B").arg(build);
+            args.push("-S".to_string());
+            args.push(source.display().to_string());
+            args.push("-B".to_string());
+            args.push(build.display().to_string());
 
             if let Some(generator) = self.generator {
-                builder = builder.arg("-G").arg(generator.as_str());
+                args.push("-G".to_string());
+                args.push(generator.as_str().to_string());
             }
 
             if let Some(architecture) = self.architecture {
-                builder = builder.arg("-A").arg(architecture.as_str());
+                if let Some(generator) = self.generator
+                    && !generator.supports_architecture()
+                {
+                    anyhow::bail!(
+                        "CMake generator '{}' does not support -A; the architecture comes \
+                         from the environment",
+                        generator.as_str()
+                    );
+                }
+                args.push("-A".to_string());
+                args.push(architecture.as_str().to_string());
             }
 
-            if !ctx.config().cmake.host.is_empty() {
-                builder = builder
-                    .arg("-T")
-                    .arg(format!("host={}", ctx.config().cmake.host));
+            if !config.cmake.host.is_empty() && !self.is_single_config() {
+                args.push("-T".to_string());
+                args.push(format!("host={}", config.cmake.host));
+            }
+        }
+
+        for (key, value) in self.configure_definitions(config, preset_toolchain) {
+            args.push(format!("-D{key}={value}"));
+        }
+
+        Ok(args)
+    }
+
+    /// Returns [`Self::configure_args`] plus `-DCMAKE_GENERATOR_INSTANCE` with
+    /// the installation pinned by `versions.vs_version`, so the Visual Studio
+    /// generator uses it instead of the newest one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`Self::configure_args`] fails, no installation
+    /// matches the pinned version, or the pinned installation is not the
+    /// version the generator needs.
+    #[cfg(windows)]
+    fn pinned_configure_args(
+        &self,
+        config: &Config,
+        preset_toolchain: bool,
+    ) -> Result<Vec<String>> {
+        let mut args = self.resolved_configure_args(config, preset_toolchain)?;
+        let (Some(pinned), Some(generator), None) = (
+            config.versions.vs_version.as_deref(),
+            self.generator,
+            &self.preset,
+        ) else {
+            return Ok(args);
+        };
+        if generator.visual_studio_major().is_none()
+            || self.definitions.contains_key("CMAKE_GENERATOR_INSTANCE")
+        {
+            return Ok(args);
+        }
+
+        let vs = VsHelper::find_installation(None, Some(pinned))?;
+        check_generator_version(generator, &vs)?;
+        args.push(format!(
+            "-DCMAKE_GENERATOR_INSTANCE={}",
+            vs.installation_path.display()
+        ));
+        Ok(args)
+    }
+
+    /// Returns the arguments passed to `cmake` for the build step.
+    ///
+    /// Ends with `--parallel`, followed by `cmake.parallel_level` or else
+    /// [`Self::parallel_jobs`] when set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no preset is set and `build_dir` is missing.
+    pub fn build_args(&self, config: &Config) -> Result<Vec<String>> {
+        let mut args = vec!["--build".to_string()];
+
+        if let Some(ref preset) = self.preset {
+            args.push("--preset".to_string());
+            args.push(preset.clone());
+        } else {
+            args.push(self.build_dir_required()?.display().to_string());
+        }
+
+        if let Some(configuration) = self.configuration {
+            args.push("--config".to_string());
+            args.push(configuration.to_string());
+        }
+
+        for target in self.combined_targets() {
+            args.push("--target".to_string());
+            args.push(target);
+        }
+
+        args.push("--parallel".to_string());
+        if let Some(level) = config.cmake.parallel_level.or(self.parallel_jobs) {
+            args.push(level.to_string());
+        }
+
+        Ok(args)
+    }
+
+    async fn do_configure(&self, ctx: &ToolContext) -> Result<()> {
+        if let Some(launcher) = &ctx.config().cmake.compiler_launcher {
+            if !self.is_single_config() {
+                warn!(
+                    launcher = %launcher,
+                    "cmake.compiler_launcher is ignored by the Visual Studio generator, \
+                     set task.cmake_generator to ninja or jom to use it"
+                );
+            } else if ProcessBuilder::find(launcher).is_none() {
+                warn!(
+                    launcher = %launcher,
+                    "cmake.compiler_launcher was not found, CMake will fail to run the compiler"
+                );
             }
         }
 
-        for (key, value) in definitions {
-            builder = builder.arg(format!("-D{key}={value}"));
+        let preset_toolchain = self.preset_has_toolchain().await;
+        #[cfg(windows)]
+        let configure_args = self.pinned_configure_args(ctx.config(), preset_toolchain);
+        #[cfg(not(windows))]
+        let configure_args = self.resolved_configure_args(ctx.config(), preset_toolchain);
+
+        if ctx.is_dry_run() {
+            info!(
+                source = ?self.source_dir,
+                build = ?self.build_dir,
+                generator = self.generator.map(CmakeGenerator::as_str),
+                architecture = self.architecture.map(CmakeArchitecture::as_str),
+                preset = ?self.preset,
+                definitions = ?self.configure_definitions(ctx.config(), preset_toolchain),
+                "[dry-run] Would configure CMake"
+            );
+            if ctx.is_recording() {
+                self.record_dry_run(ctx, configure_args?);
+            }
+            return Ok(());
         }
 
+        let builder = self.cmake_builder(ctx)?.args(configure_args?);
+
         debug!("Configuring CMake");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = run_with_diagnostics(ctx, builder, "CMake configure")
             .await
             .context("Failed to run CMake configure")?;
 
@@ -339,6 +707,10 @@ impl CmakeTool {
         }
 
         info!("CMake configure completed successfully");
+
+        if self.export_compile_commands {
+            self.copy_compile_commands().await?;
+        }
         Ok(())
     }
 
@@ -353,32 +725,19 @@ impl CmakeTool {
                 targets = ?targets,
                 "[dry-run] Would build with CMake"
             );
+            if ctx.is_recording() {
+                self.record_dry_run(ctx, self.build_args(ctx.config())?);
+            }
             return Ok(());
         }
 
-        let mut builder = Self::cmake_builder(ctx)?.arg("--build");
-
-        if let Some(ref preset) = self.preset {
-            builder = builder.arg("--preset").arg(preset);
-        } else {
-            let build = self.build_dir_required()?;
-            builder = builder.arg(build);
-        }
-
-        if let Some(configuration) = self.configuration {
-            builder = builder.arg("--config").arg(configuration.to_string());
-        }
-
-        for target in targets {
-            builder = builder.arg("--target").arg(target);
-        }
-
-        builder = builder.arg("--parallel");
+        let builder = self
+            .cmake_builder(ctx)?
+            .args(self.build_args(ctx.config())?);
 
         debug!("Building with CMake");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = run_with_diagnostics(ctx, builder, "CMake build")
             .await
             .context("Failed to run CMake build")?;
 
@@ -399,30 +758,17 @@ impl CmakeTool {
                 prefix = ?self.install_prefix,
                 "[dry-run] Would install with CMake"
             );
+            if ctx.is_recording() {
+                self.record_dry_run(ctx, self.install_args()?);
+            }
             return Ok(());
         }
 
-        let mut builder = Self::cmake_builder(ctx)?.arg("--install");
-
-        if let Some(ref preset) = self.preset {
-            builder = builder.arg("--preset").arg(preset);
-        } else {
-            let build = self.build_dir_required()?;
-            builder = builder.arg(build);
-        }
-
-        if let Some(configuration) = self.configuration {
-            builder = builder.arg("--config").arg(configuration.to_string());
-        }
-
-        if let Some(ref prefix) = self.install_prefix {
-            builder = builder.arg("--prefix").arg(prefix);
-        }
+        let builder = self.cmake_builder(ctx)?.args(self.install_args()?);
 
         debug!("Installing with CMake");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = run_with_diagnostics(ctx, builder, "CMake install")
             .await
             .context("Failed to run CMake install")?;
 
@@ -451,6 +797,32 @@ impl CmakeTool {
         info!(build = %build.display(), "Build directory cleaned");
         Ok(())
     }
+
+    async fn do_clean_cache(&self, ctx: &ToolContext) -> Result<()> {
+        let build = self.build_dir_required()?;
+        let cache_file = build.join("CMakeCache.txt");
+        let cache_dir = build.join("CMakeFiles");
+
+        if ctx.is_dry_run() {
+            info!(build = %build.display(), "[dry-run] Would remove CMakeCache.txt and CMakeFiles");
+            return Ok(());
+        }
+
+        if cache_file.exists() {
+            fs::remove_file(&cache_file)
+                .await
+                .with_context(|| format!("Failed to remove {}", cache_file.display()))?;
+        }
+
+        if cache_dir.exists() {
+            fs::remove_dir_all(&cache_dir)
+                .await
+                .with_context(|| format!("Failed to remove {}", cache_dir.display()))?;
+        }
+
+        info!(build = %build.display(), "CMake cache cleaned");
+        Ok(())
+    }
 }
 
 impl Default for CmakeTool {
@@ -464,16 +836,83 @@ impl Tool for CmakeTool {
         "cmake"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 CmakeOperation::Configure => self.do_configure(ctx).await,
                 CmakeOperation::Build => self.do_build(ctx).await,
                 CmakeOperation::Install => self.do_install(ctx).await,
                 CmakeOperation::Clean => self.do_clean(ctx).await,
+                CmakeOperation::CleanCache => self.do_clean_cache(ctx).await,
             }
         })
     }
+
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        resolve_executable(&ctx.config().tools.cmake, "cmake")
+    }
+}
+
+/// Returns the configure presets of `source` by name.
+///
+/// Reads `CMakePresets.json`, `CMakeUserPresets.json` and every file they
+/// `include`, relative to the including file; unreadable files are skipped.
+async fn read_configure_presets(source: &Path) -> BTreeMap<String, serde_json::Value> {
+    let mut presets = BTreeMap::new();
+    let mut pending = vec![
+        source.join("CMakeUserPresets.json"),
+        source.join("CMakePresets.json"),
+    ];
+    let mut seen = BTreeSet::new();
+    while let Some(file) = pending.pop() {
+        if !seen.insert(file.clone()) {
+            continue;
+        }
+        let Ok(content) = tokio::fs::read_to_string(&file).await else {
+            continue;
+        };
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+            continue;
+        };
+
+        let dir = file.parent().unwrap_or(source);
+        pending.extend(
+            json.get("include")
+                .and_then(serde_json::Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(serde_json::Value::as_str)
+                .map(|include| dir.join(include)),
+        );
+
+        let entries = json
+            .get("configurePresets")
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten();
+        for entry in entries {
+            if let Some(name) = entry.get("name").and_then(serde_json::Value::as_str) {
+                presets.insert(name.to_string(), entry.clone());
+            }
+        }
+    }
+    presets
+}
+
+/// Rejects a pinned installation whose major version is not the one
+/// `generator` needs, which `CMake` would only report after a failed search.
+#[cfg(windows)]
+fn check_generator_version(generator: CmakeGenerator, vs: &VsInstallation) -> Result<()> {
+    match generator.visual_studio_major() {
+        Some(major) if vs.major() != major => anyhow::bail!(
+            "versions.vs_version selects Visual Studio {} at {}, but the CMake generator \
+             '{}' needs Visual Studio {major}",
+            vs.installation_version,
+            vs.installation_path.display(),
+            generator.as_str()
+        ),
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -3,14 +3,16 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use anyhow::Result;
 use tokio_util::sync::CancellationToken;
 
-use super::{CmakeArchitecture, CmakeGenerator, CmakeTool};
+use super::{CmakeArchitecture, CmakeTool};
 use crate::config::Config;
-use crate::config::types::BuildConfiguration;
+use crate::config::types::{BuildConfiguration, CmakeGenerator};
+use crate::core::env::types::Arch;
 use crate::task::tools::test_utils::run_with_logs;
 use crate::task::tools::{Tool, ToolContext};
 
@@ -28,6 +30,189 @@ fn test_cmake_builder_defaults() {
     insta::assert_debug_snapshot!(tool);
 }
 
+#[test]
+fn test_cmake_architecture_from_arch() {
+    assert_eq!(CmakeArchitecture::from(Arch::X86), CmakeArchitecture::X86);
+    assert_eq!(CmakeArchitecture::from(Arch::X64), CmakeArchitecture::X64);
+}
+
+#[tokio::test]
+async fn test_cmake_configure_args() {
+    let mut config = Config::default();
+    config.cmake.host = "x64".to_string();
+
+    let tool = CmakeTool::new()
+        .source_dir("/tmp/source")
+        .build_dir("/tmp/build")
+        .generator(CmakeGenerator::VisualStudio)
+        .architecture(CmakeArchitecture::X64)
+        .install_prefix("/tmp/install")
+        .definition("BUILD_TESTING", "OFF")
+        .configure_op();
+
+    insta::assert_debug_snapshot!(tool.configure_args(&config).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_cmake_configure_args_rejects_architecture_without_vs() {
+    let config = Config::default();
+    let tool = CmakeTool::new()
+        .source_dir("/tmp/source")
+        .build_dir("/tmp/build")
+        .generator(CmakeGenerator::Ninja)
+        .architecture(CmakeArchitecture::X64)
+        .configure_op();
+
+    let error = tool.configure_args(&config).await.unwrap_err();
+    assert!(error.to_string().contains("does not support -A"), "{error}");
+}
+
+#[tokio::test]
+async fn test_cmake_export_compile_commands() {
+    let temp = tempfile::tempdir().unwrap();
+    let source = temp.path().join("source");
+    let build = temp.path().join("build");
+    std::fs::create_dir_all(&source).unwrap();
+    std::fs::create_dir_all(&build).unwrap();
+
+    let tool = CmakeTool::new()
+        .source_dir(&source)
+        .build_dir(&build)
+        .export_compile_commands(true)
+        .configure_op();
+    let args = tool.configure_args(&Config::default()).await.unwrap();
+    assert!(args.contains(&"-DCMAKE_EXPORT_COMPILE_COMMANDS=ON".to_string()));
+
+    // Nothing generated (e.g. Visual Studio): nothing copied
+    tool.copy_compile_commands().await.unwrap();
+    assert!(!source.join("compile_commands.json").exists());
+
+    std::fs::write(build.join("compile_commands.json"), "[]").unwrap();
+    tool.copy_compile_commands().await.unwrap();
+    assert_eq!(
+        std::fs::read_to_string(source.join("compile_commands.json")).unwrap(),
+        "[]"
+    );
+}
+
+#[test]
+fn test_cmake_build_args_parallel_level() {
+    let mut config = Config::default();
+    let tool = CmakeTool::new()
+        .build_dir("/tmp/build")
+        .configuration(BuildConfiguration::RelWithDebInfo)
+        .target("usvfs")
+        .build_op();
+
+    let args = tool.build_args(&config).unwrap();
+    assert_eq!(args.last().map(String::as_str), Some("--parallel"));
+
+    config.cmake.parallel_level = NonZeroUsize::new(4);
+    let args = tool.build_args(&config).unwrap();
+    assert_eq!(
+        args[args.len() - 4..],
+        ["--target", "usvfs", "--parallel", "4"]
+    );
+}
+
+#[tokio::test]
+async fn test_cmake_compiler_launcher() {
+    let mut config = Config::default();
+    let tool = CmakeTool::new()
+        .source_dir("/tmp/source")
+        .build_dir("/tmp/build")
+        .generator(CmakeGenerator::Ninja)
+        .configure_op();
+
+    let args = tool.configure_args(&config).await.unwrap();
+    assert!(!args.iter().any(|arg| arg.contains("COMPILER_LAUNCHER")));
+
+    config.cmake.compiler_launcher = Some("sccache".to_string());
+
+    // Visual Studio ignores the launchers, so they are not passed
+    let args = tool
+        .clone()
+        .generator(CmakeGenerator::VisualStudio)
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(!args.iter().any(|arg| arg.contains("COMPILER_LAUNCHER")));
+
+    let args = tool.configure_args(&config).await.unwrap();
+    assert!(args.contains(&"-DCMAKE_C_COMPILER_LAUNCHER=sccache".to_string()));
+    assert!(args.contains(&"-DCMAKE_CXX_COMPILER_LAUNCHER=sccache".to_string()));
+
+    let args = tool
+        .definition("CMAKE_CXX_COMPILER_LAUNCHER", "ccache")
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(args.contains(&"-DCMAKE_CXX_COMPILER_LAUNCHER=ccache".to_string()));
+}
+
+#[tokio::test]
+async fn test_cmake_single_config_generator_args() {
+    let mut config = Config::default();
+    config.cmake.host = "x64".to_string();
+    let tool = CmakeTool::new()
+        .source_dir("/tmp/source")
+        .build_dir("/tmp/build")
+        .configuration(BuildConfiguration::RelWithDebInfo)
+        .configure_op();
+
+    let args = tool
+        .clone()
+        .generator(CmakeGenerator::Ninja)
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(args.contains(&"-DCMAKE_BUILD_TYPE=RelWithDebInfo".to_string()));
+    assert!(!args.contains(&"-T".to_string()));
+
+    let args = tool
+        .generator(CmakeGenerator::VisualStudio)
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(!args.iter().any(|arg| arg.starts_with("-DCMAKE_BUILD_TYPE")));
+    assert!(args.windows(2).any(|pair| pair == ["-T", "host=x64"]));
+}
+
+#[tokio::test]
+async fn test_cmake_install_message_override() {
+    use crate::config::types::CmakeInstallMessage;
+
+    let mut config = Config::default();
+    config.cmake.install_message = CmakeInstallMessage::Never;
+
+    let base = CmakeTool::new()
+        .source_dir("/tmp/source")
+        .build_dir("/tmp/build")
+        .definition("CMAKE_INSTALL_MESSAGE", "LAZY");
+    let default_args = base
+        .clone()
+        .configure_op()
+        .configure_args(&config)
+        .await
+        .unwrap();
+    let override_args = base
+        .install_message(CmakeInstallMessage::Always)
+        .configure_op()
+        .configure_args(&config)
+        .await
+        .unwrap();
+
+    assert!(default_args.contains(&"-DCMAKE_INSTALL_MESSAGE=LAZY".to_string()));
+    assert!(override_args.contains(&"-DCMAKE_INSTALL_MESSAGE=ALWAYS".to_string()));
+}
+
+#[tokio::test]
+async fn test_cmake_configure_args_requires_dirs() {
+    let config = Config::default();
+    let tool = CmakeTool::new().source_dir("/tmp/source").configure_op();
+    assert!(tool.configure_args(&config).await.is_err());
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_cmake_configure_dry_run() -> Result<()> {
     let logs = run_with_logs(|| async {
@@ -38,7 +223,6 @@ async fn test_cmake_configure_dry_run() -> Result<()> {
             .source_dir("/tmp/source")
             .build_dir("/tmp/build")
             .generator(CmakeGenerator::Ninja)
-            .architecture(CmakeArchitecture::X64)
             .configure_op();
 
         tool.run(&ctx).await
@@ -88,3 +272,167 @@ async fn test_cmake_install_dry_run() -> Result<()> {
     insta::assert_snapshot!(normalize_dry_run_logs(&logs));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_cmake_clean_cache_keeps_build_outputs() -> Result<()> {
+    let temp = tempfile::tempdir()?;
+    let build = temp.path();
+    std::fs::write(
+        build.join("CMakeCache.txt"),
+        "CMAKE_BUILD_TYPE:STRING=Release",
+    )?;
+    std::fs::create_dir_all(build.join("CMakeFiles").join("3.30.0"))?;
+    std::fs::create_dir_all(build.join("_deps"))?;
+    std::fs::write(build.join("main.obj"), "")?;
+
+    let ctx = ToolContext::new(Arc::new(Config::default()), CancellationToken::new(), false);
+    CmakeTool::new()
+        .build_dir(build)
+        .clean_cache_op()
+        .run(&ctx)
+        .await?;
+
+    assert!(!build.join("CMakeCache.txt").exists());
+    assert!(!build.join("CMakeFiles").exists());
+    assert!(build.join("_deps").is_dir());
+    assert!(build.join("main.obj").is_file());
+    Ok(())
+}
+
+#[test]
+fn test_cmake_build_args_parallel_jobs() {
+    let mut config = Config::default();
+    let tool = CmakeTool::new()
+        .build_dir("/tmp/build")
+        .parallel_jobs(NonZeroUsize::new(6))
+        .build_op();
+
+    let args = tool.build_args(&config).unwrap();
+    assert_eq!(args[args.len() - 2..], ["--parallel", "6"]);
+
+    config.cmake.parallel_level = NonZeroUsize::new(2);
+    let args = tool.build_args(&config).unwrap();
+    assert_eq!(args[args.len() - 2..], ["--parallel", "2"]);
+
+    let args = tool
+        .parallel_jobs(None)
+        .build_args(&Config::default())
+        .unwrap();
+    assert_eq!(args.last().map(String::as_str), Some("--parallel"));
+}
+
+#[tokio::test]
+async fn test_cmake_toolchain_file_unless_preset_sets_one() {
+    let temp = tempfile::tempdir().unwrap();
+    let source = temp.path();
+    std::fs::write(
+        source.join("CMakePresets.json"),
+        r#"{
+            "configurePresets": [
+                {"name": "base", "toolchainFile": "custom.cmake"},
+                {"name": "vs2022-windows-x64", "inherits": ["base"]},
+                {"name": "plain"}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let config = Config::default();
+    let toolchain = "-DCMAKE_TOOLCHAIN_FILE=C:/vcpkg/scripts/buildsystems/vcpkg.cmake";
+    let base = CmakeTool::new()
+        .source_dir(source)
+        .build_dir(source.join("build"))
+        .toolchain_file("C:/vcpkg/scripts/buildsystems/vcpkg.cmake")
+        .configure_op();
+
+    let args = base.configure_args(&config).await.unwrap();
+    assert!(args.contains(&toolchain.to_string()));
+
+    let args = base
+        .clone()
+        .preset("plain")
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(args.contains(&toolchain.to_string()));
+
+    let args = base
+        .preset("vs2022-windows-x64")
+        .configure_args(&config)
+        .await
+        .unwrap();
+    assert!(
+        !args
+            .iter()
+            .any(|arg| arg.starts_with("-DCMAKE_TOOLCHAIN_FILE"))
+    );
+}
+
+#[tokio::test]
+async fn test_cmake_preset_toolchain_through_include() {
+    let temp = tempfile::tempdir().unwrap();
+    let source = temp.path();
+    std::fs::create_dir_all(source.join("cmake")).unwrap();
+    std::fs::write(
+        source.join("CMakePresets.json"),
+        r#"{"include": ["cmake/base.json"], "configurePresets": []}"#,
+    )
+    .unwrap();
+    std::fs::write(
+        source.join("cmake").join("base.json"),
+        r#"{
+            "include": ["toolchain.json"],
+            "configurePresets": [{"name": "vs2022-windows-x64", "inherits": "vcpkg"}]
+        }"#,
+    )
+    .unwrap();
+    std::fs::write(
+        source.join("cmake").join("toolchain.json"),
+        r#"{
+            "include": ["base.json"],
+            "configurePresets": [
+                {"name": "vcpkg", "cacheVariables": {"CMAKE_TOOLCHAIN_FILE": "vcpkg.cmake"}}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let args = CmakeTool::new()
+        .source_dir(source)
+        .toolchain_file("C:/vcpkg/scripts/buildsystems/vcpkg.cmake")
+        .preset("vs2022-windows-x64")
+        .configure_op()
+        .configure_args(&Config::default())
+        .await
+        .unwrap();
+    assert!(
+        !args
+            .iter()
+            .any(|arg| arg.starts_with("-DCMAKE_TOOLCHAIN_FILE"))
+    );
+}
+
+#[cfg(windows)]
+#[test]
+fn test_check_generator_version() {
+    use super::check_generator_version;
+    use crate::core::vs::VsInstallation;
+
+    let vs = |version: &str| VsInstallation {
+        instance_id: "abc123".to_string(),
+        installation_path: std::path::PathBuf::from(r"C:\VS"),
+        installation_version: version.to_string(),
+        display_name: "Visual Studio".to_string(),
+        is_complete: true,
+        is_prerelease: false,
+    };
+
+    check_generator_version(CmakeGenerator::VisualStudio, &vs("17.14.36915.13")).unwrap();
+    check_generator_version(CmakeGenerator::Ninja, &vs("16.11.0.0")).unwrap();
+    let error =
+        check_generator_version(CmakeGenerator::VisualStudio, &vs("16.11.0.0")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "versions.vs_version selects Visual Studio 16.11.0.0 at C:\\VS, but the CMake \
+         generator 'Visual Studio 17 2022' needs Visual Studio 17"
+    );
+}
@@ -7,18 +7,29 @@
 //!
 //! ```text
 //! URLs --> HTTP GET --> progress --> local file
-//! Features: fallback URLs, cache skip, force re-download, cancel
-//! Uses: crate::net::Downloader + ProgressDisplay::Bar
+//!   failure --> warn + next URL; all failed --> NetworkError::AllUrlsFailed
+//! Features: fallback URLs, cache skip, force re-download, cancel,
+//!           resume from <file>.part (discarded by force and clean)
+//! sha256(hex) --> verify download (mismatch: next URL) and cached file
+//!                 (mismatch: re-download)
+//! Large files: downloads.parallel_chunks > 1 --> parallel Range requests
+//! Uses: crate::net::Downloader + http_client([net])
+//! Progress: TerminalProgress reporter unless global.progress = false (--quiet),
+//!   drawn in the context's ProgressGroup so concurrent bars don't overlap
 //! ```
 
-use std::path::PathBuf;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use crate::error::Result;
+use crate::error::{NetworkError, Result};
 use anyhow::Context;
-use tracing::{debug, info};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+use tracing::{debug, info, warn};
 
 use super::{BoxFuture, Tool, ToolContext};
-use crate::net::{Downloader, ProgressDisplay};
+use crate::net::{Downloader, ProgressGroup, TerminalProgress, http_client, part_path};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DownloaderOperation {
@@ -32,6 +43,7 @@ pub struct DownloaderTool {
     urls: Vec<String>,
     output_file: Option<PathBuf>,
     force: bool,
+    sha256: Option<String>,
     operation: DownloaderOperation,
 }
 
@@ -48,6 +60,7 @@ impl DownloaderTool {
             urls: Vec::new(),
             output_file: None,
             force: false,
+            sha256: None,
             operation: DownloaderOperation::Download,
         }
     }
@@ -59,9 +72,10 @@ impl DownloaderTool {
         self
     }
 
+    /// Replace the candidate URLs, tried in order until one succeeds.
     #[must_use]
-    pub fn urls(mut self, urls: Vec<String>) -> Self {
-        self.urls = urls;
+    pub fn urls(mut self, urls: impl IntoIterator<Item = String>) -> Self {
+        self.urls = urls.into_iter().collect();
         self
     }
 
@@ -77,6 +91,15 @@ impl DownloaderTool {
         self
     }
 
+    /// Expected SHA-256 of the file as a hex string.
+    ///
+    /// Downloads and cached files with a different hash are rejected.
+    #[must_use]
+    pub fn sha256(mut self, expected: impl Into<String>) -> Self {
+        self.sha256 = Some(expected.into().to_ascii_lowercase());
+        self
+    }
+
     #[must_use]
     pub const fn download_op(mut self) -> Self {
         self.operation = DownloaderOperation::Download;
@@ -101,11 +124,10 @@ impl DownloaderTool {
         }
 
         // Check if file already exists and we're not forcing re-download
-        if !self.force && output_file.exists() {
-            info!(
-                path = %output_file.display(),
-                "file already exists, skipping download"
-            );
+        if !self.force
+            && output_file.exists()
+            && self.cached_file_is_valid(ctx, output_file).await?
+        {
             return Ok(());
         }
 
@@ -113,6 +135,13 @@ impl DownloaderTool {
             return Err(anyhow::anyhow!("no URLs provided for download"));
         }
 
+        let part = part_path(output_file);
+        if self.force && !ctx.is_dry_run() && part.exists() {
+            tokio::fs::remove_file(&part)
+                .await
+                .with_context(|| format!("failed to delete {}", part.display()))?;
+        }
+
         // Try each URL in order
         let mut last_error = None;
         for (idx, url) in self.urls.iter().enumerate() {
@@ -134,15 +163,38 @@ impl DownloaderTool {
                     file = %output_file.display(),
                     "[DRY-RUN] would download"
                 );
+                ctx.record_download(url, output_file);
                 return Ok(());
             }
 
-            let downloader = Downloader::new()
+            let mut downloader = Downloader::new()
+                .client(http_client(&ctx.config().net)?)
                 .url(url)
                 .file(output_file)
-                .progress(ProgressDisplay::Bar);
+                .silent()
+                .parallel_chunks(ctx.config().downloads.parallel_chunks)
+                .resume(true);
+            if ctx.config().global.progress {
+                let progress = ctx
+                    .progress_group()
+                    .map_or_else(TerminalProgress::new, ProgressGroup::reporter);
+                downloader = downloader.reporter(Arc::new(progress));
+            }
+
+            // Dropping the download on cancel keeps the .part file to resume
+            let result = tokio::select! {
+                result = downloader.download() => result,
+                () = ctx.cancel_token().cancelled() => {
+                    return Err(anyhow::anyhow!("download cancelled"));
+                }
+            };
 
-            match downloader.download().await {
+            let result = match result {
+                Ok(()) => self.verify(url, output_file).await,
+                Err(e) => Err(e.into()),
+            };
+
+            match result {
                 Ok(()) => {
                     info!(
                         url = %url,
@@ -152,22 +204,79 @@ impl DownloaderTool {
                     return Ok(());
                 }
                 Err(e) => {
-                    debug!(
-                        url = %url,
-                        error = %e,
-                        "download attempt failed, trying next URL"
-                    );
+                    if let Some(next) = self.urls.get(idx + 1) {
+                        warn!(
+                            url = %url,
+                            next = %next,
+                            error = %e,
+                            "download attempt failed, trying next URL"
+                        );
+                    }
                     last_error = Some(e);
-                    // Continue to next URL
                 }
             }
         }
 
-        // All URLs failed
-        last_error.map_or_else(
-            || Err(anyhow::anyhow!("no URLs provided for download")),
-            |error| Err(error).context("all download URLs failed"),
-        )
+        // All URLs failed; the last error stays in the chain
+        let Some(last_error) = last_error else {
+            anyhow::bail!("no URLs provided for download");
+        };
+        Err(NetworkError::AllUrlsFailed {
+            urls: self.urls.clone(),
+            last_error: last_error.into(),
+        }
+        .into())
+    }
+
+    /// Returns whether the existing `file` can be reused instead of downloading.
+    ///
+    /// Without an expected hash (or in dry-run) any existing file is reused.
+    async fn cached_file_is_valid(&self, ctx: &ToolContext, file: &Path) -> Result<bool> {
+        let Some(expected) = self.sha256.as_ref().filter(|_| !ctx.is_dry_run()) else {
+            info!(path = %file.display(), "file already exists, skipping download");
+            return Ok(true);
+        };
+
+        let actual = sha256_file(file).await?;
+        if actual == *expected {
+            info!(
+                path = %file.display(),
+                "file already exists and checksum matches, skipping download"
+            );
+            return Ok(true);
+        }
+        warn!(
+            path = %file.display(),
+            expected = %expected,
+            actual = %actual,
+            "cached file has wrong checksum, downloading again"
+        );
+        Ok(false)
+    }
+
+    /// Checks the downloaded `file` against the expected hash, if any.
+    ///
+    /// A mismatching file is deleted so it can't be reused from the cache.
+    async fn verify(&self, url: &str, file: &Path) -> Result<()> {
+        let Some(expected) = &self.sha256 else {
+            return Ok(());
+        };
+
+        let actual = sha256_file(file).await?;
+        if actual == *expected {
+            debug!(url = %url, sha256 = %actual, "checksum verified");
+            return Ok(());
+        }
+
+        tokio::fs::remove_file(file)
+            .await
+            .with_context(|| format!("failed to delete {}", file.display()))?;
+        Err(NetworkError::ChecksumMismatch {
+            expected: expected.clone(),
+            actual,
+            url: url.to_string(),
+        }
+        .into())
     }
 
     async fn execute_clean(&self, ctx: &ToolContext) -> Result<()> {
@@ -184,25 +293,57 @@ impl DownloaderTool {
             return Ok(());
         }
 
-        if output_file.exists() {
-            tokio::fs::remove_file(output_file)
-                .await
-                .with_context(|| format!("failed to delete {}", output_file.display()))?;
-            info!(file = %output_file.display(), "file deleted");
-        } else {
-            debug!(file = %output_file.display(), "file does not exist, nothing to clean");
+        for file in [output_file.clone(), part_path(output_file)] {
+            if file.exists() {
+                tokio::fs::remove_file(&file)
+                    .await
+                    .with_context(|| format!("failed to delete {}", file.display()))?;
+                info!(file = %file.display(), "file deleted");
+            } else {
+                debug!(file = %file.display(), "file does not exist, nothing to clean");
+            }
         }
 
         Ok(())
     }
 }
 
+/// Returns the SHA-256 of `path` as a lowercase hex string.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read.
+pub async fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    let mut buf = vec![0u8; 64 * 1024];
+    let mut hasher = Sha256::new();
+    loop {
+        let read = file
+            .read(&mut buf)
+            .await
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        }))
+}
+
 impl Tool for DownloaderTool {
     fn name(&self) -> &'static str {
         "downloader"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 DownloaderOperation::Download => self.execute_download(ctx).await,
@@ -3,11 +3,14 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{DownloaderOperation, DownloaderTool};
+use super::{DownloaderOperation, DownloaderTool, sha256_file};
+use crate::error::NetworkError;
 use crate::task::tools::{Tool, ToolContext};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn create_test_ctx(dry_run: bool) -> ToolContext {
     ToolContext::new(
@@ -160,3 +163,105 @@ fn test_downloader_tool_builder_chain() {
 
     insta::assert_debug_snapshot!("downloader_tool_builder_chain", tool);
 }
+
+const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[tokio::test]
+async fn test_sha256_file() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("abc.txt");
+    std::fs::write(&file, "abc").expect("write file");
+
+    assert_eq!(sha256_file(&file).await.expect("hash"), ABC_SHA256);
+}
+
+#[tokio::test]
+async fn test_download_reuses_cache_with_matching_checksum() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("abc.7z");
+    std::fs::write(&file, "abc").expect("write file");
+
+    // The URL is unreachable: re-downloading would fail.
+    let tool = DownloaderTool::new()
+        .url("http://127.0.0.1:9/abc.7z")
+        .file(&file)
+        .sha256(ABC_SHA256.to_uppercase());
+
+    tool.run(&create_test_ctx(false))
+        .await
+        .expect("cached file is reused");
+}
+
+#[tokio::test]
+async fn test_download_rejects_cache_with_wrong_checksum() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("abc.7z");
+    std::fs::write(&file, "truncated").expect("write file");
+
+    let tool = DownloaderTool::new()
+        .url("http://127.0.0.1:9/abc.7z")
+        .file(&file)
+        .sha256(ABC_SHA256);
+
+    let err = tool
+        .run(&create_test_ctx(false))
+        .await
+        .expect_err("mismatching cache is downloaded again");
+    assert!(err.to_string().contains("download failed from all URLs"));
+}
+
+#[tokio::test]
+async fn test_download_falls_back_to_next_url() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/mirror/abc.7z"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("abc"))
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().expect("tempdir");
+    let file = temp.path().join("abc.7z");
+
+    DownloaderTool::new()
+        .urls([
+            format!("{}/missing/abc.7z", server.uri()),
+            format!("{}/mirror/abc.7z", server.uri()),
+        ])
+        .file(&file)
+        .sha256(ABC_SHA256)
+        .run(&create_test_ctx(false))
+        .await
+        .expect("second URL succeeds");
+    assert_eq!(std::fs::read_to_string(&file).expect("read file"), "abc");
+}
+
+#[tokio::test]
+async fn test_download_reports_all_failed_urls() {
+    let server = MockServer::start().await;
+    let urls = [
+        format!("{}/first/abc.7z", server.uri()),
+        format!("{}/second/abc.7z", server.uri()),
+    ];
+    let temp = tempfile::tempdir().expect("tempdir");
+
+    let err = DownloaderTool::new()
+        .urls(urls.clone())
+        .file(temp.path().join("abc.7z"))
+        .run(&create_test_ctx(false))
+        .await
+        .expect_err("every URL is missing");
+
+    match err.downcast_ref::<NetworkError>() {
+        Some(NetworkError::AllUrlsFailed {
+            urls: attempted, ..
+        }) => assert_eq!(attempted, &urls),
+        other => panic!("expected AllUrlsFailed, got {other:?}"),
+    }
+    assert!(
+        err.chain()
+            .any(|cause| cause.to_string().contains("http error 404"))
+    );
+    assert_eq!(
+        crate::error::exit_code(&err),
+        crate::error::exit_codes::NETWORK
+    );
+}
@@ -16,8 +16,9 @@ use anyhow::Context;
 use tokio::fs;
 use tracing::{debug, info};
 
-use super::{BoxFuture, Tool, ToolContext};
+use super::{BoxFuture, Tool, ToolContext, resolve_executable};
 use crate::core::process::builder::ProcessBuilder;
+use crate::task::plan::PlannedAction;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ArchiveFormat {
@@ -166,6 +167,12 @@ impl ExtractorTool {
                 force = self.force,
                 "[dry-run] Would extract archive"
             );
+            let builder = Self::sevenz_builder(self.script_program(ctx, "7z"), archive, output_dir);
+            ctx.record_script_command(&builder.env_overlay(ctx.env()));
+            ctx.record_plan(PlannedAction::Extract {
+                archive: archive.to_path_buf(),
+                output: output_dir.to_path_buf(),
+            });
             return Ok(());
         }
 
@@ -199,24 +206,27 @@ impl ExtractorTool {
         Ok(())
     }
 
+    /// Builds `7z x` extracting `archive` into `output_dir`, overwriting files.
+    fn sevenz_builder(sevenz: PathBuf, archive: &Path, output_dir: &Path) -> ProcessBuilder {
+        ProcessBuilder::new(sevenz)
+            .arg("x")
+            .arg("-aoa")
+            .arg("-bd")
+            .arg("-bb0")
+            .arg(format!("-o{}", output_dir.display()))
+            .arg(archive)
+    }
+
     async fn extract_with_7z(
         &self,
         ctx: &ToolContext,
         archive: &Path,
         output_dir: &Path,
     ) -> Result<()> {
-        let mut builder = ProcessBuilder::new(&ctx.config().tools.sevenz);
-
-        builder = builder
-            .arg("x")
-            .arg("-aoa")
-            .arg("-bd")
-            .arg("-bb0")
-            .arg(format!("-o{}", output_dir.display()))
-            .arg(archive);
+        let builder = Self::sevenz_builder(self.resolved_path(ctx)?, archive, output_dir);
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .context("Failed to run 7z extraction")?;
 
@@ -241,17 +251,10 @@ impl ExtractorTool {
         output_dir: &Path,
     ) -> Result<()> {
         // For tar.gz, use 7z directly which handles both decompression and extraction
-        let mut cmd = ProcessBuilder::new(&ctx.config().tools.sevenz);
-        cmd = cmd
-            .arg("x")
-            .arg("-aoa")
-            .arg("-bd")
-            .arg("-bb0")
-            .arg(format!("-o{}", output_dir.display()))
-            .arg(archive);
+        let cmd = Self::sevenz_builder(self.resolved_path(ctx)?, archive, output_dir);
 
-        let output = cmd
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(cmd)
             .await
             .context("Failed to run 7z extraction for tar.gz")?;
 
@@ -304,7 +307,7 @@ impl Tool for ExtractorTool {
         "extractor"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 ExtractorOperation::Extract => self.do_extract(ctx).await,
@@ -312,6 +315,10 @@ impl Tool for ExtractorTool {
             }
         })
     }
+
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        resolve_executable(&ctx.config().tools.sevenz, "7z")
+    }
 }
 
 #[cfg(test)]
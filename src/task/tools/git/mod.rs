@@ -7,8 +7,8 @@
 //!
 //! ```text
 //! GitTool
-//! Operations: Clone | Pull | Fetch | Checkout | SubmoduleUpdate | Reset
-//! Builder: url/path/branch/remote/target/shallow/force/recursive
+//! Operations: Clone | Pull | Fetch | Checkout | SubmoduleUpdate | Reset | Unshallow
+//! Builder: url/path/branch/remote/target/depth/sparse_paths/single_branch/lfs/force/recursive/jobs
 //! Safety: warn on uncommitted checkout, cancellation support
 //! ```
 //!
@@ -25,20 +25,25 @@
 //!
 //! For read-only queries (like checking for uncommitted changes), use `crate::git`.
 
+use std::collections::BTreeMap;
+use std::num::{NonZeroU32, NonZeroUsize};
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use crate::error::Result;
 use anyhow::Context;
 use tracing::{debug, info, warn};
 
 use super::{BoxFuture, Tool, ToolContext};
-use crate::core::process::builder::ProcessBuilder;
-use crate::git::query::{has_uncommitted_changes, is_git_repo};
+use crate::core::process::builder::{ProcessBuilder, ProcessFlags};
+use crate::git::query::{has_uncommitted_changes, is_git_repo, is_shallow};
 
 /// Git tool for repository operations.
 ///
 /// Supports cloning, pulling, fetching, checking out, and submodule updates with:
-/// - Shallow clones (`--depth 1`)
+/// - Shallow clones (`--depth N`)
+/// - Sparse checkouts of selected directories
+/// - Git LFS content after clone and pull
 /// - Branch/tag/commit specification
 /// - Remote specification
 /// - Cancellation support
@@ -76,12 +81,26 @@ pub struct GitTool {
     branch: Option<String>,
     remote: Option<String>,
     target: Option<String>,
-    shallow: bool,
+    depth: Option<NonZeroU32>,
+    sparse_paths: Vec<String>,
+    branches: CloneBranches,
+    lfs: bool,
     force: bool,
     recursive: bool,
+    jobs: NonZeroUsize,
     operation: GitOperation,
 }
 
+/// Which remote branches a clone fetches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CloneBranches {
+    /// Fetch every remote branch.
+    #[default]
+    All,
+    /// Fetch only the cloned branch (`--single-branch`).
+    Single,
+}
+
 /// Git operation to perform.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum GitOperation {
@@ -98,6 +117,8 @@ pub enum GitOperation {
     SubmoduleUpdate,
     /// Reset repository to a clean state.
     Reset,
+    /// Fetch the full history of a shallow clone.
+    Unshallow,
 }
 
 impl GitTool {
@@ -110,9 +131,13 @@ impl GitTool {
             branch: None,
             remote: None,
             target: None,
-            shallow: false,
+            depth: None,
+            sparse_paths: Vec::new(),
+            branches: CloneBranches::All,
+            lfs: false,
             force: false,
             recursive: true,
+            jobs: NonZeroUsize::MIN,
             operation: GitOperation::Clone,
         }
     }
@@ -147,9 +172,49 @@ impl GitTool {
         self
     }
 
+    /// Shorthand for `depth(Some(1))`, or a full clone when `false`.
+    #[must_use]
+    pub const fn shallow(self, shallow: bool) -> Self {
+        self.depth(if shallow { Some(1) } else { None })
+    }
+
+    /// Limits a clone to the last `depth` commits (`--depth N`).
+    ///
+    /// `None` or `Some(0)` clones the full history.
+    #[must_use]
+    pub const fn depth(mut self, depth: Option<u32>) -> Self {
+        self.depth = match depth {
+            Some(depth) => NonZeroU32::new(depth),
+            None => None,
+        };
+        self
+    }
+
+    /// Only materializes these directories in the working tree.
+    ///
+    /// The clone is made with `--no-checkout`, followed by
+    /// `git sparse-checkout set <paths>` and a checkout.
     #[must_use]
-    pub const fn shallow(mut self, shallow: bool) -> Self {
-        self.shallow = shallow;
+    pub fn sparse_paths(mut self, paths: Vec<String>) -> Self {
+        self.sparse_paths = paths;
+        self
+    }
+
+    /// Clones only the requested branch instead of all remote branches.
+    #[must_use]
+    pub const fn single_branch(mut self, single_branch: bool) -> Self {
+        self.branches = if single_branch {
+            CloneBranches::Single
+        } else {
+            CloneBranches::All
+        };
+        self
+    }
+
+    /// Runs `git lfs pull` after a clone or pull.
+    #[must_use]
+    pub const fn lfs(mut self, lfs: bool) -> Self {
+        self.lfs = lfs;
         self
     }
 
@@ -165,6 +230,13 @@ impl GitTool {
         self
     }
 
+    /// Submodules fetched in parallel by a submodule update (`--jobs N`).
+    #[must_use]
+    pub const fn jobs(mut self, jobs: NonZeroUsize) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
     #[must_use]
     pub const fn clone_op(mut self) -> Self {
         self.operation = GitOperation::Clone;
@@ -201,6 +273,21 @@ impl GitTool {
         self
     }
 
+    #[must_use]
+    pub const fn unshallow_op(mut self) -> Self {
+        self.operation = GitOperation::Unshallow;
+        self
+    }
+
+    /// Returns the `git` executable; a dry run does not require it to be installed.
+    fn git_program(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        if ctx.is_dry_run() {
+            Ok(self.script_program(ctx, "git"))
+        } else {
+            self.resolved_path(ctx).context("git executable not found")
+        }
+    }
+
     /// Gets the remote name, defaulting to "origin".
     fn get_remote(&self) -> &str {
         self.remote.as_deref().unwrap_or("origin")
@@ -216,24 +303,22 @@ impl GitTool {
             .path
             .as_ref()
             .context("GitTool: path is required for clone")?;
+        self.check_lfs(ctx).await?;
 
-        if ctx.is_dry_run() {
-            info!(
-                url = %url,
-                path = %path.display(),
-                shallow = self.shallow,
-                branch = ?self.branch,
-                "[dry-run] Would clone repository"
-            );
-            return Ok(());
-        }
-
-        let mut builder = ProcessBuilder::which("git").context("git executable not found")?;
+        let mut builder = ProcessBuilder::new(self.git_program(ctx)?);
 
         builder = builder.arg("clone");
 
-        if self.shallow {
-            builder = builder.arg("--depth").arg("1");
+        if let Some(depth) = self.depth {
+            builder = builder.arg("--depth").arg(depth.to_string());
+        }
+
+        if !self.sparse_paths.is_empty() {
+            builder = builder.arg("--no-checkout");
+        }
+
+        if self.branches == CloneBranches::Single {
+            builder = builder.arg("--single-branch");
         }
 
         if let Some(ref branch) = self.branch {
@@ -242,15 +327,30 @@ impl GitTool {
 
         builder = builder.arg(url).arg(path);
 
+        if ctx.is_dry_run() {
+            info!(
+                url = %url,
+                path = %path.display(),
+                depth = ?self.depth,
+                sparse_paths = ?self.sparse_paths,
+                single_branch = self.branches == CloneBranches::Single,
+                branch = ?self.branch,
+                "[dry-run] Would clone repository"
+            );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            self.do_sparse_checkout(ctx, path).await?;
+            return self.do_lfs_pull(ctx, path).await;
+        }
+
         debug!(
             url = %url,
             path = %path.display(),
-            shallow = self.shallow,
+            depth = ?self.depth,
             "Cloning repository"
         );
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .with_context(|| format!("Failed to clone {url}"))?;
 
@@ -258,6 +358,9 @@ impl GitTool {
             anyhow::bail!("Git clone was interrupted");
         }
 
+        self.do_sparse_checkout(ctx, path).await?;
+        self.do_lfs_pull(ctx, path).await?;
+
         info!(
             url = %url,
             path = %path.display(),
@@ -267,23 +370,63 @@ impl GitTool {
         Ok(())
     }
 
-    /// Executes a git pull operation.
-    async fn do_pull(&self, ctx: &ToolContext) -> Result<()> {
-        let path = self
-            .path
-            .as_ref()
-            .context("GitTool: path is required for pull")?;
+    /// Restricts a `--no-checkout` clone to the sparse paths and checks it out.
+    ///
+    /// Does nothing when no sparse paths are set.
+    async fn do_sparse_checkout(&self, ctx: &ToolContext, path: &Path) -> Result<()> {
+        if self.sparse_paths.is_empty() {
+            return Ok(());
+        }
+
+        let git = self.git_program(ctx)?;
+        let steps = [
+            ProcessBuilder::new(&git)
+                .arg("sparse-checkout")
+                .arg("set")
+                .args(&self.sparse_paths)
+                .cwd(path),
+            ProcessBuilder::new(&git)
+                .arg("checkout")
+                .arg("-q")
+                .cwd(path),
+        ];
 
         if ctx.is_dry_run() {
             info!(
                 path = %path.display(),
-                "[dry-run] Would pull repository"
+                sparse_paths = ?self.sparse_paths,
+                "[dry-run] Would set up sparse checkout"
             );
+            for builder in steps {
+                ctx.record_command(&builder.env_overlay(ctx.env()));
+            }
             return Ok(());
         }
 
-        let mut builder = ProcessBuilder::which("git")
-            .context("git executable not found")?
+        debug!(path = %path.display(), sparse_paths = ?self.sparse_paths, "Setting up sparse checkout");
+
+        for builder in steps {
+            let output = ctx.run_process(builder).await.with_context(|| {
+                format!("Failed to set up sparse checkout in {}", path.display())
+            })?;
+
+            if output.is_interrupted() {
+                anyhow::bail!("Git sparse checkout was interrupted");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Executes a git pull operation.
+    async fn do_pull(&self, ctx: &ToolContext) -> Result<()> {
+        let path = self
+            .path
+            .as_ref()
+            .context("GitTool: path is required for pull")?;
+        self.check_lfs(ctx).await?;
+
+        let mut builder = ProcessBuilder::new(self.git_program(ctx)?)
             .arg("pull")
             .arg("--recurse-submodules")
             .arg("--quiet");
@@ -296,10 +439,19 @@ impl GitTool {
 
         builder = builder.cwd(path);
 
+        if ctx.is_dry_run() {
+            info!(
+                path = %path.display(),
+                "[dry-run] Would pull repository"
+            );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            return self.do_lfs_pull(ctx, path).await;
+        }
+
         debug!(path = %path.display(), remote, "Pulling repository");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .with_context(|| format!("Failed to pull in {}", path.display()))?;
 
@@ -307,11 +459,74 @@ impl GitTool {
             anyhow::bail!("Git pull was interrupted");
         }
 
+        self.do_lfs_pull(ctx, path).await?;
+
         info!(path = %path.display(), "Repository pulled successfully");
 
         Ok(())
     }
 
+    /// Fails early when LFS is requested but Git LFS is not installed.
+    ///
+    /// Probes with `git lfs version` through the configured git, so the
+    /// check matches what `git lfs pull` will later run.
+    async fn check_lfs(&self, ctx: &ToolContext) -> Result<()> {
+        if !self.lfs || ctx.is_dry_run() {
+            return Ok(());
+        }
+
+        let available = ctx
+            .run_process(
+                ProcessBuilder::new(self.git_program(ctx)?)
+                    .arg("lfs")
+                    .arg("version")
+                    .flag(ProcessFlags::ALLOW_FAILURE),
+            )
+            .await
+            .is_ok_and(|output| output.success());
+
+        if !available {
+            anyhow::bail!(
+                "git_lfs is enabled but Git LFS is not installed (`git lfs version` failed); \
+                 install it from https://git-lfs.com or set git_lfs = false"
+            );
+        }
+        Ok(())
+    }
+
+    /// Executes `git lfs pull` when LFS is enabled.
+    ///
+    /// Repositories without LFS files are left untouched by `git lfs pull`.
+    async fn do_lfs_pull(&self, ctx: &ToolContext, path: &Path) -> Result<()> {
+        if !self.lfs {
+            return Ok(());
+        }
+
+        let builder = ProcessBuilder::new(self.git_program(ctx)?)
+            .arg("lfs")
+            .arg("pull")
+            .cwd(path);
+
+        if ctx.is_dry_run() {
+            info!(path = %path.display(), "[dry-run] Would pull Git LFS files");
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            return Ok(());
+        }
+
+        debug!(path = %path.display(), "Pulling Git LFS files");
+
+        let output = ctx
+            .run_process(builder)
+            .await
+            .with_context(|| format!("Failed to pull Git LFS files in {}", path.display()))?;
+
+        if output.is_interrupted() {
+            anyhow::bail!("Git LFS pull was interrupted");
+        }
+
+        Ok(())
+    }
+
     /// Executes a git fetch operation.
     async fn do_fetch(&self, ctx: &ToolContext) -> Result<()> {
         let path = self
@@ -321,34 +536,83 @@ impl GitTool {
 
         let remote = self.get_remote();
 
+        let builder = ProcessBuilder::new(self.git_program(ctx)?)
+            .arg("fetch")
+            .arg("--quiet")
+            .arg(remote)
+            .cwd(path);
+
         if ctx.is_dry_run() {
             info!(
                 path = %path.display(),
                 remote,
                 "[dry-run] Would fetch from remote"
             );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            return Ok(());
+        }
+
+        debug!(path = %path.display(), remote, "Fetching from remote");
+
+        let output = ctx
+            .run_process(builder)
+            .await
+            .with_context(|| format!("Failed to fetch {} in {}", remote, path.display()))?;
+
+        if output.is_interrupted() {
+            anyhow::bail!("Git fetch was interrupted");
+        }
+
+        info!(path = %path.display(), remote, "Fetched successfully");
+
+        Ok(())
+    }
+
+    /// Executes `git fetch --unshallow`, skipping repositories that are not shallow.
+    async fn do_unshallow(&self, ctx: &ToolContext) -> Result<()> {
+        let path = self
+            .path
+            .as_ref()
+            .context("GitTool: path is required for unshallow")?;
+
+        if !is_shallow(path)
+            .with_context(|| format!("Failed to check {} for shallowness", path.display()))?
+        {
+            debug!(path = %path.display(), "Repository is not shallow, skipping");
             return Ok(());
         }
 
-        let builder = ProcessBuilder::which("git")
-            .context("git executable not found")?
+        let remote = self.get_remote();
+
+        let builder = ProcessBuilder::new(self.git_program(ctx)?)
             .arg("fetch")
             .arg("--quiet")
+            .arg("--unshallow")
             .arg(remote)
             .cwd(path);
 
-        debug!(path = %path.display(), remote, "Fetching from remote");
+        if ctx.is_dry_run() {
+            info!(
+                path = %path.display(),
+                remote,
+                "[dry-run] Would fetch full history"
+            );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            return Ok(());
+        }
+
+        debug!(path = %path.display(), remote, "Fetching full history");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
-            .with_context(|| format!("Failed to fetch {} in {}", remote, path.display()))?;
+            .with_context(|| format!("Failed to unshallow {}", path.display()))?;
 
         if output.is_interrupted() {
-            anyhow::bail!("Git fetch was interrupted");
+            anyhow::bail!("Git unshallow was interrupted");
         }
 
-        info!(path = %path.display(), remote, "Fetched successfully");
+        info!(path = %path.display(), remote, "Unshallowed successfully");
 
         Ok(())
     }
@@ -386,28 +650,28 @@ impl GitTool {
             }
         }
 
+        let builder = ProcessBuilder::new(self.git_program(ctx)?)
+            .arg("-c")
+            .arg("advice.detachedHead=false")
+            .arg("checkout")
+            .arg("-q")
+            .arg(target)
+            .cwd(path);
+
         if ctx.is_dry_run() {
             info!(
                 path = %path.display(),
                 target,
                 "[dry-run] Would checkout"
             );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
-        let builder = ProcessBuilder::which("git")
-            .context("git executable not found")?
-            .arg("-c")
-            .arg("advice.detachedHead=false")
-            .arg("checkout")
-            .arg("-q")
-            .arg(target)
-            .cwd(path);
-
         debug!(path = %path.display(), target, "Checking out");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .with_context(|| format!("Failed to checkout {} in {}", target, path.display()))?;
 
@@ -427,17 +691,7 @@ impl GitTool {
             .as_ref()
             .context("GitTool: path is required for submodule update")?;
 
-        if ctx.is_dry_run() {
-            info!(
-                path = %path.display(),
-                recursive = self.recursive,
-                "[dry-run] Would update submodules"
-            );
-            return Ok(());
-        }
-
-        let mut builder = ProcessBuilder::which("git")
-            .context("git executable not found")?
+        let mut builder = ProcessBuilder::new(self.git_program(ctx)?)
             .arg("submodule")
             .arg("update")
             .arg("--init");
@@ -446,16 +700,30 @@ impl GitTool {
             builder = builder.arg("--recursive");
         }
 
+        if self.jobs.get() > 1 {
+            builder = builder.arg("--jobs").arg(self.jobs.to_string());
+        }
+
         builder = builder.cwd(path);
 
+        if ctx.is_dry_run() {
+            info!(
+                path = %path.display(),
+                recursive = self.recursive,
+                "[dry-run] Would update submodules"
+            );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
+            return Ok(());
+        }
+
         debug!(
             path = %path.display(),
             recursive = self.recursive,
             "Updating submodules"
         );
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .with_context(|| format!("Failed to update submodules in {}", path.display()))?;
 
@@ -495,6 +763,16 @@ impl GitTool {
             );
         }
 
+        let mut builder = ProcessBuilder::new(self.git_program(ctx)?)
+            .arg("reset")
+            .arg(mode);
+
+        if let Some(ref target) = self.target {
+            builder = builder.arg(target);
+        }
+
+        builder = builder.cwd(path);
+
         if ctx.is_dry_run() {
             info!(
                 path = %path.display(),
@@ -502,24 +780,14 @@ impl GitTool {
                 target = ?self.target,
                 "[dry-run] Would reset repository"
             );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
-        let mut builder = ProcessBuilder::which("git")
-            .context("git executable not found")?
-            .arg("reset")
-            .arg(mode);
-
-        if let Some(ref target) = self.target {
-            builder = builder.arg(target);
-        }
-
-        builder = builder.cwd(path);
-
         debug!(path = %path.display(), mode, "Resetting repository");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .with_context(|| format!("Failed to reset {}", path.display()))?;
 
@@ -544,7 +812,7 @@ impl Tool for GitTool {
         "git"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 GitOperation::Clone => self.do_clone(ctx).await,
@@ -553,13 +821,74 @@ impl Tool for GitTool {
                 GitOperation::Checkout => self.do_checkout(ctx).await,
                 GitOperation::SubmoduleUpdate => self.do_submodule_update(ctx).await,
                 GitOperation::Reset => self.do_reset(ctx).await,
+                GitOperation::Unshallow => self.do_unshallow(ctx).await,
             }
         })
     }
 }
 
+/// Results of remote branch checks, keyed by `(url, branch)`.
+///
+/// One cache is shared by everything a command runs, so checking the same
+/// branch of the same remote again does not spawn another `git ls-remote`.
+#[derive(Debug, Default)]
+pub struct RemoteBranchCache {
+    entries: Mutex<BTreeMap<(String, String), bool>>,
+}
+
+impl RemoteBranchCache {
+    /// Creates an empty cache.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the cached result for `branch` on `url`, if checked before.
+    #[must_use]
+    pub fn get(&self, url: &str, branch: &str) -> Option<bool> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&(url.to_string(), branch.to_string()))
+            .copied()
+    }
+
+    /// Records whether `branch` exists on `url`.
+    pub fn insert(&self, url: &str, branch: &str, exists: bool) {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert((url.to_string(), branch.to_string()), exists);
+    }
+
+    /// Returns the cached result, or runs `check` and caches its result.
+    ///
+    /// Errors are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `check`.
+    pub fn get_or_check(
+        &self,
+        url: &str,
+        branch: &str,
+        check: impl FnOnce() -> Result<bool>,
+    ) -> Result<bool> {
+        if let Some(exists) = self.get(url, branch) {
+            return Ok(exists);
+        }
+        let exists = check()?;
+        self.insert(url, branch, exists);
+        Ok(exists)
+    }
+}
+
 /// Check if a remote branch exists (async version with cancellation support).
 ///
+/// Results are cached in the context's [`RemoteBranchCache`], if it has one.
+///
 /// Uses `git ls-remote --heads <url> <branch>` to check if a branch exists on a remote
 /// without cloning the repository. This is the async variant suitable for use within
 /// async task contexts with cancellation token support.
@@ -587,6 +916,11 @@ impl Tool for GitTool {
 pub async fn remote_branch_exists_ctx(ctx: &ToolContext, url: &str, branch: &str) -> Result<bool> {
     use std::time::Duration;
 
+    if let Some(exists) = ctx.branch_cache().and_then(|cache| cache.get(url, branch)) {
+        debug!(url = %url, branch, exists, "Remote branch check cached");
+        return Ok(exists);
+    }
+
     let mut builder = ProcessBuilder::which("git")
         .context("git executable not found")?
         .arg("ls-remote")
@@ -602,8 +936,8 @@ pub async fn remote_branch_exists_ctx(ctx: &ToolContext, url: &str, branch: &str
         "Checking if remote branch exists"
     );
 
-    let output = builder
-        .run_with_cancellation(ctx.cancel_token().clone())
+    let output = ctx
+        .run_process(builder)
         .await
         .with_context(|| format!("Failed to check remote branch {branch} at {url}"))?;
 
@@ -620,6 +954,9 @@ pub async fn remote_branch_exists_ctx(ctx: &ToolContext, url: &str, branch: &str
         "Remote branch check completed"
     );
 
+    if let Some(cache) = ctx.branch_cache() {
+        cache.insert(url, branch, branch_exists);
+    }
     Ok(branch_exists)
 }
 
@@ -3,8 +3,12 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{GitOperation, GitTool};
-use crate::task::tools::Tool;
+use super::{CloneBranches, GitOperation, GitTool, RemoteBranchCache, remote_branch_exists_ctx};
+use crate::task::plan::{Plan, PlanRecorder, PlannedAction};
+use crate::task::tools::{Tool, ToolContext};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
 
 #[test]
 fn test_git_tool_builder() {
@@ -17,6 +21,105 @@ fn test_git_tool_builder() {
     insta::assert_debug_snapshot!("git_tool_builder", tool);
 }
 
+#[test]
+fn test_git_tool_single_branch() {
+    assert_eq!(GitTool::new().branches, CloneBranches::All);
+    assert_eq!(
+        GitTool::new().single_branch(true).branches,
+        CloneBranches::Single
+    );
+    assert_eq!(
+        GitTool::new()
+            .single_branch(true)
+            .single_branch(false)
+            .branches,
+        CloneBranches::All
+    );
+}
+
+#[test]
+fn test_git_tool_depth() {
+    assert_eq!(GitTool::new().depth, None);
+    assert_eq!(GitTool::new().shallow(true).depth, NonZeroU32::new(1));
+    assert_eq!(GitTool::new().shallow(true).shallow(false).depth, None);
+    assert_eq!(GitTool::new().depth(Some(50)).depth, NonZeroU32::new(50));
+    assert_eq!(GitTool::new().depth(Some(0)).depth, None);
+}
+
+#[tokio::test]
+async fn test_git_tool_dry_run_sparse_clone() {
+    let plan = Arc::new(Plan::new());
+    let ctx = ToolContext::new(
+        Arc::new(crate::config::Config::default()),
+        CancellationToken::new(),
+        true,
+    )
+    .with_plan(PlanRecorder::new(Arc::clone(&plan), "usvfs".to_string()));
+
+    GitTool::new()
+        .url("https://github.com/example/repo.git")
+        .path("/tmp/repo")
+        .depth(Some(10))
+        .sparse_paths(vec!["src".to_string(), "include".to_string()])
+        .run(&ctx)
+        .await
+        .unwrap();
+
+    let commands: Vec<Vec<String>> = plan
+        .take()
+        .into_iter()
+        .map(|step| match step.action() {
+            PlannedAction::Command { args, .. } => args.clone(),
+            other => panic!("unexpected action: {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        commands,
+        vec![
+            vec![
+                "clone",
+                "--depth",
+                "10",
+                "--no-checkout",
+                "https://github.com/example/repo.git",
+                "/tmp/repo",
+            ],
+            vec!["sparse-checkout", "set", "src", "include"],
+            vec!["checkout", "-q"],
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_git_tool_dry_run_lfs_pull() {
+    let plan = Arc::new(Plan::new());
+    let ctx = ToolContext::new(
+        Arc::new(crate::config::Config::default()),
+        CancellationToken::new(),
+        true,
+    )
+    .with_plan(PlanRecorder::new(
+        Arc::clone(&plan),
+        "installer".to_string(),
+    ));
+
+    GitTool::new()
+        .path("/tmp/repo")
+        .branch("master")
+        .lfs(true)
+        .pull_op()
+        .run(&ctx)
+        .await
+        .unwrap();
+
+    let steps = plan.take();
+    assert_eq!(steps.len(), 2);
+    assert!(matches!(
+        steps[1].action(),
+        PlannedAction::Command { args, .. } if args == &["lfs", "pull"]
+    ));
+}
+
 #[test]
 fn test_git_tool_all_operations() {
     // All GitOperation variants with their builder methods
@@ -30,6 +133,7 @@ fn test_git_tool_all_operations() {
             GitTool::new().submodule_update_op().operation,
         ),
         ("reset_op", GitTool::new().reset_op().operation),
+        ("unshallow_op", GitTool::new().unshallow_op().operation),
     ];
     insta::assert_debug_snapshot!(operations);
 }
@@ -106,7 +210,57 @@ fn test_git_tool_submodule_update_builder() {
     let tool = GitTool::new()
         .path("/tmp/repo")
         .recursive(true)
+        .jobs(std::num::NonZeroUsize::new(4).unwrap())
         .submodule_update_op();
 
     insta::assert_debug_snapshot!("git_tool_submodule_update_builder", tool);
 }
+
+#[tokio::test]
+async fn test_remote_branch_exists_ctx_uses_cache() {
+    let ctx = ToolContext::new(
+        Arc::new(crate::config::Config::default()),
+        CancellationToken::new(),
+        false,
+    );
+    assert!(ctx.branch_cache().is_none());
+    let url = "file:///nonexistent/mob-rs/repo.git";
+
+    // A real `git ls-remote` against this URL would report `false`, so a
+    // `true` result proves the cached entry was used without spawning git.
+    let shared = Arc::new(RemoteBranchCache::new());
+    shared.insert(url, "master", true);
+    let ctx = ctx.with_branch_cache(Arc::clone(&shared));
+    assert!(remote_branch_exists_ctx(&ctx, url, "master").await.unwrap());
+
+    // Contexts sharing the cache see each other's results.
+    shared.insert(url, "dev", true);
+    assert!(remote_branch_exists_ctx(&ctx, url, "dev").await.unwrap());
+    assert_eq!(shared.get(url, "feature"), None);
+}
+
+#[test]
+fn test_remote_branch_cache_get_or_check() {
+    let cache = RemoteBranchCache::new();
+    let mut checks = 0;
+
+    for _ in 0..2 {
+        let exists = cache
+            .get_or_check("https://example.com/a.git", "master", || {
+                checks += 1;
+                Ok(true)
+            })
+            .unwrap();
+        assert!(exists);
+    }
+    assert_eq!(checks, 1);
+
+    assert!(
+        cache
+            .get_or_check("https://example.com/a.git", "dev", || anyhow::bail!(
+                "no git"
+            ))
+            .is_err()
+    );
+    assert_eq!(cache.get("https://example.com/a.git", "dev"), None);
+}
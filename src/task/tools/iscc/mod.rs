@@ -26,6 +26,7 @@ use tracing::{debug, info};
 
 use super::{BoxFuture, Tool, ToolContext};
 use crate::core::process::builder::ProcessBuilder;
+use crate::error::ProcessError;
 
 /// Inno Setup Compiler tool for creating installers.
 ///
@@ -105,35 +106,23 @@ impl IsccTool {
         self
     }
 
-    /// Gets the iscc binary path, falling back to config or PATH.
-    fn get_iscc_binary(&self, ctx: &ToolContext) -> Result<PathBuf> {
-        if let Some(ref binary) = self.iscc_binary {
-            return Ok(binary.clone());
-        }
+    /// Builds the `iscc` invocation for `iss` with defines and output options.
+    fn iscc_builder(&self, iscc_binary: PathBuf, iss: &Path) -> ProcessBuilder {
+        let mut builder = ProcessBuilder::new(iscc_binary);
 
-        // Try config path first
-        let config_path = &ctx.config().tools.iscc;
-        if config_path.is_absolute() && config_path.exists() {
-            return Ok(config_path.clone());
+        for (name, value) in &self.defines {
+            builder = builder.arg(format!("/D{name}={value}"));
         }
 
-        let common_paths = [
-            r"C:\Program Files (x86)\Inno Setup 6\ISCC.exe",
-            r"C:\Program Files\Inno Setup 6\ISCC.exe",
-            r"C:\Program Files (x86)\Inno Setup 5\ISCC.exe",
-            r"C:\Program Files\Inno Setup 5\ISCC.exe",
-        ];
+        if let Some(ref output_dir) = self.output_dir {
+            builder = builder.arg(format!("/O{}", output_dir.display()));
+        }
 
-        for path in &common_paths {
-            let p = PathBuf::from(path);
-            if p.exists() {
-                return Ok(p);
-            }
+        if let Some(ref output_name) = self.output_name {
+            builder = builder.arg(format!("/F{output_name}"));
         }
 
-        ProcessBuilder::find("iscc")
-            .or_else(|| ProcessBuilder::find("ISCC.exe"))
-            .context("iscc executable not found in PATH, config, or common locations")
+        builder.arg(iss)
     }
 }
 
@@ -142,7 +131,7 @@ impl Tool for IsccTool {
         "iscc"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             let iss = self
                 .iss
@@ -156,34 +145,20 @@ impl Tool for IsccTool {
                     output_name = ?self.output_name,
                     "[dry-run] Would compile Inno Setup script"
                 );
+                let builder = self.iscc_builder(self.script_program(ctx, "iscc"), iss);
+                ctx.record_command(&builder.env_overlay(ctx.env()));
                 return Ok(());
             }
 
-            let iscc_binary = self.get_iscc_binary(ctx)?;
-
-            let mut builder = ProcessBuilder::new(&iscc_binary);
-
-            for (name, value) in &self.defines {
-                builder = builder.arg(format!("/D{name}={value}"));
-            }
-
-            if let Some(ref output_dir) = self.output_dir {
-                builder = builder.arg(format!("/O{}", output_dir.display()));
-            }
-
-            if let Some(ref output_name) = self.output_name {
-                builder = builder.arg(format!("/F{output_name}"));
-            }
-
-            builder = builder.arg(iss);
+            let builder = self.iscc_builder(self.resolved_path(ctx)?, iss);
 
             debug!(
                 iss = %iss.display(),
                 "Compiling Inno Setup script"
             );
 
-            let output = builder
-                .run_with_cancellation(ctx.cancel_token().clone())
+            let output = ctx
+                .run_process(builder)
                 .await
                 .with_context(|| format!("Failed to compile {}", iss.display()))?;
 
@@ -199,6 +174,42 @@ impl Tool for IsccTool {
             Ok(())
         })
     }
+
+    /// Gets the iscc binary path, falling back to config or PATH.
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        if let Some(ref binary) = self.iscc_binary {
+            return Ok(binary.clone());
+        }
+
+        // Try config path first
+        let config_path = &ctx.config().tools.iscc;
+        if config_path.is_absolute() && config_path.exists() {
+            return Ok(config_path.clone());
+        }
+
+        let common_paths = [
+            r"C:\Program Files (x86)\Inno Setup 6\ISCC.exe",
+            r"C:\Program Files\Inno Setup 6\ISCC.exe",
+            r"C:\Program Files (x86)\Inno Setup 5\ISCC.exe",
+            r"C:\Program Files\Inno Setup 5\ISCC.exe",
+        ];
+
+        for path in &common_paths {
+            let p = PathBuf::from(path);
+            if p.exists() {
+                return Ok(p);
+            }
+        }
+
+        ProcessBuilder::find("iscc")
+            .or_else(|| ProcessBuilder::find("ISCC.exe"))
+            .ok_or_else(|| {
+                ProcessError::ExecutableNotFound {
+                    name: "iscc".to_string(),
+                }
+                .into()
+            })
+    }
 }
 
 #[cfg(test)]
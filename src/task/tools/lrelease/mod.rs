@@ -7,7 +7,7 @@
 //!
 //! ```text
 //! Sources (.ts) --> lrelease --> {project}_{lang}.qm
-//! Uses Qt lrelease (qt_bin or PATH)
+//! lrelease: tools.lrelease (path) → paths.qt_bin → PATH
 //! ```
 //!
 //! This module provides the `LreleaseTool` struct for compiling Qt translation
@@ -27,7 +27,7 @@ use crate::error::Result;
 use anyhow::Context;
 use tracing::{debug, info};
 
-use super::{BoxFuture, Tool, ToolContext};
+use super::{BoxFuture, Tool, ToolContext, resolve_executable};
 use crate::core::process::builder::ProcessBuilder;
 
 /// Qt lrelease tool for compiling translation files.
@@ -112,33 +112,6 @@ impl LreleaseTool {
         self
     }
 
-    /// Gets the lrelease binary path, falling back to config or PATH.
-    fn get_lrelease_binary(&self, ctx: &ToolContext) -> Result<PathBuf> {
-        if let Some(ref binary) = self.lrelease_binary {
-            return Ok(binary.clone());
-        }
-
-        let config_path = &ctx.config().tools.lrelease;
-        if config_path.is_absolute() && config_path.exists() {
-            return Ok(config_path.clone());
-        }
-
-        if let Some(ref qt_bin) = ctx.config().paths.qt_bin {
-            let qt_lrelease = qt_bin.join("lrelease.exe");
-            if qt_lrelease.exists() {
-                return Ok(qt_lrelease);
-            }
-            let qt_lrelease = qt_bin.join("lrelease");
-            if qt_lrelease.exists() {
-                return Ok(qt_lrelease);
-            }
-        }
-
-        ProcessBuilder::find("lrelease")
-            .or_else(|| ProcessBuilder::find("lrelease.exe"))
-            .context("lrelease executable not found in PATH or config")
-    }
-
     /// Generates the output .qm filename based on project and first source.
     ///
     /// Returns `{project}_{lang}.qm` where lang is the stem of the first source file.
@@ -174,6 +147,15 @@ impl LreleaseTool {
 
         Ok(output_dir.join(self.qm_filename()?))
     }
+
+    /// Builds `lrelease -silent <sources> -qm <qm_path>`.
+    fn lrelease_builder(&self, lrelease_binary: PathBuf, qm_path: &Path) -> ProcessBuilder {
+        ProcessBuilder::new(lrelease_binary)
+            .arg("-silent")
+            .args(&self.sources)
+            .arg("-qm")
+            .arg(qm_path)
+    }
 }
 
 impl Tool for LreleaseTool {
@@ -181,7 +163,7 @@ impl Tool for LreleaseTool {
         "lrelease"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             let output_dir = self
                 .output_dir
@@ -201,6 +183,8 @@ impl Tool for LreleaseTool {
                     output = %qm_path.display(),
                     "[dry-run] Would compile translation files"
                 );
+                let builder = self.lrelease_builder(self.script_program(ctx, "lrelease"), &qm_path);
+                ctx.record_command(&builder.env_overlay(ctx.env()));
                 return Ok(());
             }
 
@@ -212,15 +196,7 @@ impl Tool for LreleaseTool {
                     })?;
             }
 
-            let lrelease_binary = self.get_lrelease_binary(ctx)?;
-
-            let mut builder = ProcessBuilder::new(&lrelease_binary).arg("-silent");
-
-            for source in &self.sources {
-                builder = builder.arg(source);
-            }
-
-            builder = builder.arg("-qm").arg(&qm_path);
+            let builder = self.lrelease_builder(self.resolved_path(ctx)?, &qm_path);
 
             debug!(
                 sources = ?self.sources,
@@ -228,8 +204,8 @@ impl Tool for LreleaseTool {
                 "Compiling translation files"
             );
 
-            let output = builder
-                .run_with_cancellation(ctx.cancel_token().clone())
+            let output = ctx
+                .run_process(builder)
                 .await
                 .context("Failed to run lrelease")?;
 
@@ -245,6 +221,54 @@ impl Tool for LreleaseTool {
             Ok(())
         })
     }
+
+    /// Gets the lrelease binary path.
+    ///
+    /// An explicit `lrelease_binary` wins; otherwise see [`find_lrelease`].
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        if let Some(ref binary) = self.lrelease_binary {
+            return Ok(binary.clone());
+        }
+
+        let config = ctx.config();
+        let (path, source) = find_lrelease(&config.tools.lrelease, config.paths.qt_bin.as_deref())?;
+        info!(path = %path.display(), source, "Using lrelease");
+        Ok(path)
+    }
+}
+
+/// Finds lrelease, returning its path and the setting it came from.
+///
+/// An lrelease from a different Qt than the one being built against
+/// produces `.qm` files that fail to load, so the one in `paths.qt_bin` is
+/// preferred over PATH:
+///
+/// 1. `tools.lrelease` when it is a path rather than a bare name
+/// 2. `lrelease` in `paths.qt_bin`
+/// 3. `tools.lrelease` looked up in PATH
+///
+/// # Errors
+///
+/// Returns `ProcessError::ExecutableNotFound` if nothing matches.
+pub fn find_lrelease(configured: &Path, qt_bin: Option<&Path>) -> Result<(PathBuf, &'static str)> {
+    let pinned = configured.is_absolute() || configured.components().count() > 1;
+    if pinned {
+        return Ok((
+            resolve_executable(configured, "lrelease")?,
+            "tools.lrelease",
+        ));
+    }
+
+    if let Some(qt_bin) = qt_bin {
+        for name in ["lrelease.exe", "lrelease"] {
+            let candidate = qt_bin.join(name);
+            if candidate.is_file() {
+                return Ok((candidate, "paths.qt_bin"));
+            }
+        }
+    }
+
+    Ok((resolve_executable(configured, "lrelease")?, "PATH"))
 }
 
 #[cfg(test)]
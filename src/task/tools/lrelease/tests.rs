@@ -3,9 +3,9 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::LreleaseTool;
+use super::{LreleaseTool, find_lrelease};
 use crate::task::tools::Tool;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[test]
 fn test_lrelease_tool_builder() {
@@ -70,3 +70,35 @@ fn test_lrelease_tool_missing_sources() {
 
     assert!(tool.qm_filename().is_err());
 }
+
+#[test]
+fn test_find_lrelease_prefers_qt_bin_over_path() {
+    let qt = tempfile::tempdir().unwrap();
+    let qt_lrelease = qt.path().join("lrelease.exe");
+    std::fs::write(&qt_lrelease, "").unwrap();
+
+    let (path, source) = find_lrelease(Path::new("lrelease.exe"), Some(qt.path())).unwrap();
+    assert_eq!(path, qt_lrelease);
+    assert_eq!(source, "paths.qt_bin");
+}
+
+#[test]
+fn test_find_lrelease_configured_path_wins() {
+    let qt = tempfile::tempdir().unwrap();
+    std::fs::write(qt.path().join("lrelease"), "").unwrap();
+    let pinned_dir = tempfile::tempdir().unwrap();
+    let pinned = pinned_dir.path().join("lrelease-6.7.exe");
+    std::fs::write(&pinned, "").unwrap();
+
+    let (path, source) = find_lrelease(&pinned, Some(qt.path())).unwrap();
+    assert_eq!(path, pinned);
+    assert_eq!(source, "tools.lrelease");
+}
+
+#[test]
+fn test_find_lrelease_missing_everywhere() {
+    let qt = tempfile::tempdir().unwrap();
+
+    let err = find_lrelease(Path::new("mob-no-such-lrelease"), Some(qt.path())).unwrap_err();
+    assert!(err.to_string().contains("mob-no-such-lrelease"));
+}
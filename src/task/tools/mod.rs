@@ -9,16 +9,33 @@
 //! Task --> ToolContext --> ProcessBuilder --> Tools
 //!   Git, CMake, MSBuild, ...
 //! ToolContext: cancel token --> run_with_cancellation
+//!              env overlay  --> ProcessBuilder::env_overlay
+//!              dry run      --> record_command / record_download
+//!                               (--emit-script, build --plan)
+//!              branch cache --> remote_branch_exists_ctx (per command)
+//! run_with_diagnostics: MSBuild/CMake runs --> "<op>: N warnings, M errors"
+//! Tool::resolved_path: tools.* config / PATH / VsHelper
+//!              --> executable or ExecutableNotFound
 //! ```
 //!
 //! All tools support graceful cancellation via `CancellationToken`.
 
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tokio_util::sync::CancellationToken;
 
 use crate::config::Config;
-use crate::error::Result;
+use crate::core::env::container::Env;
+use crate::core::process::builder::{ProcessBuilder, ProcessOutput};
+use crate::core::process::diagnostics::DiagnosticCounter;
+use crate::core::process::priority::ProcessPriority;
+use crate::core::process::script::Script;
+use crate::core::process::timing::ProcessTimings;
+use crate::error::{ProcessError, Result};
+use crate::net::ProgressGroup;
+use crate::task::plan::{PlanRecorder, PlannedAction};
+use git::RemoteBranchCache;
 
 pub mod cmake;
 pub mod downloader;
@@ -27,14 +44,13 @@ pub mod git;
 #[cfg(windows)]
 pub mod iscc;
 pub mod lrelease;
-#[cfg(windows)]
 pub mod msbuild;
 pub mod packer;
 pub mod transifex;
-#[cfg(windows)]
 pub mod vs;
 
 use futures_util::future::BoxFuture;
+use tracing::Instrument;
 
 /// Context provided to tools during execution.
 ///
@@ -51,6 +67,29 @@ pub struct ToolContext {
 
     /// Reference to the configuration.
     config: Arc<Config>,
+
+    /// Extra environment variables layered onto every spawned process.
+    env: Env,
+
+    /// Collects the operations of a dry run for `build --plan`.
+    plan: Option<PlanRecorder>,
+
+    /// Remote branch checks already made by this command; none are cached
+    /// without it.
+    branch_cache: Option<Arc<RemoteBranchCache>>,
+
+    /// Collects the duration of every process run for `--measure`.
+    process_timings: Option<Arc<ProcessTimings>>,
+
+    /// Collects the commands of a dry run for `--emit-script`.
+    script: Option<Arc<Script>>,
+
+    /// Progress bars shared with concurrent downloads; each download draws
+    /// its own bar without it.
+    progress: Option<ProgressGroup>,
+
+    /// Scheduling priority of processes that don't set one (`--low-priority`).
+    priority: ProcessPriority,
 }
 
 impl ToolContext {
@@ -61,9 +100,66 @@ impl ToolContext {
             cancel_token,
             dry_run,
             config,
+            env: Env::new(),
+            plan: None,
+            branch_cache: None,
+            process_timings: None,
+            script: None,
+            progress: None,
+            priority: ProcessPriority::Normal,
         }
     }
 
+    /// Sets the environment overlay passed to spawned processes.
+    #[must_use]
+    pub fn with_env(mut self, env: Env) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Sets the recorder that collects dry-run operations for `build --plan`.
+    #[must_use]
+    pub fn with_plan(mut self, plan: PlanRecorder) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    /// Caches remote branch checks in `cache`, shared with other contexts.
+    #[must_use]
+    pub fn with_branch_cache(mut self, cache: Arc<RemoteBranchCache>) -> Self {
+        self.branch_cache = Some(cache);
+        self
+    }
+
+    /// Draws download progress bars in `progress`, shared with other contexts.
+    #[must_use]
+    pub fn with_progress_group(mut self, progress: ProgressGroup) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Records the duration of every process run through
+    /// [`run_process`](Self::run_process) in `timings`.
+    #[must_use]
+    pub fn with_process_timings(mut self, timings: Arc<ProcessTimings>) -> Self {
+        self.process_timings = Some(timings);
+        self
+    }
+
+    /// Sets the script that collects dry-run commands for `--emit-script`.
+    #[must_use]
+    pub fn with_script(mut self, script: Arc<Script>) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    /// Sets the scheduling priority of processes that don't set their own.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: ProcessPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
     /// Returns a reference to the configuration.
     #[must_use]
     pub const fn config(&self) -> &Arc<Config> {
@@ -82,11 +178,107 @@ impl ToolContext {
         self.dry_run
     }
 
+    /// Returns the environment overlay for spawned processes.
+    #[must_use]
+    pub const fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Returns the cache of remote branch checks.
+    #[must_use]
+    pub fn branch_cache(&self) -> Option<&RemoteBranchCache> {
+        self.branch_cache.as_deref()
+    }
+
+    /// Returns the scheduling priority of processes that don't set their own.
+    #[must_use]
+    pub const fn priority(&self) -> ProcessPriority {
+        self.priority
+    }
+
+    /// Returns the shared group of download progress bars.
+    #[must_use]
+    pub const fn progress_group(&self) -> Option<&ProgressGroup> {
+        self.progress.as_ref()
+    }
+
     /// Checks if cancellation has been requested.
     #[must_use]
     pub fn is_cancelled(&self) -> bool {
         self.cancel_token.is_cancelled()
     }
+
+    /// Runs `builder` with this context's environment overlay, priority and
+    /// cancellation token, recording its duration if timings are collected.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`ProcessBuilder::run_with_cancellation`].
+    pub async fn run_process(&self, builder: ProcessBuilder) -> Result<ProcessOutput> {
+        let builder = builder
+            .env_overlay(self.env())
+            .default_priority(self.priority);
+        let builder = match &self.process_timings {
+            Some(timings) => builder.record_timings(Arc::clone(timings)),
+            None => builder,
+        };
+        builder
+            .run_with_cancellation(self.cancel_token.clone())
+            .await
+    }
+
+    /// Returns whether dry-run operations are recorded, for `--emit-script`
+    /// or `build --plan`.
+    ///
+    /// Tools check this before building a command only needed for recording.
+    #[must_use]
+    pub const fn is_recording(&self) -> bool {
+        self.plan.is_some() || self.script.is_some()
+    }
+
+    /// Records the command `builder` would run.
+    pub fn record_command(&self, builder: &ProcessBuilder) {
+        self.record_script_command(builder);
+        self.record_plan(PlannedAction::Command {
+            program: builder.program().clone(),
+            args: builder.args_slice().to_vec(),
+            cwd: builder.working_dir().cloned(),
+        });
+    }
+
+    /// Records the command `builder` would run for `--emit-script` only.
+    pub fn record_script_command(&self, builder: &ProcessBuilder) {
+        if let Some(script) = &self.script {
+            script.record_command(builder);
+        }
+    }
+
+    /// Records a download of `url` into `file`.
+    pub fn record_download(&self, url: &str, file: &Path) {
+        if let Some(script) = &self.script {
+            script.record_download(url, file);
+        }
+        self.record_plan(PlannedAction::Download {
+            url: url.to_string(),
+            file: file.to_path_buf(),
+        });
+    }
+
+    /// Creates the span a run of `tool` executes in.
+    ///
+    /// It nests under the current task span, so log events carry both the
+    /// `task` and the `tool` field.
+    #[must_use]
+    pub fn tool_span(tool: &str) -> tracing::Span {
+        tracing::info_span!("tool", tool = %tool)
+    }
+
+    /// Records `action` for `build --plan` only.
+    pub fn record_plan(&self, action: PlannedAction) {
+        if let Some(plan) = &self.plan {
+            plan.record(action);
+        }
+    }
 }
 
 /// Trait for tools that execute external processes.
@@ -98,20 +290,53 @@ impl ToolContext {
 ///
 /// - Tools should use `ProcessBuilder::run_with_cancellation()` for process execution
 /// - The `interrupt()` method is called when cancellation is requested
-/// - Tools should respect `ctx.dry_run` and only log actions without executing
+/// - Tools should respect `ctx.dry_run` and only log actions without executing,
+///   passing the command they would run to `ToolContext::record_command`
 pub trait Tool: Send + Sync {
     /// Returns the name of this tool (e.g., "git", "cmake", "msbuild").
     fn name(&self) -> &str;
 
     /// Executes the tool's operation.
     ///
+    /// Tools implement this; callers use [`Tool::run`], which adds the tool
+    /// span.
+    ///
     /// # Arguments
     /// * `ctx` - The tool context with cancellation token and configuration
     ///
     /// # Returns
     /// * `Ok(())` if the operation completed successfully
     /// * `Err(...)` if the operation failed or was cancelled
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>>;
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>>;
+
+    /// Runs [`Tool::execute`] in the span from [`ToolContext::tool_span`].
+    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+        Box::pin(
+            self.execute(ctx)
+                .instrument(ToolContext::tool_span(self.name())),
+        )
+    }
+
+    /// Returns the executable this tool runs.
+    ///
+    /// The default looks up the tool's name in PATH. Tools with a `tools.*`
+    /// config entry or another lookup (such as `VsHelper`) override this.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProcessError::ExecutableNotFound` if the executable cannot be found.
+    fn resolved_path(&self, _ctx: &ToolContext) -> Result<PathBuf> {
+        resolve_executable(Path::new(""), self.name())
+    }
+
+    /// Returns the executable written to an `--emit-script` script.
+    ///
+    /// Falls back to `program` when [`Tool::resolved_path`] fails, so dry
+    /// runs still work where the tool is not installed.
+    fn script_program(&self, ctx: &ToolContext, program: &str) -> PathBuf {
+        self.resolved_path(ctx)
+            .unwrap_or_else(|_| PathBuf::from(program))
+    }
 
     /// Interrupts the tool's operation.
     ///
@@ -124,6 +349,67 @@ pub trait Tool: Send + Sync {
     }
 }
 
+/// Resolves a configured tool path to an executable.
+///
+/// - empty: `program` is looked up in PATH
+/// - absolute: used if the file exists
+/// - anything else: looked up in PATH, also without its extension so that
+///   `cmake.exe` resolves on every platform
+///
+/// # Errors
+///
+/// Returns `ProcessError::ExecutableNotFound` if nothing matches.
+pub fn resolve_executable(configured: &Path, program: &str) -> Result<PathBuf> {
+    let not_found = |name: String| ProcessError::ExecutableNotFound { name };
+
+    if configured.as_os_str().is_empty() {
+        return ProcessBuilder::find(program).ok_or_else(|| not_found(program.to_string()).into());
+    }
+
+    if configured.is_absolute() {
+        return if configured.is_file() {
+            Ok(configured.to_path_buf())
+        } else {
+            Err(not_found(configured.display().to_string()).into())
+        };
+    }
+
+    let name = configured.to_string_lossy();
+    ProcessBuilder::find(&name)
+        .or_else(|| {
+            configured
+                .file_stem()
+                .and_then(|stem| ProcessBuilder::find(&stem.to_string_lossy()))
+        })
+        .ok_or_else(|| not_found(name.into_owned()).into())
+}
+
+/// Runs `builder` with the context's environment and cancellation token, then
+/// logs the warnings and errors of its output as `<operation>: N warnings, M
+/// errors`, also when it fails.
+///
+/// # Errors
+///
+/// Returns the error of [`ProcessBuilder::run_with_cancellation`].
+pub async fn run_with_diagnostics(
+    ctx: &ToolContext,
+    builder: ProcessBuilder,
+    operation: &str,
+) -> Result<ProcessOutput> {
+    let diagnostics = Arc::new(DiagnosticCounter::new());
+    let output = ctx
+        .run_process(builder.count_diagnostics(Arc::clone(&diagnostics)))
+        .await;
+
+    let counts = diagnostics.counts();
+    tracing::info!(
+        warnings = counts.warnings(),
+        errors = counts.errors(),
+        "{operation}: {counts}"
+    );
+    output
+}
+
 #[cfg(test)]
 mod test_utils;
 #[cfg(test)]
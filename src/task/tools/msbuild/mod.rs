@@ -9,22 +9,27 @@
 //! MsBuildTool
 //! Operations: Build | Clean
 //! Builder: solution/configuration/architecture/targets/properties
-//! Env: VsHelper::get_env(arch)
+//! binary_log(path): -bl:<path>, relative paths under the solution's directory
+//! verbosity(v): -verbosity:<v>, else from global.output_log_level
+//!   (DEBUG → detailed, TRACE/DUMP → diagnostic, otherwise minimal)
+//! Env: VsHelper::get_env(arch, versions.vs_version)
+//! Output: warning/error counts logged after each run (run_with_diagnostics)
 //! Toolset: 14.3 → v143, 14.2 → v142
 //! ```
 
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-use crate::error::Result;
+use crate::error::{ProcessError, Result};
 use anyhow::Context;
 use tracing::{debug, info};
 
 use super::vs::VsHelper;
-use super::{BoxFuture, Tool, ToolContext};
+use super::{BoxFuture, Tool, ToolContext, resolve_executable, run_with_diagnostics};
 use crate::config::types::BuildConfiguration;
 use crate::core::env::types::Arch;
 use crate::core::process::builder::ProcessBuilder;
+use crate::logging::LogLevel;
 
 /// `MSBuild` operation to perform.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -36,6 +41,46 @@ pub enum MsBuildOperation {
     Clean,
 }
 
+/// `MSBuild` console verbosity (`-verbosity:<level>`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MsBuildVerbosity {
+    /// Errors and warnings only.
+    Quiet,
+    /// Errors, warnings and high-importance messages.
+    #[default]
+    Minimal,
+    /// `MSBuild` default.
+    Normal,
+    /// Adds target and task details.
+    Detailed,
+    /// Everything, including property and item values.
+    Diagnostic,
+}
+
+impl MsBuildVerbosity {
+    /// Returns the verbosity matching the console log level.
+    #[must_use]
+    pub const fn for_log_level(level: LogLevel) -> Self {
+        match level.as_u8() {
+            0..=3 => Self::Minimal,
+            4 => Self::Detailed,
+            _ => Self::Diagnostic,
+        }
+    }
+
+    /// Returns the value passed to `-verbosity:`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Quiet => "quiet",
+            Self::Minimal => "minimal",
+            Self::Normal => "normal",
+            Self::Detailed => "detailed",
+            Self::Diagnostic => "diagnostic",
+        }
+    }
+}
+
 /// `MSBuild` tool for Visual Studio solution/project builds.
 #[derive(Debug, Clone)]
 pub struct MsBuildTool {
@@ -46,6 +91,8 @@ pub struct MsBuildTool {
     platform: Option<String>,
     architecture: Option<Arch>,
     max_cpu_count: bool,
+    binary_log: Option<PathBuf>,
+    verbosity: Option<MsBuildVerbosity>,
     operation: MsBuildOperation,
 }
 
@@ -61,6 +108,8 @@ impl MsBuildTool {
             platform: None,
             architecture: None,
             max_cpu_count: false,
+            binary_log: None,
+            verbosity: None,
             operation: MsBuildOperation::Build,
         }
     }
@@ -117,6 +166,23 @@ impl MsBuildTool {
         self
     }
 
+    /// Writes a binary log (`-bl:<path>`) for the `MSBuild` Structured Log Viewer.
+    ///
+    /// A relative `path` is resolved against the solution's directory.
+    #[must_use]
+    pub fn binary_log(mut self, path: impl AsRef<Path>) -> Self {
+        self.binary_log = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the console verbosity, overriding the one derived from
+    /// `global.output_log_level`.
+    #[must_use]
+    pub const fn verbosity(mut self, verbosity: MsBuildVerbosity) -> Self {
+        self.verbosity = Some(verbosity);
+        self
+    }
+
     #[must_use]
     pub const fn build_op(mut self) -> Self {
         self.operation = MsBuildOperation::Build;
@@ -157,6 +223,27 @@ impl MsBuildTool {
         version.to_string()
     }
 
+    /// Returns the binary log path, resolved against the solution's directory.
+    fn binary_log_path(&self) -> Option<PathBuf> {
+        let path = self.binary_log.as_ref()?;
+        if path.is_absolute() {
+            return Some(path.clone());
+        }
+        let dir = self
+            .solution
+            .as_deref()
+            .and_then(Path::parent)
+            .unwrap_or_else(|| Path::new(""));
+        Some(dir.join(path))
+    }
+
+    /// Returns the verbosity to use, derived from the console log level unless set.
+    fn effective_verbosity(&self, ctx: &ToolContext) -> MsBuildVerbosity {
+        self.verbosity.unwrap_or_else(|| {
+            MsBuildVerbosity::for_log_level(ctx.config().global.output_log_level)
+        })
+    }
+
     /// Determines the platform to use for `MSBuild`.
     fn determine_platform(&self) -> String {
         self.platform.as_ref().map_or_else(
@@ -173,32 +260,18 @@ impl MsBuildTool {
         )
     }
 
-    async fn do_build(&self, ctx: &ToolContext) -> Result<()> {
-        if ctx.is_dry_run() {
-            info!(
-                solution = ?self.solution,
-                configuration = ?self.configuration,
-                platform = %self.determine_platform(),
-                targets = ?self.targets,
-                max_cpu_count = self.max_cpu_count,
-                "[dry-run] Would build with MSBuild"
-            );
-            return Ok(());
-        }
-
+    /// Builds the `MSBuild` command for the current operation, without the VS environment.
+    fn msbuild_builder(&self, ctx: &ToolContext, msbuild: PathBuf) -> Result<ProcessBuilder> {
         let solution = self.solution_required()?;
         let platform = self.determine_platform();
 
-        let arch = self.architecture.unwrap_or(Arch::X64);
-        let env = VsHelper::get_env(arch)?;
-
-        let msbuild = if ctx.config().tools.msbuild.as_os_str().is_empty() {
-            VsHelper::find_msbuild().context("MSBuild executable not found")?
-        } else {
-            ctx.config().tools.msbuild.clone()
-        };
-
-        let mut builder = ProcessBuilder::new(&msbuild).arg("-nologo").arg(solution);
+        let mut builder = ProcessBuilder::new(msbuild)
+            .arg("-nologo")
+            .arg(format!(
+                "-verbosity:{}",
+                self.effective_verbosity(ctx).as_str()
+            ))
+            .arg(solution);
 
         if self.max_cpu_count {
             builder = builder
@@ -225,99 +298,108 @@ impl MsBuildTool {
             ));
         }
 
-        for target in &self.targets {
-            builder = builder.arg(format!("-target:{target}"));
+        match self.operation {
+            MsBuildOperation::Build => {
+                for target in &self.targets {
+                    builder = builder.arg(format!("-target:{target}"));
+                }
+            }
+            MsBuildOperation::Clean if self.targets.is_empty() => {
+                builder = builder.arg("-target:Clean");
+            }
+            MsBuildOperation::Clean => {
+                for target in &self.targets {
+                    builder = builder.arg(format!("-target:{target}:Clean"));
+                }
+            }
         }
 
         for (key, value) in &self.properties {
             builder = builder.arg(format!("-property:{key}={value}"));
         }
 
-        builder = builder.env(env);
-
-        debug!("Building with MSBuild");
+        if let Some(binary_log) = self.binary_log_path() {
+            builder = builder.arg(format!("-bl:{}", binary_log.display()));
+        }
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
-            .await
-            .context("Failed to run MSBuild build")?;
+        Ok(builder)
+    }
 
-        if output.is_interrupted() {
-            anyhow::bail!("MSBuild build was interrupted");
+    /// Records the `MSBuild` call for `--emit-script`, with the VS environment if available.
+    fn record_dry_run(&self, ctx: &ToolContext) -> Result<()> {
+        let mut builder = self.msbuild_builder(ctx, self.script_program(ctx, "msbuild"))?;
+        let vs_version = ctx.config().versions.vs_version.as_deref();
+        match VsHelper::get_env(self.architecture.unwrap_or(Arch::X64), vs_version) {
+            Ok(env) => builder = builder.env(env),
+            Err(e) => debug!(error = %e, "VS environment unavailable for script"),
         }
-
-        info!("MSBuild build completed successfully");
+        ctx.record_command(&builder.env_overlay(ctx.env()));
         Ok(())
     }
 
-    async fn do_clean(&self, ctx: &ToolContext) -> Result<()> {
+    async fn do_build(&self, ctx: &ToolContext) -> Result<()> {
         if ctx.is_dry_run() {
             info!(
                 solution = ?self.solution,
                 configuration = ?self.configuration,
                 platform = %self.determine_platform(),
-                "[dry-run] Would clean with MSBuild"
+                targets = ?self.targets,
+                max_cpu_count = self.max_cpu_count,
+                binary_log = ?self.binary_log_path(),
+                "[dry-run] Would build with MSBuild"
             );
+            if ctx.is_recording() {
+                self.record_dry_run(ctx)?;
+            }
             return Ok(());
         }
 
-        let solution = self.solution_required()?;
-        let platform = self.determine_platform();
-
         let arch = self.architecture.unwrap_or(Arch::X64);
-        let env = VsHelper::get_env(arch)?;
+        let env = VsHelper::get_env(arch, ctx.config().versions.vs_version.as_deref())?;
 
-        let msbuild = if ctx.config().tools.msbuild.as_os_str().is_empty() {
-            VsHelper::find_msbuild().context("MSBuild executable not found")?
-        } else {
-            ctx.config().tools.msbuild.clone()
-        };
-
-        let mut builder = ProcessBuilder::new(&msbuild).arg("-nologo").arg(solution);
-
-        if self.max_cpu_count {
-            builder = builder
-                .arg("-maxCpuCount")
-                .arg("-property:UseMultiToolTask=true")
-                .arg("-property:EnforceProcessCountAcrossBuilds=true");
-        }
+        let builder = self
+            .msbuild_builder(ctx, self.resolved_path(ctx)?)?
+            .env(env);
 
-        if let Some(config) = self.configuration {
-            builder = builder.arg(format!("-property:Configuration={config}"));
-        }
+        debug!("Building with MSBuild");
 
-        builder = builder.arg(format!("-property:Platform={platform}"));
+        let output = run_with_diagnostics(ctx, builder, "MSBuild build")
+            .await
+            .context("Failed to run MSBuild build")?;
 
-        if !ctx.config().versions.vs_toolset.is_empty() {
-            let toolset = Self::convert_toolset_version(&ctx.config().versions.vs_toolset);
-            builder = builder.arg(format!("-property:PlatformToolset={toolset}"));
+        if output.is_interrupted() {
+            anyhow::bail!("MSBuild build was interrupted");
         }
 
-        if !ctx.config().versions.sdk.is_empty() {
-            builder = builder.arg(format!(
-                "-property:WindowsTargetPlatformVersion={}",
-                ctx.config().versions.sdk
-            ));
-        }
+        info!("MSBuild build completed successfully");
+        Ok(())
+    }
 
-        if self.targets.is_empty() {
-            builder = builder.arg("-target:Clean");
-        } else {
-            for target in &self.targets {
-                builder = builder.arg(format!("-target:{target}:Clean"));
+    async fn do_clean(&self, ctx: &ToolContext) -> Result<()> {
+        if ctx.is_dry_run() {
+            info!(
+                solution = ?self.solution,
+                configuration = ?self.configuration,
+                platform = %self.determine_platform(),
+                binary_log = ?self.binary_log_path(),
+                "[dry-run] Would clean with MSBuild"
+            );
+            if ctx.is_recording() {
+                self.record_dry_run(ctx)?;
             }
+            return Ok(());
         }
 
-        for (key, value) in &self.properties {
-            builder = builder.arg(format!("-property:{key}={value}"));
-        }
+        let arch = self.architecture.unwrap_or(Arch::X64);
+        let env = VsHelper::get_env(arch, ctx.config().versions.vs_version.as_deref())?;
 
-        builder = builder.env(env);
+        let builder = self
+            .msbuild_builder(ctx, self.resolved_path(ctx)?)?
+            .env(env);
 
         debug!("Cleaning with MSBuild");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = run_with_diagnostics(ctx, builder, "MSBuild clean")
             .await
             .context("Failed to run MSBuild clean")?;
 
@@ -341,7 +423,7 @@ impl Tool for MsBuildTool {
         "msbuild"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 MsBuildOperation::Build => self.do_build(ctx).await,
@@ -349,6 +431,23 @@ impl Tool for MsBuildTool {
             }
         })
     }
+
+    /// Uses `tools.msbuild`, or asks `vswhere` for the installation selected by
+    /// `versions.vs_version` when empty.
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        let configured = &ctx.config().tools.msbuild;
+        if configured.as_os_str().is_empty() {
+            let vs_version = ctx.config().versions.vs_version.as_deref();
+            return VsHelper::find_msbuild(vs_version).map_err(|e| {
+                debug!(error = %e, "vswhere did not find MSBuild");
+                ProcessError::ExecutableNotFound {
+                    name: "MSBuild".to_string(),
+                }
+                .into()
+            });
+        }
+        resolve_executable(configured, "msbuild")
+    }
 }
 
 #[cfg(test)]
@@ -8,10 +8,11 @@ use std::sync::Arc;
 use anyhow::Result;
 use tokio_util::sync::CancellationToken;
 
-use super::MsBuildTool;
+use super::{MsBuildTool, MsBuildVerbosity};
 use crate::config::Config;
 use crate::config::types::BuildConfiguration;
 use crate::core::env::types::Arch;
+use crate::logging::LogLevel;
 use crate::task::tools::test_utils::run_with_logs;
 use crate::task::tools::{Tool, ToolContext};
 
@@ -133,3 +134,63 @@ fn test_msbuild_properties_deterministic() {
 
     insta::assert_debug_snapshot!("msbuild_properties_deterministic", tool);
 }
+
+#[test]
+fn test_msbuild_binary_log_path() {
+    let solution = std::path::Path::new("build").join("usvfs.sln");
+    let relative = MsBuildTool::new()
+        .solution(&solution)
+        .binary_log("msbuild.binlog");
+    assert_eq!(
+        relative.binary_log_path(),
+        Some(std::path::Path::new("build").join("msbuild.binlog"))
+    );
+
+    let absolute = std::env::temp_dir().join("ci.binlog");
+    let tool = MsBuildTool::new().solution(&solution).binary_log(&absolute);
+    assert_eq!(tool.binary_log_path(), Some(absolute.clone()));
+
+    let ctx = ToolContext::new(Arc::new(Config::default()), CancellationToken::new(), true);
+    let builder = tool.msbuild_builder(&ctx, "msbuild".into()).unwrap();
+    assert_eq!(
+        builder.args_slice().last().cloned(),
+        Some(format!("-bl:{}", absolute.display()))
+    );
+    assert_eq!(MsBuildTool::new().binary_log_path(), None);
+}
+
+#[test]
+fn test_msbuild_verbosity_arg() {
+    let verbosity_arg = |tool: &MsBuildTool, level: LogLevel| {
+        let mut config = Config::default();
+        config.global.output_log_level = level;
+        let ctx = ToolContext::new(Arc::new(config), CancellationToken::new(), true);
+        let builder = tool.msbuild_builder(&ctx, "msbuild".into()).unwrap();
+        builder
+            .args_slice()
+            .iter()
+            .find(|arg| arg.starts_with("-verbosity:"))
+            .cloned()
+    };
+
+    let build = MsBuildTool::new().solution("test.sln");
+    let clean = MsBuildTool::new().solution("test.sln").clean_op();
+    assert_eq!(
+        verbosity_arg(&build, LogLevel::INFO).as_deref(),
+        Some("-verbosity:minimal")
+    );
+    assert_eq!(
+        verbosity_arg(&clean, LogLevel::DEBUG).as_deref(),
+        Some("-verbosity:detailed")
+    );
+    assert_eq!(
+        verbosity_arg(&build, LogLevel::TRACE).as_deref(),
+        Some("-verbosity:diagnostic")
+    );
+
+    let quiet = clean.verbosity(MsBuildVerbosity::Quiet);
+    assert_eq!(
+        verbosity_arg(&quiet, LogLevel::DEBUG).as_deref(),
+        Some("-verbosity:quiet")
+    );
+}
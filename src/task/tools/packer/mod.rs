@@ -3,28 +3,41 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-//! Archive packing tool supporting 7z format.
+//! Archive packing tool supporting 7z and zip formats.
 //!
 //! ```text
 //! PackerTool
 //! Operations: PackDir | PackFiles
+//! Formats: SevenZip (default) | Zip
 //! 7z: 7z a -t7z -mx9 -bd -bb0 <output> <source> [-xr!pattern]...
-//! Builder: archive/base_dir/exclude_patterns/files + pack_dir/pack_files
-//! Uses: config.tools.sevenz
+//! zip: written in-process (deflate), no external tool
+//! Builder: archive/base_dir/exclude_patterns/files/format + pack_dir/pack_files
+//! Uses: config.tools.sevenz (7z only)
 //! ```
 //!
-//! Provides capabilities for creating 7z archives from directories or explicit file lists.
+//! Provides capabilities for creating archives from directories or explicit file lists.
 //! Supports exclusion patterns for directory-based packing and file list-based packing.
+//! Zip packing matches exclusion patterns the way `7z -xr!` does: against every
+//! file and directory name, with `*` and `?` wildcards, ignoring case. A
+//! pattern starting with `\` or `/` is anchored to the archive root and only
+//! matches top-level names.
 
-use std::path::{Path, PathBuf};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Component, Path, PathBuf};
 
 use crate::error::Result;
 use anyhow::Context;
+use clap::ValueEnum;
 use tempfile::NamedTempFile;
 use tokio::io::AsyncWriteExt;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info};
+use wax::{Glob, Program as _};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
 
-use super::{BoxFuture, Tool, ToolContext};
+use super::{BoxFuture, Tool, ToolContext, resolve_executable};
 use crate::core::process::builder::ProcessBuilder;
 
 /// Packer operation to perform.
@@ -37,9 +50,31 @@ pub enum PackOperation {
     PackFiles,
 }
 
-/// Packer tool for creating archives using 7z.
+/// Archive format produced by [`PackerTool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ArchiveFormat {
+    /// 7z archive created by the external `7z` executable.
+    #[default]
+    #[value(name = "7z")]
+    SevenZip,
+    /// Zip archive written in-process; needs no external tool.
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Returns the file extension for archives of this format, without the dot.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::SevenZip => "7z",
+            Self::Zip => "zip",
+        }
+    }
+}
+
+/// Packer tool for creating 7z or zip archives.
 ///
-/// Supports creating 7z archives from either:
+/// Supports creating archives from either:
 /// - A directory with optional exclusion patterns
 /// - An explicit list of files
 ///
@@ -61,6 +96,7 @@ pub struct PackerTool {
     exclude_patterns: Vec<String>,
     files: Vec<PathBuf>,
     operation: PackOperation,
+    format: ArchiveFormat,
 }
 
 impl PackerTool {
@@ -73,6 +109,7 @@ impl PackerTool {
             exclude_patterns: Vec::new(),
             files: Vec::new(),
             operation: PackOperation::PackDir,
+            format: ArchiveFormat::SevenZip,
         }
     }
 
@@ -106,6 +143,13 @@ impl PackerTool {
         self
     }
 
+    /// Sets the archive format. Defaults to [`ArchiveFormat::SevenZip`].
+    #[must_use]
+    pub const fn format(mut self, format: ArchiveFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     #[must_use]
     pub const fn pack_dir_op(mut self) -> Self {
         self.operation = PackOperation::PackDir;
@@ -139,8 +183,19 @@ impl PackerTool {
                 archive = %archive.display(),
                 base_dir = %base_dir.display(),
                 exclude_patterns = ?self.exclude_patterns,
+                format = ?self.format,
                 "[dry-run] Would create archive from directory"
             );
+            if self.format == ArchiveFormat::Zip {
+                return Ok(());
+            }
+            let builder = glob_builder(
+                self.script_program(ctx, "7z"),
+                base_dir,
+                archive,
+                &self.exclude_patterns,
+            );
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
@@ -151,7 +206,14 @@ impl PackerTool {
             "Creating archive from directory"
         );
 
-        archive_from_glob(ctx, base_dir, archive, &self.exclude_patterns).await?;
+        match self.format {
+            ArchiveFormat::SevenZip => {
+                archive_from_glob(ctx, base_dir, archive, &self.exclude_patterns).await?;
+            }
+            ArchiveFormat::Zip => {
+                zip_from_dir(ctx, base_dir, archive, &self.exclude_patterns).await?;
+            }
+        }
 
         info!(
             archive = %archive.display(),
@@ -170,8 +232,17 @@ impl PackerTool {
                 archive = %archive.display(),
                 base_dir = %base_dir.display(),
                 file_count = self.files.len(),
+                format = ?self.format,
                 "[dry-run] Would create archive from file list"
             );
+            if self.format == ArchiveFormat::Zip {
+                return Ok(());
+            }
+            // The script lists the files inline instead of using a list file
+            let builder = add_builder(self.script_program(ctx, "7z"), archive)
+                .args(&self.files)
+                .args(exclude_args(&self.exclude_patterns));
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
@@ -182,7 +253,15 @@ impl PackerTool {
             "Creating archive from file list"
         );
 
-        archive_from_files(ctx, &self.files, base_dir, archive).await?;
+        match self.format {
+            ArchiveFormat::SevenZip => {
+                archive_from_files(ctx, &self.files, base_dir, archive, &self.exclude_patterns)
+                    .await?;
+            }
+            ArchiveFormat::Zip => {
+                zip_from_files(ctx, &self.files, base_dir, archive, &self.exclude_patterns).await?;
+            }
+        }
 
         info!(
             archive = %archive.display(),
@@ -204,7 +283,7 @@ impl Tool for PackerTool {
         "packer"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 PackOperation::PackDir => self.pack_dir(ctx).await,
@@ -212,6 +291,10 @@ impl Tool for PackerTool {
             }
         })
     }
+
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        resolve_executable(&ctx.config().tools.sevenz, "7z")
+    }
 }
 
 /// Creates a 7z archive from a directory with glob exclusion patterns.
@@ -234,25 +317,15 @@ pub async fn archive_from_glob(
     output: &Path,
     excludes: &[String],
 ) -> Result<()> {
-    let mut builder = ProcessBuilder::new(&ctx.config().tools.sevenz);
-
-    builder = builder
-        .arg("a")
-        .arg("-t7z")
-        .arg("-mx9")
-        .arg("-bd")
-        .arg("-bb0")
-        .arg(output);
-
-    let glob_pattern = format!("{}/*", base_dir.display());
-    builder = builder.arg(&glob_pattern);
-
-    for pattern in excludes {
-        builder = builder.arg(format!("-xr!{pattern}"));
-    }
-
-    let output_result = builder
-        .run_with_cancellation(ctx.cancel_token().clone())
+    let builder = glob_builder(
+        resolve_executable(&ctx.config().tools.sevenz, "7z")?,
+        base_dir,
+        output,
+        excludes,
+    );
+
+    let output_result = ctx
+        .run_process(builder)
         .await
         .context("Failed to run 7z archive creation")?;
 
@@ -270,6 +343,34 @@ pub async fn archive_from_glob(
     Ok(())
 }
 
+/// Builds `7z a` writing `output` with maximum compression.
+fn add_builder(sevenz: PathBuf, output: &Path) -> ProcessBuilder {
+    ProcessBuilder::new(sevenz)
+        .arg("a")
+        .arg("-t7z")
+        .arg("-mx9")
+        .arg("-bd")
+        .arg("-bb0")
+        .arg(output)
+}
+
+/// Builds `7z a` archiving `base_dir/*` into `output`, skipping `excludes`.
+fn glob_builder(
+    sevenz: PathBuf,
+    base_dir: &Path,
+    output: &Path,
+    excludes: &[String],
+) -> ProcessBuilder {
+    add_builder(sevenz, output)
+        .arg(format!("{}/*", base_dir.display()))
+        .args(exclude_args(excludes))
+}
+
+/// Maps exclusion patterns to recursive `7z -xr!` switches.
+fn exclude_args(excludes: &[String]) -> impl Iterator<Item = String> + '_ {
+    excludes.iter().map(|pattern| format!("-xr!{pattern}"))
+}
+
 /// Creates a 7z archive from an explicit list of files.
 ///
 /// # Arguments
@@ -277,9 +378,10 @@ pub async fn archive_from_glob(
 /// * `files` - List of files to archive
 /// * `base_dir` - Base directory for relative path resolution
 /// * `output` - Output archive path
+/// * `excludes` - List of glob patterns to exclude
 ///
 /// # 7z Command Format
-/// `7z a -t7z -mx9 -bd -bb0 <output> @<listfile>`
+/// `7z a -t7z -mx9 -bd -bb0 <output> @<listfile> -xr!<pattern>...`
 ///
 /// The listfile contains one file path per line.
 ///
@@ -293,6 +395,7 @@ pub async fn archive_from_files(
     files: &[PathBuf],
     base_dir: &Path,
     output: &Path,
+    excludes: &[String],
 ) -> Result<()> {
     // Use NamedTempFile for RAII cleanup - automatically deleted on drop
     let list_file = NamedTempFile::new_in(base_dir)
@@ -327,19 +430,15 @@ pub async fn archive_from_files(
     // Drop async_file to release the handle before 7z reads it
     drop(async_file);
 
-    let mut builder = ProcessBuilder::new(&ctx.config().tools.sevenz);
-
-    builder = builder
-        .arg("a")
-        .arg("-t7z")
-        .arg("-mx9")
-        .arg("-bd")
-        .arg("-bb0")
-        .arg(output)
-        .arg(format!("@{}", list_file.path().display()));
+    let builder = add_builder(
+        resolve_executable(&ctx.config().tools.sevenz, "7z")?,
+        output,
+    )
+    .arg(format!("@{}", list_file.path().display()))
+    .args(exclude_args(excludes));
 
-    let output_result = builder
-        .run_with_cancellation(ctx.cancel_token().clone())
+    let output_result = ctx
+        .run_process(builder)
         .await
         .context("Failed to run 7z archive creation")?;
 
@@ -359,5 +458,221 @@ pub async fn archive_from_files(
     Ok(())
 }
 
+/// Creates a zip archive of everything below `base_dir`.
+///
+/// Entry names are relative to `base_dir`, like `7z a <output> <base_dir>/*`.
+/// Files and directories whose name matches one of `excludes` are skipped
+/// together with their contents.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read, the archive cannot be
+/// written, or the operation is cancelled.
+pub async fn zip_from_dir(
+    ctx: &ToolContext,
+    base_dir: &Path,
+    output: &Path,
+    excludes: &[String],
+) -> Result<()> {
+    let base_dir = base_dir.to_path_buf();
+    let output = output.to_path_buf();
+    let excludes = Excludes::new(excludes)?;
+    let cancel = ctx.cancel_token().clone();
+
+    tokio::task::spawn_blocking(move || {
+        let mut entries = Vec::new();
+        collect_dir_entries(&base_dir, &base_dir, &excludes, &mut entries)?;
+        write_zip(&output, &entries, &cancel)
+    })
+    .await
+    .context("zip archive task panicked")?
+}
+
+/// Creates a zip archive from an explicit list of files.
+///
+/// Relative paths in `files` are resolved against `base_dir` and stored under
+/// that relative name; absolute paths must be below `base_dir`. Files with any
+/// path component matching one of `excludes` are skipped.
+///
+/// # Errors
+///
+/// Returns an error if a file is outside `base_dir`, a file cannot be read,
+/// the archive cannot be written, or the operation is cancelled.
+pub async fn zip_from_files(
+    ctx: &ToolContext,
+    files: &[PathBuf],
+    base_dir: &Path,
+    output: &Path,
+    excludes: &[String],
+) -> Result<()> {
+    let excludes = Excludes::new(excludes)?;
+    let mut entries = Vec::with_capacity(files.len());
+    for file in files {
+        let path = base_dir.join(file);
+        let relative = path
+            .strip_prefix(base_dir)
+            .ok()
+            .filter(|relative| {
+                relative
+                    .components()
+                    .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+            })
+            .with_context(|| {
+                format!(
+                    "{} is outside the archive root {}",
+                    file.display(),
+                    base_dir.display()
+                )
+            })?;
+        if !excludes.matches(relative) {
+            let name = entry_name(relative);
+            entries.push(ZipEntry::File { name, path });
+        }
+    }
+    let output = output.to_path_buf();
+    let cancel = ctx.cancel_token().clone();
+
+    tokio::task::spawn_blocking(move || write_zip(&output, &entries, &cancel))
+        .await
+        .context("zip archive task panicked")?
+}
+
+/// A single entry to be written to a zip archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ZipEntry {
+    Directory { name: String },
+    File { name: String, path: PathBuf },
+}
+
+/// Walks `dir` in sorted order, appending every entry not matched by `excludes`.
+fn collect_dir_entries(
+    root: &Path,
+    dir: &Path,
+    excludes: &Excludes,
+    entries: &mut Vec<ZipEntry>,
+) -> Result<()> {
+    let mut children = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read directory {}", dir.display()))?
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read directory {}", dir.display()))?;
+    children.sort_by_key(std::fs::DirEntry::file_name);
+
+    for child in children {
+        let path = child.path();
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        if excludes.matches(relative) {
+            continue;
+        }
+
+        let name = entry_name(relative);
+        let file_type = child
+            .file_type()
+            .with_context(|| format!("failed to stat {}", path.display()))?;
+
+        if file_type.is_dir() {
+            entries.push(ZipEntry::Directory { name });
+            collect_dir_entries(root, &path, excludes, entries)?;
+        } else {
+            entries.push(ZipEntry::File { name, path });
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `entries` to a deflate-compressed zip archive at `output`.
+fn write_zip(output: &Path, entries: &[ZipEntry], cancel: &CancellationToken) -> Result<()> {
+    let file = File::create(output)
+        .with_context(|| format!("failed to create archive {}", output.display()))?;
+    let mut writer = ZipWriter::new(BufWriter::new(file));
+    let options = SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .large_file(true);
+
+    for entry in entries {
+        if cancel.is_cancelled() {
+            anyhow::bail!("Archive creation was interrupted");
+        }
+
+        match entry {
+            ZipEntry::Directory { name } => {
+                writer
+                    .add_directory(name.as_str(), options)
+                    .with_context(|| format!("failed to add {name} to archive"))?;
+            }
+            ZipEntry::File { name, path } => {
+                let mut source = File::open(path)
+                    .with_context(|| format!("failed to open {}", path.display()))?;
+                writer
+                    .start_file(name.as_str(), options)
+                    .with_context(|| format!("failed to add {name} to archive"))?;
+                std::io::copy(&mut source, &mut writer)
+                    .with_context(|| format!("failed to write {name} to archive"))?;
+            }
+        }
+    }
+
+    writer
+        .finish()
+        .with_context(|| format!("failed to finish archive {}", output.display()))?;
+    Ok(())
+}
+
+/// Converts a relative path to a zip entry name with `/` separators.
+fn entry_name(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `7z -xr!` exclusion patterns, compiled to case-insensitive globs.
+struct Excludes {
+    /// Patterns matching a name at any depth.
+    anywhere: Vec<Glob<'static>>,
+    /// Patterns starting with `\` or `/`, matching top-level names only.
+    root: Vec<Glob<'static>>,
+}
+
+impl Excludes {
+    /// Compiles `patterns`.
+    fn new(patterns: &[String]) -> Result<Self> {
+        let mut excludes = Self {
+            anywhere: Vec::new(),
+            root: Vec::new(),
+        };
+        for pattern in patterns {
+            let (unanchored, anchored) = pattern
+                .strip_prefix(['\\', '/'])
+                .map_or((pattern.as_str(), false), |rest| (rest, true));
+            let glob = Glob::new(&format!("(?i){unanchored}"))
+                .with_context(|| format!("Invalid exclusion pattern: {pattern}"))?
+                .into_owned();
+            if anchored {
+                excludes.root.push(glob);
+            } else {
+                excludes.anywhere.push(glob);
+            }
+        }
+        Ok(excludes)
+    }
+
+    /// Returns whether any component of `relative`, a path below the archive
+    /// root, is excluded.
+    fn matches(&self, relative: &Path) -> bool {
+        relative
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .enumerate()
+            .any(|(depth, c)| {
+                let name = c.as_os_str().to_string_lossy();
+                let matches =
+                    |globs: &[Glob<'static>]| globs.iter().any(|glob| glob.is_match(name.as_ref()));
+                matches(&self.anywhere) || (depth == 0 && matches(&self.root))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -3,12 +3,20 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{PackOperation, PackerTool};
+use super::{ArchiveFormat, Excludes, PackOperation, PackerTool};
 use crate::task::tools::{Tool, ToolContext};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
+fn zip_names(archive: &Path) -> Vec<String> {
+    let file = std::fs::File::open(archive).unwrap();
+    let zip = zip::ZipArchive::new(file).unwrap();
+    let mut names = zip.file_names().map(str::to_string).collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
 #[test]
 fn test_packer_tool_creation() {
     let tool = PackerTool::new();
@@ -110,3 +118,132 @@ async fn test_packer_tool_dry_run_pack_files() {
     // Should succeed in dry-run mode without actually creating archive
     assert!(tool.run(&ctx).await.is_ok());
 }
+
+#[test]
+fn test_archive_format_default_and_extension() {
+    assert_eq!(PackerTool::new().format, ArchiveFormat::SevenZip);
+    assert_eq!(ArchiveFormat::SevenZip.extension(), "7z");
+    assert_eq!(ArchiveFormat::Zip.extension(), "zip");
+}
+
+#[test]
+fn test_excludes_match_like_7z() {
+    let excludes = Excludes::new(&[
+        ".*".to_string(),
+        "*.log".to_string(),
+        "bin".to_string(),
+        r"\vs?uild".to_string(),
+    ])
+    .unwrap();
+    assert!(excludes.matches(Path::new("build.LOG")));
+    assert!(excludes.matches(Path::new("src/bin/a.txt")));
+    assert!(excludes.matches(Path::new(".git")));
+    assert!(excludes.matches(Path::new("uibase/.vs/config")));
+    assert!(excludes.matches(Path::new("vsbuild")));
+    assert!(!excludes.matches(Path::new("binary")));
+    assert!(!excludes.matches(Path::new("src/main.cpp")));
+
+    // Anchored patterns only match top-level names
+    assert!(!excludes.matches(Path::new("uibase/vsbuild")));
+}
+
+#[test]
+fn test_excludes_reject_invalid_pattern() {
+    assert!(Excludes::new(&["[".to_string()]).is_err());
+}
+
+#[tokio::test]
+async fn test_packer_tool_zip_pack_dir() {
+    let temp = tempfile::tempdir().unwrap();
+    let source = temp.path().join("source");
+    std::fs::create_dir_all(source.join("src/__pycache__")).unwrap();
+    std::fs::write(source.join("README.md"), "readme").unwrap();
+    std::fs::write(source.join("src/main.cpp"), "int main() {}").unwrap();
+    std::fs::write(source.join("src/build.log"), "log").unwrap();
+    std::fs::write(source.join("src/__pycache__/x.pyc"), "pyc").unwrap();
+    let archive = temp.path().join("out.zip");
+
+    let config = Arc::new(crate::config::Config::default());
+    let ctx = ToolContext::new(config, CancellationToken::new(), false);
+    PackerTool::new()
+        .archive(&archive)
+        .base_dir(&source)
+        .exclude_patterns(["*.log", "__pycache__"])
+        .format(ArchiveFormat::Zip)
+        .pack_dir_op()
+        .run(&ctx)
+        .await
+        .unwrap();
+
+    assert_eq!(zip_names(&archive), ["README.md", "src/", "src/main.cpp"]);
+}
+
+#[tokio::test]
+async fn test_packer_tool_zip_pack_files() {
+    let temp = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp.path().join("bin")).unwrap();
+    std::fs::write(temp.path().join("bin/a.dll"), "a").unwrap();
+    std::fs::write(temp.path().join("bin/a.pdb"), "pdb").unwrap();
+    let archive = temp.path().join("out.zip");
+
+    let config = Arc::new(crate::config::Config::default());
+    let ctx = ToolContext::new(config, CancellationToken::new(), false);
+    PackerTool::new()
+        .archive(&archive)
+        .base_dir(temp.path())
+        .files(["bin/a.dll", "bin/a.pdb"])
+        .exclude_patterns(["*.pdb"])
+        .format(ArchiveFormat::Zip)
+        .pack_files_op()
+        .run(&ctx)
+        .await
+        .unwrap();
+
+    assert_eq!(zip_names(&archive), ["bin/a.dll"]);
+}
+
+#[tokio::test]
+async fn test_packer_tool_zip_pack_files_outside_root() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path().join("root");
+    std::fs::create_dir_all(&root).unwrap();
+    std::fs::write(temp.path().join("outside.dll"), "a").unwrap();
+    let archive = temp.path().join("out.zip");
+
+    let config = Arc::new(crate::config::Config::default());
+    let ctx = ToolContext::new(config, CancellationToken::new(), false);
+    for file in [
+        temp.path().join("outside.dll"),
+        PathBuf::from("../outside.dll"),
+    ] {
+        let result = PackerTool::new()
+            .archive(&archive)
+            .base_dir(&root)
+            .files([file])
+            .format(ArchiveFormat::Zip)
+            .pack_files_op()
+            .run(&ctx)
+            .await;
+        assert!(result.is_err());
+    }
+    assert!(!archive.exists());
+}
+
+#[tokio::test]
+async fn test_packer_tool_zip_dry_run_writes_nothing() {
+    let temp = tempfile::tempdir().unwrap();
+    let archive = temp.path().join("out.zip");
+
+    let config = Arc::new(crate::config::Config::default());
+    let ctx = ToolContext::new(config, CancellationToken::new(), true);
+    PackerTool::new()
+        .archive(&archive)
+        .base_dir(temp.path())
+        .format(ArchiveFormat::Zip)
+        .pack_dir_op()
+        .run(&ctx)
+        .await
+        .unwrap();
+
+    assert!(!archive.exists());
+}
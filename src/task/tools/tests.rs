@@ -3,8 +3,9 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::ToolContext;
+use super::{ToolContext, resolve_executable};
 use crate::config::Config;
+use std::path::Path;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
@@ -37,3 +38,99 @@ fn test_tool_context_dry_run() {
 
     assert!(ctx.is_dry_run());
 }
+
+#[test]
+fn test_resolve_executable_empty_uses_program() {
+    let path = resolve_executable(Path::new(""), "git").unwrap();
+    assert!(path.is_file());
+}
+
+#[test]
+fn test_resolve_executable_strips_extension() {
+    let path = resolve_executable(Path::new("git.exe"), "git").unwrap();
+    assert!(path.is_file());
+}
+
+#[test]
+fn test_resolve_executable_missing_absolute() {
+    let temp = tempfile::tempdir().unwrap();
+    let missing = temp.path().join("missing-tool");
+
+    let err = resolve_executable(&missing, "missing-tool").unwrap_err();
+    assert!(err.to_string().contains("missing-tool"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_tool_context_priority_is_default_of_processes() {
+    use crate::core::process::builder::ProcessBuilder;
+    use crate::core::process::priority::ProcessPriority;
+
+    let ctx = ToolContext::new(Arc::new(Config::default()), CancellationToken::new(), false)
+        .with_priority(ProcessPriority::BelowNormal);
+    let nice = |builder: ProcessBuilder| {
+        let ctx = ctx.clone();
+        async move {
+            let output = ctx.run_process(builder.capture_stdout()).await.unwrap();
+            output.stdout().trim().to_string()
+        }
+    };
+
+    assert_eq!(nice(ProcessBuilder::raw("nice")).await, "10");
+    // A priority set on the process itself wins
+    assert_eq!(
+        nice(ProcessBuilder::raw("nice").priority(ProcessPriority::Idle)).await,
+        "19"
+    );
+}
+
+#[tokio::test]
+async fn test_tool_span_nests_in_task_span() {
+    use super::Tool;
+    use super::packer::PackerTool;
+    use crate::task::{Phase, TaskContext};
+    use std::sync::Mutex;
+    use tracing::Instrument;
+
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let writer = Arc::clone(&output);
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_span_list(true)
+        .with_writer(move || SharedWriter(Arc::clone(&writer)))
+        .finish();
+    let _default = tracing::subscriber::set_default(subscriber);
+
+    let ctx = ToolContext::new(Arc::new(Config::default()), CancellationToken::new(), true);
+    let tool = PackerTool::new().archive("out.7z").base_dir("source");
+    // Tools run from inside a phase, so their span opens in the task span
+    async { tool.run(&ctx).await }
+        .instrument(TaskContext::task_span("demo", Phase::Fetch))
+        .await
+        .unwrap();
+
+    let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+    let line = output.lines().next().expect("dry run should log");
+    let event: serde_json::Value = serde_json::from_str(line).unwrap();
+    assert_eq!(
+        event["spans"],
+        serde_json::json!([
+            {"name": "task", "task": "demo", "phase": "fetch"},
+            {"name": "tool", "tool": "packer"},
+        ])
+    );
+}
+
+/// Collects formatted log output for [`test_tool_span_nests_in_task_span`].
+struct SharedWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
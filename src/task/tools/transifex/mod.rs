@@ -9,7 +9,13 @@
 //! TransifexTool
 //! Operations: Init → Config → Pull
 //! root/.tx/config created by tx init + tx add remote
-//! Builder: root/api_key/url/minimum/force
+//! Builder: root/api_key/url/minimum/force/pull_mode/use_git_timestamps
+//!
+//! Incremental pull:
+//!   tx pull skips files whose remote copy is not newer than the local one
+//!   root/.tx/config vs root/.tx/config.snapshot (copy from the last pull)
+//!     same      --> tx pull
+//!     changed   --> tx pull --force --> store new snapshot
 //! ```
 //!
 //! This module provides the `TransifexTool` struct for executing Transifex CLI operations
@@ -31,8 +37,13 @@ use anyhow::Context;
 use tracing::{debug, info};
 
 use super::{BoxFuture, Tool, ToolContext};
+use crate::config::types::TransifexPullMode;
 use crate::core::env::current_env;
 use crate::core::process::builder::ProcessBuilder;
+use crate::error::ProcessError;
+
+/// File under `.tx` holding a copy of `.tx/config` from the last incremental pull.
+const CONFIG_SNAPSHOT: &str = "config.snapshot";
 
 /// Operation to perform with the Transifex CLI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -78,6 +89,8 @@ pub struct TransifexTool {
     url: Option<String>,
     minimum: u8,
     force: bool,
+    pull_mode: TransifexPullMode,
+    use_git_timestamps: bool,
     operation: TransifexOperation,
     tx_binary: Option<PathBuf>,
 }
@@ -98,6 +111,8 @@ impl TransifexTool {
             url: None,
             minimum: 100,
             force: false,
+            pull_mode: TransifexPullMode::Full,
+            use_git_timestamps: false,
             operation: TransifexOperation::Init,
             tx_binary: None,
         }
@@ -133,6 +148,24 @@ impl TransifexTool {
         self
     }
 
+    /// Sets the pull strategy.
+    ///
+    /// `Incremental` leaves skipping unchanged files to `tx pull`, which
+    /// compares remote and local timestamps, and forces the pull once after
+    /// `.tx/config` changed. It is ignored when `force` is set.
+    #[must_use]
+    pub const fn pull_mode(mut self, mode: TransifexPullMode) -> Self {
+        self.pull_mode = mode;
+        self
+    }
+
+    /// Only downloads files newer than the last git commit of the local files.
+    #[must_use]
+    pub const fn use_git_timestamps(mut self, use_git_timestamps: bool) -> Self {
+        self.use_git_timestamps = use_git_timestamps;
+        self
+    }
+
     #[must_use]
     pub fn tx_binary(mut self, path: impl AsRef<Path>) -> Self {
         self.tx_binary = Some(path.as_ref().to_path_buf());
@@ -157,21 +190,6 @@ impl TransifexTool {
         self
     }
 
-    fn get_tx_binary(&self, ctx: &ToolContext) -> Result<PathBuf> {
-        if let Some(ref binary) = self.tx_binary {
-            return Ok(binary.clone());
-        }
-
-        let config_path = &ctx.config().tools.tx;
-        if config_path.is_absolute() && config_path.exists() {
-            return Ok(config_path.clone());
-        }
-
-        ProcessBuilder::find("tx")
-            .or_else(|| ProcessBuilder::find("tx.exe"))
-            .context("tx executable not found in PATH or config")
-    }
-
     async fn do_init(&self, ctx: &ToolContext) -> Result<()> {
         let root = self
             .root
@@ -183,6 +201,8 @@ impl TransifexTool {
                 path = %root.display(),
                 "[dry-run] Would initialize transifex directory"
             );
+            let builder = Self::init_builder(self.script_program(ctx, "tx"), root);
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
@@ -192,16 +212,13 @@ impl TransifexTool {
                 .with_context(|| format!("Failed to create directory: {}", root.display()))?;
         }
 
-        let tx_binary = self.get_tx_binary(ctx)?;
+        let tx_binary = self.resolved_path(ctx)?;
 
         // tx init - exit code 2 means directory already initialized
         debug!(path = %root.display(), "Initializing transifex directory");
 
-        let output = ProcessBuilder::new(&tx_binary)
-            .arg("init")
-            .cwd(root)
-            .success_codes([0, 2])
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(Self::init_builder(tx_binary, root))
             .await
             .context("Failed to run tx init")?;
 
@@ -230,6 +247,9 @@ impl TransifexTool {
                 url = %url,
                 "[dry-run] Would configure transifex remote"
             );
+            // Recorded without `TX_TOKEN` so the script never contains the key
+            let builder = Self::config_builder(self.script_program(ctx, "tx"), root, url);
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
@@ -239,24 +259,14 @@ impl TransifexTool {
                 .with_context(|| format!("Failed to create directory: {}", root.display()))?;
         }
 
-        let tx_binary = self.get_tx_binary(ctx)?;
-
-        let mut builder = ProcessBuilder::new(&tx_binary)
-            .arg("add")
-            .arg("remote")
-            .arg(url)
-            .cwd(root);
+        let tx_binary = self.resolved_path(ctx)?;
 
-        if let Some(ref key) = self.api_key {
-            let mut env = current_env();
-            env.set("TX_TOKEN", key);
-            builder = builder.env(env);
-        }
+        let builder = self.with_token(Self::config_builder(tx_binary, root, url));
 
         debug!(path = %root.display(), url = %url, "Configuring transifex remote");
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(builder)
             .await
             .context("Failed to run tx add remote")?;
 
@@ -269,6 +279,54 @@ impl TransifexTool {
         Ok(())
     }
 
+    /// Builds `tx init`; exit code 2 means already initialized.
+    fn init_builder(tx_binary: PathBuf, root: &Path) -> ProcessBuilder {
+        ProcessBuilder::new(tx_binary)
+            .arg("init")
+            .cwd(root)
+            .success_codes([0, 2])
+    }
+
+    /// Builds `tx add remote <url>`.
+    fn config_builder(tx_binary: PathBuf, root: &Path, url: &str) -> ProcessBuilder {
+        ProcessBuilder::new(tx_binary)
+            .arg("add")
+            .arg("remote")
+            .arg(url)
+            .cwd(root)
+    }
+
+    /// Builds `tx pull` with the configured minimum and flags.
+    fn pull_builder(&self, tx_binary: PathBuf, root: &Path, force: bool) -> ProcessBuilder {
+        let mut builder = ProcessBuilder::new(tx_binary)
+            .arg("pull")
+            .arg("--all")
+            .arg("--minimum-perc")
+            .arg(self.minimum.to_string())
+            .cwd(root);
+
+        if force {
+            builder = builder.arg("--force");
+        }
+
+        if self.use_git_timestamps {
+            builder = builder.arg("--use-git-timestamps");
+        }
+
+        builder
+    }
+
+    /// Sets `TX_TOKEN` on the builder when an API key is configured.
+    fn with_token(&self, builder: ProcessBuilder) -> ProcessBuilder {
+        if let Some(ref key) = self.api_key {
+            let mut env = current_env();
+            env.set("TX_TOKEN", key);
+            builder.env(env)
+        } else {
+            builder
+        }
+    }
+
     async fn do_pull(&self, ctx: &ToolContext) -> Result<()> {
         let root = self
             .root
@@ -280,8 +338,12 @@ impl TransifexTool {
                 path = %root.display(),
                 minimum = self.minimum,
                 force = self.force,
+                pull_mode = %self.pull_mode,
                 "[dry-run] Would pull translations"
             );
+            // Recorded without `TX_TOKEN` so the script never contains the key
+            let builder = self.pull_builder(self.script_program(ctx, "tx"), root, self.force);
+            ctx.record_command(&builder.env_overlay(ctx.env()));
             return Ok(());
         }
 
@@ -291,24 +353,31 @@ impl TransifexTool {
                 .with_context(|| format!("Failed to create directory: {}", root.display()))?;
         }
 
-        let tx_binary = self.get_tx_binary(ctx)?;
-
-        let mut builder = ProcessBuilder::new(&tx_binary)
-            .arg("pull")
-            .arg("--all")
-            .arg("--minimum-perc")
-            .arg(self.minimum.to_string())
-            .cwd(root);
+        let tx_binary = self.resolved_path(ctx)?;
 
-        if self.force {
-            builder = builder.arg("--force");
+        // Local timestamps say nothing about resources added or remapped in
+        // .tx/config, so a changed config pulls everything once
+        let snapshot_path = root.join(".tx").join(CONFIG_SNAPSHOT);
+        let tx_config = match self.pull_mode {
+            TransifexPullMode::Incremental if !self.force => {
+                tokio::fs::read(root.join(".tx").join("config")).await.ok()
+            }
+            TransifexPullMode::Incremental | TransifexPullMode::Full => None,
+        };
+        let config_changed = match &tx_config {
+            Some(tx_config) => {
+                tokio::fs::read(&snapshot_path).await.ok().as_ref() != Some(tx_config)
+            }
+            None => false,
+        };
+        if config_changed {
+            info!(
+                path = %root.display(),
+                ".tx/config changed since last pull, pulling all translations"
+            );
         }
 
-        if let Some(ref key) = self.api_key {
-            let mut env = current_env();
-            env.set("TX_TOKEN", key);
-            builder = builder.env(env);
-        }
+        let builder = self.pull_builder(tx_binary, root, self.force || config_changed);
 
         debug!(
             path = %root.display(),
@@ -317,8 +386,8 @@ impl TransifexTool {
             "Pulling translations"
         );
 
-        let output = builder
-            .run_with_cancellation(ctx.cancel_token().clone())
+        let output = ctx
+            .run_process(self.with_token(builder))
             .await
             .context("Failed to run tx pull")?;
 
@@ -326,6 +395,17 @@ impl TransifexTool {
             anyhow::bail!("tx pull was interrupted");
         }
 
+        if config_changed && let Some(tx_config) = tx_config {
+            tokio::fs::write(&snapshot_path, tx_config)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to write config snapshot: {}",
+                        snapshot_path.display()
+                    )
+                })?;
+        }
+
         info!(path = %root.display(), "Translations pulled successfully");
 
         Ok(())
@@ -337,7 +417,7 @@ impl Tool for TransifexTool {
         "transifex"
     }
 
-    fn run<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
+    fn execute<'a>(&'a self, ctx: &'a ToolContext) -> BoxFuture<'a, Result<()>> {
         Box::pin(async move {
             match self.operation {
                 TransifexOperation::Init => self.do_init(ctx).await,
@@ -346,6 +426,26 @@ impl Tool for TransifexTool {
             }
         })
     }
+
+    fn resolved_path(&self, ctx: &ToolContext) -> Result<PathBuf> {
+        if let Some(ref binary) = self.tx_binary {
+            return Ok(binary.clone());
+        }
+
+        let config_path = &ctx.config().tools.tx;
+        if config_path.is_absolute() && config_path.exists() {
+            return Ok(config_path.clone());
+        }
+
+        ProcessBuilder::find("tx")
+            .or_else(|| ProcessBuilder::find("tx.exe"))
+            .ok_or_else(|| {
+                ProcessError::ExecutableNotFound {
+                    name: "tx".to_string(),
+                }
+                .into()
+            })
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::TransifexTool;
+use crate::config::types::TransifexPullMode;
 use crate::task::tools::Tool;
 
 #[test]
@@ -25,6 +26,19 @@ fn test_transifex_tool_minimum_clamped() {
     assert_eq!(tool.minimum, 100);
 }
 
+#[test]
+fn test_transifex_tool_incremental_options() {
+    let tool = TransifexTool::new();
+    assert_eq!(tool.pull_mode, TransifexPullMode::Full);
+    assert!(!tool.use_git_timestamps);
+
+    let tool = tool
+        .pull_mode(TransifexPullMode::Incremental)
+        .use_git_timestamps(true);
+    assert_eq!(tool.pull_mode, TransifexPullMode::Incremental);
+    assert!(tool.use_git_timestamps);
+}
+
 #[test]
 fn test_transifex_tool_operations() {
     let operations = [
@@ -40,3 +54,47 @@ fn test_transifex_tool_name() {
     let tool = TransifexTool::new();
     assert_eq!(tool.name(), "transifex");
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_transifex_incremental_pull_forces_after_config_change() {
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::Arc;
+
+    use tokio_util::sync::CancellationToken;
+
+    use crate::config::Config;
+    use crate::task::tools::ToolContext;
+
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path().join("tx");
+    std::fs::create_dir_all(root.join(".tx")).unwrap();
+    std::fs::write(root.join(".tx/config"), "[main]\n").unwrap();
+    let log = temp.path().join("args.log");
+    let tx = temp.path().join("tx.sh");
+    std::fs::write(
+        &tx,
+        format!("#!/bin/sh\necho \"$@\" >> {}\n", log.display()),
+    )
+    .unwrap();
+    std::fs::set_permissions(&tx, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let ctx = ToolContext::new(Arc::new(Config::default()), CancellationToken::new(), false);
+    let tool = TransifexTool::new()
+        .root(&root)
+        .tx_binary(&tx)
+        .pull_mode(TransifexPullMode::Incremental)
+        .pull_op();
+
+    tool.run(&ctx).await.unwrap();
+    tool.run(&ctx).await.unwrap();
+    std::fs::write(root.join(".tx/config"), "[main]\n[o:team:p:project]\n").unwrap();
+    tool.run(&ctx).await.unwrap();
+
+    let forced: Vec<bool> = std::fs::read_to_string(&log)
+        .unwrap()
+        .lines()
+        .map(|line| line.contains("--force"))
+        .collect();
+    assert_eq!(forced, [true, false, true]);
+}
@@ -6,9 +6,9 @@
 //! Visual Studio helper utilities.
 //!
 //! ```text
-//! vs::find_latest() --> VsInstallation
-//! find_msbuild() / find_devenv()
-//! get_env(arch)  --> VS Dev Prompt env
+//! vs::find_installation(vs_version) --> VsInstallation   (else latest)
+//! find_msbuild(vs_version) / find_devenv(vs_version)
+//! get_env(arch, vs_version)  --> VS Dev Prompt env
 //! ```
 //!
 //! This module provides high-level utilities for locating Visual Studio
@@ -24,7 +24,7 @@
 //! ```ignore
 //! use mob_rs::task::tools::vs::VsHelper;
 //!
-//! let msbuild = VsHelper::find_msbuild()?;
+//! let msbuild = VsHelper::find_msbuild(config.versions.vs_version.as_deref())?;
 //! ```
 
 use crate::error::Result;
@@ -75,6 +75,26 @@ impl VsHelper {
         crate::core::vs::find_latest(vswhere_override)
     }
 
+    /// Find the Visual Studio installation to build with.
+    ///
+    /// Uses the installation pinned by `vs_version` when set, the latest one
+    /// otherwise.
+    ///
+    /// # Arguments
+    /// * `vswhere_override` - Optional config-provided path to vswhere.exe
+    /// * `vs_version` - `versions.vs_version`: version prefix or instance ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if discovery fails, or if no installation matches the
+    /// pinned version; the error lists the discovered installations.
+    pub fn find_installation(
+        vswhere_override: Option<&Path>,
+        vs_version: Option<&str>,
+    ) -> Result<VsInstallation> {
+        crate::core::vs::find_installation(vswhere_override, vs_version)
+    }
+
     /// Find the vswhere executable path.
     ///
     /// # Arguments
@@ -92,7 +112,8 @@ impl VsHelper {
 
     /// Find the `MSBuild` executable path.
     ///
-    /// Locates MSBuild.exe in the latest VS installation.
+    /// Locates MSBuild.exe in the installation selected by `vs_version`, see
+    /// [`find_installation`](Self::find_installation).
     ///
     /// # Returns
     /// Path to MSBuild.exe, or an error if VS cannot be found.
@@ -102,10 +123,10 @@ impl VsHelper {
     /// Returns an error if:
     /// - No Visual Studio installation is found.
     /// - MSBuild.exe does not exist in the found installation.
-    pub fn find_msbuild() -> Result<PathBuf> {
+    pub fn find_msbuild(vs_version: Option<&str>) -> Result<PathBuf> {
         debug!("Finding MSBuild executable");
 
-        let vs = Self::find_latest(None)?;
+        let vs = Self::find_installation(None, vs_version)?;
         let msbuild = vs.msbuild_path();
 
         if !msbuild.exists() {
@@ -121,7 +142,7 @@ impl VsHelper {
 
     /// Find the devenv executable path (may not exist).
     ///
-    /// Locates devenv.exe in the latest VS installation. Returns `Ok(None)` if
+    /// Locates devenv.exe in the installation selected by `vs_version`. Returns `Ok(None)` if
     /// VS is found but devenv is not installed (e.g., `BuildTools` SKU).
     ///
     /// # Returns
@@ -131,10 +152,10 @@ impl VsHelper {
     /// # Errors
     ///
     /// Returns an error if no Visual Studio installation is found.
-    pub fn find_devenv() -> Result<Option<PathBuf>> {
+    pub fn find_devenv(vs_version: Option<&str>) -> Result<Option<PathBuf>> {
         debug!("Finding devenv executable");
 
-        let vs = Self::find_latest(None)?;
+        let vs = Self::find_installation(None, vs_version)?;
         let devenv = vs.devenv_path();
 
         if devenv.exists() {
@@ -152,6 +173,7 @@ impl VsHelper {
     ///
     /// # Arguments
     /// * `arch` - Target architecture (x86 or x64)
+    /// * `vs_version` - `versions.vs_version`, the latest installation if `None`
     ///
     /// # Returns
     /// The VS environment variables for the given architecture.
@@ -159,8 +181,8 @@ impl VsHelper {
     /// # Errors
     ///
     /// Returns an error if the Visual Studio environment cannot be captured.
-    pub fn get_env(arch: Arch) -> Result<Env> {
-        Env::vs(arch).context("Failed to capture VS environment")
+    pub fn get_env(arch: Arch, vs_version: Option<&str>) -> Result<Env> {
+        Env::vs(arch, vs_version).context("Failed to capture VS environment")
     }
 }
 
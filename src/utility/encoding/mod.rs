@@ -3,16 +3,17 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-//! Legacy Windows encoding conversion (UTF-8 ↔ CP1252/CP437).
+//! Legacy Windows encoding conversion (UTF-8 ↔ CP1252/CP1251/CP866).
 //!
 //! ```text
-//! External I/O        Internal      External I/O
-//! CP1252/CP437 --(decode)--> UTF-8 --(encode)--> UTF-8/CP1252
+//! External I/O                                     Internal                 External I/O
+//! UTF-16 LE/CP1252/CP1251/CP866 --bytes_to_utf8--> UTF-8 --utf8_to_bytes--> UTF-16 LE/CP1252/CP1251/CP866
 //! ```
 //!
-//! Uses `encoding_rs`. Invalid sequences → U+FFFD.
+//! Uses `encoding_rs`. Invalid sequences → U+FFFD when decoding, unmappable
+//! characters → `?` when encoding.
 
-use encoding_rs::{IBM866, WINDOWS_1252};
+use encoding_rs::{EncoderResult, IBM866, WINDOWS_1251, WINDOWS_1252};
 use std::borrow::Cow;
 
 /// Encoding types for process output and file content.
@@ -21,6 +22,7 @@ use std::borrow::Cow;
 /// - `Utf8`: UTF-8 (65001)
 /// - `Utf16`: UTF-16 LE (1200) - handled separately
 /// - `Acp`: Active Code Page, typically Windows-1252 (1252)
+/// - `Acp1251`: Cyrillic Active Code Page, Windows-1251 (1251)
 /// - `Oem`: OEM Code Page, typically IBM437/866 (437/866)
 /// - `Unknown`: Treat as ASCII/UTF-8 passthrough
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -34,6 +36,8 @@ pub enum Encoding {
     Utf16Le,
     /// Active Code Page - typically Windows-1252
     Acp,
+    /// Cyrillic Active Code Page - Windows-1251
+    Acp1251,
     /// OEM Code Page - typically IBM437 for US Windows
     Oem,
 }
@@ -71,6 +75,11 @@ pub fn bytes_to_utf8(encoding: Encoding, bytes: &[u8]) -> Cow<'_, str> {
             let (result, _had_errors) = WINDOWS_1252.decode_without_bom_handling(bytes);
             result
         }
+        Encoding::Acp1251 => {
+            // Windows-1251 (Cyrillic Active Code Page)
+            let (result, _had_errors) = WINDOWS_1251.decode_without_bom_handling(bytes);
+            result
+        }
         Encoding::Oem => {
             // IBM866 (OEM Code Page)
             let (result, _had_errors) = IBM866.decode_without_bom_handling(bytes);
@@ -79,6 +88,49 @@ pub fn bytes_to_utf8(encoding: Encoding, bytes: &[u8]) -> Cow<'_, str> {
     }
 }
 
+/// Converts a UTF-8 string to bytes in the given encoding.
+///
+/// Inverse of [`bytes_to_utf8`], used to write files back in a legacy
+/// encoding. Characters that cannot be represented in the target code page
+/// are replaced with `?`, like `WideCharToMultiByte` does.
+///
+/// # Example
+/// ```
+/// use mob_rs::utility::encoding::{utf8_to_bytes, Encoding};
+///
+/// assert_eq!(utf8_to_bytes(Encoding::Acp, "café"), b"caf\xe9");
+/// assert_eq!(utf8_to_bytes(Encoding::Acp1251, "мод"), b"\xec\xee\xe4");
+/// ```
+#[must_use]
+pub fn utf8_to_bytes(encoding: Encoding, text: &str) -> Vec<u8> {
+    match encoding {
+        Encoding::Utf8 | Encoding::Unknown => text.as_bytes().to_vec(),
+        Encoding::Utf16Le => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+        Encoding::Acp => encode_legacy(WINDOWS_1252, text),
+        Encoding::Acp1251 => encode_legacy(WINDOWS_1251, text),
+        Encoding::Oem => encode_legacy(IBM866, text),
+    }
+}
+
+/// Encodes UTF-8 text into a single-byte code page, replacing unmappable
+/// characters with `?`.
+fn encode_legacy(code_page: &'static encoding_rs::Encoding, text: &str) -> Vec<u8> {
+    let mut encoder = code_page.new_encoder();
+    let mut output = Vec::with_capacity(text.len());
+    let mut input = text;
+
+    loop {
+        let (result, read) =
+            encoder.encode_from_utf8_to_vec_without_replacement(input, &mut output, true);
+        input = &input[read..];
+        match result {
+            EncoderResult::InputEmpty => return output,
+            EncoderResult::OutputFull => output.reserve(input.len().max(16)),
+            EncoderResult::Unmappable(_) => output.push(b'?'),
+        }
+    }
+}
+
 /// Converts UTF-16 LE bytes to UTF-8.
 fn utf16_le_to_utf8(bytes: &[u8]) -> Cow<'static, str> {
     // Handle odd byte count by ignoring the last byte
@@ -97,11 +149,31 @@ fn utf16_le_to_utf8(bytes: &[u8]) -> Cow<'static, str> {
     Cow::Owned(String::from_utf16_lossy(&u16_slice))
 }
 
+/// UTF-8 byte order mark.
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// UTF-16 LE byte order mark.
+const UTF16_LE_BOM: &[u8] = b"\xff\xfe";
+
+/// Returns the encoding and length of the byte order mark `bytes` start with.
+fn detect_bom(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    if bytes.starts_with(UTF8_BOM) {
+        Some((Encoding::Utf8, UTF8_BOM.len()))
+    } else if bytes.starts_with(UTF16_LE_BOM) {
+        Some((Encoding::Utf16Le, UTF16_LE_BOM.len()))
+    } else {
+        None
+    }
+}
+
 /// A buffer for streaming process output with encoding conversion.
 ///
 /// Collects raw bytes from a process and provides line-by-line iteration
 /// with automatic UTF-8 conversion based on the specified encoding.
 ///
+/// A leading UTF-8 or UTF-16 LE byte order mark overrides the configured
+/// encoding and is stripped before the first line is returned.
+///
 /// # Example
 /// ```
 /// use mob_rs::utility::encoding::{EncodedBuffer, Encoding};
@@ -119,6 +191,8 @@ pub struct EncodedBuffer {
     bytes: Vec<u8>,
     /// Byte offset of last processed position
     last_offset: usize,
+    /// Whether the leading bytes have been checked for a byte order mark
+    bom_checked: bool,
 }
 
 impl EncodedBuffer {
@@ -129,6 +203,7 @@ impl EncodedBuffer {
             encoding,
             bytes: Vec::new(),
             last_offset: 0,
+            bom_checked: false,
         }
     }
 
@@ -139,6 +214,7 @@ impl EncodedBuffer {
             encoding,
             bytes,
             last_offset: 0,
+            bom_checked: false,
         }
     }
 
@@ -147,10 +223,23 @@ impl EncodedBuffer {
         self.bytes.extend_from_slice(bytes);
     }
 
+    /// Returns the encoding used to decode the buffer.
+    ///
+    /// This is the configured encoding unless a byte order mark was detected.
+    #[must_use]
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
     /// Returns the entire buffer content as UTF-8.
     #[must_use]
     pub fn utf8_string(&self) -> String {
-        bytes_to_utf8(self.encoding, &self.bytes).into_owned()
+        match detect_bom(&self.bytes) {
+            Some((encoding, len)) if !self.bom_checked => {
+                bytes_to_utf8(encoding, &self.bytes[len..]).into_owned()
+            }
+            _ => bytes_to_utf8(self.encoding, &self.bytes).into_owned(),
+        }
     }
 
     /// Returns an iterator over UTF-8 lines that haven't been processed yet.
@@ -163,7 +252,9 @@ impl EncodedBuffer {
     /// - Empty lines are skipped
     /// - Handles both LF and CRLF line endings
     /// - Updates internal offset to avoid reprocessing lines
+    /// - A leading byte order mark is detected and stripped on first read
     pub fn next_utf8_lines(&mut self, finished: bool) -> impl Iterator<Item = String> + '_ {
+        self.check_bom(finished);
         EncodedLineIterator {
             buffer: self,
             finished,
@@ -175,6 +266,28 @@ impl EncodedBuffer {
         self.bytes.clear();
         self.last_offset = 0;
     }
+
+    /// Switches to the encoding of a leading byte order mark and strips it.
+    ///
+    /// While the buffer could still be the start of a BOM, the check is
+    /// deferred until more bytes arrive or `finished` is set.
+    fn check_bom(&mut self, finished: bool) {
+        if self.bom_checked {
+            return;
+        }
+
+        if let Some((encoding, len)) = detect_bom(&self.bytes) {
+            self.encoding = encoding;
+            self.bytes.drain(..len);
+        } else if !finished
+            && [UTF8_BOM, UTF16_LE_BOM]
+                .iter()
+                .any(|bom| bom.len() > self.bytes.len() && bom.starts_with(&self.bytes))
+        {
+            return;
+        }
+        self.bom_checked = true;
+    }
 }
 
 /// Iterator over lines in an `EncodedBuffer`.
@@ -195,7 +308,7 @@ impl Iterator for EncodedLineIterator<'_> {
 }
 
 impl EncodedLineIterator<'_> {
-    /// Extracts the next line for byte-based encodings (UTF-8, ACP, OEM).
+    /// Extracts the next line for byte-based encodings (UTF-8, ACP, CP1251, OEM).
     fn next_byte_line(&mut self) -> Option<String> {
         let bytes = &self.buffer.bytes;
         let mut offset = self.buffer.last_offset;
@@ -3,7 +3,7 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::{EncodedBuffer, Encoding, bytes_to_utf8};
+use super::{EncodedBuffer, Encoding, bytes_to_utf8, utf8_to_bytes};
 
 #[test]
 fn test_utf8_passthrough() {
@@ -20,6 +20,41 @@ fn test_windows_1252_conversion() {
     insta::assert_snapshot!(result);
 }
 
+#[test]
+fn test_windows_1251_conversion() {
+    // "Привет" in Windows-1251
+    let input = b"\xcf\xf0\xe8\xe2\xe5\xf2";
+    let result = bytes_to_utf8(Encoding::Acp1251, input);
+    assert_eq!(result, "Привет");
+}
+
+#[test]
+fn test_legacy_round_trip() {
+    let cases = [
+        (Encoding::Acp, "Café déjà vu – naïve"),
+        (Encoding::Acp1251, "Менеджер модов №1 — готов"),
+        (Encoding::Oem, "Ошибка сборки"),
+        (Encoding::Utf16Le, "Hi 世界"),
+        (Encoding::Utf8, "Hello, 世界!"),
+    ];
+
+    for (encoding, text) in cases {
+        let bytes = utf8_to_bytes(encoding, text);
+        assert_eq!(bytes_to_utf8(encoding, &bytes), text, "{encoding:?}");
+    }
+}
+
+#[test]
+fn test_utf8_to_bytes_unmappable() {
+    // Cyrillic is not representable in Windows-1252, Latin accents are not in 1251
+    assert_eq!(utf8_to_bytes(Encoding::Acp, "a\u{0436}b"), b"a?b");
+    assert_eq!(utf8_to_bytes(Encoding::Acp1251, "caf\u{e9}"), b"caf?");
+    assert_eq!(
+        utf8_to_bytes(Encoding::Oem, "caf\u{e9} \u{20ac}"),
+        b"caf? ?"
+    );
+}
+
 #[test]
 fn test_utf16_le_conversion() {
     // "Hi" in UTF-16 LE: 0x48 0x00 0x69 0x00
@@ -68,3 +103,52 @@ fn test_encoded_buffer_empty_lines_skipped() {
     let lines: Vec<String> = buffer.next_utf8_lines(true).collect();
     insta::assert_yaml_snapshot!(lines);
 }
+
+#[test]
+fn test_encoded_buffer_utf8_bom() {
+    let mut buffer = EncodedBuffer::new(Encoding::Acp);
+    buffer.add(b"\xef\xbb\xbfcaf\xc3\xa9\r\nline2\r\n");
+
+    let lines: Vec<String> = buffer.next_utf8_lines(true).collect();
+    assert_eq!(lines, vec!["café", "line2"]);
+    assert_eq!(buffer.encoding(), Encoding::Utf8);
+}
+
+#[test]
+fn test_encoded_buffer_utf16_le_bom() {
+    let mut buffer = EncodedBuffer::new(Encoding::Unknown);
+    buffer.add(b"\xff\xfeH\x00i\x00\r\x00\n\x00");
+    buffer.add(b"o\x00k\x00");
+
+    let lines: Vec<String> = buffer.next_utf8_lines(true).collect();
+    assert_eq!(lines, vec!["Hi", "ok"]);
+    assert_eq!(buffer.encoding(), Encoding::Utf16Le);
+}
+
+#[test]
+fn test_encoded_buffer_split_bom() {
+    let mut buffer = EncodedBuffer::new(Encoding::Acp);
+    buffer.add(b"\xef\xbb");
+    assert_eq!(buffer.next_utf8_lines(false).count(), 0);
+
+    buffer.add(b"\xbfline1\n");
+    let lines: Vec<String> = buffer.next_utf8_lines(false).collect();
+    assert_eq!(lines, vec!["line1"]);
+    assert_eq!(buffer.encoding(), Encoding::Utf8);
+}
+
+#[test]
+fn test_encoded_buffer_no_bom_passthrough() {
+    let mut buffer = EncodedBuffer::new(Encoding::Acp);
+    buffer.add(b"caf\xe9\n");
+
+    let lines: Vec<String> = buffer.next_utf8_lines(true).collect();
+    assert_eq!(lines, vec!["café"]);
+    assert_eq!(buffer.encoding(), Encoding::Acp);
+}
+
+#[test]
+fn test_encoded_buffer_utf8_string_strips_bom() {
+    let buffer = EncodedBuffer::with_bytes(Encoding::Acp, b"\xef\xbb\xbfcaf\xc3\xa9".to_vec());
+    assert_eq!(buffer.utf8_string(), "café");
+}
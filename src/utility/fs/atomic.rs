@@ -0,0 +1,117 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Atomic file writes for installed and released files.
+//!
+//! ```text
+//! write_atomic(dst, bytes) / copy_atomic(src, dst)   (on the blocking pool)
+//!   --> NamedTempFile::new_in(dst's directory) --> persist over dst
+//!   error or interrupt --> temp file dropped and removed, dst untouched
+//! Windows: persist over a read-only dst --> clear read-only, persist again
+//! ```
+//!
+//! A later run never sees a truncated file: `dst` is either the old file or
+//! the complete new one.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use tempfile::NamedTempFile;
+
+use crate::error::Result;
+
+/// Writes `bytes` to `dst` through a temporary file in the same directory.
+///
+/// # Errors
+///
+/// Returns an error if creating or writing the temporary file, or
+/// persisting it over `dst`, fails.
+pub async fn write_atomic(dst: &Path, bytes: &[u8]) -> Result<()> {
+    let dst = dst.to_path_buf();
+    let bytes = bytes.to_vec();
+    tokio::task::spawn_blocking(move || {
+        let mut temp = temp_file(&dst)?;
+        temp.write_all(&bytes)
+            .with_context(|| format!("failed to write {}", temp.path().display()))?;
+        persist(temp, &dst)
+    })
+    .await
+    .context("atomic write task panicked")?
+}
+
+/// Copies `src` to `dst` through a temporary file in the destination
+/// directory, keeping the permissions of `src`.
+///
+/// # Errors
+///
+/// Returns an error if `src` cannot be read, or if copying to the temporary
+/// file or persisting it over `dst` fails.
+pub async fn copy_atomic(src: &Path, dst: &Path) -> Result<()> {
+    let src = src.to_path_buf();
+    let dst = dst.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let mut source = std::fs::File::open(&src)
+            .with_context(|| format!("failed to open {}", src.display()))?;
+        let permissions = source
+            .metadata()
+            .with_context(|| format!("failed to read metadata of {}", src.display()))?
+            .permissions();
+        let mut temp = temp_file(&dst)?;
+        std::io::copy(&mut source, &mut temp).with_context(|| {
+            format!(
+                "failed to copy {} to {}",
+                src.display(),
+                temp.path().display()
+            )
+        })?;
+        temp.as_file()
+            .set_permissions(permissions)
+            .with_context(|| format!("failed to set permissions of {}", temp.path().display()))?;
+        persist(temp, &dst)
+    })
+    .await
+    .context("atomic copy task panicked")?
+}
+
+/// Creates a temporary file in the directory of `dst`.
+fn temp_file(dst: &Path) -> Result<NamedTempFile> {
+    let dir = dst
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+    NamedTempFile::new_in(&dir)
+        .with_context(|| format!("failed to create a temporary file in {}", dir.display()))
+}
+
+/// Persists `temp` over `dst`, replacing an existing file.
+///
+/// Windows refuses to replace a read-only file, so the flag is cleared and
+/// the persist retried once.
+fn persist(temp: NamedTempFile, dst: &Path) -> Result<()> {
+    let temp_path = temp.path().to_path_buf();
+    let context = || {
+        format!(
+            "failed to rename {} to {}",
+            temp_path.display(),
+            dst.display()
+        )
+    };
+    match temp.persist(dst) {
+        Ok(_) => Ok(()),
+        #[cfg(windows)]
+        Err(e) if e.error.kind() == std::io::ErrorKind::PermissionDenied && dst.is_file() => {
+            let mut permissions = std::fs::metadata(dst).with_context(context)?.permissions();
+            permissions.set_readonly(false);
+            std::fs::set_permissions(dst, permissions).with_context(context)?;
+            e.file
+                .persist(dst)
+                .map(drop)
+                .map_err(|e| e.error)
+                .with_context(context)
+        }
+        Err(e) => Err(e.error).with_context(context),
+    }
+}
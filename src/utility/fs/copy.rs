@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::error::Result;
+use crate::utility::fs::atomic::copy_atomic;
 use anyhow::Context;
 use std::path::Path;
 use tokio::fs;
@@ -128,3 +129,54 @@ pub async fn copy_files_async(src: &Path, dst: &Path, pattern: Option<&str>) ->
 
     Ok(())
 }
+
+/// Recursively moves all contents from src directory into dst directory (async version).
+///
+/// Existing files in dst are replaced, existing directories are merged. Each
+/// entry is renamed when possible and copied through a temporary file then
+/// deleted when rename fails, e.g. because src and dst are on different
+/// volumes. src is removed afterwards.
+///
+/// # Arguments
+/// * `src` - Source directory path
+/// * `dst` - Destination directory path
+///
+/// # Errors
+///
+/// Returns an error if any IO operation fails (creating directory, reading, copying, removing).
+pub async fn move_dir_contents_async(src: &Path, dst: &Path) -> Result<()> {
+    fs::create_dir_all(dst)
+        .await
+        .with_context(|| format!("failed to create directory {}", dst.display()))?;
+
+    let mut entries = fs::read_dir(src)
+        .await
+        .with_context(|| format!("failed to read directory {}", src.display()))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .with_context(|| format!("failed to read entry from {}", src.display()))?
+    {
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            if !dst_path.exists() && fs::rename(&src_path, &dst_path).await.is_ok() {
+                continue;
+            }
+            Box::pin(move_dir_contents_async(&src_path, &dst_path)).await?;
+        } else if fs::rename(&src_path, &dst_path).await.is_err() {
+            copy_atomic(&src_path, &dst_path).await?;
+            fs::remove_file(&src_path)
+                .await
+                .with_context(|| format!("failed to remove {}", src_path.display()))?;
+        }
+    }
+
+    fs::remove_dir_all(src)
+        .await
+        .with_context(|| format!("failed to remove directory {}", src.display()))?;
+
+    Ok(())
+}
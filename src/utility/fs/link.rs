@@ -0,0 +1,131 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Deploying an install tree into another directory by symlink or copy.
+//!
+//! ```text
+//! deploy_dir_async(src, dst, Link)
+//!   directories --> created in dst (existing contents kept)
+//!   files       --> symlink dst/file -> src/file
+//!                   (copy if symlinks are not permitted)
+//! deploy_dir_async(src, dst, Copy)
+//!   files       --> copied, replacing existing files
+//! ```
+
+use std::path::Path;
+
+use anyhow::Context;
+use tokio::fs;
+
+use crate::error::Result;
+
+/// How files are placed into the destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeployMode {
+    /// Symlink each file, falling back to a copy when linking fails.
+    Link,
+    /// Copy each file.
+    Copy,
+}
+
+/// Number of files placed by [`deploy_dir_async`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeploySummary {
+    linked: usize,
+    copied: usize,
+}
+
+impl DeploySummary {
+    /// Returns the number of symlinked files.
+    #[must_use]
+    pub const fn linked(&self) -> usize {
+        self.linked
+    }
+
+    /// Returns the number of copied files.
+    #[must_use]
+    pub const fn copied(&self) -> usize {
+        self.copied
+    }
+}
+
+/// Recursively places every file of src into dst, merging with existing contents.
+///
+/// Only files that exist in src are replaced in dst, so other files already in
+/// dst (e.g. third-party plugins) are left alone. In [`DeployMode::Link`] each
+/// file becomes a symlink to src; on Windows without symlink privileges the
+/// file is copied instead.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read or created, or a file can be
+/// neither linked nor copied.
+pub async fn deploy_dir_async(src: &Path, dst: &Path, mode: DeployMode) -> Result<DeploySummary> {
+    let mut summary = DeploySummary::default();
+    Box::pin(deploy_dir_inner(src, dst, mode, &mut summary)).await?;
+    Ok(summary)
+}
+
+async fn deploy_dir_inner(
+    src: &Path,
+    dst: &Path,
+    mode: DeployMode,
+    summary: &mut DeploySummary,
+) -> Result<()> {
+    fs::create_dir_all(dst)
+        .await
+        .with_context(|| format!("failed to create directory {}", dst.display()))?;
+
+    let mut entries = fs::read_dir(src)
+        .await
+        .with_context(|| format!("failed to read directory {}", src.display()))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .with_context(|| format!("failed to read entry from {}", src.display()))?
+    {
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
+
+        if src_path.is_dir() {
+            Box::pin(deploy_dir_inner(&src_path, &dst_path, mode, summary)).await?;
+            continue;
+        }
+
+        // Replace an existing file or stale link, never a directory
+        if fs::symlink_metadata(&dst_path).await.is_ok() {
+            fs::remove_file(&dst_path)
+                .await
+                .with_context(|| format!("failed to replace {}", dst_path.display()))?;
+        }
+
+        if mode == DeployMode::Link && symlink_file(&src_path, &dst_path).await.is_ok() {
+            summary.linked += 1;
+            continue;
+        }
+
+        fs::copy(&src_path, &dst_path).await.with_context(|| {
+            format!(
+                "failed to copy {} to {}",
+                src_path.display(),
+                dst_path.display()
+            )
+        })?;
+        summary.copied += 1;
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+async fn symlink_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs::symlink_file(src, dst).await
+}
+
+#[cfg(not(windows))]
+async fn symlink_file(src: &Path, dst: &Path) -> std::io::Result<()> {
+    fs::symlink(src, dst).await
+}
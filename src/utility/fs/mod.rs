@@ -11,9 +11,16 @@
 //!        WalkOptions      max_depth, hidden, gitignore
 //! copy:  copy_files_async()        tokio::fs parallel copy
 //!        copy_dir_contents_async() recursive directory copy
+//!        move_dir_contents_async() rename, copy across volumes
+//! link:  deploy_dir_async()        symlink or copy into another tree
+//! atomic: write_atomic() copy_atomic() temp file + rename, no partial files
+//! text:  write_text()              generated files with global.line_endings
 //! ```
 
+pub mod atomic;
 pub mod copy;
+pub mod link;
+pub mod text;
 pub mod walk;
 
 #[cfg(test)]
@@ -3,6 +3,10 @@
 // SPDX-FileCopyrightText: 2026 Romeo Ahmed
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use super::atomic::{copy_atomic, write_atomic};
+use super::copy::move_dir_contents_async;
+use super::link::{DeployMode, deploy_dir_async};
+use super::text::{LineEndings, write_text};
 use super::walk::{WalkOptions, find_files, parallel_walk, parallel_walk_with_callback};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -104,3 +108,136 @@ fn test_parallel_walk_with_callback() {
         })
     );
 }
+
+#[tokio::test]
+async fn test_move_dir_contents_merges_and_replaces() {
+    let temp = temp_dir();
+    let src = temp.path().join("staging");
+    let dst = temp.path().join("install");
+
+    std::fs::create_dir_all(src.join("bin")).unwrap();
+    std::fs::create_dir_all(src.join("lib")).unwrap();
+    std::fs::write(src.join("bin/new.dll"), "new").unwrap();
+    std::fs::write(src.join("bin/app.exe"), "v2").unwrap();
+    std::fs::write(src.join("lib/a.lib"), "lib").unwrap();
+
+    std::fs::create_dir_all(dst.join("bin")).unwrap();
+    std::fs::write(dst.join("bin/app.exe"), "v1").unwrap();
+    std::fs::write(dst.join("bin/keep.dll"), "keep").unwrap();
+
+    move_dir_contents_async(&src, &dst).await.unwrap();
+
+    assert!(!src.exists());
+    assert_eq!(
+        std::fs::read_to_string(dst.join("bin/app.exe")).unwrap(),
+        "v2"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dst.join("bin/new.dll")).unwrap(),
+        "new"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dst.join("bin/keep.dll")).unwrap(),
+        "keep"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dst.join("lib/a.lib")).unwrap(),
+        "lib"
+    );
+}
+
+#[tokio::test]
+async fn test_deploy_dir_merges_into_instance() {
+    let temp = temp_dir();
+    let src = temp.path().join("install/bin");
+    let dst = temp.path().join("instance");
+
+    std::fs::create_dir_all(src.join("plugins")).unwrap();
+    std::fs::write(src.join("ModOrganizer.exe"), "exe").unwrap();
+    std::fs::write(src.join("plugins/ours.dll"), "new").unwrap();
+
+    std::fs::create_dir_all(dst.join("plugins")).unwrap();
+    std::fs::write(dst.join("plugins/ours.dll"), "old").unwrap();
+    std::fs::write(dst.join("plugins/third_party.dll"), "keep").unwrap();
+
+    for mode in [DeployMode::Copy, DeployMode::Link] {
+        let summary = deploy_dir_async(&src, &dst, mode).await.unwrap();
+        assert_eq!(summary.linked() + summary.copied(), 2);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("plugins/ours.dll")).unwrap(),
+            "new"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("plugins/third_party.dll")).unwrap(),
+            "keep"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("ModOrganizer.exe")).unwrap(),
+            "exe"
+        );
+    }
+}
+
+#[test]
+fn test_line_endings_apply() {
+    let text = "a\r\nb\nc\n";
+    assert_eq!(LineEndings::Lf.apply(text), "a\nb\nc\n");
+    assert_eq!(LineEndings::Crlf.apply(text), "a\r\nb\r\nc\r\n");
+    let native = if cfg!(windows) {
+        "a\r\nb\r\nc\r\n"
+    } else {
+        "a\nb\nc\n"
+    };
+    assert_eq!(LineEndings::Native.apply(text), native);
+}
+
+#[tokio::test]
+async fn test_write_text_converts_line_endings() {
+    let temp = temp_dir();
+    let path = temp.path().join("out.txt");
+
+    write_text(&path, "one\ntwo\n", LineEndings::Crlf)
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&path).unwrap(), b"one\r\ntwo\r\n");
+}
+
+/// Returns the names of the entries in `dir`, sorted.
+fn entry_names(dir: &std::path::Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_write_atomic_replaces_file() {
+    let temp = temp_dir();
+    let dst = temp.path().join("plugin.dll");
+    std::fs::write(&dst, "old").unwrap();
+
+    write_atomic(&dst, b"new").await.unwrap();
+
+    assert_eq!(std::fs::read_to_string(&dst).unwrap(), "new");
+    assert_eq!(entry_names(temp.path()), ["plugin.dll"]);
+}
+
+#[tokio::test]
+async fn test_copy_atomic_keeps_destination_on_error() {
+    let temp = temp_dir();
+    let src = temp.path().join("src.dll");
+    let dst = temp.path().join("dst.dll");
+    std::fs::write(&dst, "old").unwrap();
+
+    assert!(copy_atomic(&src, &dst).await.is_err());
+    assert_eq!(std::fs::read_to_string(&dst).unwrap(), "old");
+    assert_eq!(entry_names(temp.path()), ["dst.dll"]);
+
+    std::fs::write(&src, "new").unwrap();
+    copy_atomic(&src, &dst).await.unwrap();
+    assert_eq!(std::fs::read_to_string(&dst).unwrap(), "new");
+    assert_eq!(entry_names(temp.path()), ["dst.dll", "src.dll"]);
+}
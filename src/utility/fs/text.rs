@@ -0,0 +1,73 @@
+// mob-rs: `ModOrganizer` Build Tool - Rust Port
+//
+// SPDX-FileCopyrightText: 2026 Romeo Ahmed
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Writing mob-generated text files with configurable line endings.
+//!
+//! ```text
+//! global.line_endings: native (default) | lf | crlf
+//!
+//! write_text(path, contents, endings)
+//!   contents --> \r\n and \n unified --> \n or \r\n --> tokio::fs::write
+//! ```
+//!
+//! Used for scripts, reports, build profiles and diagnostic bundles so that
+//! committed outputs don't differ between Windows and Linux.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Line endings of generated text files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndings {
+    /// `\r\n` on Windows, `\n` elsewhere.
+    #[default]
+    Native,
+    /// Always `\n`.
+    Lf,
+    /// Always `\r\n`.
+    Crlf,
+}
+
+impl LineEndings {
+    /// Returns `text` with every line ending converted.
+    #[must_use]
+    pub fn apply(self, text: &str) -> String {
+        let unified = text.replace("\r\n", "\n");
+        if self.is_crlf() {
+            unified.replace('\n', "\r\n")
+        } else {
+            unified
+        }
+    }
+
+    const fn is_crlf(self) -> bool {
+        match self {
+            Self::Native => cfg!(windows),
+            Self::Lf => false,
+            Self::Crlf => true,
+        }
+    }
+}
+
+impl std::fmt::Display for LineEndings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Native => write!(f, "native"),
+            Self::Lf => write!(f, "lf"),
+            Self::Crlf => write!(f, "crlf"),
+        }
+    }
+}
+
+/// Writes `contents` to `path` with `endings`.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub async fn write_text(path: &Path, contents: &str, endings: LineEndings) -> std::io::Result<()> {
+    tokio::fs::write(path, endings.apply(contents)).await
+}
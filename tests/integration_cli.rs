@@ -72,6 +72,62 @@ fn cli_build_task_phase_flags() {
     insta::assert_debug_snapshot!(cli);
 }
 
+#[test]
+fn cli_build_phases_selector() {
+    let cli = Cli::try_parse_from(["mob", "build", "--phases", "clean,fetch"]).unwrap();
+    let Some(mob_rs::cli::Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    insta::assert_debug_snapshot!(args.phase_control());
+}
+
+#[test]
+fn cli_build_phases_unknown_rejected() {
+    let result = Cli::try_parse_from(["mob", "build", "--phases", "fetch,deploy"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn cli_build_phases_conflicts_with_toggles() {
+    let result = Cli::try_parse_from(["mob", "build", "--phases", "build", "--no-fetch-task"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn cli_build_dump_cmake_args() {
+    let cli = Cli::try_parse_from(["mob", "build", "--dump-cmake-args", "usvfs"]).unwrap();
+    let Some(mob_rs::cli::Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert_eq!(args.dump_cmake_args.as_deref(), Some("usvfs"));
+}
+
+#[test]
+fn cli_build_install_message() {
+    use mob_rs::config::types::CmakeInstallMessage;
+
+    let cli =
+        Cli::try_parse_from(["mob", "build", "--install-message", "ALWAYS", "usvfs"]).unwrap();
+    let Some(mob_rs::cli::Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert_eq!(
+        args.install.install_message,
+        Some(CmakeInstallMessage::Always)
+    );
+    assert!(Cli::try_parse_from(["mob", "build", "--install-message", "verbose"]).is_err());
+}
+
+#[test]
+fn cli_build_link_into() {
+    let cli = Cli::try_parse_from(["mob", "build", "--link-into", "C:/MO2"]).unwrap();
+    let Some(mob_rs::cli::Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert_eq!(args.link_into, Some(std::path::PathBuf::from("C:/MO2")));
+    assert!(Cli::try_parse_from(["mob", "build", "--link-into", "a", "--copy-into", "b"]).is_err());
+}
+
 #[test]
 fn cli_build_conflicting_flags_rejected() {
     // --clean-task and --no-clean-task should conflict
@@ -122,6 +178,26 @@ fn cli_global_options_set_options() {
     insta::assert_debug_snapshot!(cli);
 }
 
+#[test]
+fn cli_global_options_env() {
+    let cli = Cli::try_parse_from([
+        "mob",
+        "--env",
+        "VCPKG_ROOT=C:/vcpkg",
+        "--env",
+        "CL=/MP",
+        "build",
+    ])
+    .unwrap();
+    insta::assert_debug_snapshot!(cli.global.env);
+}
+
+#[test]
+fn cli_global_options_env_requires_assignment() {
+    assert!(Cli::try_parse_from(["mob", "--env", "VCPKG_ROOT", "build"]).is_err());
+    assert!(Cli::try_parse_from(["mob", "--env", "=value", "build"]).is_err());
+}
+
 #[test]
 fn cli_global_options_to_config_overrides() {
     let opts = GlobalOptions {
@@ -135,6 +211,25 @@ fn cli_global_options_to_config_overrides() {
     insta::assert_debug_snapshot!(overrides);
 }
 
+#[test]
+fn cli_global_quiet_to_config_overrides() {
+    let cli = Cli::try_parse_from(["mob", "-q", "build"]).unwrap();
+    assert_eq!(
+        cli.global.to_config_overrides(),
+        ["global/output_log_level=2", "global/progress=false"]
+    );
+
+    let cli = Cli::try_parse_from(["mob", "--quiet", "-l", "4", "build"]).unwrap();
+    assert_eq!(
+        cli.global.to_config_overrides(),
+        [
+            "global/output_log_level=4",
+            "global/progress=false",
+            "global/file_log_level=4"
+        ]
+    );
+}
+
 // =============================================================================
 // List Command
 // =============================================================================
@@ -253,6 +348,23 @@ fn build_args_to_config_overrides() {
     insta::assert_debug_snapshot!(overrides);
 }
 
+#[test]
+fn build_args_remote_override() {
+    let cli = Cli::try_parse_from(["mob", "build", "--remote", "upstream"]).unwrap();
+    let Some(mob_rs::cli::Command::Build(args)) = cli.command else {
+        panic!("expected build command");
+    };
+    assert_eq!(args.pull_behavior.remote.as_deref(), Some("upstream"));
+    // Applied to the config by the build command, not as an override
+    assert!(
+        !args
+            .to_config_overrides()
+            .iter()
+            .any(|o| o.contains("pull_remote"))
+    );
+    assert!(Cli::try_parse_from(["mob", "build", "--remote", "upstream", "--no-pull"]).is_err());
+}
+
 // =============================================================================
 // Error Cases
 // =============================================================================
@@ -270,3 +382,24 @@ fn cli_missing_required_args() {
     let result = Cli::try_parse_from(["mob", "git", "set-remotes", "-u", "user"]);
     assert!(result.is_err());
 }
+
+#[test]
+fn cli_list_missing() {
+    let cli = Cli::try_parse_from(["mob", "list", "--missing", "usvfs"]).unwrap();
+    let Some(mob_rs::cli::Command::List(args)) = cli.command else {
+        panic!("expected list command");
+    };
+    assert!(args.missing);
+    assert_eq!(args.tasks, vec!["usvfs".to_string()]);
+    assert!(Cli::try_parse_from(["mob", "list", "-m", "-i"]).is_err());
+}
+
+#[test]
+fn cli_list_json() {
+    let cli = Cli::try_parse_from(["mob", "list", "--json", "-a", "usvfs"]).unwrap();
+    let Some(mob_rs::cli::Command::List(args)) = cli.command else {
+        panic!("expected list command");
+    };
+    assert!(args.output.json);
+    assert!(Cli::try_parse_from(["mob", "list", "--json", "-i"]).is_err());
+}
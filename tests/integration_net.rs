@@ -12,14 +12,18 @@
 //! - Progress callbacks
 //! - Interrupt support
 //! - Custom headers
+//! - Chunked parallel downloads
+//! - Resumed downloads
 
+use mob_rs::config::types::NetConfig;
 use mob_rs::error::{MobError, NetworkError};
-use mob_rs::net::Downloader;
-use std::sync::Arc;
+use mob_rs::net::{Downloader, ProgressReporter, build_http_client};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tempfile::TempDir;
 use wiremock::matchers::{header, method, path};
-use wiremock::{Mock, MockServer, ResponseTemplate};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
 
 fn temp_dir() -> TempDir {
     tempfile::tempdir().expect("failed to create temp dir")
@@ -298,6 +302,74 @@ async fn test_progress_callback_multiple_calls() {
     assert_eq!(final_downloaded, body_content.len() as u64);
 }
 
+/// Records every `ProgressReporter` call.
+#[derive(Default)]
+struct RecordingReporter {
+    chunks: Mutex<Vec<(u64, Option<u64>)>>,
+    finished: Mutex<Option<bool>>,
+}
+
+impl ProgressReporter for RecordingReporter {
+    fn on_chunk(&self, downloaded: u64, total: Option<u64>) {
+        self.chunks.lock().unwrap().push((downloaded, total));
+    }
+
+    fn on_finish(&self, success: bool) {
+        *self.finished.lock().unwrap() = Some(success);
+    }
+}
+
+#[tokio::test]
+async fn test_progress_reporter_receives_chunks() {
+    let mock_server = MockServer::start().await;
+    let body = chunk_body();
+    Mock::given(method("GET"))
+        .and(path("/reported.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let reporter = Arc::new(RecordingReporter::default());
+    Downloader::new()
+        .url(format!("{}/reported.bin", mock_server.uri()))
+        .file(temp_dir.path().join("reported.bin"))
+        .silent()
+        .reporter(Arc::clone(&reporter) as Arc<dyn ProgressReporter>)
+        .download()
+        .await
+        .unwrap();
+
+    let total = body.len() as u64;
+    let chunks = reporter.chunks.lock().unwrap().clone();
+    assert!(!chunks.is_empty());
+    assert!(chunks.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(chunks.last(), Some(&(total, Some(total))));
+    assert_eq!(*reporter.finished.lock().unwrap(), Some(true));
+}
+
+#[tokio::test]
+async fn test_progress_reporter_reports_failure() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/gone.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(chunk_body()))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let reporter = Arc::new(RecordingReporter::default());
+    let downloader = Downloader::new()
+        .url(format!("{}/gone.bin", mock_server.uri()))
+        .file(temp_dir.path().join("gone.bin"))
+        .silent()
+        .reporter(Arc::clone(&reporter) as Arc<dyn ProgressReporter>);
+    downloader.interrupt_handle().store(true, Ordering::SeqCst);
+
+    assert!(downloader.download().await.is_err());
+    assert_eq!(*reporter.finished.lock().unwrap(), Some(false));
+}
+
 // =============================================================================
 // Interrupt tests
 // =============================================================================
@@ -439,6 +511,75 @@ async fn test_user_agent_set() {
     assert_eq!(result.unwrap(), "ok");
 }
 
+#[tokio::test]
+async fn test_client_read_timeout() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/slow.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("late")
+                .set_delay(Duration::from_secs(3)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let url = format!("{}/slow.txt", mock_server.uri());
+    let config = NetConfig {
+        timeout_secs: 1,
+        ..NetConfig::default()
+    };
+    let client = build_http_client(&config).expect("client should build");
+    let result = Downloader::new()
+        .client(client)
+        .url(&url)
+        .download_string()
+        .await;
+
+    assert!(result.is_err(), "slow response should time out");
+}
+
+#[tokio::test]
+async fn test_client_proxy_with_credentials() {
+    // The mock server acts as the proxy and sees the absolute-form request
+    let proxy = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/file.txt"))
+        .and(header("Proxy-Authorization", "Basic dXNlcjpzZWNyZXQ="))
+        .respond_with(ResponseTemplate::new(200).set_body_string("proxied"))
+        .mount(&proxy)
+        .await;
+
+    let address = proxy.address();
+    let config = NetConfig {
+        proxy: Some(format!("http://user:secret@{address}")),
+        ..NetConfig::default()
+    };
+    let client = build_http_client(&config).expect("client should build");
+    let result = Downloader::new()
+        .client(client)
+        .url("http://mob-rs.invalid/file.txt")
+        .download_string()
+        .await;
+
+    assert_eq!(result.expect("download through proxy failed"), "proxied");
+}
+
+#[test]
+fn test_client_invalid_proxy() {
+    let config = NetConfig {
+        proxy: Some("not a url".to_string()),
+        ..NetConfig::default()
+    };
+
+    match build_http_client(&config) {
+        Err(MobError::Network(boxed)) => {
+            assert!(matches!(*boxed, NetworkError::InvalidUrl(_)));
+        }
+        other => panic!("Expected MobError::Network, got {other:?}"),
+    }
+}
+
 // =============================================================================
 // Error handling tests
 // =============================================================================
@@ -509,3 +650,353 @@ async fn test_download_no_file_error() {
         other => panic!("Expected MobError::Network, got {other:?}"),
     }
 }
+
+// =============================================================================
+// parallel chunk tests
+// =============================================================================
+
+/// Serves `body`, honouring single `Range: bytes=start-end` and `bytes=start-` requests.
+struct RangeResponder {
+    body: Vec<u8>,
+    range_requests: Arc<AtomicU64>,
+}
+
+impl Respond for RangeResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let Some(range) = request.headers.get("range") else {
+            return ResponseTemplate::new(200).set_body_bytes(self.body.clone());
+        };
+        self.range_requests.fetch_add(1, Ordering::SeqCst);
+
+        let range = range.to_str().unwrap().trim_start_matches("bytes=");
+        let (start, end) = range.split_once('-').unwrap();
+        let start: usize = start.parse().unwrap();
+        let end: usize = if end.is_empty() {
+            self.body.len() - 1
+        } else {
+            end.parse().unwrap()
+        };
+        ResponseTemplate::new(206)
+            .insert_header(
+                "content-range",
+                format!("bytes {start}-{end}/{}", self.body.len()).as_str(),
+            )
+            .set_body_bytes(self.body[start..=end].to_vec())
+    }
+}
+
+fn chunk_body() -> Vec<u8> {
+    (0..3 * 1024 * 1024).map(|i: u32| (i % 251) as u8).collect()
+}
+
+#[tokio::test]
+async fn test_download_parallel_chunks() {
+    let mock_server = MockServer::start().await;
+    let body = chunk_body();
+    let range_requests = Arc::new(AtomicU64::new(0));
+
+    Mock::given(method("GET"))
+        .and(path("/large.bin"))
+        .respond_with(RangeResponder {
+            body: body.clone(),
+            range_requests: Arc::clone(&range_requests),
+        })
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("large.bin");
+
+    let url = format!("{}/large.bin", mock_server.uri());
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(3)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+    // One probe plus three chunks
+    assert_eq!(range_requests.load(Ordering::SeqCst), 4);
+    assert!(!temp_dir.path().join("large.bin.part").exists());
+}
+
+#[tokio::test]
+async fn test_download_parallel_chunks_small_file_single_stream() {
+    let mock_server = MockServer::start().await;
+    let body = b"small file".to_vec();
+    let range_requests = Arc::new(AtomicU64::new(0));
+
+    Mock::given(method("GET"))
+        .and(path("/small.bin"))
+        .respond_with(RangeResponder {
+            body: body.clone(),
+            range_requests: Arc::clone(&range_requests),
+        })
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("small.bin");
+
+    let url = format!("{}/small.bin", mock_server.uri());
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(4)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+    // Only the probe used a range
+    assert_eq!(range_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_download_parallel_chunks_without_range_support() {
+    let mock_server = MockServer::start().await;
+    let body = chunk_body();
+
+    Mock::given(method("GET"))
+        .and(path("/large.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("large.bin");
+
+    let url = format!("{}/large.bin", mock_server.uri());
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(4)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+}
+
+/// Serves ranges of `body` like [`RangeResponder`], but fails the chunk
+/// `bytes=<fail_at>-<end>`, either with a 500 or, with `wrong_total`, with a
+/// `Content-Range` of a larger file. Open ranges of a resume always succeed.
+struct FailingRangeResponder {
+    body: Vec<u8>,
+    fail_at: usize,
+    wrong_total: bool,
+}
+
+impl Respond for FailingRangeResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let range = request.headers.get("range").unwrap().to_str().unwrap();
+        let (start, end) = range.trim_start_matches("bytes=").split_once('-').unwrap();
+        let start: usize = start.parse().unwrap();
+        let chunk = !end.is_empty();
+        let end: usize = end.parse().map_or(self.body.len() - 1, |end| end);
+        let total = if chunk && start == self.fail_at {
+            if !self.wrong_total {
+                return ResponseTemplate::new(500);
+            }
+            self.body.len() + 1
+        } else {
+            self.body.len()
+        };
+        ResponseTemplate::new(206)
+            .insert_header(
+                "content-range",
+                format!("bytes {start}-{end}/{total}").as_str(),
+            )
+            .set_body_bytes(self.body[start..=end].to_vec())
+    }
+}
+
+#[tokio::test]
+async fn test_download_parallel_chunks_failure_keeps_part_for_resume() {
+    let mock_server = MockServer::start().await;
+    let body = chunk_body();
+
+    Mock::given(method("GET"))
+        .and(path("/large.bin"))
+        .respond_with(FailingRangeResponder {
+            body: body.clone(),
+            fail_at: 1024 * 1024,
+            wrong_total: false,
+        })
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("large.bin");
+    let part_file = temp_dir.path().join("large.bin.part");
+
+    let url = format!("{}/large.bin", mock_server.uri());
+    let result = Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(3)
+        .resume(true)
+        .download()
+        .await;
+
+    assert!(result.is_err());
+    assert!(!output_file.exists());
+    // Only the first chunk is in the file without a gap
+    assert_eq!(std::fs::read(&part_file).unwrap(), &body[..1024 * 1024]);
+
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(3)
+        .resume(true)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+    assert!(!part_file.exists());
+}
+
+#[tokio::test]
+async fn test_download_parallel_chunks_mismatch_removes_part() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/large.bin"))
+        .respond_with(FailingRangeResponder {
+            body: chunk_body(),
+            fail_at: 2 * 1024 * 1024,
+            wrong_total: true,
+        })
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("large.bin");
+
+    let url = format!("{}/large.bin", mock_server.uri());
+    let result = Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .parallel_chunks(3)
+        .download()
+        .await;
+
+    assert!(result.is_err());
+    assert!(!output_file.exists());
+    assert!(!temp_dir.path().join("large.bin.part").exists());
+}
+
+// =============================================================================
+// resume tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_download_resume_appends_to_part_file() {
+    let mock_server = MockServer::start().await;
+    let body = chunk_body();
+    let range_requests = Arc::new(AtomicU64::new(0));
+
+    Mock::given(method("GET"))
+        .and(path("/large.bin"))
+        .respond_with(RangeResponder {
+            body: body.clone(),
+            range_requests: Arc::clone(&range_requests),
+        })
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("large.bin");
+    let part_file = temp_dir.path().join("large.bin.part");
+    std::fs::write(&part_file, &body[..1000]).unwrap();
+
+    let url = format!("{}/large.bin", mock_server.uri());
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .resume(true)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+    assert_eq!(range_requests.load(Ordering::SeqCst), 1);
+    assert!(!part_file.exists());
+}
+
+#[tokio::test]
+async fn test_download_resume_restarts_without_range_support() {
+    let mock_server = MockServer::start().await;
+    let body = b"complete file".to_vec();
+
+    Mock::given(method("GET"))
+        .and(path("/file.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("file.bin");
+    std::fs::write(temp_dir.path().join("file.bin.part"), b"stale").unwrap();
+
+    let url = format!("{}/file.bin", mock_server.uri());
+    Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .resume(true)
+        .download()
+        .await
+        .unwrap();
+
+    assert_eq!(std::fs::read(&output_file).unwrap(), body);
+}
+
+#[tokio::test]
+async fn test_download_resume_wrong_range() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/file.bin"))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("content-range", "bytes 0-9/10")
+                .set_body_bytes(b"0123456789".to_vec()),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let temp_dir = temp_dir();
+    let output_file = temp_dir.path().join("file.bin");
+    let part_file = temp_dir.path().join("file.bin.part");
+    std::fs::write(&part_file, b"01234").unwrap();
+
+    let url = format!("{}/file.bin", mock_server.uri());
+    let result = Downloader::new()
+        .url(&url)
+        .file(&output_file)
+        .silent()
+        .resume(true)
+        .download()
+        .await;
+
+    match result.unwrap_err() {
+        MobError::Network(boxed) => match *boxed {
+            NetworkError::RangeNotSupported { .. } => {}
+            other => panic!("Expected NetworkError::RangeNotSupported, got {other:?}"),
+        },
+        other => panic!("Expected MobError::Network, got {other:?}"),
+    }
+    assert_eq!(std::fs::read(&part_file).unwrap(), b"01234");
+    assert!(!output_file.exists());
+}
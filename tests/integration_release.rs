@@ -123,6 +123,12 @@ fn release_devbuild_custom_rc_path() {
     insta::assert_debug_snapshot!(cli);
 }
 
+#[test]
+fn release_devbuild_zip_format() {
+    let cli = Cli::try_parse_from(["mob", "release", "devbuild", "--format", "zip"]).unwrap();
+    insta::assert_debug_snapshot!(cli);
+}
+
 // =============================================================================
 // Release Official Command
 // =============================================================================
@@ -205,6 +211,12 @@ fn release_official_requires_branch() {
     );
 }
 
+#[test]
+fn release_invalid_format() {
+    let result = Cli::try_parse_from(["mob", "release", "devbuild", "--format", "rar"]);
+    assert!(result.is_err());
+}
+
 #[test]
 fn release_invalid_subcommand() {
     let result = Cli::try_parse_from(["mob", "release", "invalid"]);
@@ -23,7 +23,7 @@ fn test_config() -> Arc<Config> {
 #[tokio::test]
 async fn test_task_manager_empty_run() {
     let config = test_config();
-    let manager = TaskManager::new(config);
+    let manager = TaskManager::new(config, &CancellationToken::new());
 
     // Running empty task list should succeed
     let result = manager.run_all().await;
@@ -33,7 +33,7 @@ async fn test_task_manager_empty_run() {
 #[tokio::test]
 async fn test_task_manager_with_parallel_tasks() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     // Add empty parallel tasks
     let parallel = ParallelTasks::new(vec![
@@ -49,7 +49,7 @@ async fn test_task_manager_with_parallel_tasks() {
 #[tokio::test]
 async fn test_task_manager_cancellation_before_start() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     // Add a task
     manager.add(Task::Parallel(ParallelTasks::new(vec![Task::Parallel(
@@ -103,7 +103,7 @@ async fn test_task_context_tool_context_conversion() {
 #[tokio::test]
 async fn test_task_manager_builder_pattern() {
     let config = test_config();
-    let manager = TaskManager::new(config)
+    let manager = TaskManager::new(config, &CancellationToken::new())
         .with_dry_run(true)
         .with_do_clean(true)
         .with_do_fetch(false)
@@ -117,7 +117,7 @@ async fn test_task_manager_builder_pattern() {
 #[tokio::test]
 async fn test_task_manager_concurrency_semaphore() {
     let config = test_config();
-    let manager = TaskManager::with_concurrency(config, 8);
+    let manager = TaskManager::with_concurrency(config, 8, &CancellationToken::new());
 
     let semaphore = manager.concurrency_semaphore();
     assert_eq!(semaphore.available_permits(), 8);
@@ -158,7 +158,7 @@ async fn test_task_name() {
 #[tokio::test]
 async fn test_nested_parallel_tasks() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     // Create deeply nested parallel structure
     let inner_most = ParallelTasks::new(vec![Task::Parallel(ParallelTasks::new(vec![]))]);
@@ -177,7 +177,7 @@ async fn test_nested_parallel_tasks() {
 #[tokio::test]
 async fn test_multiple_sequential_tasks() {
     let config = test_config();
-    let mut manager = TaskManager::new(config);
+    let mut manager = TaskManager::new(config, &CancellationToken::new());
 
     // Add multiple tasks that run sequentially
     manager.add(Task::Parallel(ParallelTasks::new(vec![])));
@@ -203,7 +203,7 @@ async fn test_phase_names() {
 #[tokio::test]
 async fn test_task_manager_cancel_token_sharing() {
     let config = test_config();
-    let manager = TaskManager::new(config);
+    let manager = TaskManager::new(config, &CancellationToken::new());
 
     let token = manager.cancel_token();
     assert!(!token.is_cancelled());